@@ -3,11 +3,13 @@
 //! Run with: cargo run --example showcase
 
 use floem::IntoView;
-use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
+use floem::reactive::{Effect, RwSignal, SignalGet, SignalUpdate};
 use floem::text::Weight;
 use floem::views::{Decorators, Label, Stack};
 use floem_shadcn::prelude::*;
-use floem_shadcn::theme::{ShadcnTheme, ShadcnThemeExt, ThemeMode};
+use floem_shadcn::theme::{
+    ShadcnThemeExt, ThemeMode, ThemePair, ThemePreset, ThemeProvider, ThemeState,
+};
 use floem_tailwind::TailwindExt;
 
 fn main() {
@@ -15,10 +17,21 @@ fn main() {
 }
 
 fn app_view() -> impl IntoView {
+    // Track current theme mode for reactive theme switching
+    let theme_mode = RwSignal::new(ThemeMode::System);
+    // Track the shadcn color theme
+    let theme_preset = RwSignal::new(ThemePreset::Neutral);
+
+    let theme = ThemeState::new(theme_mode);
+    Effect::new(move |_| theme.themes().set(ThemePair::preset(theme_preset.get())));
+
+    ThemeProvider::new(theme, move || app_shell(theme, theme_preset))
+}
+
+fn app_shell(theme: ThemeState, theme_preset: RwSignal<ThemePreset>) -> impl IntoView {
     // Track which component section is active
     let active_section = RwSignal::new("buttons".to_string());
-    // Track current theme mode for reactive theme switching
-    let theme_mode = RwSignal::new(ThemeMode::Light);
+    let theme_mode = theme.mode();
 
     Stack::horizontal((
         // Sidebar navigation using full Sidebar APIs
@@ -35,10 +48,33 @@ fn app_view() -> impl IntoView {
                                 theme_mode.update(|m| {
                                     *m = match m {
                                         ThemeMode::Light => ThemeMode::Dark,
-                                        ThemeMode::Dark => ThemeMode::Light,
+                                        ThemeMode::Dark => ThemeMode::System,
+                                        ThemeMode::System => ThemeMode::Light,
                                     }
                                 });
                             }),
+                        Button::new("Next Color")
+                            .outline()
+                            .sm()
+                            .on_click_stop(move |_| {
+                                theme_preset.update(|p| {
+                                    let index =
+                                        ThemePreset::ALL.iter().position(|x| x == p).unwrap_or(0);
+                                    *p = ThemePreset::ALL[(index + 1) % ThemePreset::ALL.len()];
+                                });
+                            }),
+                        Label::derived(move || format!("Color: {}", theme_preset.get().name()))
+                            .style(|s| s.font_size(12.0)),
+                        Label::derived(move || {
+                            match (theme_mode.get(), theme.resolved().get()) {
+                                (ThemeMode::System, ThemeMode::Dark) => "Mode: system (dark)",
+                                (ThemeMode::System, _) => "Mode: system (light)",
+                                (ThemeMode::Dark, _) => "Mode: dark",
+                                _ => "Mode: light",
+                            }
+                            .to_string()
+                        })
+                        .style(|s| s.font_size(12.0)),
                     ))
                     .style(|s| s.gap_3()),
                 ),
@@ -196,17 +232,7 @@ fn app_view() -> impl IntoView {
         ))
         .style(|s| s.flex_grow(1.0).h_full().p_8().bg_background()),
     ))
-    .style(move |s| {
-        let theme = match theme_mode.get() {
-            ThemeMode::Light => ShadcnTheme::light(),
-            ThemeMode::Dark => ShadcnTheme::dark(),
-        };
-        s.shadcn_theme(theme)
-            .w_full()
-            .h_full()
-            .bg_background()
-            .text_foreground()
-    })
+    .style(|s| s.w_full().h_full().bg_background().text_foreground())
 }
 
 // ============================================================================
@@ -726,26 +752,40 @@ fn radio_demo() -> impl IntoView {
 
 fn popover_demo() -> impl IntoView {
     let popover_open = RwSignal::new(false);
+    let arrow_open = RwSignal::new(false);
 
     demo_section(
         "Popover",
         "A floating panel that appears when triggered.",
-        Stack::vertical((subsection(
-            "Basic",
-            Popover::new(popover_open)
-                .trigger(|| Button::new("Open Popover"))
-                .content(|| {
-                    Stack::vertical((
-                        Label::derived(|| "Dimensions")
-                            .style(|s| s.font_size(14.0).font_weight(Weight::MEDIUM)),
-                        Label::derived(|| "Set the dimensions for the layer.").style(|s| {
-                            s.font_size(12.0)
-                                .with_shadcn_theme(|s, t| s.color(t.muted_foreground))
-                        }),
-                    ))
-                    .style(|s| s.gap_2().min_width(200.0))
-                }),
-        ),))
+        Stack::vertical((
+            subsection(
+                "Basic",
+                Popover::new(popover_open)
+                    .trigger(|| Button::new("Open Popover"))
+                    .content(|| {
+                        Stack::vertical((
+                            Label::derived(|| "Dimensions")
+                                .style(|s| s.font_size(14.0).font_weight(Weight::MEDIUM)),
+                            Label::derived(|| "Set the dimensions for the layer.").style(|s| {
+                                s.font_size(12.0)
+                                    .with_shadcn_theme(|s, t| s.color(t.muted_foreground))
+                            }),
+                        ))
+                        .style(|s| s.gap_2().min_width(200.0))
+                    }),
+            ),
+            subsection(
+                "Side and Arrow",
+                Popover::new(arrow_open)
+                    .side(PopoverSide::Right)
+                    .arrow(true)
+                    .trigger(|| Button::new("Open to the Right").outline())
+                    .content(|| {
+                        Label::derived(|| "Flips to the left near the window edge.")
+                            .style(|s| s.font_size(14.0))
+                    }),
+            ),
+        ))
         .style(|s| s.gap_8()),
     )
 }
@@ -845,48 +885,111 @@ fn table_demo() -> impl IntoView {
     demo_section(
         "Table",
         "A responsive table component for displaying data.",
-        Stack::vertical((subsection(
-            "Basic Table",
-            Table::new()
-                .child(
-                    TableHeader::new().child(
-                        TableRow::new()
-                            .child(TableHead::new("Name"))
-                            .child(TableHead::new("Email"))
-                            .child(TableHead::new("Role"))
-                            .child(TableHead::new("Status")),
-                    ),
-                )
-                .child(
-                    TableBody::new()
-                        .child(
-                            TableRow::new()
-                                .child(TableCell::new("John Doe"))
-                                .child(TableCell::new("john@example.com"))
-                                .child(TableCell::new("Admin"))
-                                .child(TableCell::new("Active")),
-                        )
-                        .child(
-                            TableRow::new()
-                                .child(TableCell::new("Jane Smith"))
-                                .child(TableCell::new("jane@example.com"))
-                                .child(TableCell::new("Editor"))
-                                .child(TableCell::new("Active")),
-                        )
-                        .child(
+        Stack::vertical((
+            subsection(
+                "Basic Table",
+                Table::new()
+                    .child(
+                        TableHeader::new().child(
                             TableRow::new()
-                                .child(TableCell::new("Bob Wilson"))
-                                .child(TableCell::new("bob@example.com"))
-                                .child(TableCell::new("Viewer"))
-                                .child(TableCell::new("Pending")),
+                                .child(TableHead::new("Name"))
+                                .child(TableHead::new("Email"))
+                                .child(TableHead::new("Role"))
+                                .child(TableHead::new("Status")),
                         ),
-                )
-                .into_view(),
-        ),))
+                    )
+                    .child(
+                        TableBody::new()
+                            .child(
+                                TableRow::new()
+                                    .child(TableCell::new("John Doe"))
+                                    .child(TableCell::new("john@example.com"))
+                                    .child(TableCell::new("Admin"))
+                                    .child(TableCell::new("Active")),
+                            )
+                            .child(
+                                TableRow::new()
+                                    .child(TableCell::new("Jane Smith"))
+                                    .child(TableCell::new("jane@example.com"))
+                                    .child(TableCell::new("Editor"))
+                                    .child(TableCell::new("Active")),
+                            )
+                            .child(
+                                TableRow::new()
+                                    .child(TableCell::new("Bob Wilson"))
+                                    .child(TableCell::new("bob@example.com"))
+                                    .child(TableCell::new("Viewer"))
+                                    .child(TableCell::new("Pending")),
+                            ),
+                    )
+                    .into_view(),
+            ),
+            subsection("Data Table", data_table_example()),
+        ))
         .style(|s| s.gap_8().max_w_2xl()),
     )
 }
 
+fn data_table_example() -> impl IntoView {
+    #[derive(Clone)]
+    struct Payment {
+        status: &'static str,
+        email: &'static str,
+        amount: f64,
+    }
+
+    let payments = RwSignal::new(vec![
+        Payment {
+            status: "success",
+            email: "ken99@example.com",
+            amount: 316.0,
+        },
+        Payment {
+            status: "success",
+            email: "abe45@example.com",
+            amount: 242.0,
+        },
+        Payment {
+            status: "processing",
+            email: "monserrat44@example.com",
+            amount: 837.0,
+        },
+        Payment {
+            status: "success",
+            email: "silas22@example.com",
+            amount: 874.0,
+        },
+        Payment {
+            status: "failed",
+            email: "carmella@example.com",
+            amount: 721.0,
+        },
+        Payment {
+            status: "pending",
+            email: "jason78@example.com",
+            amount: 450.0,
+        },
+        Payment {
+            status: "success",
+            email: "sarah23@example.com",
+            amount: 1280.0,
+        },
+    ]);
+
+    DataTable::new(
+        payments,
+        vec![
+            DataColumn::new("Status", |p: &Payment| p.status.to_string()),
+            DataColumn::new("Email", |p: &Payment| p.email.to_string()).sortable(),
+            DataColumn::new("Amount", |p: &Payment| format!("${:.2}", p.amount))
+                .sort_by(|a, b| a.amount.total_cmp(&b.amount)),
+        ],
+    )
+    .selectable()
+    .page_size(5)
+    .filter_placeholder("Filter payments...")
+}
+
 fn dropdown_demo() -> impl IntoView {
     let dropdown_open = RwSignal::new(false);
 
@@ -1308,11 +1411,61 @@ fn label_demo() -> impl IntoView {
                         .on_update(move |s| username.set(s.to_string())),
                 )
             }),
+            subsection("Validation", form_example()),
         ))
         .style(|s| s.gap_8().max_w_md()),
     )
 }
 
+fn form_example() -> impl IntoView {
+    let username = RwSignal::new(String::new());
+    let terms = RwSignal::new(false);
+    let submitted = RwSignal::new(false);
+
+    let form = Form::new().mode(ValidateOn::Touched);
+    let username_field = form.register(
+        "username",
+        username,
+        Rules::new()
+            .required("Username is required.")
+            .min_length(2, "Username must be at least 2 characters."),
+    );
+    let terms_field = form.register(
+        "terms",
+        terms,
+        Rules::new().required("You must accept the terms."),
+    );
+    form.on_submit(move || submitted.set(true));
+
+    Stack::vertical((
+        FormField::new(
+            FormLabel::new("Username").field(username_field),
+            username_field.bind(
+                Input::new()
+                    .placeholder("shadcn")
+                    .value(move || username.get())
+                    .on_update(move |s| username.set(s.to_string())),
+            ),
+        )
+        .description("This is your public display name.")
+        .field(username_field),
+        FormField::new(
+            FormLabel::new("Terms").field(terms_field),
+            Checkbox::new(terms).label("Accept terms and conditions"),
+        )
+        .field(terms_field),
+        Button::new("Submit").on_click_stop(move |_| form.submit()),
+        Label::derived(move || {
+            if submitted.get() {
+                "Submitted!".to_string()
+            } else {
+                String::new()
+            }
+        }),
+    ))
+    .style(|s| s.gap_4())
+}
+
 fn collapsible_demo() -> impl IntoView {
     let is_open = RwSignal::new(false);
 
@@ -1483,34 +1636,82 @@ fn scroll_area_demo() -> impl IntoView {
     demo_section(
         "Scroll Area",
         "A scrollable container for overflow content.",
-        Stack::vertical((subsection(
-            "Vertical Scroll",
-            ScrollArea::new(
-                Stack::vertical((
-                    Label::derived(|| "Item 1"),
-                    Label::derived(|| "Item 2"),
-                    Label::derived(|| "Item 3"),
-                    Label::derived(|| "Item 4"),
-                    Label::derived(|| "Item 5"),
-                    Label::derived(|| "Item 6"),
-                    Label::derived(|| "Item 7"),
-                    Label::derived(|| "Item 8"),
-                    Label::derived(|| "Item 9"),
-                    Label::derived(|| "Item 10"),
-                ))
-                .style(|s| s.gap_4().padding(8.0)),
-            )
-            .style(|s| {
-                s.height(150.0)
-                    .width(200.0)
-                    .border(1.0)
-                    .with_shadcn_theme(|s, t| s.border_color(t.border).border_radius(t.radius))
-            }),
-        ),))
+        Stack::vertical((
+            subsection(
+                "Vertical Scroll",
+                ScrollArea::new(
+                    Stack::vertical((
+                        Label::derived(|| "Item 1"),
+                        Label::derived(|| "Item 2"),
+                        Label::derived(|| "Item 3"),
+                        Label::derived(|| "Item 4"),
+                        Label::derived(|| "Item 5"),
+                        Label::derived(|| "Item 6"),
+                        Label::derived(|| "Item 7"),
+                        Label::derived(|| "Item 8"),
+                        Label::derived(|| "Item 9"),
+                        Label::derived(|| "Item 10"),
+                    ))
+                    .style(|s| s.gap_4().padding(8.0)),
+                )
+                .style(|s| {
+                    s.height(150.0)
+                        .width(200.0)
+                        .border(1.0)
+                        .with_shadcn_theme(|s, t| s.border_color(t.border).border_radius(t.radius))
+                }),
+            ),
+            subsection("Virtualized (50,000 rows)", virtual_scroll_example()),
+        ))
         .style(|s| s.gap_8()),
     )
 }
 
+fn virtual_scroll_example() -> impl IntoView {
+    let lines = RwSignal::new(
+        (0..50_000)
+            .map(|i| format!("[{:05}] log line", i))
+            .collect::<Vec<_>>(),
+    );
+    let selected = RwSignal::new(Some(0usize));
+
+    let list = VirtualScrollArea::new(lines, move |index, line: &String| {
+        Label::new(line.clone())
+            .style(move |s| {
+                s.width_full()
+                    .padding_horiz(8.0)
+                    .items_center()
+                    .font_size(13.0)
+                    .with_shadcn_theme(move |s, t| {
+                        s.apply_if(selected.get() == Some(index), |s| s.background(t.accent))
+                    })
+            })
+            .on_click_stop(move |_| selected.set(Some(index)))
+    })
+    .item_height(24.0)
+    .selected(selected)
+    .width(320.0)
+    .height(200.0);
+    let scroll_to = list.scroll_to_index_signal();
+
+    Stack::vertical((
+        Stack::horizontal((
+            Button::new("Top")
+                .outline()
+                .on_click_stop(move |_| scroll_to.set(Some(0))),
+            Button::new("Row 25,000")
+                .outline()
+                .on_click_stop(move |_| scroll_to.set(Some(25_000))),
+            Button::new("Next").outline().on_click_stop(move |_| {
+                selected.update(|s| *s = Some(s.map_or(0, |i| (i + 1).min(49_999))))
+            }),
+        ))
+        .style(|s| s.gap_2()),
+        list,
+    ))
+    .style(|s| s.gap_4())
+}
+
 fn resizable_demo() -> impl IntoView {
     demo_section(
         "Resizable",
@@ -1650,14 +1851,14 @@ fn command_demo() -> impl IntoView {
                             CommandGroup::new("Suggestions")
                                 .child(CommandItem::new("calendar", "Calendar"))
                                 .child(CommandItem::new("search", "Search Emoji"))
-                                .child(CommandItem::new("calculator", "Calculator"))
+                                .child(CommandItem::new("calculator", "Calculator")),
                         )
                         .child(
                             CommandGroup::new("Settings")
                                 .child(CommandItem::new("profile", "Profile"))
                                 .child(CommandItem::new("billing", "Billing"))
-                                .child(CommandItem::new("settings", "Settings"))
-                        )
+                                .child(CommandItem::new("settings", "Settings")),
+                        ),
                 )
                 .style(|s| s.max_width(400.0)),
         ),))
@@ -121,7 +121,7 @@ impl AccordionItem {
             floem::views::Stack::horizontal((
                 floem::views::Label::new(title).style(|s| {
                     s.with_shadcn_theme(|s, t| {
-                        s.font_size(14.0)
+                        s.font_size(t.font_size.sm)
                             .font_weight(Weight::MEDIUM)
                             .color(t.foreground)
                             .flex_grow(1.0)
@@ -171,7 +171,9 @@ impl AccordionItem {
         let content_view =
             floem::views::Container::new(floem::views::Label::new(content).style(|s| {
                 s.with_shadcn_theme(|s, t| {
-                    s.font_size(14.0).color(t.muted_foreground).line_height(1.5)
+                    s.font_size(t.font_size.sm)
+                        .color(t.muted_foreground)
+                        .line_height(1.5)
                 })
             }))
             .style(move |s| {
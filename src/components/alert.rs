@@ -84,16 +84,13 @@ impl Alert {
 
         children.push(Box::new(
             floem::views::svg(move || icon_svg.to_string()).style(move |s| {
-                s.width(16.0)
-                    .height(16.0)
-                    .flex_shrink(0.0)
-                    .with_shadcn_theme(move |s, t| {
-                        let color = match variant {
-                            AlertVariant::Default => t.foreground,
-                            AlertVariant::Destructive => t.destructive,
-                        };
-                        s.color(color)
-                    })
+                s.flex_shrink(0.0).with_shadcn_theme(move |s, t| {
+                    let color = match variant {
+                        AlertVariant::Default => t.foreground,
+                        AlertVariant::Destructive => t.destructive,
+                    };
+                    s.size(t.space(4.0), t.space(4.0)).color(color)
+                })
             }),
         ));
 
@@ -102,15 +99,14 @@ impl Alert {
 
         if let Some(title) = self.title {
             content_children.push(Box::new(floem::views::Label::new(title).style(move |s| {
-                s.font_size(14.0)
-                    .font_weight(Weight::MEDIUM)
+                s.font_weight(Weight::MEDIUM)
                     .line_height(1.0)
                     .with_shadcn_theme(move |s, t| {
                         let color = match variant {
                             AlertVariant::Default => t.foreground,
                             AlertVariant::Destructive => t.destructive,
                         };
-                        s.color(color)
+                        s.font_size(t.font_size.sm).color(color)
                     })
             })));
         }
@@ -118,23 +114,22 @@ impl Alert {
         if let Some(description) = self.description {
             content_children.push(Box::new(floem::views::Label::new(description).style(
                 move |s| {
-                    s.font_size(14.0)
-                        .with_shadcn_theme(|s, t| s.color(t.muted_foreground))
+                    s.with_shadcn_theme(|s, t| {
+                        s.font_size(t.font_size.sm).color(t.muted_foreground)
+                    })
                 },
             )));
         }
 
         children.push(Box::new(
             floem::views::Stack::vertical_from_iter(content_children)
-                .style(|s| s.gap(4.0).flex_grow(1.0)),
+                .style(|s| s.with_shadcn_theme(|s, t| s.gap(t.space(1.0)).flex_grow(1.0))),
         ));
 
         floem::views::Stack::horizontal_from_iter(children).style(move |s| {
             s.width_full()
-                .padding(16.0)
                 .border_radius(8.0)
                 .border(1.0)
-                .gap(12.0)
                 .items_start()
                 .with_shadcn_theme(move |s, t| {
                     let (bg, border_color) = match variant {
@@ -150,7 +145,10 @@ impl Alert {
                             (destructive_bg, t.destructive)
                         }
                     };
-                    s.border_color(border_color).background(bg)
+                    s.padding(t.space(4.0))
+                        .gap(t.space(3.0))
+                        .border_color(border_color)
+                        .background(bg)
                 })
         })
     }
@@ -19,6 +19,12 @@
 //!         // Handle deletion
 //!     });
 //! ```
+//!
+//! While open, focus is trapped inside the dialog: Cancel is focused first,
+//! Tab/Shift+Tab cycle between the buttons and Escape cancels (unless vetoed
+//! with `on_escape_key_down`). Focus returns to the trigger on close.
+
+use std::rc::Rc;
 
 use floem::prelude::*;
 use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
@@ -27,6 +33,7 @@ use floem::views::{Decorators, Overlay};
 use floem::{HasViewId, ViewId};
 use floem_tailwind::TailwindExt;
 
+use crate::components::focus_scope::{self, FocusScope};
 use crate::theme::ShadcnThemeExt;
 
 // ============================================================================
@@ -43,6 +50,7 @@ pub struct AlertDialog {
     cancel_text: String,
     action_text: String,
     on_action: Option<Box<dyn Fn() + 'static>>,
+    on_escape: Option<Rc<dyn Fn() -> bool>>,
     destructive: bool,
 }
 
@@ -58,6 +66,7 @@ impl AlertDialog {
             cancel_text: "Cancel".to_string(),
             action_text: "Continue".to_string(),
             on_action: None,
+            on_escape: None,
             destructive: false,
         }
     }
@@ -98,6 +107,14 @@ impl AlertDialog {
         self.destructive = true;
         self
     }
+
+    /// Set a handler that runs when Escape is pressed while the dialog is open
+    ///
+    /// Return `false` to keep the dialog open.
+    pub fn on_escape_key_down(mut self, handler: impl Fn() -> bool + 'static) -> Self {
+        self.on_escape = Some(Rc::new(handler));
+        self
+    }
 }
 
 impl HasViewId for AlertDialog {
@@ -124,30 +141,39 @@ impl IntoView for AlertDialog {
         let on_action = self.on_action;
         let destructive = self.destructive;
 
+        let focus = FocusScope::new(is_open);
+        focus.set_on_escape(self.on_escape);
+
         // Trigger button
-        let trigger = floem::views::Label::new(trigger_text)
+        let trigger = floem::views::Label::new(trigger_text);
+        let trigger_id = trigger.id();
+        let trigger = trigger
             .style(|s| {
                 s.with_shadcn_theme(move |s, t| {
-                    s.padding_left(16.0)
-                        .padding_right(16.0)
-                        .padding_top(8.0)
-                        .padding_bottom(8.0)
-                        .font_size(14.0)
+                    s.padding_horiz(t.space(4.0))
+                        .padding_vert(t.space(2.0))
+                        .font_size(t.font_size.sm)
                         .font_weight(floem::text::Weight::MEDIUM)
                         .background(t.primary)
                         .color(t.primary_foreground)
                         .border_radius(t.radius)
                         .cursor(CursorStyle::Pointer)
+                        .focusable(true)
                         .hover(|s| s.background(t.primary.with_alpha(0.9)))
                 })
             })
             .on_click_stop(move |_| {
+                focus_scope::remember_opener(trigger_id);
                 is_open.set(true);
             });
 
         // Title
         let title_view = floem::views::Label::new(title).style(|s| {
-            s.with_shadcn_theme(move |s, t| s.text_lg().font_semibold().color(t.foreground))
+            s.with_shadcn_theme(move |s, t| {
+                s.font_size(t.font_size.lg)
+                    .font_semibold()
+                    .color(t.foreground)
+            })
         });
 
         // Description
@@ -155,7 +181,9 @@ impl IntoView for AlertDialog {
             floem::views::Label::new(description)
                 .style(|s| {
                     s.with_shadcn_theme(move |s, t| {
-                        s.text_sm().color(t.muted_foreground).margin_top(8.0)
+                        s.font_size(t.font_size.sm)
+                            .color(t.muted_foreground)
+                            .margin_top(t.space(2.0))
                     })
                 })
                 .into_any()
@@ -164,20 +192,26 @@ impl IntoView for AlertDialog {
         };
 
         // Cancel button
-        let cancel_btn = floem::views::Label::new(cancel_text)
+        let cancel_btn = floem::views::Label::new(cancel_text);
+        let cancel_id = cancel_btn.id();
+        focus.register(cancel_id);
+        focus_scope::activate_on_key(cancel_id, move || is_open.set(false));
+        let cancel_btn = cancel_btn
             .style(|s| {
                 s.with_shadcn_theme(move |s, t| {
-                    s.padding_left(16.0)
-                        .padding_right(16.0)
-                        .padding_top(8.0)
-                        .padding_bottom(8.0)
-                        .font_size(14.0)
+                    let ring = t.ring;
+                    let ring_width = t.ring_width;
+                    s.padding_horiz(t.space(4.0))
+                        .padding_vert(t.space(2.0))
+                        .font_size(t.font_size.sm)
                         .font_weight(floem::text::Weight::MEDIUM)
                         .background(t.secondary)
                         .color(t.secondary_foreground)
                         .border_radius(t.radius)
                         .cursor(CursorStyle::Pointer)
+                        .focusable(true)
                         .hover(|s| s.background(t.secondary.with_alpha(0.8)))
+                        .focus(move |s| s.outline(ring_width).outline_color(ring))
                 })
             })
             .on_click_stop(move |_| {
@@ -185,7 +219,20 @@ impl IntoView for AlertDialog {
             });
 
         // Action button
-        let action_btn = floem::views::Label::new(action_text)
+        let on_action = Rc::new(move || {
+            if let Some(ref handler) = on_action {
+                handler();
+            }
+            is_open.set(false);
+        });
+        let action_btn = floem::views::Label::new(action_text);
+        let action_id = action_btn.id();
+        focus.register(action_id);
+        {
+            let on_action = on_action.clone();
+            focus_scope::activate_on_key(action_id, move || on_action());
+        }
+        let action_btn = action_btn
             .style(move |s| {
                 s.with_shadcn_theme(move |s, t| {
                     let bg = if destructive {
@@ -198,30 +245,52 @@ impl IntoView for AlertDialog {
                     } else {
                         t.primary_foreground
                     };
+                    let ring = t.ring;
+                    let ring_width = t.ring_width;
 
-                    s.padding_left(16.0)
-                        .padding_right(16.0)
-                        .padding_top(8.0)
-                        .padding_bottom(8.0)
-                        .font_size(14.0)
+                    s.padding_horiz(t.space(4.0))
+                        .padding_vert(t.space(2.0))
+                        .font_size(t.font_size.sm)
                         .font_weight(floem::text::Weight::MEDIUM)
                         .background(bg)
                         .color(fg)
                         .border_radius(t.radius)
                         .cursor(CursorStyle::Pointer)
+                        .focusable(true)
                         .hover(|s| s.background(bg.with_alpha(0.9)))
+                        .focus(move |s| s.outline(ring_width).outline_color(ring))
                 })
             })
-            .on_click_stop(move |_| {
-                if let Some(ref handler) = on_action {
-                    handler();
-                }
-                is_open.set(false);
-            });
+            .on_click_stop(move |_| on_action());
 
         // Footer with buttons
         let footer = floem::views::Stack::horizontal((cancel_btn, action_btn))
-            .style(|s| s.gap(8.0).justify_end());
+            .style(|s| s.with_shadcn_theme(|s, t| s.gap(t.space(2.0)).justify_end()));
+
+        // Content wrapper - centered modal, traps focus while open
+        let content = floem::views::Stack::vertical((title_view, desc_view, footer))
+            .style(move |s| {
+                s.absolute()
+                    .left_1_2()
+                    .top_1_2()
+                    .translate_x_neg_1_2()
+                    .translate_y_neg_1_2()
+                    .z_index(10)
+                    .max_w_lg()
+                    .rounded_lg()
+                    .shadow_lg()
+                    .focusable(true)
+            })
+            .style(move |s| {
+                s.with_shadcn_theme(move |s, t| {
+                    s.padding(t.space(6.0)) // p-6
+                        .gap(t.space(4.0)) // gap-4
+                        .background(t.background)
+                        .border_1()
+                        .border_color(t.border)
+                })
+            });
+        focus.attach(content.id());
 
         // Dialog content in Overlay - escapes parent clipping
         let dialog_overlay = Overlay::new().child(
@@ -236,26 +305,7 @@ impl IntoView for AlertDialog {
                     .on_click_stop(move |_| {
                         // Don't close on backdrop click for alert dialogs
                     }),
-                // Content wrapper - centered modal
-                floem::views::Stack::vertical((title_view, desc_view, footer))
-                    .style(move |s| {
-                        s.absolute()
-                            .left_1_2()
-                            .top_1_2()
-                            .translate_x_neg_1_2()
-                            .translate_y_neg_1_2()
-                            .z_index(10)
-                            .max_w_lg()
-                            .rounded_lg()
-                            .p_6()
-                            .gap_4()
-                            .shadow_lg()
-                    })
-                    .style(move |s| {
-                        s.with_shadcn_theme(move |s, t| {
-                            s.background(t.background).border_1().border_color(t.border)
-                        })
-                    }),
+                content,
             ))
             .style(move |s| {
                 let open = is_open.get();
@@ -309,11 +359,14 @@ impl<V: IntoView + 'static> IntoView for AlertDialogTrigger<V> {
 
     fn into_view(self) -> Self::V {
         let is_open = self.is_open;
+        let id = self.id;
 
         Box::new(
-            floem::views::Container::with_id(self.id, self.child)
-                .style(|s| s.cursor(CursorStyle::Pointer))
+            floem::views::Container::with_id(id, self.child)
+                .style(|s| s.cursor(CursorStyle::Pointer).focusable(true))
                 .on_click_stop(move |_| {
+                    // Focus returns here when the dialog closes
+                    focus_scope::remember_opener(id);
                     is_open.set(true);
                 }),
         )
@@ -329,6 +382,7 @@ pub struct AlertDialogContent<V> {
     id: ViewId,
     child: V,
     is_open: RwSignal<bool>,
+    on_escape: Option<Rc<dyn Fn() -> bool>>,
 }
 
 impl<V: IntoView + 'static> AlertDialogContent<V> {
@@ -338,8 +392,17 @@ impl<V: IntoView + 'static> AlertDialogContent<V> {
             id: ViewId::new(),
             child,
             is_open,
+            on_escape: None,
         }
     }
+
+    /// Set a handler that runs when Escape is pressed while the dialog is open
+    ///
+    /// Return `false` to keep the dialog open.
+    pub fn on_escape_key_down(mut self, handler: impl Fn() -> bool + 'static) -> Self {
+        self.on_escape = Some(Rc::new(handler));
+        self
+    }
 }
 
 impl<V: IntoView + 'static> HasViewId for AlertDialogContent<V> {
@@ -360,6 +423,35 @@ impl<V: IntoView + 'static> IntoView for AlertDialogContent<V> {
         let is_open = self.is_open;
         let child = self.child;
 
+        // Build the content in the focus scope so actions can register with it
+        let focus = FocusScope::new(is_open);
+        focus.set_on_escape(self.on_escape);
+        let content = focus
+            .provide()
+            .enter(move || floem::views::Container::new(child))
+            .style(move |s| {
+                s.absolute()
+                    .left_1_2()
+                    .top_1_2()
+                    .translate_x_neg_1_2()
+                    .translate_y_neg_1_2()
+                    .z_index(10)
+                    .max_w_lg()
+                    .rounded_lg()
+                    .shadow_lg()
+                    .focusable(true)
+            })
+            .style(move |s| {
+                s.with_shadcn_theme(move |s, t| {
+                    s.padding(t.space(6.0)) // p-6
+                        .gap(t.space(4.0)) // gap-4
+                        .background(t.background)
+                        .border_1()
+                        .border_color(t.border)
+                })
+            });
+        focus.attach(content.id());
+
         // Alert dialog content in Overlay - escapes parent clipping
         Box::new(
             Overlay::new().child(
@@ -374,26 +466,7 @@ impl<V: IntoView + 'static> IntoView for AlertDialogContent<V> {
                         .on_click_stop(move |_| {
                             // Don't close on backdrop click for alert dialogs
                         }),
-                    // Content wrapper - centered modal
-                    floem::views::Container::new(child)
-                        .style(move |s| {
-                            s.absolute()
-                                .left_1_2()
-                                .top_1_2()
-                                .translate_x_neg_1_2()
-                                .translate_y_neg_1_2()
-                                .z_index(10)
-                                .max_w_lg()
-                                .rounded_lg()
-                                .p_6()
-                                .gap_4()
-                                .shadow_lg()
-                        })
-                        .style(move |s| {
-                            s.with_shadcn_theme(move |s, t| {
-                                s.background(t.background).border_1().border_color(t.border)
-                            })
-                        }),
+                    content,
                 ))
                 .style(move |s| {
                     let open = is_open.get();
@@ -445,9 +518,11 @@ impl<V: IntoView + 'static> IntoView for AlertDialogHeader<V> {
     fn into_view(self) -> Self::V {
         Box::new(
             floem::views::Container::with_id(self.id, self.child).style(|s| {
-                s.display(floem::style::Display::Flex)
-                    .flex_direction(floem::style::FlexDirection::Column)
-                    .margin_bottom(16.0)
+                s.with_shadcn_theme(|s, t| {
+                    s.display(floem::style::Display::Flex)
+                        .flex_direction(floem::style::FlexDirection::Column)
+                        .margin_bottom(t.space(4.0))
+                })
             }),
         )
     }
@@ -490,10 +565,12 @@ impl<V: IntoView + 'static> IntoView for AlertDialogFooter<V> {
     fn into_view(self) -> Self::V {
         Box::new(
             floem::views::Container::with_id(self.id, self.child).style(|s| {
-                s.display(floem::style::Display::Flex)
-                    .flex_direction(floem::style::FlexDirection::Row)
-                    .justify_end()
-                    .gap(8.0)
+                s.with_shadcn_theme(|s, t| {
+                    s.display(floem::style::Display::Flex)
+                        .flex_direction(floem::style::FlexDirection::Row)
+                        .justify_end()
+                        .gap(t.space(2.0))
+                })
             }),
         )
     }
@@ -538,7 +615,7 @@ impl IntoView for AlertDialogTitle {
 
         Box::new(floem::views::Label::with_id(self.id, text).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.font_size(18.0)
+                s.font_size(t.font_size.lg)
                     .font_weight(floem::text::Weight::SEMIBOLD)
                     .color(t.foreground)
             })
@@ -585,7 +662,9 @@ impl IntoView for AlertDialogDescription {
 
         Box::new(floem::views::Label::with_id(self.id, text).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.font_size(14.0).color(t.muted_foreground).margin_top(8.0)
+                s.font_size(t.font_size.sm)
+                    .color(t.muted_foreground)
+                    .margin_top(t.space(2.0))
             })
         }))
     }
@@ -652,11 +731,26 @@ impl IntoView for AlertDialogAction {
     fn into_view(self) -> Self::V {
         let text = self.text;
         let destructive = self.destructive;
-        let on_click = self.on_click;
+        let on_click: Option<Rc<dyn Fn()>> = self.on_click.map(Rc::from);
         let is_open = self.is_open;
+        let activate = move || {
+            if let Some(ref handler) = on_click {
+                handler();
+            }
+            if let Some(signal) = is_open {
+                signal.set(false);
+            }
+        };
+
+        // Reachable with Tab inside the dialog, activated with Enter/Space
+        FocusScope::register_current(self.id);
+        {
+            let activate = activate.clone();
+            focus_scope::activate_on_key(self.id, activate);
+        }
 
         Box::new(
-            floem::views::Label::new(text)
+            floem::views::Label::with_id(self.id, text)
                 .style(move |s| {
                     s.with_shadcn_theme(move |s, t| {
                         let bg = if destructive {
@@ -669,28 +763,23 @@ impl IntoView for AlertDialogAction {
                         } else {
                             t.primary_foreground
                         };
+                        let ring = t.ring;
+                        let ring_width = t.ring_width;
 
-                        s.padding_left(16.0)
-                            .padding_right(16.0)
-                            .padding_top(8.0)
-                            .padding_bottom(8.0)
-                            .font_size(14.0)
+                        s.padding_horiz(t.space(4.0))
+                            .padding_vert(t.space(2.0))
+                            .font_size(t.font_size.sm)
                             .font_weight(floem::text::Weight::MEDIUM)
                             .background(bg)
                             .color(fg)
                             .border_radius(t.radius)
                             .cursor(CursorStyle::Pointer)
+                            .focusable(true)
                             .hover(|s| s.background(bg.with_alpha(0.9)))
+                            .focus(move |s| s.outline(ring_width).outline_color(ring))
                     })
                 })
-                .on_click_stop(move |_| {
-                    if let Some(ref handler) = on_click {
-                        handler();
-                    }
-                    if let Some(signal) = is_open {
-                        signal.set(false);
-                    }
-                }),
+                .on_click_stop(move |_| activate()),
         )
     }
 }
@@ -740,29 +829,36 @@ impl IntoView for AlertDialogCancel {
     fn into_view(self) -> Self::V {
         let text = self.text;
         let is_open = self.is_open;
+        let cancel = move || {
+            if let Some(signal) = is_open {
+                signal.set(false);
+            }
+        };
+
+        // Reachable with Tab inside the dialog, activated with Enter/Space
+        FocusScope::register_current(self.id);
+        focus_scope::activate_on_key(self.id, cancel);
 
         Box::new(
-            floem::views::Label::new(text)
+            floem::views::Label::with_id(self.id, text)
                 .style(|s| {
                     s.with_shadcn_theme(move |s, t| {
-                        s.padding_left(16.0)
-                            .padding_right(16.0)
-                            .padding_top(8.0)
-                            .padding_bottom(8.0)
-                            .font_size(14.0)
+                        let ring = t.ring;
+                        let ring_width = t.ring_width;
+                        s.padding_horiz(t.space(4.0))
+                            .padding_vert(t.space(2.0))
+                            .font_size(t.font_size.sm)
                             .font_weight(floem::text::Weight::MEDIUM)
                             .background(t.secondary)
                             .color(t.secondary_foreground)
                             .border_radius(t.radius)
                             .cursor(CursorStyle::Pointer)
+                            .focusable(true)
                             .hover(|s| s.background(t.secondary.with_alpha(0.8)))
+                            .focus(move |s| s.outline(ring_width).outline_color(ring))
                     })
                 })
-                .on_click_stop(move |_| {
-                    if let Some(signal) = is_open {
-                        signal.set(false);
-                    }
-                }),
+                .on_click_stop(move |_| cancel()),
         )
     }
 }
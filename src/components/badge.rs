@@ -112,9 +112,6 @@ fn build_badge_style(s: Style, variant: BadgeVariant) -> Style {
         .items_center()
         .rounded_full() // 12px radius for pill shape
         .border_1()
-        .px_2() // 8px horizontal padding
-        .py_0p5() // 2px vertical padding
-        .text_xs() // 12px font size
         .font_medium()
         .transition(
             floem::style::Background,
@@ -122,7 +119,13 @@ fn build_badge_style(s: Style, variant: BadgeVariant) -> Style {
         );
 
     // Theme-dependent styles
-    s.with_shadcn_theme(move |s, t| apply_variant_style(s, variant, t))
+    s.with_shadcn_theme(move |s, t| {
+        let s = s
+            .padding_horiz(t.space(2.0)) // px-2
+            .padding_vert(t.space(0.5)) // py-0.5
+            .font_size(t.font_size.xs); // text-xs
+        apply_variant_style(s, variant, t)
+    })
 }
 
 fn apply_variant_style(s: Style, variant: BadgeVariant, t: &ShadcnTheme) -> Style {
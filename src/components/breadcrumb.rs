@@ -60,11 +60,13 @@ impl<V: IntoView + 'static> IntoView for Breadcrumb<V> {
     fn into_view(self) -> Self::V {
         Box::new(
             floem::views::Container::with_id(self.id, self.child).style(|s| {
-                s.display(floem::style::Display::Flex)
-                    .flex_direction(floem::style::FlexDirection::Row)
-                    .items_center()
-                    .gap(4.0)
-                    .flex_wrap(floem::style::FlexWrap::Wrap)
+                s.with_shadcn_theme(|s, t| {
+                    s.display(floem::style::Display::Flex)
+                        .flex_direction(floem::style::FlexDirection::Row)
+                        .items_center()
+                        .gap(t.space(1.0))
+                        .flex_wrap(floem::style::FlexWrap::Wrap)
+                })
             }),
         )
     }
@@ -107,11 +109,13 @@ impl<V: IntoView + 'static> IntoView for BreadcrumbList<V> {
     fn into_view(self) -> Self::V {
         Box::new(
             floem::views::Container::with_id(self.id, self.child).style(|s| {
-                s.display(floem::style::Display::Flex)
-                    .flex_direction(floem::style::FlexDirection::Row)
-                    .items_center()
-                    .gap(4.0)
-                    .flex_wrap(floem::style::FlexWrap::Wrap)
+                s.with_shadcn_theme(|s, t| {
+                    s.display(floem::style::Display::Flex)
+                        .flex_direction(floem::style::FlexDirection::Row)
+                        .items_center()
+                        .gap(t.space(1.0))
+                        .flex_wrap(floem::style::FlexWrap::Wrap)
+                })
             }),
         )
     }
@@ -183,7 +187,7 @@ impl IntoView for BreadcrumbItem {
 
         let label = floem::views::Label::new(text).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
-                let base = s.font_size(14.0);
+                let base = s.font_size(t.font_size.sm);
 
                 if is_current {
                     base.color(t.foreground)
@@ -309,7 +313,7 @@ impl IntoView for BreadcrumbPage {
         let text = self.text;
         Box::new(floem::views::Label::with_id(self.id, text).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.font_size(14.0)
+                s.font_size(t.font_size.sm)
                     .color(t.foreground)
                     .font_weight(floem::text::Weight::MEDIUM)
             })
@@ -377,10 +381,9 @@ impl IntoView for BreadcrumbSeparator {
         let separator = self.separator;
         Box::new(floem::views::Label::with_id(self.id, separator).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.font_size(14.0)
+                s.font_size(t.font_size.sm)
                     .color(t.muted_foreground)
-                    .padding_left(4.0)
-                    .padding_right(4.0)
+                    .padding_horiz(t.space(1.0))
             })
         }))
     }
@@ -439,10 +442,9 @@ impl IntoView for BreadcrumbEllipsis {
         let label = floem::views::Label::new("...").style(move |s| {
             s.with_shadcn_theme(move |s, t| {
                 let base = s
-                    .font_size(14.0)
+                    .font_size(t.font_size.sm)
                     .color(t.muted_foreground)
-                    .padding_left(4.0)
-                    .padding_right(4.0);
+                    .padding_horiz(t.space(1.0));
 
                 if has_click {
                     base.cursor(CursorStyle::Pointer)
@@ -171,6 +171,7 @@ fn build_button_style(s: Style, size: ButtonSize, variant: ButtonVariant) -> Sty
         .self_center()
         .flex_grow(0.0)
         .cursor_pointer()
+        .focusable(true)
         .font_medium()
         .transition(
             floem::style::Background,
@@ -181,22 +182,38 @@ fn build_button_style(s: Style, size: ButtonSize, variant: ButtonVariant) -> Sty
             floem::style::Transition::linear(millis(100)),
         );
 
-    // Size styles using floem-tailwind
-    let s = match size {
-        ButtonSize::Sm => s.h_9().px_3().rounded_md().text_xs(),
-        ButtonSize::Default => s.h_10().px_4().py_2().rounded_md().text_sm(),
-        ButtonSize::Lg => s.h_11().px_8().rounded_md().text_sm(),
-        ButtonSize::Icon => s.h_10().w_10().rounded_md(),
-    };
-
-    // Theme-dependent styles (variant colors + hover + active)
-    s.with_shadcn_theme(move |s, t| {
+    // Theme-dependent styles (size scales + variant colors + hover + active)
+    s.rounded_md().with_shadcn_theme(move |s, t| {
+        let s = apply_size_style(s, size, t);
         let s = apply_variant_style(s, variant, t);
         let s = apply_hover_style(s, variant, t);
-        apply_active_style(s, variant, t)
+        let s = apply_active_style(s, variant, t);
+        // High-contrast themes draw a visible focus ring
+        s.apply_if(t.is_high_contrast(), |s| {
+            s.focus(|s| s.outline(t.ring_width).outline_color(t.ring))
+        })
     })
 }
 
+fn apply_size_style(s: Style, size: ButtonSize, t: &ShadcnTheme) -> Style {
+    match size {
+        ButtonSize::Sm => s
+            .height(t.control_height.sm)
+            .padding_horiz(t.space(3.0))
+            .font_size(t.font_size.xs),
+        ButtonSize::Default => s
+            .height(t.control_height.md)
+            .padding_horiz(t.space(4.0))
+            .padding_vert(t.space(2.0))
+            .font_size(t.font_size.sm),
+        ButtonSize::Lg => s
+            .height(t.control_height.lg)
+            .padding_horiz(t.space(8.0))
+            .font_size(t.font_size.sm),
+        ButtonSize::Icon => s.height(t.control_height.md).width(t.control_height.md),
+    }
+}
+
 fn apply_variant_style(s: Style, variant: ButtonVariant, t: &ShadcnTheme) -> Style {
     // All variants have border_1() for consistent box sizing
     // Non-outline variants use transparent border
@@ -158,8 +158,8 @@ impl IntoView for Calendar {
         let prev_button = floem::views::Label::new("<")
             .style(|s| {
                 s.with_shadcn_theme(move |s, t| {
-                    s.padding(8.0)
-                        .font_size(14.0)
+                    s.padding(t.space(2.0))
+                        .font_size(t.font_size.sm)
                         .color(t.foreground)
                         .cursor(CursorStyle::Pointer)
                         .border_radius(t.radius)
@@ -179,8 +179,8 @@ impl IntoView for Calendar {
         let next_button = floem::views::Label::new(">")
             .style(|s| {
                 s.with_shadcn_theme(move |s, t| {
-                    s.padding(8.0)
-                        .font_size(14.0)
+                    s.padding(t.space(2.0))
+                        .font_size(t.font_size.sm)
                         .color(t.foreground)
                         .cursor(CursorStyle::Pointer)
                         .border_radius(t.radius)
@@ -205,7 +205,7 @@ impl IntoView for Calendar {
         .style(|s| {
             s.with_shadcn_theme(move |s, t| {
                 s.flex_grow(1.0)
-                    .font_size(14.0)
+                    .font_size(t.font_size.sm)
                     .font_weight(floem::text::Weight::SEMIBOLD)
                     .color(t.foreground)
                     .justify_center()
@@ -213,7 +213,11 @@ impl IntoView for Calendar {
         });
 
         let header = floem::views::Stack::horizontal((prev_button, month_label, next_button))
-            .style(|s| s.width_full().padding_bottom(8.0).items_center());
+            .style(|s| {
+                s.with_shadcn_theme(|s, t| {
+                    s.width_full().padding_bottom(t.space(2.0)).items_center()
+                })
+            });
 
         // Day names row
         let day_names = floem::views::Stack::horizontal((
@@ -225,7 +229,7 @@ impl IntoView for Calendar {
             day_header("Fr"),
             day_header("Sa"),
         ))
-        .style(|s| s.width_full().margin_bottom(4.0));
+        .style(|s| s.with_shadcn_theme(|s, t| s.width_full().margin_bottom(t.space(1.0))));
 
         // Calendar grid - create cells for all 42 positions (6 weeks x 7 days)
         // We'll use reactive labels that update based on the current month
@@ -237,12 +241,12 @@ impl IntoView for Calendar {
             create_week_row(4, selected, view_year, view_month),
             create_week_row(5, selected, view_year, view_month),
         ))
-        .style(|s| s.gap(2.0));
+        .style(|s| s.with_shadcn_theme(|s, t| s.gap(t.space(0.5))));
 
         Box::new(
             floem::views::Stack::vertical((header, day_names, grid)).style(|s| {
                 s.with_shadcn_theme(move |s, t| {
-                    s.padding(12.0)
+                    s.padding(t.space(3.0))
                         .background(t.card)
                         .border(1.0)
                         .border_color(t.border)
@@ -305,9 +309,9 @@ fn create_day_cell(
                     .map(|d| d.year == year && d.month == month && d.day == day_num as u32)
                     .unwrap_or(false);
             let base = s
-                .width(32.0)
-                .height(32.0)
-                .font_size(14.0)
+                .width(t.space(8.0))
+                .height(t.space(8.0))
+                .font_size(t.font_size.sm)
                 .border_radius(t.radius)
                 .display(floem::style::Display::Flex)
                 .items_center()
@@ -341,8 +345,8 @@ fn create_day_cell(
 fn day_header(text: &'static str) -> impl IntoView {
     floem::views::Label::new(text).style(|s| {
         s.with_shadcn_theme(move |s, t| {
-            s.width(32.0)
-                .font_size(12.0)
+            s.width(t.space(8.0))
+                .font_size(t.font_size.xs)
                 .font_weight(floem::text::Weight::MEDIUM)
                 .color(t.muted_foreground)
                 .justify_center()
@@ -395,8 +399,8 @@ impl IntoView for CalendarSimple {
             .style(|s| {
                 s.with_shadcn_theme(move |s, t| {
                     s.width_full()
-                        .padding_bottom(8.0)
-                        .font_size(14.0)
+                        .padding_bottom(t.space(2.0))
+                        .font_size(t.font_size.sm)
                         .font_weight(floem::text::Weight::SEMIBOLD)
                         .color(t.foreground)
                         .justify_center()
@@ -413,7 +417,7 @@ impl IntoView for CalendarSimple {
             day_header("Fr"),
             day_header("Sa"),
         ))
-        .style(|s| s.width_full().margin_bottom(4.0));
+        .style(|s| s.with_shadcn_theme(|s, t| s.width_full().margin_bottom(t.space(1.0))));
 
         // Build grid - static version
         let first_day = SimpleDate::first_day_of_week(year, month) as i32;
@@ -427,12 +431,12 @@ impl IntoView for CalendarSimple {
             create_static_week(4, first_day, days_in_month),
             create_static_week(5, first_day, days_in_month),
         ))
-        .style(|s| s.gap(2.0));
+        .style(|s| s.with_shadcn_theme(|s, t| s.gap(t.space(0.5))));
 
         Box::new(
             floem::views::Stack::vertical((title, day_names, grid)).style(|s| {
                 s.with_shadcn_theme(move |s, t| {
-                    s.padding(12.0)
+                    s.padding(t.space(3.0))
                         .background(t.card)
                         .border(1.0)
                         .border_color(t.border)
@@ -466,9 +470,9 @@ fn static_day_cell(cell_index: i32, first_day: i32, days_in_month: i32) -> impl
 
     floem::views::Label::new(text).style(move |s| {
         s.with_shadcn_theme(move |s, t| {
-            s.width(32.0)
-                .height(32.0)
-                .font_size(14.0)
+            s.width(t.space(8.0))
+                .height(t.space(8.0))
+                .font_size(t.font_size.sm)
                 .border_radius(t.radius)
                 .color(t.foreground)
                 .display(floem::style::Display::Flex)
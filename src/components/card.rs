@@ -47,16 +47,14 @@ impl<C: IntoViewIter> Card<C> {
     /// Build the card view with reactive styling
     pub fn build(self) -> impl IntoView {
         floem::views::Stack::vertical_from_iter(self.children.into_view_iter()).style(|s| {
-            s.gap_6()
-                .rounded_xl()
-                .border(1.0)
-                .py_6()
-                // Note: shadow-sm not available in floem-tailwind yet
-                .with_shadcn_theme(|s, t| {
-                    s.border_color(t.border)
-                        .background(t.card)
-                        .color(t.card_foreground)
-                })
+            s.rounded_xl().border(1.0).with_shadcn_theme(|s, t| {
+                s.gap(t.space(6.0))
+                    .padding_vert(t.space(6.0))
+                    .apply_shadow(t.shadow.sm)
+                    .border_color(t.border)
+                    .background(t.card)
+                    .color(t.card_foreground)
+            })
         })
     }
 }
@@ -138,23 +136,29 @@ impl IntoView for CardHeader {
         let mut children: Vec<Box<dyn View>> = Vec::new();
 
         if let Some(title) = self.title {
-            children
-                .push(Box::new(Label::derived(move || title.clone()).style(|s| {
-                    s.text_lg().font_weight(Weight::SEMIBOLD).leading_none()
-                })));
+            children.push(Box::new(Label::derived(move || title.clone()).style(|s| {
+                s.font_weight(Weight::SEMIBOLD).with_shadcn_theme(|s, t| {
+                    s.font_size(t.font_size.lg).line_height(t.line_height.none)
+                })
+            })));
         }
 
         if let Some(description) = self.description {
             children.push(Box::new(Label::derived(move || description.clone()).style(
                 |s| {
-                    s.text_sm()
-                        .line_height(1.43) // 20px / 14px
-                        .with_shadcn_theme(|s, t| s.color(t.muted_foreground))
+                    s.line_height(1.43) // 20px / 14px
+                        .with_shadcn_theme(|s, t| {
+                            s.font_size(t.font_size.sm).color(t.muted_foreground)
+                        })
                 },
             )));
         }
 
-        Box::new(floem::views::Stack::vertical_from_iter(children).style(|s| s.gap(8.0).px_6())) // gap-2 px-6
+        Box::new(
+            floem::views::Stack::vertical_from_iter(children).style(|s| {
+                s.with_shadcn_theme(|s, t| s.gap(t.space(2.0)).padding_horiz(t.space(6.0))) // gap-2 px-6
+            }),
+        )
     }
 }
 
@@ -193,7 +197,10 @@ impl<V: IntoView + 'static> IntoView for CardContent<V> {
     }
 
     fn into_view(self) -> Self::V {
-        Box::new(floem::views::Container::with_id(self.id, self.child).style(|s| s.px_6()))
+        Box::new(
+            floem::views::Container::with_id(self.id, self.child)
+                .style(|s| s.with_shadcn_theme(|s, t| s.padding_horiz(t.space(6.0)))),
+        )
     }
 }
 
@@ -234,7 +241,9 @@ impl<V: IntoView + 'static> IntoView for CardFooter<V> {
     fn into_view(self) -> Self::V {
         Box::new(
             floem::views::Container::with_id(self.id, self.child).style(|s| {
-                s.flex().items_center().px_6() // flex items-center px-6
+                s.flex()
+                    .items_center()
+                    .with_shadcn_theme(|s, t| s.padding_horiz(t.space(6.0))) // flex items-center px-6
             }),
         )
     }
@@ -276,8 +285,11 @@ impl<V: IntoView + 'static> IntoView for CardTitle<V> {
 
     fn into_view(self) -> Self::V {
         Box::new(
-            floem::views::Container::with_id(self.id, self.child)
-                .style(|s| s.text_lg().font_weight(Weight::SEMIBOLD).leading_none()),
+            floem::views::Container::with_id(self.id, self.child).style(|s| {
+                s.font_weight(Weight::SEMIBOLD).with_shadcn_theme(|s, t| {
+                    s.font_size(t.font_size.lg).line_height(t.line_height.none)
+                })
+            }),
         )
     }
 }
@@ -319,9 +331,8 @@ impl<V: IntoView + 'static> IntoView for CardDescription<V> {
     fn into_view(self) -> Self::V {
         Box::new(
             floem::views::Container::with_id(self.id, self.child).style(|s| {
-                s.text_sm()
-                    .line_height(1.43) // 20px / 14px
-                    .with_shadcn_theme(|s, t| s.color(t.muted_foreground))
+                s.line_height(1.43) // 20px / 14px
+                    .with_shadcn_theme(|s, t| s.font_size(t.font_size.sm).color(t.muted_foreground))
             }),
         )
     }
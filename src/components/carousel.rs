@@ -124,9 +124,9 @@ impl<I: IntoView + 'static> IntoView for Carousel<I> {
             })
             .style(|s| {
                 s.with_shadcn_theme(move |s, t| {
-                    s.width(40.0)
-                        .height(40.0)
-                        .font_size(18.0)
+                    s.width(t.control_height.md)
+                        .height(t.control_height.md)
+                        .font_size(t.font_size.lg)
                         .color(t.foreground)
                         .background(t.background)
                         .border(1.0)
@@ -161,9 +161,9 @@ impl<I: IntoView + 'static> IntoView for Carousel<I> {
             })
             .style(|s| {
                 s.with_shadcn_theme(move |s, t| {
-                    s.width(40.0)
-                        .height(40.0)
-                        .font_size(18.0)
+                    s.width(t.control_height.md)
+                        .height(t.control_height.md)
+                        .font_size(t.font_size.lg)
                         .color(t.foreground)
                         .background(t.background)
                         .border(1.0)
@@ -202,12 +202,16 @@ impl<I: IntoView + 'static> IntoView for Carousel<I> {
         let carousel_body = match orientation {
             CarouselOrientation::Horizontal => {
                 floem::views::Stack::horizontal((prev_button, content, next_button))
-                    .style(|s| s.width_full().items_center().gap(8.0))
+                    .style(|s| {
+                        s.with_shadcn_theme(|s, t| s.width_full().items_center().gap(t.space(2.0)))
+                    })
                     .into_any()
             }
             CarouselOrientation::Vertical => {
                 floem::views::Stack::vertical((prev_button, content, next_button))
-                    .style(|s| s.height_full().items_center().gap(8.0))
+                    .style(|s| {
+                        s.with_shadcn_theme(|s, t| s.height_full().items_center().gap(t.space(2.0)))
+                    })
                     .into_any()
             }
         };
@@ -337,9 +341,9 @@ impl IntoView for CarouselPrevious {
             floem::views::Label::new("<")
                 .style(|s| {
                     s.with_shadcn_theme(move |s, t| {
-                        s.width(40.0)
-                            .height(40.0)
-                            .font_size(18.0)
+                        s.width(t.control_height.md)
+                            .height(t.control_height.md)
+                            .font_size(t.font_size.lg)
                             .color(t.foreground)
                             .background(t.background)
                             .border(1.0)
@@ -414,9 +418,9 @@ impl IntoView for CarouselNext {
             floem::views::Label::new(">")
                 .style(|s| {
                     s.with_shadcn_theme(move |s, t| {
-                        s.width(40.0)
-                            .height(40.0)
-                            .font_size(18.0)
+                        s.width(t.control_height.md)
+                            .height(t.control_height.md)
+                            .font_size(t.font_size.lg)
                             .color(t.foreground)
                             .background(t.background)
                             .border(1.0)
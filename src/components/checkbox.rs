@@ -24,6 +24,7 @@ use floem::views::Decorators;
 use floem::{HasViewId, ViewId};
 use floem_tailwind::TailwindExt;
 
+use crate::components::focus_scope;
 use crate::theme::ShadcnThemeExt;
 
 /// A styled checkbox builder
@@ -70,7 +71,8 @@ impl Checkbox {
         //       disabled:cursor-not-allowed disabled:opacity-50
         // Indicator: grid place-content-center text-current
         //            CheckIcon size-3.5
-        let checkbox_box = floem::views::Container::new(
+        let checkbox_box = floem::views::Container::with_id(
+            self.id,
             // Checkmark (only visible when checked) - uses text-primary-foreground color
             floem::views::svg(|| CHECKMARK_SVG.to_string()).style(move |s| {
                 s.with_shadcn_theme(move |s, t| {
@@ -109,7 +111,10 @@ impl Checkbox {
         });
 
         let checkbox_box = if !disabled {
+            // Reachable with Tab, toggled with Enter/Space
+            focus_scope::activate_on_key(self.id, move || checked.update(|c| *c = !*c));
             checkbox_box
+                .style(|s| s.focusable(true))
                 .on_click_stop(move |_| {
                     checked.update(|c| *c = !*c);
                 })
@@ -35,6 +35,7 @@ use floem::views::Decorators;
 use floem::{HasViewId, ViewId};
 use floem_tailwind::TailwindExt;
 
+use crate::components::floating::{Align, Floating, FloatingOptions, Side};
 use crate::text::TextInput;
 use crate::theme::ShadcnThemeExt;
 
@@ -51,10 +52,8 @@ pub struct ComboboxContext {
     pub selected: RwSignal<Option<String>>,
     pub search: RwSignal<String>,
     pub is_open: RwSignal<bool>,
-    /// Trigger position (window coordinates) - set by ComboboxTrigger
-    pub trigger_origin: RwSignal<floem::kurbo::Point>,
-    /// Trigger size - set by ComboboxTrigger
-    pub trigger_size: RwSignal<floem::kurbo::Size>,
+    /// Positions the dropdown next to the trigger - measured by ComboboxTrigger
+    pub floating: Floating,
 }
 
 // ============================================================================
@@ -95,8 +94,8 @@ impl Combobox {
     /// ```
     pub fn new(selected: RwSignal<Option<String>>, search: RwSignal<String>) -> Self {
         let is_open = RwSignal::new(false);
-        let trigger_origin = RwSignal::new(floem::kurbo::Point::ZERO);
-        let trigger_size = RwSignal::new(floem::kurbo::Size::ZERO);
+        let floating =
+            Floating::new(FloatingOptions::new(Side::Bottom, Align::Start).side_offset(6.0));
         let scope = Scope::current().create_child();
 
         // Provide the combobox context in the child scope
@@ -104,8 +103,7 @@ impl Combobox {
             selected,
             search,
             is_open,
-            trigger_origin,
-            trigger_size,
+            floating,
         });
 
         Self {
@@ -225,76 +223,76 @@ impl IntoView for ComboboxTrigger {
         if let Some(ctx) = ctx {
             let selected = ctx.selected;
             let is_open = ctx.is_open;
-            let trigger_origin = ctx.trigger_origin;
-            let trigger_size = ctx.trigger_size;
+            let floating = ctx.floating;
             let items_for_label = items.clone();
 
             Box::new(
-                floem::views::Stack::horizontal((
-                    // Selected value or placeholder
-                    floem::views::Label::derived(move || {
-                        if let Some(val) = selected.get() {
-                            items_for_label
-                                .iter()
-                                .find(|(v, _)| v == &val)
-                                .map(|(_, l)| l.clone())
-                                .unwrap_or(val)
-                        } else {
-                            placeholder.clone()
-                        }
-                    })
-                    .style(move |s| {
-                        s.with_shadcn_theme(move |s, t| {
-                            let has_value = selected.get().is_some();
-                            s.flex_grow(1.0).text_sm().color(if has_value {
-                                t.foreground
+                floating.anchor(
+                    floem::views::Stack::horizontal((
+                        // Selected value or placeholder
+                        floem::views::Label::derived(move || {
+                            if let Some(val) = selected.get() {
+                                items_for_label
+                                    .iter()
+                                    .find(|(v, _)| v == &val)
+                                    .map(|(_, l)| l.clone())
+                                    .unwrap_or(val)
                             } else {
-                                t.muted_foreground
-                            })
+                                placeholder.clone()
+                            }
                         })
-                    }),
-                    // ChevronDown icon
-                    floem::views::Label::new("▼").style(|s| {
+                        .style(move |s| {
+                            s.with_shadcn_theme(move |s, t| {
+                                let has_value = selected.get().is_some();
+                                s.flex_grow(1.0)
+                                    .font_size(t.font_size.sm)
+                                    .color(if has_value {
+                                        t.foreground
+                                    } else {
+                                        t.muted_foreground
+                                    })
+                            })
+                        }),
+                        // ChevronDown icon
+                        floem::views::Label::new("▼").style(|s| {
+                            s.with_shadcn_theme(move |s, t| {
+                                s.font_size(t.font_size.xs - 2.0) // 10px glyph
+                                    .color(t.muted_foreground)
+                                    .flex_shrink(0.0)
+                            })
+                        }),
+                    ))
+                    .style(|s| {
                         s.with_shadcn_theme(move |s, t| {
-                            s.font_size(10.0).color(t.muted_foreground).flex_shrink(0.0)
+                            s.min_width(200.0)
+                                .height(t.control_height.sm) // h-9 = 36px
+                                .padding_horiz(t.space(3.0)) // px-3 = 12px
+                                .padding_vert(t.space(2.0)) // py-2 = 8px
+                                .gap(t.space(2.0)) // gap-2 = 8px
+                                .items_center()
+                                .border_1()
+                                .border_color(t.input)
+                                .rounded_md()
+                                .background(t.background)
+                                .shadow_sm()
+                                .cursor(CursorStyle::Pointer)
+                                .hover(|s| s.border_color(t.ring))
                         })
-                    }),
-                ))
-                .style(|s| {
-                    s.with_shadcn_theme(move |s, t| {
-                        s.min_width(200.0)
-                            .h_9()
-                            .px_3()
-                            .py_2()
-                            .gap_2()
-                            .items_center()
-                            .border_1()
-                            .border_color(t.input)
-                            .rounded_md()
-                            .background(t.background)
-                            .shadow_sm()
-                            .cursor(CursorStyle::Pointer)
-                            .hover(|s| s.border_color(t.ring))
                     })
-                })
-                .on_click_stop(move |_| {
-                    is_open.update(|v| *v = !*v);
-                })
-                .on_move(move |origin| {
-                    trigger_origin.set(origin);
-                })
-                .on_resize(move |rect| {
-                    trigger_size.set(rect.size());
-                }),
+                    .on_click_stop(move |_| {
+                        is_open.update(|v| *v = !*v);
+                    }),
+                ),
             )
         } else {
             // No context - render static trigger
             Box::new(floem::views::Label::new(placeholder).style(|s| {
                 s.with_shadcn_theme(move |s, t| {
                     s.min_width(200.0)
-                        .h_9()
-                        .px_3()
-                        .py_2()
+                        .height(t.control_height.sm) // h-9 = 36px
+                        .padding_horiz(t.space(3.0)) // px-3 = 12px
+                        .padding_vert(t.space(2.0)) // py-2 = 8px
+                        .font_size(t.font_size.sm)
                         .items_center()
                         .border_1()
                         .border_color(t.input)
@@ -356,12 +354,12 @@ impl IntoView for ComboboxContent {
         if let Some(ctx) = ctx {
             let is_open = ctx.is_open;
             let search = ctx.search;
-            let trigger_origin = ctx.trigger_origin;
-            let trigger_size = ctx.trigger_size;
+            let floating = ctx.floating;
 
             Box::new(
                 floem::views::Overlay::new().child(
                     floem::views::Stack::new((
+                        floating.window_probe(),
                         // Backdrop - closes dropdown when clicking outside
                         floem::views::Empty::new()
                             .style(move |s| s.absolute().inset_0())
@@ -370,24 +368,24 @@ impl IntoView for ComboboxContent {
                                 search.set(String::new());
                             }),
                         // Dropdown content
-                        floem::views::Container::new(content_stem).style(move |s| {
-                            s.with_shadcn_theme(move |s, t| {
-                                let origin = trigger_origin.get();
-                                let size = trigger_size.get();
-                                s.absolute()
-                                    .inset_left(origin.x)
-                                    .inset_top(origin.y + size.height + 6.0)
-                                    .min_width(size.width.max(200.0))
-                                    .flex_col()
-                                    .background(t.popover)
-                                    .color(t.popover_foreground)
-                                    .border_1()
-                                    .border_color(t.border)
-                                    .rounded_md()
-                                    .shadow_lg()
-                                    .z_index(100)
-                            })
-                        }),
+                        floating.content(floem::views::Container::new(content_stem).style(
+                            move |s| {
+                                s.with_shadcn_theme(move |s, t| {
+                                    let width = floating.anchor_rect().width();
+                                    floating
+                                        .position(s)
+                                        .min_width(width.max(200.0))
+                                        .flex_col()
+                                        .background(t.popover)
+                                        .color(t.popover_foreground)
+                                        .border_1()
+                                        .border_color(t.border)
+                                        .rounded_md()
+                                        .shadow_lg()
+                                        .z_index(100)
+                                })
+                            },
+                        )),
                     ))
                     .style(move |s| {
                         let open = is_open.get();
@@ -471,9 +469,9 @@ impl IntoView for ComboboxInput {
                     .style(|s| {
                         s.with_shadcn_theme(move |s, t| {
                             s.width_full()
-                                .h_8()
-                                .px_3()
-                                .text_sm()
+                                .height(t.space(8.0)) // h-8 = 32px
+                                .padding_horiz(t.space(3.0)) // px-3 = 12px
+                                .font_size(t.font_size.sm)
                                 .border(0.0)
                                 .border_bottom(1.0)
                                 .border_color(t.border)
@@ -486,9 +484,9 @@ impl IntoView for ComboboxInput {
             Box::new(TextInput::new().placeholder(placeholder).style(|s| {
                 s.with_shadcn_theme(move |s, t| {
                     s.width_full()
-                        .h_8()
-                        .px_3()
-                        .text_sm()
+                        .height(t.space(8.0)) // h-8 = 32px
+                        .padding_horiz(t.space(3.0)) // px-3 = 12px
+                        .font_size(t.font_size.sm)
                         .border(0.0)
                         .border_bottom(1.0)
                         .border_color(t.border)
@@ -552,8 +550,8 @@ impl IntoView for ComboboxList {
         let max_height = self.max_height;
 
         // Create a Stem that children will be added to via .child()
-        let items_container =
-            floem::views::Stem::with_id(self.id).style(|s| s.flex_col().width_full().p_1());
+        let items_container = floem::views::Stem::with_id(self.id)
+            .style(|s| s.with_shadcn_theme(|s, t| s.flex_col().width_full().padding(t.space(1.0))));
 
         // Wrap in Scroll for actual scrolling
         floem::views::Scroll::new(items_container)
@@ -627,14 +625,16 @@ impl IntoView for ComboboxItem {
                 floem::views::Container::new(
                     floem::views::Stack::horizontal((
                         // Label text
-                        floem::views::Label::new(label).style(|s| s.text_sm().flex_grow(1.0)),
+                        floem::views::Label::new(label).style(|s| {
+                            s.with_shadcn_theme(|s, t| s.font_size(t.font_size.sm).flex_grow(1.0))
+                        }),
                         // Check icon (visible when selected)
                         floem::views::Label::new("✓").style(move |s| {
                             let val = value_for_check.clone();
                             s.with_shadcn_theme(move |s, t| {
                                 let is_selected = selected.get() == Some(val.clone());
-                                s.size_4()
-                                    .text_sm()
+                                s.size(t.space(4.0), t.space(4.0)) // size-4 = 16px
+                                    .font_size(t.font_size.sm)
                                     .color(t.foreground)
                                     .items_center()
                                     .justify_center()
@@ -645,7 +645,9 @@ impl IntoView for ComboboxItem {
                             })
                         }),
                     ))
-                    .style(|s| s.width_full().items_center().gap_2()),
+                    .style(|s| {
+                        s.with_shadcn_theme(|s, t| s.width_full().items_center().gap(t.space(2.0)))
+                    }),
                 )
                 .style(move |s| {
                     let val = value_for_style.clone();
@@ -658,10 +660,8 @@ impl IntoView for ComboboxItem {
 
                         let base = s
                             .width_full()
-                            .padding_top(6.0)
-                            .padding_bottom(6.0)
-                            .padding_left(8.0)
-                            .padding_right(8.0)
+                            .padding_vert(t.space(1.5))
+                            .padding_horiz(t.space(2.0))
                             .items_center()
                             .rounded_sm()
                             .cursor(if disabled {
@@ -694,7 +694,11 @@ impl IntoView for ComboboxItem {
             // No context - render static item
             Box::new(floem::views::Label::new(label).style(|s| {
                 s.with_shadcn_theme(move |s, t| {
-                    s.width_full().padding(6.0).text_sm().color(t.foreground)
+                    s.width_full()
+                        .padding_vert(t.space(1.5))
+                        .padding_horiz(t.space(2.0))
+                        .font_size(t.font_size.sm)
+                        .color(t.foreground)
                 })
             }))
         }
@@ -749,9 +753,8 @@ impl IntoView for ComboboxEmpty {
         Box::new(floem::views::Label::new(text).style(|s| {
             s.with_shadcn_theme(move |s, t| {
                 s.width_full()
-                    .padding_top(8.0)
-                    .padding_bottom(8.0)
-                    .text_sm()
+                    .padding_vert(t.space(2.0))
+                    .font_size(t.font_size.sm)
                     .color(t.muted_foreground)
                     .justify_center()
             })
@@ -842,10 +845,9 @@ impl IntoView for ComboboxLabel {
     fn into_view(self) -> Self::V {
         Box::new(floem::views::Label::new(self.text).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.px_2()
-                    .padding_top(6.0)
-                    .padding_bottom(6.0)
-                    .text_xs()
+                s.padding_horiz(t.space(2.0))
+                    .padding_vert(t.space(1.5))
+                    .font_size(t.font_size.xs)
                     .font_medium()
                     .color(t.muted_foreground)
             })
@@ -893,10 +895,8 @@ impl IntoView for ComboboxSeparator {
                 s.width_full()
                     .height(1.0)
                     .background(t.border)
-                    .margin_left(-4.0)
-                    .margin_right(-4.0)
-                    .margin_top(4.0)
-                    .margin_bottom(4.0)
+                    .margin_horiz(-t.space(1.0))
+                    .margin_vert(t.space(1.0))
             })
         }))
     }
@@ -101,11 +101,9 @@ impl IntoView for Command {
                 s.with_shadcn_theme(move |s, t| {
                     s.width_full()
                         .min_height(36.0)
-                        .padding_left(12.0)
-                        .padding_right(12.0)
-                        .padding_top(8.0)
-                        .padding_bottom(8.0)
-                        .font_size(14.0)
+                        .padding_horiz(t.space(3.0))
+                        .padding_vert(t.space(2.0))
+                        .font_size(t.font_size.sm)
                         .border(0.0)
                         .border_bottom(1.0)
                         .border_color(t.border)
@@ -190,11 +188,9 @@ impl IntoView for CommandInput {
                     s.with_shadcn_theme(move |s, t| {
                         s.width_full()
                             .min_height(36.0)
-                            .padding_left(12.0)
-                            .padding_right(12.0)
-                            .padding_top(8.0)
-                            .padding_bottom(8.0)
-                            .font_size(14.0)
+                            .padding_horiz(t.space(3.0))
+                            .padding_vert(t.space(2.0))
+                            .font_size(t.font_size.sm)
                             .border(0.0)
                             .border_bottom(1.0)
                             .border_color(t.border)
@@ -300,7 +296,7 @@ impl IntoView for CommandEmpty {
             s.with_shadcn_theme(move |s, t| {
                 s.width_full()
                     .padding(24.0)
-                    .font_size(14.0)
+                    .font_size(t.font_size.sm)
                     .color(t.muted_foreground)
                     .justify_center()
             })
@@ -353,11 +349,9 @@ impl IntoView for CommandGroup {
         // Group heading
         let heading_view = floem::views::Label::new(heading).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.padding_left(8.0)
-                    .padding_right(8.0)
-                    .padding_top(6.0)
-                    .padding_bottom(6.0)
-                    .font_size(12.0)
+                s.padding_horiz(t.space(2.0))
+                    .padding_vert(t.space(1.5))
+                    .font_size(t.font_size.xs)
                     .font_weight(floem::text::Weight::MEDIUM)
                     .color(t.muted_foreground)
             })
@@ -436,11 +430,9 @@ impl IntoView for CommandItem {
             s.with_shadcn_theme(move |s, t| {
                 let base = s
                     .width_full()
-                    .padding_left(8.0)
-                    .padding_right(8.0)
-                    .padding_top(6.0)
-                    .padding_bottom(6.0)
-                    .font_size(14.0)
+                    .padding_horiz(t.space(2.0))
+                    .padding_vert(t.space(1.5))
+                    .font_size(t.font_size.sm)
                     .border_radius(2.0)
                     .cursor(if disabled {
                         CursorStyle::Default
@@ -526,10 +518,8 @@ impl<V: IntoView + 'static> IntoView for CommandItemCustom<V> {
             s.with_shadcn_theme(move |s, t| {
                 let base = s
                     .width_full()
-                    .padding_left(8.0)
-                    .padding_right(8.0)
-                    .padding_top(6.0)
-                    .padding_bottom(6.0)
+                    .padding_horiz(t.space(2.0))
+                    .padding_vert(t.space(1.5))
                     .border_radius(2.0)
                     .display(floem::style::Display::Flex)
                     .flex_direction(floem::style::FlexDirection::Row)
@@ -646,7 +636,7 @@ impl IntoView for CommandShortcut {
 
         Box::new(floem::views::Label::new(keys).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.font_size(12.0)
+                s.font_size(t.font_size.xs)
                     .color(t.muted_foreground)
                     .margin_left(floem::style::PxPctAuto::Auto)
             })
@@ -23,13 +23,20 @@
 //!         ContextMenuItem::new("Delete").destructive(),
 //!     ));
 //! ```
+//!
+//! The trigger area is focusable: the ContextMenu key or Shift+F10 opens the
+//! menu, the arrow keys, Home/End and typeahead move the highlight, Enter
+//! activates the highlighted item and Escape closes the menu.
+
+use std::rc::Rc;
 
 use floem::prelude::*;
-use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
+use floem::reactive::{RwSignal, SignalGet};
 use floem::style::CursorStyle;
 use floem::views::Decorators;
 use floem::{HasViewId, ViewId};
 
+use super::menu_nav::{MenuNav, MenuNavEntry, MenuTriggerKind};
 use crate::theme::ShadcnThemeExt;
 
 // ============================================================================
@@ -89,34 +96,39 @@ where
         let open = self.open;
         let trigger = self.trigger;
         let content = self.content;
+        let nav = MenuNav::with_kind(open, MenuTriggerKind::ContextArea);
 
-        // Trigger wrapper - handles right-click
+        // Trigger wrapper - handles right-click, focusable for keyboard access
         let trigger_view = if let Some(trigger_fn) = trigger {
-            floem::views::Container::new(trigger_fn())
+            let container = floem::views::Container::new(trigger_fn()).style(|s| s.focusable(true));
+            let trigger_id = container.id();
+            nav.attach(trigger_id);
+            container
                 .on_secondary_click_stop(move |_| {
-                    open.set(true);
+                    trigger_id.request_focus();
+                    nav.open_unhighlighted();
                 })
                 .into_any()
         } else {
             floem::views::Empty::new().into_any()
         };
 
-        // Menu content (positioned at trigger's bottom-left)
+        // Menu content (positioned at trigger's bottom-left), built in the
+        // menu's scope so items can register with it
         let content_view = if let Some(menu_content) = content {
-            floem::views::Container::new(menu_content)
+            nav.provide()
+                .enter(move || floem::views::Container::new(menu_content))
                 .style(move |s| {
                     s.with_shadcn_theme(move |s, t| {
                         let is_open = open.get();
                         let base = s
                             .min_width(160.0)
-                            .padding_top(4.0)
-                            .padding_bottom(4.0)
+                            .padding_vert(t.space(1.0))
                             .background(t.popover)
                             .border(1.0)
                             .border_color(t.border)
                             .border_radius(t.radius)
-                            .box_shadow_blur(8.0)
-                            .box_shadow_color(t.foreground.with_alpha(0.1))
+                            .apply_shadow(t.shadow.md)
                             .position(floem::style::Position::Absolute)
                             .inset_top_pct(100.0)
                             .inset_left(0.0)
@@ -152,7 +164,7 @@ where
                 }
             })
             .on_click_stop(move |_| {
-                open.set(false);
+                nav.close();
             });
 
         floem::views::Container::new(floem::views::Stack::new((
@@ -308,12 +320,13 @@ impl IntoView for ContextMenuItem {
         let disabled = self.disabled;
         let destructive = self.destructive;
         let shortcut = self.shortcut;
-        let on_click = self.on_click;
+        let on_click: Option<Rc<dyn Fn()>> = self.on_click.map(Rc::from);
+        let entry = MenuNavEntry::register(text.clone(), disabled, on_click.clone());
 
         // Main label
         let label = floem::views::Label::new(text.clone()).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
-                let base = s.font_size(14.0).flex_grow(1.0);
+                let base = s.font_size(t.font_size.sm).flex_grow(1.0);
                 if destructive {
                     base.color(t.destructive)
                 } else if disabled {
@@ -329,7 +342,7 @@ impl IntoView for ContextMenuItem {
             floem::views::Label::new(sc)
                 .style(|s| {
                     s.with_shadcn_theme(move |s, t| {
-                        s.font_size(12.0)
+                        s.font_size(t.font_size.xs)
                             .color(t.muted_foreground)
                             .margin_left(16.0)
                     })
@@ -341,12 +354,11 @@ impl IntoView for ContextMenuItem {
 
         let row = floem::views::Stack::horizontal((label, shortcut_view)).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
+                let highlighted = entry.is_some_and(|e| e.is_highlighted());
                 let base = s
                     .width_full()
-                    .padding_left(12.0)
-                    .padding_right(12.0)
-                    .padding_top(8.0)
-                    .padding_bottom(8.0)
+                    .padding_horiz(t.space(3.0))
+                    .padding_vert(t.space(2.0))
                     .cursor(if disabled {
                         CursorStyle::Default
                     } else {
@@ -355,17 +367,19 @@ impl IntoView for ContextMenuItem {
                 if disabled {
                     base
                 } else {
-                    base.hover(|s| s.background(t.accent))
+                    base.apply_if(highlighted, |s| s.background(t.accent))
+                        .hover(|s| s.background(t.accent))
                 }
             })
         });
 
-        if let Some(handler) = on_click {
-            if !disabled {
-                Box::new(row.on_click_stop(move |_| handler()))
-            } else {
-                Box::new(row)
-            }
+        if disabled {
+            Box::new(row)
+        } else if let Some(entry) = entry {
+            // Runs the handler and closes the menu
+            Box::new(row.on_click_stop(move |_| entry.activate()))
+        } else if let Some(handler) = on_click {
+            Box::new(row.on_click_stop(move |_| handler()))
         } else {
             Box::new(row)
         }
@@ -459,11 +473,10 @@ impl IntoView for ContextMenuLabel {
         Box::new(floem::views::Label::new(text).style(|s| {
             s.with_shadcn_theme(move |s, t| {
                 s.width_full()
-                    .padding_left(12.0)
-                    .padding_right(12.0)
-                    .padding_top(8.0)
-                    .padding_bottom(4.0)
-                    .font_size(12.0)
+                    .padding_horiz(t.space(3.0))
+                    .padding_top(t.space(2.0))
+                    .padding_bottom(t.space(1.0))
+                    .font_size(t.font_size.xs)
                     .font_weight(floem::text::Weight::SEMIBOLD)
                     .color(t.foreground)
             })
@@ -0,0 +1,665 @@
+//! DataTable component with builder-style API
+//!
+//! Based on shadcn/ui Data Table - a data-driven table built on the `Table`
+//! primitives, with sortable columns, a global filter, pagination and row
+//! selection.
+//!
+//! # Example
+//!
+//! ```rust
+//! use floem::reactive::RwSignal;
+//! use floem_shadcn::components::data_table::{DataColumn, DataTable};
+//!
+//! #[derive(Clone)]
+//! struct Payment {
+//!     email: String,
+//!     status: String,
+//!     amount: f64,
+//! }
+//!
+//! let payments = RwSignal::new(vec![/* ... */]);
+//!
+//! let table = DataTable::new(
+//!     payments,
+//!     vec![
+//!         DataColumn::new("Status", |p: &Payment| p.status.clone()),
+//!         DataColumn::new("Email", |p: &Payment| p.email.clone()).sortable(),
+//!         DataColumn::new("Amount", |p: &Payment| format!("${:.2}", p.amount))
+//!             .sort_by(|a, b| a.amount.total_cmp(&b.amount)),
+//!     ],
+//! )
+//! .selectable()
+//! .page_size(10)
+//! .filter_placeholder("Filter emails...");
+//!
+//! // Indices (into `payments`) of the checked rows
+//! let selection = table.selection_signal();
+//! ```
+//!
+//! Sorting cycles ascending → descending → unsorted when a sortable header is
+//! clicked. The filter matches rows whose column text contains the query
+//! (case-insensitive). Row selection is keyed by the row's index in the data.
+
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::rc::Rc;
+
+use floem::prelude::*;
+use floem::reactive::{Effect, RwSignal, SignalGet, SignalTrack, SignalUpdate, SignalWith};
+use floem::style::CursorStyle;
+use floem::view::ParentView;
+use floem::views::Decorators;
+use floem::{HasViewId, ViewId};
+
+use crate::components::checkbox::Checkbox;
+use crate::components::input::Input;
+use crate::components::pagination::Pagination;
+use crate::components::table::{
+    Table, TableBody, TableCellCustom, TableHeadCustom, TableHeader, TableRow,
+};
+use crate::theme::ShadcnThemeExt;
+
+// ============================================================================
+// DataColumn
+// ============================================================================
+
+/// Sort direction of a column
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// The column a table is sorted by
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColumnSort {
+    /// Index of the column in the table's column list
+    pub column: usize,
+    pub direction: SortDirection,
+}
+
+/// Typed column definition for a [`DataTable`]
+///
+/// The accessor returns the column's text, which is shown in the cell unless
+/// a custom cell renderer is set, and is matched by the global filter.
+#[allow(clippy::type_complexity)]
+pub struct DataColumn<T> {
+    header: String,
+    accessor: Rc<dyn Fn(&T) -> String>,
+    cell: Option<Rc<dyn Fn(&T) -> Box<dyn View>>>,
+    compare: Option<Rc<dyn Fn(&T, &T) -> Ordering>>,
+    filterable: bool,
+    width: Option<f64>,
+}
+
+impl<T: 'static> DataColumn<T> {
+    /// Create a column with a header and a text accessor
+    pub fn new(header: impl Into<String>, accessor: impl Fn(&T) -> String + 'static) -> Self {
+        Self {
+            header: header.into(),
+            accessor: Rc::new(accessor),
+            cell: None,
+            compare: None,
+            filterable: true,
+            width: None,
+        }
+    }
+
+    /// Render cells with a custom view instead of the accessor text
+    pub fn cell<V: IntoView + 'static>(mut self, cell: impl Fn(&T) -> V + 'static) -> Self {
+        self.cell = Some(Rc::new(move |row| cell(row).into_any()));
+        self
+    }
+
+    /// Make the column sortable by its accessor text
+    pub fn sortable(mut self) -> Self {
+        let accessor = self.accessor.clone();
+        self.compare = Some(Rc::new(move |a, b| accessor(a).cmp(&accessor(b))));
+        self
+    }
+
+    /// Make the column sortable with a custom comparator
+    pub fn sort_by(mut self, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    /// Make the column sortable by a key extracted from the row
+    pub fn sort_by_key<K: Ord>(self, key: impl Fn(&T) -> K + 'static) -> Self {
+        self.sort_by(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Include or exclude the column from the global filter (default: included)
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.filterable = filterable;
+        self
+    }
+
+    /// Set fixed width for the column
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Returns true if the column can be sorted
+    pub fn is_sortable(&self) -> bool {
+        self.compare.is_some()
+    }
+}
+
+// ============================================================================
+// DataTable
+// ============================================================================
+
+/// Data-driven table with sorting, filtering, pagination and row selection
+pub struct DataTable<T: 'static> {
+    id: ViewId,
+    data: RwSignal<Vec<T>>,
+    columns: Rc<Vec<DataColumn<T>>>,
+    sort: RwSignal<Option<ColumnSort>>,
+    filter: RwSignal<String>,
+    page: RwSignal<usize>,
+    page_size: Option<usize>,
+    selection: RwSignal<BTreeSet<usize>>,
+    selectable: bool,
+    show_filter: bool,
+    filter_placeholder: String,
+    empty_text: String,
+}
+
+impl<T: Clone + 'static> DataTable<T> {
+    /// Create a new data table over the given rows
+    pub fn new(data: RwSignal<Vec<T>>, columns: Vec<DataColumn<T>>) -> Self {
+        Self {
+            id: ViewId::new(),
+            data,
+            columns: Rc::new(columns),
+            sort: RwSignal::new(None),
+            filter: RwSignal::new(String::new()),
+            page: RwSignal::new(1),
+            page_size: None,
+            selection: RwSignal::new(BTreeSet::new()),
+            selectable: false,
+            show_filter: true,
+            filter_placeholder: "Filter...".to_string(),
+            empty_text: "No results.".to_string(),
+        }
+    }
+
+    /// Show a page of `size` rows at a time with pagination controls
+    pub fn page_size(mut self, size: usize) -> Self {
+        self.page_size = Some(size.max(1));
+        self
+    }
+
+    /// Add a checkbox column for row selection
+    pub fn selectable(mut self) -> Self {
+        self.selectable = true;
+        self
+    }
+
+    /// Show/hide the global filter input
+    pub fn show_filter(mut self, show: bool) -> Self {
+        self.show_filter = show;
+        self
+    }
+
+    /// Set the filter input placeholder
+    pub fn filter_placeholder(mut self, text: impl Into<String>) -> Self {
+        self.filter_placeholder = text.into();
+        self
+    }
+
+    /// Set the text shown when no rows match
+    pub fn empty_text(mut self, text: impl Into<String>) -> Self {
+        self.empty_text = text.into();
+        self
+    }
+
+    /// Get the sort signal for external control
+    pub fn sort_signal(&self) -> RwSignal<Option<ColumnSort>> {
+        self.sort
+    }
+
+    /// Get the global filter signal for external control
+    pub fn filter_signal(&self) -> RwSignal<String> {
+        self.filter
+    }
+
+    /// Get the current page signal (1-based)
+    pub fn page_signal(&self) -> RwSignal<usize> {
+        self.page
+    }
+
+    /// Get the selection signal (indices into the data of the checked rows)
+    ///
+    /// Once the table is shown, the selection is cleared whenever the data
+    /// changes.
+    pub fn selection_signal(&self) -> RwSignal<BTreeSet<usize>> {
+        self.selection
+    }
+
+    /// Cycle the sort of a column: ascending → descending → unsorted
+    ///
+    /// Does nothing if the column is not sortable.
+    pub fn toggle_sort(&self, column: usize) {
+        toggle_sort(&self.columns, self.sort, column);
+    }
+
+    /// Indices of the rows that match the filter, in sorted order (tracked)
+    pub fn rows(&self) -> Vec<usize> {
+        visible_rows(&self.columns, self.data, self.sort, self.filter)
+    }
+
+    /// Number of pages for the filtered rows (at least 1; tracked)
+    pub fn page_count(&self) -> usize {
+        page_count(self.rows().len(), self.page_size)
+    }
+
+    /// Indices of the rows on the current page (tracked)
+    pub fn page_rows(&self) -> Vec<usize> {
+        page_rows(self.rows(), self.page.get(), self.page_size)
+    }
+
+    /// Clones of the selected rows, in data order
+    pub fn selected_rows(&self) -> Vec<T> {
+        let selection = self.selection.get();
+        self.data.with(|data| {
+            selection
+                .iter()
+                .filter_map(|&index| data.get(index).cloned())
+                .collect()
+        })
+    }
+}
+
+impl<T: 'static> HasViewId for DataTable<T> {
+    fn view_id(&self) -> ViewId {
+        self.id
+    }
+}
+
+impl<T: Clone + 'static> IntoView for DataTable<T> {
+    type V = Box<dyn View>;
+    type Intermediate = Self;
+
+    fn into_intermediate(self) -> Self::Intermediate {
+        self
+    }
+
+    fn into_view(self) -> Self::V {
+        let data = self.data;
+        let columns = self.columns;
+        let sort = self.sort;
+        let filter = self.filter;
+        let page = self.page;
+        let page_size = self.page_size;
+        let selection = self.selection;
+        let selectable = self.selectable;
+        let empty_text = self.empty_text;
+
+        let rows = {
+            let columns = columns.clone();
+            Rc::new(move || visible_rows(&columns, data, sort, filter))
+        };
+
+        // Keep the current page in range when the data or filter shrinks
+        {
+            let rows = rows.clone();
+            Effect::new(move |_| {
+                let count = page_count(rows().len(), page_size);
+                if page.get() > count {
+                    page.set(count);
+                }
+            });
+        }
+
+        // Selection indices point at the old rows once the data changes
+        Effect::new(move |prev: Option<()>| {
+            data.track();
+            if prev.is_some() && selection.with_untracked(|s| !s.is_empty()) {
+                selection.set(BTreeSet::new());
+            }
+        });
+
+        // Filter input
+        let filter_view = if self.show_filter {
+            floem::views::Container::new(
+                Input::new()
+                    .placeholder(self.filter_placeholder)
+                    .on_update(move |text| {
+                        filter.set(text.to_string());
+                        page.set(1);
+                    }),
+            )
+            .style(|s| s.width_full().max_width(384.0)) // max-w-sm
+            .into_any()
+        } else {
+            floem::views::Empty::new().into_any()
+        };
+
+        // Header row
+        let mut header_row = TableRow::new();
+        if selectable {
+            let current_rows = {
+                let rows = rows.clone();
+                move || page_rows(rows(), page.get(), page_size)
+            };
+            header_row = header_row.child(TableHeadCustom::new(select_all_checkbox(
+                current_rows,
+                selection,
+            )));
+        }
+        for (index, column) in columns.iter().enumerate() {
+            let mut head = TableHeadCustom::new(sortable_header(&columns, column, index, sort));
+            if let Some(width) = column.width {
+                head = head.width(width);
+            }
+            header_row = header_row.child(head);
+        }
+
+        // Body - rebuilt when the visible page changes
+        let body = {
+            let columns = columns.clone();
+            let rows = rows.clone();
+            floem::views::dyn_container(
+                move || page_rows(rows(), page.get(), page_size),
+                move |indices| {
+                    if indices.is_empty() {
+                        let empty = floem::views::Label::new(empty_text.clone()).style(|s| {
+                            s.with_shadcn_theme(|s, t| {
+                                s.width_full()
+                                    .height(t.space(24.0)) // h-24
+                                    .items_center()
+                                    .justify_center()
+                                    .font_size(t.font_size.sm) // text-sm
+                                    .color(t.muted_foreground)
+                            })
+                        });
+                        return TableBody::new()
+                            .child(TableRow::new().child(empty))
+                            .into_any();
+                    }
+                    indices
+                        .into_iter()
+                        .fold(TableBody::new(), |body, index| {
+                            body.child(data_row(&columns, data, index, selectable, selection))
+                        })
+                        .into_any()
+                },
+            )
+            .style(|s| s.width_full())
+        };
+
+        let table = Table::new()
+            .child(TableHeader::new().child(header_row))
+            .child(body);
+
+        // Footer - selection summary and pagination
+        let summary = floem::views::Label::derived({
+            let rows = rows.clone();
+            move || {
+                if selectable {
+                    format!(
+                        "{} of {} row(s) selected.",
+                        selection.with(|s| s.len()),
+                        rows().len()
+                    )
+                } else {
+                    format!("{} row(s)", rows().len())
+                }
+            }
+        })
+        .style(|s| {
+            s.with_shadcn_theme(|s, t| {
+                s.flex_grow(1.0)
+                    .font_size(t.font_size.sm) // text-sm
+                    .color(t.muted_foreground) // text-muted-foreground
+            })
+        });
+
+        let pagination = if page_size.is_some() {
+            Pagination::dynamic(page, move || page_count(rows().len(), page_size)).into_any()
+        } else {
+            floem::views::Empty::new().into_any()
+        };
+
+        let footer = floem::views::Stack::horizontal((summary, pagination))
+            .style(|s| s.with_shadcn_theme(|s, t| s.width_full().items_center().gap(t.space(2.0))));
+
+        Box::new(
+            floem::views::Stack::vertical((filter_view, table.into_view(), footer))
+                .style(|s| s.with_shadcn_theme(|s, t| s.width_full().gap(t.space(4.0)))),
+        )
+    }
+}
+
+/// Header cell content: the column title plus a sort indicator when sortable
+fn sortable_header<T: 'static>(
+    columns: &Rc<Vec<DataColumn<T>>>,
+    column: &DataColumn<T>,
+    index: usize,
+    sort: RwSignal<Option<ColumnSort>>,
+) -> Box<dyn View> {
+    let title = floem::views::Label::new(column.header.clone());
+    if !column.is_sortable() {
+        return title.into_any();
+    }
+
+    let indicator = floem::views::Label::derived(move || {
+        let arrow = match sort.get() {
+            Some(ColumnSort {
+                column,
+                direction: SortDirection::Ascending,
+            }) if column == index => "↑",
+            Some(ColumnSort {
+                column,
+                direction: SortDirection::Descending,
+            }) if column == index => "↓",
+            _ => "↕",
+        };
+        arrow.to_string()
+    })
+    .style(|s| s.with_shadcn_theme(|s, t| s.font_size(t.font_size.xs).color(t.muted_foreground)));
+
+    let columns = columns.clone();
+    floem::views::Stack::horizontal((title, indicator))
+        .style(|s| {
+            s.with_shadcn_theme(|s, t| {
+                s.items_center()
+                    .gap(t.space(2.0))
+                    .padding_horiz(t.space(2.0)) // ghost button: -ml-3 h-8
+                    .height(t.space(8.0))
+                    .border_radius(t.radius)
+                    .cursor(CursorStyle::Pointer)
+                    .hover(|s| s.background(t.accent))
+            })
+        })
+        .on_click_stop(move |_| toggle_sort(&columns, sort, index))
+        .into_any()
+}
+
+/// A body row for the data at `index`
+fn data_row<T: Clone + 'static>(
+    columns: &Rc<Vec<DataColumn<T>>>,
+    data: RwSignal<Vec<T>>,
+    index: usize,
+    selectable: bool,
+    selection: RwSignal<BTreeSet<usize>>,
+) -> floem::views::Stem {
+    let item = data.with_untracked(|data| data.get(index).cloned());
+
+    let mut row = TableRow::new();
+    if selectable {
+        row = row.child(TableCellCustom::new(row_checkbox(index, selection)));
+    }
+    if let Some(item) = &item {
+        for column in columns.iter() {
+            let content = match &column.cell {
+                Some(cell) => cell(item),
+                None => floem::views::Label::new((column.accessor)(item)).into_any(),
+            };
+            let mut cell = TableCellCustom::new(content);
+            if let Some(width) = column.width {
+                cell = cell.width(width);
+            }
+            row = row.child(cell);
+        }
+    }
+
+    // data-[state=selected]:bg-muted
+    row.into_view().style(move |s| {
+        let is_selected = selection.with(|s| s.contains(&index));
+        s.with_shadcn_theme(move |s, t| s.apply_if(is_selected, |s| s.background(t.muted)))
+    })
+}
+
+/// Checkbox bound to whether the row at `index` is in the selection
+fn row_checkbox(index: usize, selection: RwSignal<BTreeSet<usize>>) -> Checkbox {
+    let checked = RwSignal::new(selection.with_untracked(|s| s.contains(&index)));
+
+    // Selection → checkbox
+    Effect::new(move |_| {
+        let is_selected = selection.with(|s| s.contains(&index));
+        if checked.get_untracked() != is_selected {
+            checked.set(is_selected);
+        }
+    });
+    // Checkbox → selection
+    Effect::new(move |_| {
+        let is_checked = checked.get();
+        if selection.with_untracked(|s| s.contains(&index)) != is_checked {
+            selection.update(|s| {
+                if is_checked {
+                    s.insert(index);
+                } else {
+                    s.remove(&index);
+                }
+            });
+        }
+    });
+
+    Checkbox::new(checked)
+}
+
+/// Header checkbox that selects or clears every row on the current page
+fn select_all_checkbox(
+    page_rows: impl Fn() -> Vec<usize> + 'static,
+    selection: RwSignal<BTreeSet<usize>>,
+) -> Checkbox {
+    let page_rows = Rc::new(page_rows);
+    let all_selected = {
+        let page_rows = page_rows.clone();
+        move || {
+            let rows = page_rows();
+            !rows.is_empty() && selection.with(|s| rows.iter().all(|index| s.contains(index)))
+        }
+    };
+    let checked = RwSignal::new(false);
+
+    // Page selection → checkbox
+    {
+        let all_selected = all_selected.clone();
+        Effect::new(move |_| {
+            let all = all_selected();
+            if checked.get_untracked() != all {
+                checked.set(all);
+            }
+        });
+    }
+    // Checkbox → page selection (only when it disagrees, so unchecking a
+    // single row doesn't clear the whole page)
+    Effect::new(move |_| {
+        let is_checked = checked.get();
+        let rows = page_rows();
+        let all = !rows.is_empty()
+            && selection.with_untracked(|s| rows.iter().all(|index| s.contains(index)));
+        if is_checked != all {
+            selection.update(|s| {
+                for index in rows {
+                    if is_checked {
+                        s.insert(index);
+                    } else {
+                        s.remove(&index);
+                    }
+                }
+            });
+        }
+    });
+
+    Checkbox::new(checked)
+}
+
+fn toggle_sort<T>(columns: &[DataColumn<T>], sort: RwSignal<Option<ColumnSort>>, column: usize) {
+    if !columns.get(column).is_some_and(|c| c.is_sortable()) {
+        return;
+    }
+    let next = match sort.get_untracked() {
+        Some(ColumnSort {
+            column: current,
+            direction: SortDirection::Ascending,
+        }) if current == column => Some(SortDirection::Descending),
+        Some(ColumnSort {
+            column: current,
+            direction: SortDirection::Descending,
+        }) if current == column => None,
+        _ => Some(SortDirection::Ascending),
+    };
+    sort.set(next.map(|direction| ColumnSort { column, direction }));
+}
+
+/// Indices of the rows matching `filter`, ordered by `sort`
+fn visible_rows<T>(
+    columns: &[DataColumn<T>],
+    data: RwSignal<Vec<T>>,
+    sort: RwSignal<Option<ColumnSort>>,
+    filter: RwSignal<String>,
+) -> Vec<usize> {
+    let query = filter.with(|f| f.trim().to_lowercase());
+    let sort = sort.get();
+    data.with(|data| {
+        let mut indices: Vec<usize> = (0..data.len())
+            .filter(|&index| {
+                query.is_empty()
+                    || columns
+                        .iter()
+                        .filter(|c| c.filterable)
+                        .any(|c| (c.accessor)(&data[index]).to_lowercase().contains(&query))
+            })
+            .collect();
+
+        let compare = sort.and_then(|sort| {
+            let compare = columns.get(sort.column)?.compare.clone()?;
+            Some((compare, sort.direction))
+        });
+        if let Some((compare, direction)) = compare {
+            // Stable sort keeps the data order for equal keys
+            indices.sort_by(|&a, &b| {
+                let ordering = compare(&data[a], &data[b]);
+                match direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            });
+        }
+        indices
+    })
+}
+
+fn page_count(rows: usize, page_size: Option<usize>) -> usize {
+    match page_size {
+        Some(size) => rows.div_ceil(size).max(1),
+        None => 1,
+    }
+}
+
+fn page_rows(rows: Vec<usize>, page: usize, page_size: Option<usize>) -> Vec<usize> {
+    match page_size {
+        Some(size) => rows
+            .into_iter()
+            .skip(page.saturating_sub(1) * size)
+            .take(size)
+            .collect(),
+        None => rows,
+    }
+}
@@ -12,6 +12,9 @@
 //!
 //! DatePicker::new(date);
 //! ```
+//!
+//! The calendar opens in an overlay below the trigger and flips above it when
+//! there is not enough room, see [`floating`](crate::components::floating).
 
 use floem::prelude::*;
 use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
@@ -20,6 +23,7 @@ use floem::views::Decorators;
 use floem::{HasViewId, ViewId};
 
 use crate::components::calendar::SimpleDate;
+use crate::components::floating::{Align, Floating, FloatingOptions, Side};
 use crate::theme::ShadcnThemeExt;
 
 // ============================================================================
@@ -81,7 +85,8 @@ impl IntoView for DatePicker {
         // Trigger button
         let trigger = floem::views::Stack::horizontal((
             // Calendar icon
-            floem::views::Label::new("📅").style(|s| s.font_size(14.0)),
+            floem::views::Label::new("📅")
+                .style(|s| s.with_shadcn_theme(|s, t| s.font_size(t.font_size.sm))),
             // Date text or placeholder
             floem::views::Label::derived(move || {
                 if let Some(date) = selected.get() {
@@ -104,13 +109,11 @@ impl IntoView for DatePicker {
         .style(move |s| {
             s.with_shadcn_theme(move |s, t| {
                 let base = s
-                    .gap(8.0)
-                    .padding_left(12.0)
-                    .padding_right(12.0)
-                    .padding_top(8.0)
-                    .padding_bottom(8.0)
+                    .gap(t.space(2.0))
+                    .padding_horiz(t.space(3.0))
+                    .padding_vert(t.space(2.0))
                     .min_width(200.0)
-                    .font_size(14.0)
+                    .font_size(t.font_size.sm)
                     .background(t.background)
                     .border(1.0)
                     .border_color(t.input)
@@ -129,6 +132,8 @@ impl IntoView for DatePicker {
             })
         });
 
+        let floating = Floating::new(FloatingOptions::new(Side::Bottom, Align::Start));
+        let trigger = floating.anchor(trigger);
         let trigger = if disabled {
             trigger.into_any()
         } else {
@@ -154,18 +159,14 @@ impl IntoView for DatePicker {
         let popup = floem::views::Container::new(calendar).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
                 let open = is_open.get();
-                let base = s
-                    .position(floem::style::Position::Absolute)
-                    .inset_top_pct(100.0)
-                    .inset_left(0.0)
-                    .margin_top(4.0)
-                    .padding(12.0)
+                let base = floating
+                    .position(s)
+                    .padding(t.space(3.0))
                     .background(t.popover)
                     .border(1.0)
                     .border_color(t.border)
                     .border_radius(t.radius)
-                    .box_shadow_blur(8.0)
-                    .box_shadow_color(t.foreground.with_alpha(0.1))
+                    .apply_shadow(t.shadow.md)
                     .z_index(100);
                 if open {
                     base
@@ -175,35 +176,41 @@ impl IntoView for DatePicker {
             })
         });
 
-        // Backdrop - using absolute positioning with large area
-        let backdrop = floem::views::Empty::new()
-            .style(move |s| {
-                let open = is_open.get();
-                let base = s
-                    .position(floem::style::Position::Absolute)
-                    .inset_top(-1000.0)
-                    .inset_left(-1000.0)
-                    .width(3000.0)
-                    .height(3000.0)
-                    .z_index(99);
-
-                if open {
-                    base
-                } else {
-                    base.display(floem::style::Display::None)
-                }
-            })
-            .on_click_stop(move |_| {
-                is_open.set(false);
-            });
-
-        Box::new(
-            floem::views::Container::new(floem::views::Stack::new((trigger, backdrop, popup)))
-                .style(|s| s.position(floem::style::Position::Relative)),
-        )
+        Box::new(floem::views::Stack::new((
+            trigger,
+            popup_overlay(is_open, floating, popup),
+        )))
     }
 }
 
+/// Render a picker popup in an Overlay with a backdrop that closes it
+fn popup_overlay(
+    is_open: RwSignal<bool>,
+    floating: Floating,
+    popup: impl IntoView + 'static,
+) -> impl IntoView {
+    floem::views::Overlay::new().child(
+        floem::views::Stack::new((
+            floating.window_probe(),
+            // Backdrop - closes the popup when clicking outside
+            floem::views::Empty::new()
+                .style(|s| s.absolute().inset_0())
+                .on_click_stop(move |_| {
+                    is_open.set(false);
+                }),
+            floating.content(popup),
+        ))
+        .style(move |s| {
+            let open = is_open.get();
+            s.fixed()
+                .inset_0()
+                .width_full()
+                .height_full()
+                .apply_if(!open, |s| s.hide())
+        }),
+    )
+}
+
 fn create_calendar_content(
     selected: RwSignal<Option<SimpleDate>>,
     view_year: RwSignal<i32>,
@@ -216,8 +223,8 @@ fn create_calendar_content(
         floem::views::Label::new("◀")
             .style(|s| {
                 s.with_shadcn_theme(move |s, t| {
-                    s.padding(4.0)
-                        .font_size(12.0)
+                    s.padding(t.space(1.0))
+                        .font_size(t.font_size.xs)
                         .color(t.foreground)
                         .border_radius(4.0)
                         .cursor(CursorStyle::Pointer)
@@ -257,7 +264,7 @@ fn create_calendar_content(
         .style(|s| {
             s.with_shadcn_theme(move |s, t| {
                 s.flex_grow(1.0)
-                    .font_size(14.0)
+                    .font_size(t.font_size.sm)
                     .font_weight(floem::text::Weight::SEMIBOLD)
                     .color(t.foreground)
                     .justify_center()
@@ -267,8 +274,8 @@ fn create_calendar_content(
         floem::views::Label::new("▶")
             .style(|s| {
                 s.with_shadcn_theme(move |s, t| {
-                    s.padding(4.0)
-                        .font_size(12.0)
+                    s.padding(t.space(1.0))
+                        .font_size(t.font_size.xs)
                         .color(t.foreground)
                         .border_radius(4.0)
                         .cursor(CursorStyle::Pointer)
@@ -285,7 +292,9 @@ fn create_calendar_content(
                 }
             }),
     ))
-    .style(|s| s.width_full().items_center().margin_bottom(8.0));
+    .style(|s| {
+        s.with_shadcn_theme(|s, t| s.width_full().items_center().margin_bottom(t.space(2.0)))
+    });
 
     // Day of week headers
     let day_headers = floem::views::Stack::horizontal((
@@ -297,7 +306,7 @@ fn create_calendar_content(
         day_header("Fr"),
         day_header("Sa"),
     ))
-    .style(|s| s.margin_bottom(4.0));
+    .style(|s| s.with_shadcn_theme(|s, t| s.margin_bottom(t.space(1.0))));
 
     // Calendar grid (6 weeks)
     let week1 = create_picker_week(0, selected, view_year, view_month, is_open);
@@ -323,9 +332,9 @@ fn create_calendar_content(
 fn day_header(label: &str) -> impl IntoView {
     floem::views::Label::new(label.to_string()).style(|s| {
         s.with_shadcn_theme(move |s, t| {
-            s.width(32.0)
-                .height(32.0)
-                .font_size(12.0)
+            s.width(t.space(8.0))
+                .height(t.space(8.0))
+                .font_size(t.font_size.xs)
                 .color(t.muted_foreground)
                 .display(floem::style::Display::Flex)
                 .items_center()
@@ -381,9 +390,9 @@ fn create_picker_day(
                 false
             };
             let base = s
-                .width(32.0)
-                .height(32.0)
-                .font_size(14.0)
+                .width(t.space(8.0))
+                .height(t.space(8.0))
+                .font_size(t.font_size.sm)
                 .border_radius(4.0)
                 .display(floem::style::Display::Flex)
                 .items_center()
@@ -534,7 +543,8 @@ impl IntoView for DateRangePicker {
 
         // Trigger button
         let trigger = floem::views::Stack::horizontal((
-            floem::views::Label::new("📅").style(|s| s.font_size(14.0)),
+            floem::views::Label::new("📅")
+                .style(|s| s.with_shadcn_theme(|s, t| s.font_size(t.font_size.sm))),
             floem::views::Label::derived(move || match (start.get(), end.get()) {
                 (Some(s), Some(e)) => {
                     format!(
@@ -561,13 +571,11 @@ impl IntoView for DateRangePicker {
         .style(move |s| {
             s.with_shadcn_theme(move |s, t| {
                 let base = s
-                    .gap(8.0)
-                    .padding_left(12.0)
-                    .padding_right(12.0)
-                    .padding_top(8.0)
-                    .padding_bottom(8.0)
+                    .gap(t.space(2.0))
+                    .padding_horiz(t.space(3.0))
+                    .padding_vert(t.space(2.0))
                     .min_width(280.0)
-                    .font_size(14.0)
+                    .font_size(t.font_size.sm)
                     .background(t.background)
                     .border(1.0)
                     .border_color(t.input)
@@ -586,6 +594,8 @@ impl IntoView for DateRangePicker {
             })
         });
 
+        let floating = Floating::new(FloatingOptions::new(Side::Bottom, Align::Start));
+        let trigger = floating.anchor(trigger);
         let trigger = if disabled {
             trigger.into_any()
         } else {
@@ -600,21 +610,17 @@ impl IntoView for DateRangePicker {
         let popup = floem::views::Label::new("Date range selection").style(move |s| {
             s.with_shadcn_theme(move |s, t| {
                 let open = is_open.get();
-                let base = s
-                    .position(floem::style::Position::Absolute)
-                    .inset_top_pct(100.0)
-                    .inset_left(0.0)
-                    .margin_top(4.0)
-                    .padding(16.0)
+                let base = floating
+                    .position(s)
+                    .padding(t.space(4.0))
                     .background(t.popover)
                     .border(1.0)
                     .border_color(t.border)
                     .border_radius(t.radius)
-                    .box_shadow_blur(8.0)
-                    .box_shadow_color(t.foreground.with_alpha(0.1))
+                    .apply_shadow(t.shadow.md)
                     .z_index(100)
                     .color(t.muted_foreground)
-                    .font_size(14.0);
+                    .font_size(t.font_size.sm);
                 if open {
                     base
                 } else {
@@ -623,31 +629,9 @@ impl IntoView for DateRangePicker {
             })
         });
 
-        // Backdrop - using absolute positioning with large area
-        let backdrop = floem::views::Empty::new()
-            .style(move |s| {
-                let open = is_open.get();
-                let base = s
-                    .position(floem::style::Position::Absolute)
-                    .inset_top(-1000.0)
-                    .inset_left(-1000.0)
-                    .width(3000.0)
-                    .height(3000.0)
-                    .z_index(99);
-
-                if open {
-                    base
-                } else {
-                    base.display(floem::style::Display::None)
-                }
-            })
-            .on_click_stop(move |_| {
-                is_open.set(false);
-            });
-
-        Box::new(
-            floem::views::Container::new(floem::views::Stack::new((trigger, backdrop, popup)))
-                .style(|s| s.position(floem::style::Position::Relative)),
-        )
+        Box::new(floem::views::Stack::new((
+            trigger,
+            popup_overlay(is_open, floating, popup),
+        )))
     }
 }
@@ -33,6 +33,14 @@
 //! - `DialogFooter` - Container for action buttons
 //! - `DialogClose` - Closes the dialog when clicked
 //!
+//! # Keyboard
+//!
+//! While the dialog is open, focus is trapped inside `DialogContent`: the first
+//! focusable element (e.g. a `DialogClose` or an `Input`) is focused on open,
+//! Tab/Shift+Tab cycle within the content and Escape closes the dialog. Use
+//! `Dialog::on_escape_key_down` to veto Escape. When the dialog closes, focus
+//! returns to the `DialogTrigger` that opened it.
+//!
 //! # External state control
 //!
 //! Use `dialog.open_signal()` to get the signal for programmatic control:
@@ -43,6 +51,8 @@
 //! // Later: open.set(true) to open programmatically
 //! ```
 
+use std::rc::Rc;
+
 use floem::prelude::*;
 use floem::reactive::{Context, RwSignal, Scope, SignalGet, SignalUpdate};
 use floem::views::Decorators;
@@ -50,6 +60,7 @@ use floem::views::Overlay;
 use floem::{HasViewId, ViewId};
 use floem_tailwind::TailwindExt;
 
+use crate::components::focus_scope::{self, FocusScope};
 use crate::theme::ShadcnThemeExt;
 
 // ============================================================================
@@ -100,8 +111,9 @@ impl<V: IntoView + 'static> Dialog<V> {
         let open = RwSignal::new(false);
         let scope = Scope::current().create_child();
 
-        // Provide the dialog context in the child scope
+        // Provide the dialog context and focus scope in the child scope
         scope.provide_context(DialogContext { open });
+        scope.provide_context(FocusScope::new(open));
 
         Self {
             id: ViewId::new(),
@@ -117,6 +129,16 @@ impl<V: IntoView + 'static> Dialog<V> {
     pub fn open_signal(&self) -> RwSignal<bool> {
         self.open
     }
+
+    /// Set a handler that runs when Escape is pressed while the dialog is open
+    ///
+    /// Return `false` to keep the dialog open.
+    pub fn on_escape_key_down(self, handler: impl Fn() -> bool + 'static) -> Self {
+        if let Some(focus) = self.scope.enter(FocusScope::current) {
+            focus.set_on_escape(Some(Rc::new(handler)));
+        }
+        self
+    }
 }
 
 impl<V: IntoView + 'static> HasViewId for Dialog<V> {
@@ -182,13 +204,18 @@ impl<V: IntoView + 'static> IntoView for DialogTrigger<V> {
     fn into_view(self) -> Self::V {
         // Get the dialog context from the current scope
         let ctx = Context::get::<DialogContext>();
+        let id = self.id;
 
         Box::new(
-            floem::views::Container::with_id(self.id, self.child).on_click_stop(move |_| {
-                if let Some(ctx) = ctx {
-                    ctx.open.set(true);
-                }
-            }),
+            floem::views::Container::with_id(id, self.child)
+                .style(|s| s.focusable(true))
+                .on_click_stop(move |_| {
+                    if let Some(ctx) = ctx {
+                        // Focus returns here when the dialog closes
+                        focus_scope::remember_opener(id);
+                        ctx.open.set(true);
+                    }
+                }),
         )
     }
 }
@@ -232,13 +259,20 @@ impl<V: IntoView + 'static> IntoView for DialogClose<V> {
     fn into_view(self) -> Self::V {
         // Get the dialog context from the current scope
         let ctx = Context::get::<DialogContext>();
+        let close = move || {
+            if let Some(ctx) = ctx {
+                ctx.open.set(false);
+            }
+        };
+
+        // Reachable with Tab inside the dialog, activated with Enter/Space
+        FocusScope::register_current(self.id);
+        focus_scope::activate_on_key(self.id, close);
 
         Box::new(
-            floem::views::Container::with_id(self.id, self.child).on_click_stop(move |_| {
-                if let Some(ctx) = ctx {
-                    ctx.open.set(false);
-                }
-            }),
+            floem::views::Container::with_id(self.id, self.child)
+                .style(|s| s.focusable(true))
+                .on_click_stop(move |_| close()),
         )
     }
 }
@@ -305,6 +339,33 @@ impl IntoView for DialogContent {
         if let Some(ctx) = ctx {
             let open = ctx.open;
 
+            // Content wrapper - centered modal with vertical stack for children.
+            // Focusable so Escape still works when it has no focusable children.
+            let content = floem::views::Stack::vertical_from_iter(children)
+                .style(move |s| {
+                    s.absolute()
+                        .left_1_2()
+                        .top_1_2()
+                        .translate_x_neg_1_2()
+                        .translate_y_neg_1_2()
+                        .z_index(10)
+                        .max_w_lg()
+                        .rounded_lg()
+                        .p_6()
+                        .gap_4()
+                        .shadow_lg()
+                        .focusable(true)
+                })
+                .style(move |s| {
+                    s.with_shadcn_theme(move |s, t| {
+                        s.background(t.background).border_1().border_color(t.border)
+                    })
+                });
+            // Trap focus inside the content while open
+            if let Some(focus) = FocusScope::current() {
+                focus.attach(content.id());
+            }
+
             // Like shadcn/ui, DialogContent includes the portal and overlay
             Box::new(
                 Overlay::with_id(id).child(
@@ -319,26 +380,7 @@ impl IntoView for DialogContent {
                             .on_click_stop(move |_| {
                                 open.set(false);
                             }),
-                        // Content wrapper - centered modal with vertical stack for children
-                        floem::views::Stack::vertical_from_iter(children)
-                            .style(move |s| {
-                                s.absolute()
-                                    .left_1_2()
-                                    .top_1_2()
-                                    .translate_x_neg_1_2()
-                                    .translate_y_neg_1_2()
-                                    .z_index(10)
-                                    .max_w_lg()
-                                    .rounded_lg()
-                                    .p_6()
-                                    .gap_4()
-                                    .shadow_lg()
-                            })
-                            .style(move |s| {
-                                s.with_shadcn_theme(move |s, t| {
-                                    s.background(t.background).border_1().border_color(t.border)
-                                })
-                            }),
+                        content,
                     ))
                     .style(move |s| {
                         let is_open = open.get();
@@ -14,6 +14,13 @@
 //!     .side(DrawerSide::Bottom)
 //!     .content(drawer_content_view);
 //! ```
+//!
+//! While open, focus is trapped inside the drawer: the first focusable element
+//! is focused, Tab/Shift+Tab cycle within the drawer and Escape closes it
+//! (unless vetoed with `Drawer::on_escape_key_down`). Focus returns to the
+//! `DrawerTrigger` that opened it.
+
+use std::rc::Rc;
 
 use floem::prelude::*;
 use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
@@ -22,6 +29,7 @@ use floem::views::{Decorators, Overlay};
 use floem::{HasViewId, ViewId};
 use floem_tailwind::TailwindExt;
 
+use crate::components::focus_scope::{self, FocusScope};
 use crate::theme::ShadcnThemeExt;
 
 /// Side from which the drawer appears
@@ -44,6 +52,7 @@ pub struct Drawer<V> {
     is_open: RwSignal<bool>,
     side: DrawerSide,
     content: Option<V>,
+    on_escape: Option<Rc<dyn Fn() -> bool>>,
 }
 
 impl Drawer<()> {
@@ -54,6 +63,7 @@ impl Drawer<()> {
             is_open,
             side: DrawerSide::Bottom,
             content: None,
+            on_escape: None,
         }
     }
 }
@@ -72,8 +82,17 @@ impl<V> Drawer<V> {
             is_open: self.is_open,
             side: self.side,
             content: Some(content),
+            on_escape: self.on_escape,
         }
     }
+
+    /// Set a handler that runs when Escape is pressed while the drawer is open
+    ///
+    /// Return `false` to keep the drawer open.
+    pub fn on_escape_key_down(mut self, handler: impl Fn() -> bool + 'static) -> Self {
+        self.on_escape = Some(Rc::new(handler));
+        self
+    }
 }
 
 impl<V: IntoView + 'static> HasViewId for Drawer<V> {
@@ -98,30 +117,36 @@ impl<V: IntoView + 'static> IntoView for Drawer<V> {
         let handle = floem::views::Empty::new().style(move |s| {
             s.with_shadcn_theme(move |s, t| {
                 if side == DrawerSide::Bottom || side == DrawerSide::Top {
-                    s.width(100.0)
-                        .height(4.0)
+                    s.width(t.space(25.0))
+                        .height(t.space(1.0))
                         .background(t.muted_foreground)
                         .border_radius(2.0)
-                        .margin_top(8.0)
-                        .margin_bottom(8.0)
+                        .margin_vert(t.space(2.0))
                 } else {
                     s.display(floem::style::Display::None)
                 }
             })
         });
 
-        // Content container
+        // Content container - built in the focus scope so closers and inputs
+        // register with it
+        let focus = FocusScope::new(is_open);
+        focus.set_on_escape(self.on_escape);
         let content_view = if let Some(content) = self.content {
-            floem::views::Container::new(content).into_any()
+            focus
+                .provide()
+                .enter(move || floem::views::Container::new(content))
+                .into_any()
         } else {
             floem::views::Empty::new().into_any()
         };
 
-        // Drawer panel
+        // Drawer panel - focusable so Escape works without focusable children
         let drawer_panel = floem::views::Stack::vertical((handle, content_view)).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
                 let base = s
                     .absolute()
+                    .focusable(true)
                     .background(t.background)
                     .border(1.0)
                     .border_color(t.border)
@@ -164,6 +189,8 @@ impl<V: IntoView + 'static> IntoView for Drawer<V> {
             })
         });
 
+        focus.attach(drawer_panel.id());
+
         // Backdrop
         let backdrop = floem::views::Empty::new()
             .style(move |s| {
@@ -232,11 +259,14 @@ impl<V: IntoView + 'static> IntoView for DrawerTrigger<V> {
 
     fn into_view(self) -> Self::V {
         let is_open = self.is_open;
+        let id = self.id;
 
         Box::new(
-            floem::views::Container::with_id(self.id, self.child)
-                .style(|s| s.cursor(CursorStyle::Pointer))
+            floem::views::Container::with_id(id, self.child)
+                .style(|s| s.cursor(CursorStyle::Pointer).focusable(true))
                 .on_click_stop(move |_| {
+                    // Focus returns here when the drawer closes
+                    focus_scope::remember_opener(id);
                     is_open.set(true);
                 }),
         )
@@ -280,10 +310,12 @@ impl<V: IntoView + 'static> IntoView for DrawerContent<V> {
     fn into_view(self) -> Self::V {
         Box::new(
             floem::views::Container::with_id(self.id, self.child).style(|s| {
-                s.width_full()
-                    .padding(16.0)
-                    .display(floem::style::Display::Flex)
-                    .flex_direction(floem::style::FlexDirection::Column)
+                s.with_shadcn_theme(|s, t| {
+                    s.width_full()
+                        .padding(t.space(4.0))
+                        .display(floem::style::Display::Flex)
+                        .flex_direction(floem::style::FlexDirection::Column)
+                })
             }),
         )
     }
@@ -326,11 +358,13 @@ impl<V: IntoView + 'static> IntoView for DrawerHeader<V> {
     fn into_view(self) -> Self::V {
         Box::new(
             floem::views::Container::with_id(self.id, self.child).style(|s| {
-                s.width_full()
-                    .padding_bottom(16.0)
-                    .display(floem::style::Display::Flex)
-                    .flex_direction(floem::style::FlexDirection::Column)
-                    .items_center()
+                s.with_shadcn_theme(|s, t| {
+                    s.width_full()
+                        .padding_bottom(t.space(4.0))
+                        .display(floem::style::Display::Flex)
+                        .flex_direction(floem::style::FlexDirection::Column)
+                        .items_center()
+                })
             }),
         )
     }
@@ -375,7 +409,7 @@ impl IntoView for DrawerTitle {
 
         Box::new(floem::views::Label::with_id(self.id, text).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.font_size(18.0)
+                s.font_size(t.font_size.lg)
                     .font_weight(floem::text::Weight::SEMIBOLD)
                     .color(t.foreground)
             })
@@ -422,7 +456,9 @@ impl IntoView for DrawerDescription {
 
         Box::new(floem::views::Label::with_id(self.id, text).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.font_size(14.0).color(t.muted_foreground).margin_top(4.0)
+                s.font_size(t.font_size.sm)
+                    .color(t.muted_foreground)
+                    .margin_top(t.space(1.0))
             })
         }))
     }
@@ -465,11 +501,13 @@ impl<V: IntoView + 'static> IntoView for DrawerFooter<V> {
     fn into_view(self) -> Self::V {
         Box::new(
             floem::views::Container::with_id(self.id, self.child).style(|s| {
-                s.width_full()
-                    .padding_top(16.0)
-                    .display(floem::style::Display::Flex)
-                    .flex_direction(floem::style::FlexDirection::Column)
-                    .gap(8.0)
+                s.with_shadcn_theme(|s, t| {
+                    s.width_full()
+                        .padding_top(t.space(4.0))
+                        .display(floem::style::Display::Flex)
+                        .flex_direction(floem::style::FlexDirection::Column)
+                        .gap(t.space(2.0))
+                })
             }),
         )
     }
@@ -513,13 +551,16 @@ impl<V: IntoView + 'static> IntoView for DrawerClose<V> {
 
     fn into_view(self) -> Self::V {
         let is_open = self.is_open;
+        let close = move || is_open.set(false);
+
+        // Reachable with Tab inside the drawer, activated with Enter/Space
+        FocusScope::register_current(self.id);
+        focus_scope::activate_on_key(self.id, close);
 
         Box::new(
             floem::views::Container::with_id(self.id, self.child)
-                .style(|s| s.cursor(CursorStyle::Pointer))
-                .on_click_stop(move |_| {
-                    is_open.set(false);
-                }),
+                .style(|s| s.cursor(CursorStyle::Pointer).focusable(true))
+                .on_click_stop(move |_| close()),
         )
     }
 }
@@ -19,13 +19,24 @@
 //!         DropdownMenuItem::new("Delete").destructive(),
 //!     ));
 //! ```
+//!
+//! The trigger is focusable: Enter, Space or the arrow keys open the menu,
+//! the arrow keys, Home/End and typeahead move the highlight, Enter activates
+//! the highlighted item and Escape closes the menu.
+//!
+//! The menu opens below the trigger and flips above it when there is not
+//! enough room, see [`floating`](super::floating).
+
+use std::rc::Rc;
 
 use floem::prelude::*;
-use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
+use floem::reactive::{RwSignal, SignalGet};
 use floem::style::CursorStyle;
 use floem::views::Decorators;
 use floem::{HasViewId, ViewId};
 
+use super::floating::{Align, Floating, FloatingOptions, Side};
+use super::menu_nav::{MenuNav, MenuNavEntry};
 use crate::theme::ShadcnThemeExt;
 
 // ============================================================================
@@ -81,56 +92,62 @@ where
         let open = self.open;
         let trigger = self.trigger;
         let content = self.content;
+        let nav = MenuNav::new(open);
+        let floating = Floating::new(FloatingOptions::new(Side::Bottom, Align::Start));
 
-        // Trigger wrapper
+        // Trigger wrapper - focusable so the menu can be opened from the keyboard
         let trigger_view = if let Some(trigger_fn) = trigger {
-            floem::views::Container::new(trigger_fn())
-                .style(|s| s.cursor(CursorStyle::Pointer))
-                .on_click_stop(move |_| {
-                    open.update(|v| *v = !*v);
-                })
+            let container = floem::views::Container::new(trigger_fn())
+                .style(|s| s.cursor(CursorStyle::Pointer).focusable(true));
+            let trigger_id = container.id();
+            nav.attach(trigger_id);
+            floating
+                .anchor(container.on_click_stop(move |_| {
+                    trigger_id.request_focus();
+                    nav.toggle();
+                }))
                 .into_any()
         } else {
             floem::views::Empty::new().into_any()
         };
 
-        // Menu content
+        // Menu content - built in the menu's scope so items can register with it,
+        // and rendered in an Overlay so it is not clipped by the trigger's parents
         let content_view = if let Some(menu_content) = content {
-            floem::views::Container::new(menu_content)
+            let menu = nav
+                .provide()
+                .enter(move || floem::views::Container::new(menu_content))
                 .style(move |s| {
                     s.with_shadcn_theme(move |s, t| {
-                        let is_open = open.get();
-                        let base = s
+                        floating
+                            .position(s)
                             .min_width(180.0)
-                            .padding_top(4.0)
-                            .padding_bottom(4.0)
+                            .padding_vert(t.space(1.0))
                             .background(t.popover)
                             .border(1.0)
                             .border_color(t.border)
                             .border_radius(t.radius)
-                            .box_shadow_blur(8.0)
-                            .box_shadow_color(t.foreground.with_alpha(0.1))
-                            .position(floem::style::Position::Absolute)
-                            .inset_top_pct(100.0)
-                            .margin_top(4.0)
-                            .inset_left(0.0)
+                            .apply_shadow(t.shadow.md)
                             .z_index(50)
                             .display(floem::style::Display::Flex)
-                            .flex_direction(floem::style::FlexDirection::Column);
-                        if is_open {
-                            base
-                        } else {
-                            base.display(floem::style::Display::None)
-                        }
+                            .flex_direction(floem::style::FlexDirection::Column)
                     })
-                })
+                });
+
+            floem::views::Overlay::new()
+                .child(
+                    floem::views::Stack::new((floating.window_probe(), floating.content(menu)))
+                        .style(move |s| {
+                            let is_open = open.get();
+                            s.apply_if(!is_open, |s| s.hide())
+                        }),
+                )
                 .into_any()
         } else {
             floem::views::Empty::new().into_any()
         };
 
-        floem::views::Container::new(floem::views::Stack::new((trigger_view, content_view)))
-            .style(|s| s.position(floem::style::Position::Relative))
+        floem::views::Stack::new((trigger_view, content_view))
     }
 }
 
@@ -269,17 +286,17 @@ impl IntoView for DropdownMenuItem {
         let text = self.text;
         let disabled = self.disabled;
         let destructive = self.destructive;
-        let on_click = self.on_click;
+        let on_click: Option<Rc<dyn Fn()>> = self.on_click.map(Rc::from);
+        let entry = MenuNavEntry::register(text.clone(), disabled, on_click.clone());
 
         let label = floem::views::Label::new(text).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
+                let highlighted = entry.is_some_and(|e| e.is_highlighted());
                 let base = s
                     .width_full()
-                    .padding_left(12.0)
-                    .padding_right(12.0)
-                    .padding_top(8.0)
-                    .padding_bottom(8.0)
-                    .font_size(14.0)
+                    .padding_horiz(t.space(3.0))
+                    .padding_vert(t.space(2.0))
+                    .font_size(t.font_size.sm)
                     .cursor(if disabled {
                         CursorStyle::Default
                     } else {
@@ -295,17 +312,22 @@ impl IntoView for DropdownMenuItem {
                 if disabled {
                     colored
                 } else {
-                    colored.hover(|s| s.background(t.accent).color(t.accent_foreground))
+                    colored
+                        .apply_if(highlighted, |s| {
+                            s.background(t.accent).color(t.accent_foreground)
+                        })
+                        .hover(|s| s.background(t.accent).color(t.accent_foreground))
                 }
             })
         });
 
-        if let Some(handler) = on_click {
-            if !disabled {
-                Box::new(label.on_click_stop(move |_| handler()))
-            } else {
-                Box::new(label)
-            }
+        if disabled {
+            Box::new(label)
+        } else if let Some(entry) = entry {
+            // Runs the handler and closes the menu
+            Box::new(label.on_click_stop(move |_| entry.activate()))
+        } else if let Some(handler) = on_click {
+            Box::new(label.on_click_stop(move |_| handler()))
         } else {
             Box::new(label)
         }
@@ -364,16 +386,17 @@ impl<V: IntoView + 'static> IntoView for DropdownMenuItemCustom<V> {
 
     fn into_view(self) -> Self::V {
         let disabled = self.disabled;
-        let on_click = self.on_click;
+        let on_click: Option<Rc<dyn Fn()>> = self.on_click.map(Rc::from);
+        // Custom content has no text label, so it is skipped by typeahead
+        let entry = MenuNavEntry::register("", disabled, on_click.clone());
 
         let container = floem::views::Container::new(self.child).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
+                let highlighted = entry.is_some_and(|e| e.is_highlighted());
                 let base = s
                     .width_full()
-                    .padding_left(12.0)
-                    .padding_right(12.0)
-                    .padding_top(8.0)
-                    .padding_bottom(8.0)
+                    .padding_horiz(t.space(3.0))
+                    .padding_vert(t.space(2.0))
                     .cursor(if disabled {
                         CursorStyle::Default
                     } else {
@@ -382,17 +405,18 @@ impl<V: IntoView + 'static> IntoView for DropdownMenuItemCustom<V> {
                 if disabled {
                     base
                 } else {
-                    base.hover(|s| s.background(t.accent))
+                    base.apply_if(highlighted, |s| s.background(t.accent))
+                        .hover(|s| s.background(t.accent))
                 }
             })
         });
 
-        if let Some(handler) = on_click {
-            if !disabled {
-                Box::new(container.on_click_stop(move |_| handler()))
-            } else {
-                Box::new(container)
-            }
+        if disabled {
+            Box::new(container)
+        } else if let Some(entry) = entry {
+            Box::new(container.on_click_stop(move |_| entry.activate()))
+        } else if let Some(handler) = on_click {
+            Box::new(container.on_click_stop(move |_| handler()))
         } else {
             Box::new(container)
         }
@@ -486,11 +510,10 @@ impl IntoView for DropdownMenuLabel {
         Box::new(floem::views::Label::new(text).style(|s| {
             s.with_shadcn_theme(move |s, t| {
                 s.width_full()
-                    .padding_left(12.0)
-                    .padding_right(12.0)
-                    .padding_top(8.0)
-                    .padding_bottom(4.0)
-                    .font_size(12.0)
+                    .padding_horiz(t.space(3.0))
+                    .padding_top(t.space(2.0))
+                    .padding_bottom(t.space(1.0))
+                    .font_size(t.font_size.xs)
                     .font_weight(floem::text::Weight::SEMIBOLD)
                     .color(t.foreground)
             })
@@ -581,9 +604,9 @@ impl IntoView for DropdownMenuShortcut {
 
         Box::new(floem::views::Label::with_id(self.id, text).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.font_size(12.0)
+                s.font_size(t.font_size.xs)
                     .color(t.muted_foreground)
-                    .margin_left(16.0)
+                    .margin_left(t.space(4.0))
             })
         }))
     }
@@ -0,0 +1,390 @@
+//! Shared positioning for floating content
+//!
+//! `Popover`, `HoverCard`, tooltips, `DropdownMenu`, `Select`, `Combobox` and
+//! the date pickers render their content in an `Overlay` and place it next to
+//! the element that anchors it. [`compute_placement`] works out where the
+//! content goes:
+//!
+//! - The content sits `side_offset` pixels away from the preferred side of the anchor
+//! - If it does not fit there but fits better on the opposite side, it flips
+//! - It shifts along the anchor edge so it stays `collision_padding` pixels
+//!   inside the window
+//! - The arrow offset points at the center of the anchor, even after shifting
+//!
+//! [`Floating`] holds the measurements as signals. Attach the anchor with
+//! [`Floating::anchor`], the content with [`Floating::content`], add
+//! [`Floating::window_probe`] to the overlay, then style the content with
+//! [`Floating::position`].
+
+use floem::context::PaintCx;
+use floem::kurbo::{BezPath, Point, Rect, Size, Stroke};
+use floem::prelude::*;
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
+use floem::style::Style;
+use floem::views::Decorators;
+use floem::{Renderer, View, ViewId};
+
+use crate::theme::ShadcnThemeProp;
+
+/// Size of the arrow drawn by [`Floating::arrow`]
+pub const ARROW_SIZE: f64 = 10.0;
+
+/// Side of the anchor the content is placed on
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Side {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Side {
+    /// The side across the anchor from this one
+    pub fn opposite(self) -> Self {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+
+    /// Returns true for `Top` and `Bottom`
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Side::Top | Side::Bottom)
+    }
+}
+
+/// Alignment of the content along the anchor edge
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Align {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+/// Options for placing floating content
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FloatingOptions {
+    /// Preferred side of the anchor
+    pub side: Side,
+    /// Alignment along the anchor edge
+    pub align: Align,
+    /// Gap between the anchor and the content
+    pub side_offset: f64,
+    /// Offset along the anchor edge, applied after alignment
+    pub align_offset: f64,
+    /// Minimum distance between the content and the window edges
+    pub collision_padding: f64,
+    /// Flip and shift the content to keep it inside the window
+    pub avoid_collisions: bool,
+    /// Minimum distance between the arrow and the content corners
+    pub arrow_padding: f64,
+}
+
+impl Default for FloatingOptions {
+    fn default() -> Self {
+        Self {
+            side: Side::Bottom,
+            align: Align::Center,
+            side_offset: 4.0,
+            align_offset: 0.0,
+            collision_padding: 8.0,
+            avoid_collisions: true,
+            arrow_padding: 8.0,
+        }
+    }
+}
+
+impl FloatingOptions {
+    /// Create options for the given side and alignment
+    pub fn new(side: Side, align: Align) -> Self {
+        Self {
+            side,
+            align,
+            ..Self::default()
+        }
+    }
+
+    /// Set the gap between the anchor and the content
+    pub fn side_offset(mut self, offset: f64) -> Self {
+        self.side_offset = offset;
+        self
+    }
+
+    /// Set the offset along the anchor edge
+    pub fn align_offset(mut self, offset: f64) -> Self {
+        self.align_offset = offset;
+        self
+    }
+
+    /// Set the minimum distance to the window edges
+    pub fn collision_padding(mut self, padding: f64) -> Self {
+        self.collision_padding = padding;
+        self
+    }
+
+    /// Enable or disable flipping and shifting
+    pub fn avoid_collisions(mut self, avoid: bool) -> Self {
+        self.avoid_collisions = avoid;
+        self
+    }
+
+    /// Set the minimum distance between the arrow and the content corners
+    pub fn arrow_padding(mut self, padding: f64) -> Self {
+        self.arrow_padding = padding;
+        self
+    }
+}
+
+/// Where floating content ended up
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Placement {
+    /// Top-left corner of the content in window coordinates
+    pub origin: Point,
+    /// Side the content is on, after flipping
+    pub side: Side,
+    /// Distance from the content's leading edge to the arrow center
+    ///
+    /// Measured along x for `Top`/`Bottom` and along y for `Left`/`Right`.
+    pub arrow: f64,
+}
+
+/// Compute where to place content of size `content` next to `anchor`
+///
+/// `anchor` is in window coordinates. A zero `window` size means the window
+/// has not been measured yet, in which case the content is placed on the
+/// preferred side without collision handling.
+pub fn compute_placement(
+    anchor: Rect,
+    content: Size,
+    window: Size,
+    options: &FloatingOptions,
+) -> Placement {
+    let avoid = options.avoid_collisions && window.width > 0.0 && window.height > 0.0;
+    let padding = options.collision_padding;
+
+    let mut side = options.side;
+    if avoid {
+        let needed = if side.is_vertical() {
+            content.height
+        } else {
+            content.width
+        } + options.side_offset;
+        let space = available_space(anchor, window, padding, side);
+        let opposite = available_space(anchor, window, padding, side.opposite());
+        if needed > space && opposite > space {
+            side = side.opposite();
+        }
+    }
+
+    // Main axis: next to the anchor on the chosen side
+    let main = match side {
+        Side::Top => anchor.y0 - options.side_offset - content.height,
+        Side::Bottom => anchor.y1 + options.side_offset,
+        Side::Left => anchor.x0 - options.side_offset - content.width,
+        Side::Right => anchor.x1 + options.side_offset,
+    };
+
+    // Cross axis: aligned to the anchor edge, then shifted into the window
+    let (anchor_start, anchor_end, size, limit) = if side.is_vertical() {
+        (anchor.x0, anchor.x1, content.width, window.width)
+    } else {
+        (anchor.y0, anchor.y1, content.height, window.height)
+    };
+    let mut cross = match options.align {
+        Align::Start => anchor_start,
+        Align::Center => (anchor_start + anchor_end - size) / 2.0,
+        Align::End => anchor_end - size,
+    } + options.align_offset;
+    if avoid {
+        let max = (limit - padding - size).max(padding);
+        cross = cross.clamp(padding, max);
+    }
+
+    // Arrow: points at the anchor center, kept clear of the rounded corners
+    let target = (anchor_start + anchor_end) / 2.0 - cross;
+    let arrow = if size >= options.arrow_padding * 2.0 {
+        target.clamp(options.arrow_padding, size - options.arrow_padding)
+    } else {
+        size / 2.0
+    };
+
+    let origin = if side.is_vertical() {
+        Point::new(cross, main)
+    } else {
+        Point::new(main, cross)
+    };
+
+    Placement {
+        origin,
+        side,
+        arrow,
+    }
+}
+
+/// Space between the anchor and the window edge on `side`
+fn available_space(anchor: Rect, window: Size, padding: f64, side: Side) -> f64 {
+    match side {
+        Side::Top => anchor.y0 - padding,
+        Side::Bottom => window.height - padding - anchor.y1,
+        Side::Left => anchor.x0 - padding,
+        Side::Right => window.width - padding - anchor.x1,
+    }
+}
+
+// ============================================================================
+// Floating
+// ============================================================================
+
+/// Reactive measurements for one piece of floating content
+#[derive(Clone, Copy)]
+pub struct Floating {
+    anchor_origin: RwSignal<Point>,
+    anchor_size: RwSignal<Size>,
+    content_size: RwSignal<Size>,
+    window_size: RwSignal<Size>,
+    options: RwSignal<FloatingOptions>,
+}
+
+impl Floating {
+    /// Create positioning state with the given options
+    pub fn new(options: FloatingOptions) -> Self {
+        Self {
+            anchor_origin: RwSignal::new(Point::ZERO),
+            anchor_size: RwSignal::new(Size::ZERO),
+            content_size: RwSignal::new(Size::ZERO),
+            window_size: RwSignal::new(Size::ZERO),
+            options: RwSignal::new(options),
+        }
+    }
+
+    /// Signal holding the placement options
+    pub fn options(&self) -> RwSignal<FloatingOptions> {
+        self.options
+    }
+
+    /// Track the position and size of the anchor view
+    pub fn anchor<V: Decorators>(self, view: V) -> V {
+        view.on_move(move |origin| {
+            self.anchor_origin.set(origin);
+        })
+        .on_resize(move |rect| {
+            self.anchor_size.set(rect.size());
+        })
+    }
+
+    /// Track the size of the content view
+    pub fn content<V: Decorators>(self, view: V) -> V {
+        view.on_resize(move |rect| {
+            self.content_size.set(rect.size());
+        })
+    }
+
+    /// An invisible view that measures the window
+    ///
+    /// Add it to the overlay that holds the content.
+    pub fn window_probe(self) -> impl IntoView {
+        floem::views::Empty::new()
+            .style(|s| s.fixed().inset_0().pointer_events_none())
+            .on_resize(move |rect| {
+                self.window_size.set(rect.size());
+            })
+    }
+
+    /// The anchor rectangle in window coordinates
+    pub fn anchor_rect(&self) -> Rect {
+        Rect::from_origin_size(self.anchor_origin.get(), self.anchor_size.get())
+    }
+
+    /// The current placement of the content
+    pub fn placement(&self) -> Placement {
+        compute_placement(
+            self.anchor_rect(),
+            self.content_size.get(),
+            self.window_size.get(),
+            &self.options.get(),
+        )
+    }
+
+    /// Position the content absolutely at the current placement
+    pub fn position(&self, s: Style) -> Style {
+        let placement = self.placement();
+        s.absolute()
+            .inset_left(placement.origin.x)
+            .inset_top(placement.origin.y)
+    }
+
+    /// An arrow that points from the content at the anchor
+    ///
+    /// Add it as a child of the content; it is drawn in the gap on the side
+    /// facing the anchor, so the side offset should be at least [`ARROW_SIZE`].
+    pub fn arrow(self) -> impl IntoView {
+        Arrow {
+            id: ViewId::new(),
+            floating: self,
+        }
+        .style(move |s| {
+            let placement = self.placement();
+            let content = self.content_size.get();
+            let start = placement.arrow - ARROW_SIZE / 2.0;
+            let s = s.absolute().width(ARROW_SIZE).height(ARROW_SIZE);
+            match placement.side {
+                Side::Top => s.inset_left(start).inset_top(content.height),
+                Side::Bottom => s.inset_left(start).inset_top(-ARROW_SIZE),
+                Side::Left => s.inset_top(start).inset_left(content.width),
+                Side::Right => s.inset_top(start).inset_left(-ARROW_SIZE),
+            }
+        })
+    }
+}
+
+/// The view drawn by [`Floating::arrow`]
+///
+/// A triangle filled with the popover color, with a border along its two
+/// slanted edges. Its base overlaps the content border so the two join.
+struct Arrow {
+    id: ViewId,
+    floating: Floating,
+}
+
+impl View for Arrow {
+    fn id(&self) -> ViewId {
+        self.id
+    }
+
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let theme = self.id.get_combined_style().get(ShadcnThemeProp);
+        let side = self.floating.placement().side;
+
+        // Laid out pointing down, then turned to point at the anchor
+        let point = |x: f64, y: f64| match side {
+            Side::Top => Point::new(x, y),
+            Side::Bottom => Point::new(x, ARROW_SIZE - y),
+            Side::Left => Point::new(y, x),
+            Side::Right => Point::new(ARROW_SIZE - y, x),
+        };
+        let tip = point(ARROW_SIZE / 2.0, ARROW_SIZE);
+
+        let mut fill = BezPath::new();
+        fill.move_to(point(0.0, -1.0));
+        fill.line_to(tip);
+        fill.line_to(point(ARROW_SIZE, -1.0));
+        fill.close_path();
+        cx.fill(&fill, theme.popover, 0.0);
+
+        let mut edges = BezPath::new();
+        edges.move_to(point(0.0, 0.0));
+        edges.line_to(tip);
+        edges.line_to(point(ARROW_SIZE, 0.0));
+        cx.stroke(&edges, theme.border, &Stroke::new(1.0));
+    }
+}
+
+impl Default for Floating {
+    fn default() -> Self {
+        Self::new(FloatingOptions::default())
+    }
+}
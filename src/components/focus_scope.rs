@@ -0,0 +1,334 @@
+//! Shared focus management for modal overlays
+//!
+//! `Dialog`, `AlertDialog`, `Sheet` and `Drawer` use [`FocusScope`] so they
+//! behave the same way from the keyboard:
+//!
+//! - When the overlay opens, the first focusable element inside it is focused
+//!   (or the content panel itself when it has no focusable elements)
+//! - Tab and Shift+Tab cycle through the focusable elements and never leave
+//!   the overlay
+//! - Escape closes the overlay, unless an `on_escape` callback vetoes it
+//! - When the overlay closes, focus returns to the element that opened it
+//!
+//! Tab order is every focusable, visible view inside the content panel, in
+//! tree order, so any control placed in the content takes part. Elements that
+//! aren't styled `focusable` can still join by registering through the
+//! reactive `Context`, when built inside the scope returned by
+//! [`FocusScope::provide`]. Triggers call [`remember_opener`] right before
+//! opening the overlay.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use floem::ViewId;
+use floem::event::{Event, EventListener, EventPropagation};
+use floem::reactive::{Context, Effect, RwSignal, Scope, SignalGet, SignalUpdate, SignalWith};
+use floem::style::{Display, DisplayProp, Focusable};
+use ui_events::keyboard::{Key, KeyState, KeyboardEvent, Modifiers, NamedKey};
+
+thread_local! {
+    /// The trigger that is about to open an overlay, claimed by the next scope that opens
+    static PENDING_OPENER: Cell<Option<ViewId>> = const { Cell::new(None) };
+}
+
+/// Record the view that is opening an overlay
+///
+/// The next [`FocusScope`] that opens returns focus to this view when it
+/// closes. Call this from a trigger right before setting the open signal.
+pub fn remember_opener(opener: ViewId) {
+    PENDING_OPENER.with(|pending| pending.set(Some(opener)));
+}
+
+/// Focus state for a single modal overlay
+#[derive(Clone, Copy)]
+pub struct FocusScope {
+    /// Whether the overlay is open
+    pub open: RwSignal<bool>,
+    items: RwSignal<Vec<ViewId>>,
+    /// Views with a listener that reports focus back to the scope
+    watched: RwSignal<Vec<ViewId>>,
+    focused: RwSignal<Option<ViewId>>,
+    container: RwSignal<Option<ViewId>>,
+    opener: RwSignal<Option<ViewId>>,
+    on_escape: RwSignal<Option<Rc<dyn Fn() -> bool>>>,
+}
+
+impl FocusScope {
+    /// Create focus state for an overlay controlled by `open`
+    pub fn new(open: RwSignal<bool>) -> Self {
+        Self {
+            open,
+            items: RwSignal::new(Vec::new()),
+            watched: RwSignal::new(Vec::new()),
+            focused: RwSignal::new(None),
+            container: RwSignal::new(None),
+            opener: RwSignal::new(None),
+            on_escape: RwSignal::new(None),
+        }
+    }
+
+    /// Get the focus scope of the enclosing overlay, if any
+    pub fn current() -> Option<Self> {
+        Context::get::<FocusScope>()
+    }
+
+    /// Create a child scope that provides this focus state to the content
+    pub fn provide(self) -> Scope {
+        let scope = Scope::current().create_child();
+        scope.provide_context(self);
+        scope
+    }
+
+    /// Register a focusable element and return its registration index
+    ///
+    /// Registered elements are part of the tab order even when they aren't
+    /// styled `focusable`. Until the scope is attached to a content panel,
+    /// tab order follows registration order.
+    pub fn register(&self, id: ViewId) -> usize {
+        let mut index = 0;
+        self.items.update(|items| {
+            index = items.len();
+            items.push(id);
+        });
+        self.watch(id);
+        index
+    }
+
+    /// Register `id` with the focus scope in the current `Context`, if any
+    pub fn register_current(id: ViewId) {
+        if let Some(scope) = Self::current() {
+            scope.register(id);
+        }
+    }
+
+    /// The focusable elements in tab order
+    ///
+    /// Once attached, these are the visible descendants of the content panel
+    /// that are focusable or registered. The children of such an element are
+    /// not visited, so a wrapper like `DialogClose` around a `Button` is a
+    /// single stop.
+    pub fn tab_order(&self) -> Vec<ViewId> {
+        let items = self.items.get_untracked();
+        let Some(container) = self.container.get_untracked() else {
+            return items;
+        };
+        let mut order = Vec::new();
+        collect_focusable(container, &items, &mut order);
+        order
+    }
+
+    /// Number of focusable elements
+    pub fn len(&self) -> usize {
+        self.tab_order().len()
+    }
+
+    /// Returns true if there are no focusable elements
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Index in the tab order of the focused element, if focus is on one
+    pub fn focused_index(&self) -> Option<usize> {
+        let focused = self.focused.get_untracked()?;
+        self.tab_order().iter().position(|id| *id == focused)
+    }
+
+    /// Set the callback that runs when Escape is pressed
+    ///
+    /// Return `false` from the callback to keep the overlay open.
+    pub fn set_on_escape(&self, on_escape: Option<Rc<dyn Fn() -> bool>>) {
+        self.on_escape.set(on_escape);
+    }
+
+    /// Set the view that receives focus back when the overlay closes
+    pub fn set_opener(&self, opener: ViewId) {
+        self.opener.set(Some(opener));
+    }
+
+    /// Attach keyboard handling to the content panel
+    ///
+    /// The panel should be focusable; it receives focus when the overlay has
+    /// no focusable elements. Focus moves into the overlay whenever it opens
+    /// and back to the opener whenever it closes.
+    pub fn attach(&self, container: ViewId) {
+        let scope = *self;
+        self.container.set(Some(container));
+        container.add_event_listener(
+            EventListener::KeyDown,
+            Box::new(move |event| {
+                let Event::Key(KeyboardEvent {
+                    state: KeyState::Down,
+                    key,
+                    modifiers,
+                    ..
+                }) = event
+                else {
+                    return EventPropagation::Continue;
+                };
+
+                if scope.handle_key(key, modifiers) {
+                    EventPropagation::Stop
+                } else {
+                    EventPropagation::Continue
+                }
+            }),
+        );
+
+        Effect::new(move |was_open: Option<bool>| {
+            let is_open = scope.open.get();
+            if is_open && was_open != Some(true) {
+                scope.on_open();
+            } else if !is_open && was_open == Some(true) {
+                scope.restore_focus();
+            }
+            is_open
+        });
+    }
+
+    /// Handle a key press inside the overlay
+    ///
+    /// Returns true if the key was consumed.
+    pub fn handle_key(&self, key: &Key, modifiers: &Modifiers) -> bool {
+        if !self.open.get_untracked() {
+            return false;
+        }
+        match key {
+            Key::Named(NamedKey::Escape) => {
+                self.escape();
+                true
+            }
+            Key::Named(NamedKey::Tab) => {
+                self.move_focus(!modifiers.shift());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Close the overlay unless the `on_escape` callback vetoes it
+    pub fn escape(&self) {
+        let on_escape = self.on_escape.get_untracked();
+        if on_escape.is_none_or(|f| f()) {
+            self.open.set(false);
+        }
+    }
+
+    /// Move focus to the first focusable element, or to the content panel
+    pub fn focus_first(&self) {
+        let order = self.tab_order();
+        for &id in &order {
+            self.watch(id);
+        }
+        match order.first() {
+            Some(&first) => self.focus_item(first),
+            None => {
+                self.focused.set(None);
+                if let Some(container) = self.container.get_untracked() {
+                    container.request_focus();
+                }
+            }
+        }
+    }
+
+    /// Move focus to the next (or previous) element, wrapping at the ends
+    pub fn move_focus(&self, forward: bool) {
+        let order = self.tab_order();
+        let len = order.len();
+        if len == 0 {
+            self.focus_first();
+            return;
+        }
+        let current = self
+            .focused
+            .get_untracked()
+            .and_then(|focused| order.iter().position(|id| *id == focused));
+        let next = match current {
+            Some(current) if forward => (current + 1) % len,
+            Some(current) => (current + len - 1) % len,
+            None if forward => 0,
+            None => len - 1,
+        };
+        self.focus_item(order[next]);
+    }
+
+    /// Return focus to the view that opened the overlay
+    pub fn restore_focus(&self) {
+        self.focused.set(None);
+        if let Some(opener) = self.opener.get_untracked() {
+            opener.request_focus();
+        }
+    }
+
+    fn on_open(&self) {
+        if let Some(opener) = PENDING_OPENER.with(|pending| pending.take()) {
+            self.opener.set(Some(opener));
+        }
+        self.focus_first();
+    }
+
+    fn focus_item(&self, id: ViewId) {
+        self.watch(id);
+        self.focused.set(Some(id));
+        id.request_focus();
+    }
+
+    /// Keep track of focus moving to `id` by other means, such as a click
+    fn watch(&self, id: ViewId) {
+        if self.watched.with_untracked(|watched| watched.contains(&id)) {
+            return;
+        }
+        self.watched.update(|watched| watched.push(id));
+
+        let focused = self.focused;
+        id.add_event_listener(
+            EventListener::FocusGained,
+            Box::new(move |_| {
+                focused.set(Some(id));
+                EventPropagation::Continue
+            }),
+        );
+    }
+}
+
+/// Collect the focusable or registered descendants of `parent` in tree
+/// order, skipping hidden subtrees
+fn collect_focusable(parent: ViewId, registered: &[ViewId], order: &mut Vec<ViewId>) {
+    for child in parent.children() {
+        let style = child.get_combined_style();
+        if style.get(DisplayProp) == Display::None {
+            continue;
+        }
+        if registered.contains(&child) || style.get(Focusable) {
+            order.push(child);
+        } else {
+            collect_focusable(child, registered, order);
+        }
+    }
+}
+
+/// Run `on_activate` when Enter or Space is pressed on the focused view
+///
+/// Used by buttons inside overlays so they can be activated after tabbing to them.
+pub fn activate_on_key(id: ViewId, on_activate: impl Fn() + 'static) {
+    id.add_event_listener(
+        EventListener::KeyDown,
+        Box::new(move |event| {
+            let Event::Key(KeyboardEvent {
+                state: KeyState::Down,
+                key,
+                ..
+            }) = event
+            else {
+                return EventPropagation::Continue;
+            };
+
+            match key {
+                Key::Named(NamedKey::Enter) => {}
+                Key::Character(c) if c == " " => {}
+                _ => return EventPropagation::Continue,
+            }
+            on_activate();
+            EventPropagation::Stop
+        }),
+    );
+}
@@ -0,0 +1,587 @@
+//! Form state and validation
+//!
+//! Based on shadcn/ui Form, which wraps react-hook-form. A [`Form`] tracks a
+//! set of named fields, validates them and only runs the submit callback once
+//! every field is valid.
+//!
+//! Fields are registered with the signal that holds their value, so any
+//! component that writes a signal works: `Input`, `Textarea`, `Select`,
+//! `Checkbox`, `Switch`, `Slider` and `DatePicker`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use floem::reactive::RwSignal;
+//! use floem_shadcn::components::form::{Form, Rules, ValidateOn};
+//! use floem_shadcn::components::label::{FormField, FormLabel};
+//!
+//! let username = RwSignal::new(String::new());
+//! let terms = RwSignal::new(false);
+//!
+//! let form = Form::new().mode(ValidateOn::Touched);
+//! let username_field = form.register(
+//!     "username",
+//!     username,
+//!     Rules::new()
+//!         .required("Username is required")
+//!         .min_length(2, "Username must be at least 2 characters."),
+//! );
+//! form.register("terms", terms, Rules::new().required("You must accept the terms"));
+//! form.on_submit(move || println!("Submitted {}", username.get()));
+//!
+//! FormField::new(
+//!     FormLabel::new("Username").field(username_field),
+//!     username_field.bind(
+//!         Input::new()
+//!             .value(move || username.get())
+//!             .on_update(move |s| username.set(s.to_string())),
+//!     ),
+//! )
+//! .field(username_field);
+//!
+//! Button::new("Submit").on_click_stop(move |_| form.submit());
+//! ```
+//!
+//! # Async validation
+//!
+//! Async validators receive the value and an [`AsyncValidation`] handle and
+//! call [`AsyncValidation::resolve`] when they are done. The field reports
+//! `validating` until then, and a submit waits for it. Results that arrive
+//! after the value changed again are ignored. `resolve` must run on the UI
+//! thread; forward results from a worker thread with
+//! `floem::ext_event::create_ext_action`.
+
+use std::rc::Rc;
+
+use floem::event::{EventListener, EventPropagation};
+use floem::reactive::{Effect, RwSignal, SignalGet, SignalUpdate, SignalWith};
+use floem::{HasViewId, ViewId};
+
+/// When fields are validated, like react-hook-form's `mode`
+///
+/// After the first submit every field is also re-validated on change.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidateOn {
+    /// Validate on every change
+    Change,
+    /// Validate when the field loses focus
+    Blur,
+    /// Validate on the first blur, then on every change
+    Touched,
+    /// Validate only on submit
+    #[default]
+    Submit,
+}
+
+/// A value that can be held by a form field
+pub trait FormValue: Clone + PartialEq + 'static {
+    /// Whether the value counts as missing for [`Rules::required`]
+    fn is_blank(&self) -> bool;
+}
+
+impl FormValue for String {
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+/// An unchecked checkbox or switch counts as blank
+impl FormValue for bool {
+    fn is_blank(&self) -> bool {
+        !*self
+    }
+}
+
+impl FormValue for f64 {
+    fn is_blank(&self) -> bool {
+        self.is_nan()
+    }
+}
+
+/// Nothing selected counts as blank (`Select`, `Combobox`, `DatePicker`)
+impl<V: Clone + PartialEq + 'static> FormValue for Option<V> {
+    fn is_blank(&self) -> bool {
+        self.is_none()
+    }
+}
+
+// ============================================================================
+// Rules
+// ============================================================================
+
+type SyncValidator<T> = Rc<dyn Fn(&T) -> Result<(), String>>;
+type AsyncValidator<T> = Rc<dyn Fn(T, AsyncValidation)>;
+
+/// Validation rules for a field
+///
+/// Sync rules run in order and stop at the first error. Async rules only run
+/// once every sync rule passes.
+pub struct Rules<T> {
+    sync: Vec<SyncValidator<T>>,
+    asynchronous: Vec<AsyncValidator<T>>,
+}
+
+impl<T: FormValue> Rules<T> {
+    /// Create an empty rule set
+    pub fn new() -> Self {
+        Self {
+            sync: Vec::new(),
+            asynchronous: Vec::new(),
+        }
+    }
+
+    /// Require a value, see [`FormValue::is_blank`]
+    pub fn required(self, message: impl Into<String>) -> Self {
+        let message = message.into();
+        self.validate(move |value: &T| {
+            if value.is_blank() {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Add a sync validator that returns the error message on failure
+    pub fn validate(mut self, validator: impl Fn(&T) -> Result<(), String> + 'static) -> Self {
+        self.sync.push(Rc::new(validator));
+        self
+    }
+
+    /// Add an async validator
+    ///
+    /// The validator must eventually call [`AsyncValidation::resolve`].
+    pub fn validate_async(mut self, validator: impl Fn(T, AsyncValidation) + 'static) -> Self {
+        self.asynchronous.push(Rc::new(validator));
+        self
+    }
+}
+
+impl Rules<String> {
+    /// Require at least `min` characters
+    pub fn min_length(self, min: usize, message: impl Into<String>) -> Self {
+        let message = message.into();
+        self.validate(move |value: &String| {
+            if value.chars().count() < min {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Allow at most `max` characters
+    pub fn max_length(self, max: usize, message: impl Into<String>) -> Self {
+        let message = message.into();
+        self.validate(move |value: &String| {
+            if value.chars().count() > max {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+}
+
+impl<T: FormValue> Default for Rules<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Handle passed to async validators to report their result
+pub struct AsyncValidation {
+    field: FieldState,
+    generation: u64,
+}
+
+impl AsyncValidation {
+    /// Report the result of the validation
+    ///
+    /// Ignored if the field was validated again in the meantime.
+    pub fn resolve(self, result: Result<(), String>) {
+        let field = self.field;
+        if field.generation.get_untracked() != self.generation {
+            return;
+        }
+        match result {
+            Ok(()) => {
+                field
+                    .pending
+                    .update(|pending| *pending = pending.saturating_sub(1));
+                if field.pending.get_untracked() == 0 {
+                    field.validating.set(false);
+                }
+            }
+            Err(message) => {
+                // The first failure decides; later results are stale
+                field.generation.update(|g| *g += 1);
+                field.pending.set(0);
+                field.error.set(Some(message));
+                field.validating.set(false);
+            }
+        }
+        field.form.finish_pending_submit();
+    }
+}
+
+// ============================================================================
+// FieldState
+// ============================================================================
+
+/// Reactive state of one registered field
+#[derive(Clone, Copy)]
+pub struct FieldState {
+    form: Form,
+    index: usize,
+    error: RwSignal<Option<String>>,
+    dirty: RwSignal<bool>,
+    touched: RwSignal<bool>,
+    validating: RwSignal<bool>,
+    generation: RwSignal<u64>,
+    pending: RwSignal<usize>,
+}
+
+impl FieldState {
+    /// The current error message, if any
+    pub fn error(&self) -> Option<String> {
+        self.error.get()
+    }
+
+    /// Returns true if the value differs from the initial value
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.get()
+    }
+
+    /// Returns true once the field has lost focus (or the form was submitted)
+    pub fn is_touched(&self) -> bool {
+        self.touched.get()
+    }
+
+    /// Returns true while async validators are running
+    pub fn is_validating(&self) -> bool {
+        self.validating.get()
+    }
+
+    /// Returns true if the field has no error and nothing is pending
+    pub fn is_valid(&self) -> bool {
+        self.error.with(|error| error.is_none()) && !self.validating.get()
+    }
+
+    /// Set or clear the error by hand, e.g. from a server response
+    pub fn set_error(&self, error: Option<String>) {
+        self.error.set(error);
+    }
+
+    /// Run the field's validators now
+    pub fn validate(&self) {
+        self.form.validate_field(self.index);
+    }
+
+    /// Mark the field as touched, validating it if the form mode asks for it
+    pub fn blur(&self) {
+        self.touched.set(true);
+        if matches!(
+            self.form.mode.get_untracked(),
+            ValidateOn::Blur | ValidateOn::Touched
+        ) {
+            self.validate();
+        }
+    }
+
+    /// Call [`blur`](Self::blur) when `view` loses focus
+    pub fn bind<V: HasViewId>(&self, view: V) -> V {
+        self.attach(view.view_id());
+        view
+    }
+
+    /// Call [`blur`](Self::blur) when the view `id` loses focus
+    pub fn attach(&self, id: ViewId) {
+        let field = *self;
+        id.add_event_listener(
+            EventListener::FocusLost,
+            Box::new(move |_| {
+                field.blur();
+                EventPropagation::Continue
+            }),
+        );
+    }
+
+    fn is_valid_untracked(&self) -> bool {
+        self.error.with_untracked(|error| error.is_none()) && !self.validating.get_untracked()
+    }
+
+    fn on_change(&self) {
+        // Editing after a submit that was waiting on async validators cancels it
+        self.form.submit_pending.set(false);
+        let mode = self.form.mode.get_untracked();
+        let revalidate = match mode {
+            ValidateOn::Change => true,
+            ValidateOn::Touched => self.touched.get_untracked(),
+            ValidateOn::Blur | ValidateOn::Submit => false,
+        };
+        if revalidate || self.form.submit_count.get_untracked() > 0 {
+            self.validate();
+        }
+    }
+}
+
+// ============================================================================
+// Form
+// ============================================================================
+
+#[derive(Clone)]
+struct FieldEntry {
+    name: String,
+    state: FieldState,
+    validate: Rc<dyn Fn()>,
+    reset: Rc<dyn Fn()>,
+}
+
+/// Form model that owns the validation state of its fields
+#[derive(Clone, Copy)]
+pub struct Form {
+    fields: RwSignal<Vec<FieldEntry>>,
+    mode: RwSignal<ValidateOn>,
+    submit_count: RwSignal<usize>,
+    submit_pending: RwSignal<bool>,
+    on_submit: RwSignal<Option<Rc<dyn Fn()>>>,
+}
+
+impl Form {
+    /// Create a form that validates on submit
+    pub fn new() -> Self {
+        Self {
+            fields: RwSignal::new(Vec::new()),
+            mode: RwSignal::new(ValidateOn::Submit),
+            submit_count: RwSignal::new(0),
+            submit_pending: RwSignal::new(false),
+            on_submit: RwSignal::new(None),
+        }
+    }
+
+    /// Set when fields are validated
+    pub fn mode(self, mode: ValidateOn) -> Self {
+        self.mode.set(mode);
+        self
+    }
+
+    /// Set the callback that runs when a submit finds every field valid
+    pub fn on_submit(&self, on_submit: impl Fn() + 'static) {
+        self.on_submit.set(Some(Rc::new(on_submit)));
+    }
+
+    /// Register a field by name
+    ///
+    /// `value` is the signal the field's component writes. Its current value
+    /// is the initial value used for dirty tracking and [`reset`](Self::reset).
+    pub fn register<T: FormValue>(
+        &self,
+        name: impl Into<String>,
+        value: RwSignal<T>,
+        rules: Rules<T>,
+    ) -> FieldState {
+        let state = FieldState {
+            form: *self,
+            index: self.fields.with_untracked(|fields| fields.len()),
+            error: RwSignal::new(None),
+            dirty: RwSignal::new(false),
+            touched: RwSignal::new(false),
+            validating: RwSignal::new(false),
+            generation: RwSignal::new(0),
+            pending: RwSignal::new(0),
+        };
+        let initial = value.get_untracked();
+
+        let rules = Rc::new(rules);
+        let validate = Rc::new(move || {
+            let current = value.get_untracked();
+            state.generation.update(|g| *g += 1);
+            state.pending.set(0);
+
+            if let Some(message) = rules.sync.iter().find_map(|rule| rule(&current).err()) {
+                state.error.set(Some(message));
+                state.validating.set(false);
+                return;
+            }
+            state.error.set(None);
+            if rules.asynchronous.is_empty() {
+                state.validating.set(false);
+                return;
+            }
+
+            let generation = state.generation.get_untracked();
+            state.pending.set(rules.asynchronous.len());
+            state.validating.set(true);
+            for rule in rules.asynchronous.iter() {
+                rule(
+                    current.clone(),
+                    AsyncValidation {
+                        field: state,
+                        generation,
+                    },
+                );
+            }
+        });
+
+        let initial_for_reset = initial.clone();
+        let reset = Rc::new(move || {
+            value.set(initial_for_reset.clone());
+            state.generation.update(|g| *g += 1);
+            state.pending.set(0);
+            state.validating.set(false);
+            state.dirty.set(false);
+            state.touched.set(false);
+            state.error.set(None);
+        });
+
+        self.fields.update(|fields| {
+            fields.push(FieldEntry {
+                name: name.into(),
+                state,
+                validate,
+                reset,
+            })
+        });
+
+        // Track changes made by the field's component
+        Effect::new(move |prev: Option<()>| {
+            let is_dirty = value.with(|current| *current != initial);
+            if prev.is_some() {
+                state.dirty.set(is_dirty);
+                state.on_change();
+            }
+        });
+
+        state
+    }
+
+    /// Get the state of a field by name
+    pub fn field(&self, name: &str) -> Option<FieldState> {
+        self.fields.with_untracked(|fields| {
+            fields
+                .iter()
+                .find(|entry| entry.name == name)
+                .map(|entry| entry.state)
+        })
+    }
+
+    /// The error of a field by name, if any
+    pub fn error(&self, name: &str) -> Option<String> {
+        self.field(name).and_then(|field| field.error())
+    }
+
+    /// All current errors as `(name, message)` pairs, in registration order
+    pub fn errors(&self) -> Vec<(String, String)> {
+        let fields = self.fields.get();
+        fields
+            .iter()
+            .filter_map(|entry| Some((entry.name.clone(), entry.state.error()?)))
+            .collect()
+    }
+
+    /// Returns true if no field has an error or a pending async validation
+    pub fn is_valid(&self) -> bool {
+        let fields = self.fields.get();
+        fields.iter().all(|entry| entry.state.is_valid())
+    }
+
+    /// Returns true if any field differs from its initial value
+    pub fn is_dirty(&self) -> bool {
+        let fields = self.fields.get();
+        fields.iter().any(|entry| entry.state.is_dirty())
+    }
+
+    /// Returns true while a submit waits for async validators
+    pub fn is_submitting(&self) -> bool {
+        self.submit_pending.get()
+    }
+
+    /// Number of submit attempts since the form was created or reset
+    pub fn submit_count(&self) -> usize {
+        self.submit_count.get()
+    }
+
+    /// Validate every field and return whether the form is valid right now
+    ///
+    /// Fields with async validators count as invalid until they resolve.
+    pub fn validate(&self) -> bool {
+        let fields = self.fields.get_untracked();
+        for entry in fields.iter() {
+            (entry.validate)();
+        }
+        fields.iter().all(|entry| entry.state.is_valid_untracked())
+    }
+
+    /// Touch and validate every field, then run `on_submit` if the form is valid
+    ///
+    /// If async validators are still running, the submit completes once they
+    /// all pass.
+    pub fn submit(&self) {
+        self.submit_count.update(|count| *count += 1);
+        let fields = self.fields.get_untracked();
+        for entry in fields.iter() {
+            entry.state.touched.set(true);
+        }
+
+        if self.validate() {
+            self.submit_pending.set(false);
+            self.run_on_submit();
+        } else {
+            let pending = fields
+                .iter()
+                .any(|entry| entry.state.validating.get_untracked());
+            self.submit_pending.set(pending);
+        }
+    }
+
+    /// Restore every field to its initial value and clear all state
+    pub fn reset(&self) {
+        self.submit_count.set(0);
+        self.submit_pending.set(false);
+        let fields = self.fields.get_untracked();
+        for entry in fields.iter() {
+            (entry.reset)();
+        }
+    }
+
+    fn validate_field(&self, index: usize) {
+        let validate = self
+            .fields
+            .with_untracked(|fields| fields.get(index).map(|entry| entry.validate.clone()));
+        if let Some(validate) = validate {
+            validate();
+        }
+        self.finish_pending_submit();
+    }
+
+    /// Complete a submit that was waiting for async validators
+    fn finish_pending_submit(&self) {
+        if !self.submit_pending.get_untracked() {
+            return;
+        }
+        let fields = self.fields.get_untracked();
+        if fields
+            .iter()
+            .any(|entry| entry.state.validating.get_untracked())
+        {
+            return;
+        }
+        self.submit_pending.set(false);
+        if fields.iter().all(|entry| entry.state.is_valid_untracked()) {
+            self.run_on_submit();
+        }
+    }
+
+    fn run_on_submit(&self) {
+        if let Some(on_submit) = self.on_submit.get_untracked() {
+            on_submit();
+        }
+    }
+}
+
+impl Default for Form {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -23,6 +23,7 @@ use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
 use floem::views::Decorators;
 use floem::{HasViewId, ViewId};
 
+use crate::components::floating::{Align, Floating, FloatingOptions, Side};
 use crate::theme::ShadcnThemeExt;
 
 /// Side where the hover card appears
@@ -44,6 +45,27 @@ pub enum HoverCardAlign {
     End,
 }
 
+impl From<HoverCardSide> for Side {
+    fn from(side: HoverCardSide) -> Self {
+        match side {
+            HoverCardSide::Top => Side::Top,
+            HoverCardSide::Bottom => Side::Bottom,
+            HoverCardSide::Left => Side::Left,
+            HoverCardSide::Right => Side::Right,
+        }
+    }
+}
+
+impl From<HoverCardAlign> for Align {
+    fn from(align: HoverCardAlign) -> Self {
+        match align {
+            HoverCardAlign::Start => Align::Start,
+            HoverCardAlign::Center => Align::Center,
+            HoverCardAlign::End => Align::End,
+        }
+    }
+}
+
 // ============================================================================
 // HoverCard
 // ============================================================================
@@ -54,6 +76,7 @@ pub struct HoverCard<T, C> {
     content: Option<C>,
     side: HoverCardSide,
     align: HoverCardAlign,
+    side_offset: f64,
 }
 
 impl HoverCard<(), ()> {
@@ -64,6 +87,7 @@ impl HoverCard<(), ()> {
             content: None,
             side: HoverCardSide::Bottom,
             align: HoverCardAlign::Center,
+            side_offset: 4.0,
         }
     }
 }
@@ -82,6 +106,7 @@ impl<T, C> HoverCard<T, C> {
             content: self.content,
             side: self.side,
             align: self.align,
+            side_offset: self.side_offset,
         }
     }
 
@@ -92,10 +117,13 @@ impl<T, C> HoverCard<T, C> {
             content: Some(content),
             side: self.side,
             align: self.align,
+            side_offset: self.side_offset,
         }
     }
 
     /// Set which side the card appears on
+    ///
+    /// The card flips to the opposite side if there is not enough room.
     pub fn side(mut self, side: HoverCardSide) -> Self {
         self.side = side;
         self
@@ -106,6 +134,12 @@ impl<T, C> HoverCard<T, C> {
         self.align = align;
         self
     }
+
+    /// Set the gap between the trigger and the card (default 4px)
+    pub fn side_offset(mut self, offset: f64) -> Self {
+        self.side_offset = offset;
+        self
+    }
 }
 
 impl<T, C, TV, CV> HoverCard<T, C>
@@ -119,78 +153,45 @@ where
     pub fn build(self) -> impl IntoView {
         let trigger = self.trigger;
         let content = self.content;
-        let side = self.side;
-        let align = self.align;
+        let floating = Floating::new(
+            FloatingOptions::new(self.side.into(), self.align.into()).side_offset(self.side_offset),
+        );
 
         // Hover state
         let is_hovered = RwSignal::new(false);
 
         // Trigger wrapper
         let trigger_view = if let Some(trigger_fn) = trigger {
-            floem::views::Container::new(trigger_fn())
-                .on_event_stop(floem::event::EventListener::PointerEnter, move |_| {
-                    is_hovered.set(true);
-                })
-                .on_event_stop(floem::event::EventListener::PointerLeave, move |_| {
-                    is_hovered.set(false);
-                })
+            floating
+                .anchor(
+                    floem::views::Container::new(trigger_fn())
+                        .on_event_stop(floem::event::EventListener::PointerEnter, move |_| {
+                            is_hovered.set(true);
+                        })
+                        .on_event_stop(floem::event::EventListener::PointerLeave, move |_| {
+                            is_hovered.set(false);
+                        }),
+                )
                 .into_any()
         } else {
             floem::views::Empty::new().into_any()
         };
 
-        // Content card
+        // Content card in an Overlay, positioned next to the trigger
         let content_view = if let Some(content_fn) = content {
-            floem::views::Container::new(content_fn())
+            let card = floem::views::Container::new(content_fn())
                 .style(move |s| {
                     s.with_shadcn_theme(move |s, t| {
-                        let hovered = is_hovered.get();
-                        let base = s
+                        floating
+                            .position(s)
                             .padding(16.0)
                             .min_width(200.0)
                             .background(t.popover)
                             .border(1.0)
                             .border_color(t.border)
                             .border_radius(t.radius)
-                            .box_shadow_blur(8.0)
-                            .box_shadow_color(t.foreground.with_alpha(0.1))
-                            .position(floem::style::Position::Absolute)
-                            .z_index(50);
-                        // Position based on side
-                        let positioned = match side {
-                            HoverCardSide::Top => base.inset_bottom_pct(100.0).margin_bottom(8.0),
-                            HoverCardSide::Bottom => base.inset_top_pct(100.0).margin_top(8.0),
-                            HoverCardSide::Left => base.inset_right_pct(100.0).margin_right(8.0),
-                            HoverCardSide::Right => base.inset_left_pct(100.0).margin_left(8.0),
-                        };
-                        // Alignment
-                        let aligned = match (side, align) {
-                            (HoverCardSide::Top | HoverCardSide::Bottom, HoverCardAlign::Start) => {
-                                positioned.inset_left(0.0)
-                            }
-                            (
-                                HoverCardSide::Top | HoverCardSide::Bottom,
-                                HoverCardAlign::Center,
-                            ) => positioned.inset_left_pct(50.0).margin_left(-100.0),
-                            (HoverCardSide::Top | HoverCardSide::Bottom, HoverCardAlign::End) => {
-                                positioned.inset_right(0.0)
-                            }
-                            (HoverCardSide::Left | HoverCardSide::Right, HoverCardAlign::Start) => {
-                                positioned.inset_top(0.0)
-                            }
-                            (
-                                HoverCardSide::Left | HoverCardSide::Right,
-                                HoverCardAlign::Center,
-                            ) => positioned.inset_top_pct(50.0).margin_top(-50.0),
-                            (HoverCardSide::Left | HoverCardSide::Right, HoverCardAlign::End) => {
-                                positioned.inset_bottom(0.0)
-                            }
-                        };
-                        if hovered {
-                            aligned
-                        } else {
-                            aligned.display(floem::style::Display::None)
-                        }
+                            .apply_shadow(t.shadow.md)
+                            .z_index(50)
                     })
                 })
                 .on_event_stop(floem::event::EventListener::PointerEnter, move |_| {
@@ -198,14 +199,22 @@ where
                 })
                 .on_event_stop(floem::event::EventListener::PointerLeave, move |_| {
                     is_hovered.set(false);
-                })
+                });
+
+            floem::views::Overlay::new()
+                .child(
+                    floem::views::Stack::new((floating.window_probe(), floating.content(card)))
+                        .style(move |s| {
+                            let hovered = is_hovered.get();
+                            s.apply_if(!hovered, |s| s.hide())
+                        }),
+                )
                 .into_any()
         } else {
             floem::views::Empty::new().into_any()
         };
 
-        floem::views::Container::new(floem::views::Stack::new((trigger_view, content_view)))
-            .style(|s| s.position(floem::style::Position::Relative))
+        floem::views::Stack::new((trigger_view, content_view))
     }
 }
 
@@ -20,6 +20,13 @@
 //!
 //! // With initial text
 //! let input = Input::with_text("Hello");
+//!
+//! // Password with a button that shows the text
+//! let input = Input::new().password().revealable();
+//!
+//! // Locked while a form is submitting
+//! let submitting = RwSignal::new(false);
+//! let input = Input::new().disabled(move || submitting.get());
 //! ```
 
 use floem::prelude::*;
@@ -27,7 +34,9 @@ use floem::views::Decorators;
 use floem::{HasViewId, ViewId};
 use floem_tailwind::TailwindExt;
 
-use crate::text::TextInput;
+use crate::components::button::Button;
+use crate::components::focus_scope::FocusScope;
+use crate::text::{Document, Keymap, TextInput};
 use crate::theme::ShadcnThemeExt;
 
 /// A styled input builder
@@ -39,6 +48,12 @@ pub struct Input {
     on_enter: Option<Box<dyn Fn(&str)>>,
     on_update: Option<Box<dyn Fn(&str)>>,
     value_fn: Option<Box<dyn Fn() -> String>>,
+    password: bool,
+    revealable: bool,
+    read_only_fn: Option<Box<dyn Fn() -> bool>>,
+    disabled_fn: Option<Box<dyn Fn() -> bool>>,
+    keymap: Option<Keymap>,
+    commands: Vec<(String, Box<dyn Fn(&Document)>)>,
 }
 
 impl Input {
@@ -51,6 +66,12 @@ impl Input {
             on_enter: None,
             on_update: None,
             value_fn: None,
+            password: false,
+            revealable: false,
+            read_only_fn: None,
+            disabled_fn: None,
+            keymap: None,
+            commands: Vec::new(),
         }
     }
 
@@ -63,6 +84,12 @@ impl Input {
             on_enter: None,
             on_update: None,
             value_fn: None,
+            password: false,
+            revealable: false,
+            read_only_fn: None,
+            disabled_fn: None,
+            keymap: None,
+            commands: Vec::new(),
         }
     }
 
@@ -90,8 +117,58 @@ impl Input {
         self
     }
 
+    /// Mask the text, as for passwords
+    pub fn password(mut self) -> Self {
+        self.password = true;
+        self
+    }
+
+    /// Add an eye button that toggles between masked and plain text
+    ///
+    /// Implies [`password`](Self::password).
+    pub fn revealable(mut self) -> Self {
+        self.password = true;
+        self.revealable = true;
+        self
+    }
+
+    /// Reject edits while `read_only` returns true (reactive)
+    ///
+    /// The text can still be selected and copied.
+    pub fn read_only(mut self, read_only: impl Fn() -> bool + 'static) -> Self {
+        self.read_only_fn = Some(Box::new(read_only));
+        self
+    }
+
+    /// Disable the input while `disabled` returns true (reactive)
+    pub fn disabled(mut self, disabled: impl Fn() -> bool + 'static) -> Self {
+        self.disabled_fn = Some(Box::new(disabled));
+        self
+    }
+
+    /// Replace the key bindings, [`Keymap::single_line`] by default
+    pub fn keymap(mut self, keymap: Keymap) -> Self {
+        self.keymap = Some(keymap);
+        self
+    }
+
+    /// Register the callback run by the custom command `name`
+    ///
+    /// See [`TextInput::command`].
+    pub fn command(
+        mut self,
+        name: impl Into<String>,
+        callback: impl Fn(&Document) + 'static,
+    ) -> Self {
+        self.commands.push((name.into(), Box::new(callback)));
+        self
+    }
+
     /// Build and return the styled TextInput view
     pub fn build(self) -> impl IntoView {
+        // Take part in the focus trap of an enclosing dialog, sheet or drawer
+        FocusScope::register_current(self.id);
+
         // Use with_text_and_id to pass our ViewId for proper HasViewId impl
         let mut input = TextInput::with_text_and_id(self.initial_text, self.id);
 
@@ -115,22 +192,79 @@ impl Input {
             input = input.on_enter(callback);
         }
 
-        input.style(move |s| {
-            s.h_10()
-                .w_full()
+        if let Some(read_only) = self.read_only_fn {
+            input = input.read_only(read_only);
+        }
+        if let Some(disabled) = self.disabled_fn {
+            input = input.disabled(disabled);
+        }
+        if let Some(keymap) = self.keymap {
+            input = input.keymap(keymap);
+        }
+        for (name, callback) in self.commands {
+            input = input.command(name, callback);
+        }
+
+        // Mask the text unless the eye button has revealed it
+        let revealed = RwSignal::new(false);
+        if self.password {
+            input = input.password(move || !revealed.get());
+        }
+
+        let revealable = self.revealable;
+        let input = input.style(move |s| {
+            s.w_full()
                 .rounded_md()
                 .border(1.0)
-                .px_3()
-                .py_2()
-                .font_size(14.0)
                 .with_shadcn_theme(|s, t| {
                     let ring = t.ring;
-                    s.border_color(t.input)
+                    let ring_width = t.ring_width;
+                    s.height(t.control_height.md)
+                        .padding_horiz(t.space(3.0))
+                        .padding_vert(t.space(2.0))
+                        .font_size(t.font_size.sm)
+                        .border_color(t.input)
                         .background(t.background)
                         .color(t.foreground)
-                        .focus(move |s| s.outline(2.0).outline_color(ring))
+                        .focus(move |s| s.outline(ring_width).outline_color(ring))
+                        .disabled(|s| s.background(t.muted).color(t.muted_foreground))
+                        // Keep text clear of the eye button
+                        .apply_if(revealable, |s| s.padding_right(t.control_height.md))
+                })
+        });
+
+        if !revealable {
+            return input.into_any();
+        }
+
+        let input_id = self.id;
+        let toggle = Button::new(
+            floem::views::svg(move || {
+                if revealed.get() {
+                    EYE_OFF_ICON_SVG
+                } else {
+                    EYE_ICON_SVG
+                }
+                .to_string()
+            })
+            .style(|s| {
+                s.with_shadcn_theme(|s, t| {
+                    s.size(t.space(4.0), t.space(4.0)) // size-4 = 16px
+                        .color(t.muted_foreground)
                 })
+            }),
+        )
+        .ghost()
+        .icon()
+        .on_click_stop(move |_| {
+            revealed.update(|revealed| *revealed = !*revealed);
+            input_id.request_focus();
         })
+        .style(|s| s.absolute().inset_top(0.0).inset_right(0.0));
+
+        floem::views::Stack::new((input, toggle))
+            .style(|s| s.w_full())
+            .into_any()
     }
 }
 
@@ -146,6 +280,12 @@ impl HasViewId for Input {
     }
 }
 
+// Eye icon SVG
+const EYE_ICON_SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M2 12s3-7 10-7 10 7 10 7-3 7-10 7-10-7-10-7z"></path><circle cx="12" cy="12" r="3"></circle></svg>"#;
+
+// Eye-off icon SVG
+const EYE_OFF_ICON_SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M17.94 17.94A10.07 10.07 0 0 1 12 20c-7 0-11-8-11-8a18.45 18.45 0 0 1 5.06-5.94"></path><path d="M9.9 4.24A9.12 9.12 0 0 1 12 4c7 0 11 8 11 8a18.5 18.5 0 0 1-2.16 3.19"></path><path d="M14.12 14.12a3 3 0 1 1-4.24-4.24"></path><line x1="1" y1="1" x2="23" y2="23"></line></svg>"#;
+
 impl IntoView for Input {
     type V = Box<dyn View>;
     type Intermediate = Self;
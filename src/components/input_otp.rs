@@ -85,7 +85,7 @@ fn create_otp_group_4(value: RwSignal<String>, mask: bool) -> impl IntoView {
         create_otp_slot(value, 2, mask),
         create_otp_slot(value, 3, mask),
     ))
-    .style(|s| s.gap(8.0))
+    .style(|s| s.with_shadcn_theme(|s, t| s.gap(t.space(2.0))))
 }
 
 fn create_otp_group_6(value: RwSignal<String>, mask: bool) -> impl IntoView {
@@ -98,7 +98,7 @@ fn create_otp_group_6(value: RwSignal<String>, mask: bool) -> impl IntoView {
         create_otp_slot(value, 4, mask),
         create_otp_slot(value, 5, mask),
     ))
-    .style(|s| s.gap(8.0).items_center())
+    .style(|s| s.with_shadcn_theme(|s, t| s.gap(t.space(2.0)).items_center()))
 }
 
 fn create_otp_slot(value: RwSignal<String>, index: usize, mask: bool) -> impl IntoView {
@@ -121,9 +121,9 @@ fn create_otp_slot(value: RwSignal<String>, index: usize, mask: bool) -> impl In
             let val = value.get();
             let is_current = index == val.len();
             let base = s
-                .width(40.0)
-                .height(48.0)
-                .font_size(20.0)
+                .width(t.control_height.md)
+                .height(t.space(12.0))
+                .font_size(t.font_size.xl)
                 .font_weight(floem::text::Weight::MEDIUM)
                 .color(t.foreground)
                 .background(t.background)
@@ -179,9 +179,11 @@ impl<V: IntoView + 'static> IntoView for InputOTPGroup<V> {
     fn into_view(self) -> Self::V {
         Box::new(
             floem::views::Container::with_id(self.id, self.child).style(|s| {
-                s.display(floem::style::Display::Flex)
-                    .flex_direction(floem::style::FlexDirection::Row)
-                    .gap(8.0)
+                s.with_shadcn_theme(|s, t| {
+                    s.display(floem::style::Display::Flex)
+                        .flex_direction(floem::style::FlexDirection::Row)
+                        .gap(t.space(2.0))
+                })
             }),
         )
     }
@@ -256,9 +258,9 @@ impl IntoView for InputOTPSlot {
                     let val = value.get();
                     let is_current = index == val.len();
                     let base = s
-                        .width(40.0)
-                        .height(48.0)
-                        .font_size(20.0)
+                        .width(t.control_height.md)
+                        .height(t.space(12.0))
+                        .font_size(t.font_size.xl)
                         .font_weight(floem::text::Weight::MEDIUM)
                         .color(t.foreground)
                         .background(t.background)
@@ -316,10 +318,9 @@ impl IntoView for InputOTPSeparator {
     fn into_view(self) -> Self::V {
         Box::new(floem::views::Label::new("-").style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.font_size(20.0)
+                s.font_size(t.font_size.xl)
                     .color(t.muted_foreground)
-                    .padding_left(4.0)
-                    .padding_right(4.0)
+                    .padding_horiz(t.space(1.0))
             })
         }))
     }
@@ -15,11 +15,15 @@
 //!     .required()
 //!     .disabled(false);
 //! ```
+//!
+//! `FormField` and `FormLabel` can show the validation state of a
+//! [`Form`](crate::components::form::Form) field with `.field(state)`.
 
 use floem::prelude::*;
 use floem::views::Decorators;
 use floem::{HasViewId, ViewId};
 
+use crate::components::form::FieldState;
 use crate::theme::ShadcnThemeExt;
 
 // ============================================================================
@@ -33,6 +37,7 @@ pub struct FormLabel {
     required: bool,
     disabled: bool,
     error: bool,
+    field: Option<FieldState>,
 }
 
 impl FormLabel {
@@ -44,6 +49,7 @@ impl FormLabel {
             required: false,
             disabled: false,
             error: false,
+            field: None,
         }
     }
 
@@ -64,6 +70,12 @@ impl FormLabel {
         self.error = error;
         self
     }
+
+    /// Show the error state of a form field
+    pub fn field(mut self, field: FieldState) -> Self {
+        self.field = Some(field);
+        self
+    }
 }
 
 impl HasViewId for FormLabel {
@@ -85,6 +97,7 @@ impl IntoView for FormLabel {
         let required = self.required;
         let disabled = self.disabled;
         let error = self.error;
+        let field = self.field;
 
         // Build the display text
         let display_text = if required {
@@ -94,11 +107,11 @@ impl IntoView for FormLabel {
         };
 
         Box::new(floem::views::Label::new(display_text).style(move |s| {
-            s.font_size(14.0)
-                .font_weight(floem::text::Weight::MEDIUM)
-                .line_height(1.0)
+            s.font_weight(floem::text::Weight::MEDIUM)
                 .with_shadcn_theme(move |s, t| {
-                    if error {
+                    let s = s.font_size(t.font_size.sm).line_height(t.line_height.none);
+                    let invalid = field.is_some_and(|field| field.error().is_some());
+                    if error || invalid {
                         s.color(t.destructive)
                     } else if disabled {
                         s.color(t.muted_foreground)
@@ -174,10 +187,9 @@ impl<V: IntoView + 'static> IntoView for LabelWithIcon<V> {
         };
 
         let label = floem::views::Label::new(display_text).style(move |s| {
-            s.font_size(14.0)
-                .font_weight(floem::text::Weight::MEDIUM)
-                .line_height(1.0)
+            s.font_weight(floem::text::Weight::MEDIUM)
                 .with_shadcn_theme(move |s, t| {
+                    let s = s.font_size(t.font_size.sm).line_height(t.line_height.none);
                     if disabled {
                         s.color(t.muted_foreground)
                     } else {
@@ -208,6 +220,7 @@ pub struct FormField<L, I> {
     input: I,
     description: Option<String>,
     error_message: Option<String>,
+    field: Option<FieldState>,
 }
 
 impl<L: IntoView + 'static, I: IntoView + 'static> FormField<L, I> {
@@ -219,6 +232,7 @@ impl<L: IntoView + 'static, I: IntoView + 'static> FormField<L, I> {
             input,
             description: None,
             error_message: None,
+            field: None,
         }
     }
 
@@ -233,6 +247,14 @@ impl<L: IntoView + 'static, I: IntoView + 'static> FormField<L, I> {
         self.error_message = Some(message.into());
         self
     }
+
+    /// Show the validation error of a form field below the input
+    ///
+    /// A message set with [`error`](Self::error) takes precedence.
+    pub fn field(mut self, field: FieldState) -> Self {
+        self.field = Some(field);
+        self
+    }
 }
 
 impl<L: IntoView + 'static, I: IntoView + 'static> HasViewId for FormField<L, I> {
@@ -257,8 +279,9 @@ impl<L: IntoView + 'static, I: IntoView + 'static> IntoView for FormField<L, I>
         let desc_view = if let Some(desc) = description {
             floem::views::Label::new(desc)
                 .style(move |s| {
-                    s.font_size(12.0)
-                        .with_shadcn_theme(|s, t| s.color(t.muted_foreground))
+                    s.with_shadcn_theme(|s, t| {
+                        s.font_size(t.font_size.xs).color(t.muted_foreground)
+                    })
                 })
                 .into_any()
         } else {
@@ -269,8 +292,15 @@ impl<L: IntoView + 'static, I: IntoView + 'static> IntoView for FormField<L, I>
         let error_view = if let Some(err) = error_message {
             floem::views::Label::new(err)
                 .style(move |s| {
-                    s.font_size(12.0)
-                        .with_shadcn_theme(|s, t| s.color(t.destructive))
+                    s.with_shadcn_theme(|s, t| s.font_size(t.font_size.xs).color(t.destructive))
+                })
+                .into_any()
+        } else if let Some(field) = self.field {
+            floem::views::Label::derived(move || field.error().unwrap_or_default())
+                .style(move |s| {
+                    let has_error = field.error().is_some();
+                    s.with_shadcn_theme(|s, t| s.font_size(t.font_size.xs).color(t.destructive))
+                        .apply_if(!has_error, |s| s.hide())
                 })
                 .into_any()
         } else {
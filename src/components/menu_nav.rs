@@ -0,0 +1,425 @@
+//! Shared keyboard navigation for menu-like components
+//!
+//! `Select`, `DropdownMenu`, `ContextMenu` and `Menubar` all use [`MenuNav`] so
+//! they behave the same way from the keyboard:
+//!
+//! - Enter, Space or ArrowDown on the trigger opens the menu (ArrowUp opens it
+//!   with the last item highlighted)
+//! - ArrowUp/ArrowDown move the highlight, skipping disabled items and wrapping
+//! - Home/End jump to the first/last enabled item
+//! - Typing jumps to the next item whose label starts with the typed text
+//! - Enter or Space activates the highlighted item and closes the menu
+//! - Escape closes the menu and returns focus to the trigger
+//!
+//! Focus stays on the trigger while the menu is open. The highlighted item is
+//! tracked in a signal that items read for their styling.
+//!
+//! Items find the navigation state through the reactive `Context`, so menu
+//! content must be built inside the scope returned by [`MenuNav::provide`].
+
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use floem::ViewId;
+use floem::event::{Event, EventListener, EventPropagation};
+use floem::reactive::{Context, RwSignal, Scope, SignalGet, SignalUpdate, SignalWith};
+use ui_events::keyboard::{Key, KeyState, KeyboardEvent, Modifiers, NamedKey};
+
+/// Typed characters within this interval extend the typeahead search.
+const TYPEAHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// How a menu is opened from its trigger with the keyboard
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MenuTriggerKind {
+    /// Enter, Space, ArrowDown and ArrowUp open the menu
+    Button,
+    /// Only the ContextMenu key and Shift+F10 open the menu
+    ContextArea,
+}
+
+/// A navigable entry registered by a menu item
+struct MenuNavItem {
+    label: String,
+    disabled: bool,
+    on_activate: Option<Rc<dyn Fn()>>,
+}
+
+/// Keyboard navigation state for a single menu
+#[derive(Clone, Copy)]
+pub struct MenuNav {
+    /// Whether the menu is open
+    pub open: RwSignal<bool>,
+    /// Index of the highlighted item, if any
+    pub highlighted: RwSignal<Option<usize>>,
+    /// Index of the item to highlight first when opening (e.g. the selected value)
+    pub selected_index: RwSignal<Option<usize>>,
+    kind: MenuTriggerKind,
+    items: RwSignal<Vec<MenuNavItem>>,
+    trigger: RwSignal<Option<ViewId>>,
+    typeahead: RwSignal<Option<(String, Instant)>>,
+}
+
+impl MenuNav {
+    /// Create navigation state for a menu opened from a button-like trigger
+    pub fn new(open: RwSignal<bool>) -> Self {
+        Self::with_kind(open, MenuTriggerKind::Button)
+    }
+
+    /// Create navigation state for a menu with the given trigger kind
+    pub fn with_kind(open: RwSignal<bool>, kind: MenuTriggerKind) -> Self {
+        Self {
+            open,
+            highlighted: RwSignal::new(None),
+            selected_index: RwSignal::new(None),
+            kind,
+            items: RwSignal::new(Vec::new()),
+            trigger: RwSignal::new(None),
+            typeahead: RwSignal::new(None),
+        }
+    }
+
+    /// Get the navigation state of the enclosing menu, if any
+    pub fn current() -> Option<Self> {
+        Context::get::<MenuNav>()
+    }
+
+    /// Create a child scope that provides this navigation state to menu items
+    pub fn provide(self) -> Scope {
+        let scope = Scope::current().create_child();
+        scope.provide_context(self);
+        scope
+    }
+
+    /// Register an item and return its index
+    ///
+    /// Items are navigated in registration order. `on_activate` runs when the
+    /// item is chosen with Enter/Space (or clicked via [`MenuNav::activate`]).
+    pub fn register(
+        &self,
+        label: impl Into<String>,
+        disabled: bool,
+        on_activate: Option<Rc<dyn Fn()>>,
+    ) -> usize {
+        let label = label.into();
+        let mut index = 0;
+        self.items.update(|items| {
+            index = items.len();
+            items.push(MenuNavItem {
+                label,
+                disabled,
+                on_activate,
+            });
+        });
+        index
+    }
+
+    /// Number of registered items
+    pub fn len(&self) -> usize {
+        self.items.with_untracked(|items| items.len())
+    }
+
+    /// Returns true if no items are registered
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the item at `index` is highlighted (tracked)
+    pub fn is_highlighted(&self, index: usize) -> bool {
+        self.highlighted.get() == Some(index)
+    }
+
+    /// Attach keyboard handling to the trigger view
+    ///
+    /// The trigger should be focusable; it keeps focus while the menu is open.
+    pub fn attach(&self, trigger: ViewId) {
+        let nav = *self;
+        self.set_trigger(trigger);
+        trigger.add_event_listener(
+            EventListener::KeyDown,
+            Box::new(move |event| {
+                let Event::Key(KeyboardEvent {
+                    state: KeyState::Down,
+                    key,
+                    modifiers,
+                    ..
+                }) = event
+                else {
+                    return EventPropagation::Continue;
+                };
+
+                if nav.handle_key(key, modifiers) {
+                    EventPropagation::Stop
+                } else {
+                    EventPropagation::Continue
+                }
+            }),
+        );
+    }
+
+    /// Set the view that receives focus back when the menu closes
+    ///
+    /// Use this instead of [`MenuNav::attach`] when the trigger handles key
+    /// events itself and forwards them to [`MenuNav::handle_key`].
+    pub fn set_trigger(&self, trigger: ViewId) {
+        self.trigger.set(Some(trigger));
+    }
+
+    /// Move keyboard focus to the trigger
+    pub fn focus_trigger(&self) {
+        if let Some(trigger) = self.trigger.get_untracked() {
+            trigger.request_focus();
+        }
+    }
+
+    /// Handle a key press on the trigger
+    ///
+    /// Returns true if the key was consumed.
+    pub fn handle_key(&self, key: &Key, modifiers: &Modifiers) -> bool {
+        if !self.open.get_untracked() {
+            return self.handle_closed_key(key, modifiers);
+        }
+
+        match key {
+            Key::Named(NamedKey::ArrowDown) => self.move_highlight(true),
+            Key::Named(NamedKey::ArrowUp) => self.move_highlight(false),
+            Key::Named(NamedKey::Home) => self.highlighted.set(self.first_enabled()),
+            Key::Named(NamedKey::End) => self.highlighted.set(self.last_enabled()),
+            Key::Named(NamedKey::Enter) => self.activate_highlighted(),
+            Key::Named(NamedKey::Escape) => self.close(),
+            Key::Named(NamedKey::Tab) => {
+                // Close without refocusing the trigger so focus can move on
+                self.open.set(false);
+                self.highlighted.set(None);
+                return false;
+            }
+            Key::Character(c) if !has_command_modifier(modifiers) => {
+                if c.as_str() == " " && !self.typeahead_active() {
+                    self.activate_highlighted();
+                } else {
+                    self.typeahead(c);
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn handle_closed_key(&self, key: &Key, modifiers: &Modifiers) -> bool {
+        match self.kind {
+            MenuTriggerKind::Button => match key {
+                Key::Named(NamedKey::Enter | NamedKey::ArrowDown) => self.open_menu(false),
+                Key::Named(NamedKey::ArrowUp) => self.open_menu(true),
+                Key::Character(c) if c.as_str() == " " && !has_command_modifier(modifiers) => {
+                    self.open_menu(false)
+                }
+                _ => return false,
+            },
+            MenuTriggerKind::ContextArea => match key {
+                Key::Named(NamedKey::ContextMenu) => self.open_menu(false),
+                Key::Named(NamedKey::F10) if modifiers.shift() => self.open_menu(false),
+                _ => return false,
+            },
+        }
+        true
+    }
+
+    /// Open the menu and highlight the selected item, or the first (or last)
+    /// enabled item if nothing is selected
+    pub fn open_menu(&self, from_end: bool) {
+        let selected = self
+            .selected_index
+            .get_untracked()
+            .filter(|&index| self.is_enabled(index));
+        let highlight = selected.or_else(|| {
+            if from_end {
+                self.last_enabled()
+            } else {
+                self.first_enabled()
+            }
+        });
+        self.highlighted.set(highlight);
+        self.typeahead.set(None);
+        self.open.set(true);
+    }
+
+    /// Open the menu without highlighting anything (used for pointer opens)
+    pub fn open_unhighlighted(&self) {
+        self.highlighted.set(None);
+        self.typeahead.set(None);
+        self.open.set(true);
+    }
+
+    /// Toggle the menu from a pointer click on the trigger
+    ///
+    /// Opening highlights the selected item if there is one, and nothing otherwise.
+    pub fn toggle(&self) {
+        if self.open.get_untracked() {
+            self.close();
+        } else {
+            let selected = self
+                .selected_index
+                .get_untracked()
+                .filter(|&index| self.is_enabled(index));
+            self.highlighted.set(selected);
+            self.typeahead.set(None);
+            self.open.set(true);
+        }
+    }
+
+    /// Close the menu and return focus to the trigger
+    pub fn close(&self) {
+        self.open.set(false);
+        self.highlighted.set(None);
+        self.typeahead.set(None);
+        self.focus_trigger();
+    }
+
+    /// Activate the item at `index` and close the menu
+    ///
+    /// Does nothing if the item is disabled.
+    pub fn activate(&self, index: usize) {
+        if !self.is_enabled(index) {
+            return;
+        }
+        let on_activate = self
+            .items
+            .with_untracked(|items| items[index].on_activate.clone());
+        self.close();
+        if let Some(on_activate) = on_activate {
+            on_activate();
+        }
+    }
+
+    fn activate_highlighted(&self) {
+        if let Some(index) = self.highlighted.get_untracked() {
+            self.activate(index);
+        }
+    }
+
+    fn is_enabled(&self, index: usize) -> bool {
+        self.items
+            .with_untracked(|items| items.get(index).is_some_and(|item| !item.disabled))
+    }
+
+    fn first_enabled(&self) -> Option<usize> {
+        (0..self.len()).find(|&index| self.is_enabled(index))
+    }
+
+    fn last_enabled(&self) -> Option<usize> {
+        (0..self.len()).rev().find(|&index| self.is_enabled(index))
+    }
+
+    /// Move the highlight to the next/previous enabled item, wrapping around
+    fn move_highlight(&self, forward: bool) {
+        let len = self.len();
+        if len == 0 {
+            return;
+        }
+
+        let next = match self.highlighted.get_untracked() {
+            None if forward => self.first_enabled(),
+            None => self.last_enabled(),
+            Some(current) => (1..=len)
+                .map(|step| {
+                    if forward {
+                        (current + step) % len
+                    } else {
+                        (current + len - step) % len
+                    }
+                })
+                .find(|&index| self.is_enabled(index)),
+        };
+        self.highlighted.set(next);
+    }
+
+    fn typeahead_active(&self) -> bool {
+        self.typeahead.with_untracked(|t| {
+            t.as_ref()
+                .is_some_and(|(_, at)| at.elapsed() < TYPEAHEAD_TIMEOUT)
+        })
+    }
+
+    /// Extend the typeahead search and highlight the next matching item
+    fn typeahead(&self, text: &str) {
+        let extending = self.typeahead_active();
+        let mut query = if extending {
+            self.typeahead
+                .with_untracked(|t| t.as_ref().map(|(q, _)| q.clone()).unwrap_or_default())
+        } else {
+            String::new()
+        };
+        query.push_str(&text.to_lowercase());
+        self.typeahead.set(Some((query.clone(), Instant::now())));
+
+        let len = self.len();
+        if len == 0 {
+            return;
+        }
+
+        // Typing the same letter repeatedly cycles through items starting with it
+        let first = query.chars().next();
+        let repeated = query.chars().count() > 1 && query.chars().all(|c| Some(c) == first);
+        let search = if repeated {
+            first.map(String::from).unwrap_or_default()
+        } else {
+            query
+        };
+
+        // A new search starts after the current item; an extended one may stay put
+        let start = match self.highlighted.get_untracked() {
+            Some(current) if extending && !repeated => current,
+            Some(current) => current + 1,
+            None => 0,
+        };
+        let found = (0..len).map(|step| (start + step) % len).find(|&index| {
+            self.items.with_untracked(|items| {
+                let item = &items[index];
+                !item.disabled && item.label.to_lowercase().starts_with(&search)
+            })
+        });
+        if found.is_some() {
+            self.highlighted.set(found);
+        }
+    }
+}
+
+/// Returns true if a modifier that turns a character into a shortcut is held
+fn has_command_modifier(modifiers: &Modifiers) -> bool {
+    modifiers.ctrl() || modifiers.meta() || modifiers.alt()
+}
+
+// ============================================================================
+// MenuNavEntry
+// ============================================================================
+
+/// An item's registration with the enclosing menu's [`MenuNav`]
+#[derive(Clone, Copy)]
+pub struct MenuNavEntry {
+    nav: MenuNav,
+    index: usize,
+}
+
+impl MenuNavEntry {
+    /// Register an item with the menu in the current scope
+    ///
+    /// Returns `None` when the item is used outside a menu.
+    pub fn register(
+        label: impl Into<String>,
+        disabled: bool,
+        on_activate: Option<Rc<dyn Fn()>>,
+    ) -> Option<Self> {
+        let nav = MenuNav::current()?;
+        let index = nav.register(label, disabled, on_activate);
+        Some(Self { nav, index })
+    }
+
+    /// Returns true if this item is highlighted (tracked)
+    pub fn is_highlighted(&self) -> bool {
+        self.nav.is_highlighted(self.index)
+    }
+
+    /// Activate this item and close the menu
+    pub fn activate(&self) {
+        self.nav.activate(self.index);
+    }
+}
@@ -22,15 +22,71 @@
 //!     )),
 //! ));
 //! ```
+//!
+//! Menu triggers are focusable: Enter, Space or the arrow keys open a menu,
+//! ArrowLeft/ArrowRight move to the adjacent menu, the arrow keys, Home/End and
+//! typeahead move the highlight, Enter activates the highlighted item and
+//! Escape closes the menu.
+
+use std::rc::Rc;
 
+use floem::event::{Event, EventListener, EventPropagation};
 use floem::prelude::*;
-use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
+use floem::reactive::{Context, RwSignal, Scope, SignalGet, SignalUpdate, SignalWith};
 use floem::style::CursorStyle;
 use floem::views::Decorators;
 use floem::{HasViewId, ViewId};
+use ui_events::keyboard::{Key, KeyState, KeyboardEvent, NamedKey};
 
+use super::menu_nav::{MenuNav, MenuNavEntry};
 use crate::theme::ShadcnThemeExt;
 
+// ============================================================================
+// Menubar Context - lets menus move focus to their neighbours
+// ============================================================================
+
+/// Menubar context holding the navigation state of each menu, in order
+#[derive(Clone, Copy)]
+pub struct MenubarContext {
+    menus: RwSignal<Vec<MenuNav>>,
+}
+
+impl MenubarContext {
+    /// Register a menu and return its position in the bar
+    fn register(&self, nav: MenuNav) -> usize {
+        let mut index = 0;
+        self.menus.update(|menus| {
+            index = menus.len();
+            menus.push(nav);
+        });
+        index
+    }
+
+    /// Move focus from the menu at `from` to the next or previous one, wrapping.
+    /// The target menu opens if the current one was open.
+    fn move_focus(&self, from: usize, forward: bool) {
+        let len = self.menus.with_untracked(|menus| menus.len());
+        if len < 2 {
+            return;
+        }
+        let to = if forward {
+            (from + 1) % len
+        } else {
+            (from + len - 1) % len
+        };
+        let (current, target) = self.menus.with_untracked(|menus| (menus[from], menus[to]));
+
+        let was_open = current.open.get_untracked();
+        if was_open {
+            current.close();
+        }
+        target.focus_trigger();
+        if was_open {
+            target.open_menu(false);
+        }
+    }
+}
+
 // ============================================================================
 // Menubar
 // ============================================================================
@@ -39,14 +95,23 @@ use crate::theme::ShadcnThemeExt;
 pub struct Menubar<V> {
     id: ViewId,
     child: V,
+    scope: Scope,
 }
 
 impl<V: IntoView + 'static> Menubar<V> {
     /// Create a new menubar
     pub fn new(child: V) -> Self {
+        let scope = Scope::current().create_child();
+
+        // Provide the menubar context in the child scope
+        scope.provide_context(MenubarContext {
+            menus: RwSignal::new(Vec::new()),
+        });
+
         Self {
             id: ViewId::new(),
             child,
+            scope,
         }
     }
 }
@@ -66,18 +131,24 @@ impl<V: IntoView + 'static> IntoView for Menubar<V> {
     }
 
     fn into_view(self) -> Self::V {
+        let id = self.id;
+        let child = self.child;
+
+        // Build the menus within the menubar's scope so they have access to context
         Box::new(
-            floem::views::Container::with_id(self.id, self.child).style(|s| {
-                s.with_shadcn_theme(move |s, t| {
-                    s.display(floem::style::Display::Flex)
-                        .flex_direction(floem::style::FlexDirection::Row)
-                        .items_center()
-                        .padding(4.0)
-                        .background(t.background)
-                        .border_bottom(1.0)
-                        .border_color(t.border)
-                })
-            }),
+            self.scope
+                .enter(move || floem::views::Container::with_id(id, child))
+                .style(|s| {
+                    s.with_shadcn_theme(move |s, t| {
+                        s.display(floem::style::Display::Flex)
+                            .flex_direction(floem::style::FlexDirection::Row)
+                            .items_center()
+                            .padding(4.0)
+                            .background(t.background)
+                            .border_bottom(1.0)
+                            .border_color(t.border)
+                    })
+                }),
         )
     }
 }
@@ -132,22 +203,64 @@ impl<C: IntoView + 'static> IntoView for MenubarMenu<C> {
     fn into_view(self) -> Self::V {
         let label = self.label;
         let is_open = RwSignal::new(false);
+        let nav = MenuNav::new(is_open);
+        let menubar = Context::get::<MenubarContext>();
+        let position = menubar.map(|ctx| ctx.register(nav));
 
         // Menu trigger
-        let trigger = floem::views::Label::new(label)
+        let trigger = floem::views::Label::new(label);
+        let trigger_id = trigger.id();
+        nav.set_trigger(trigger_id);
+        trigger_id.add_event_listener(
+            EventListener::KeyDown,
+            Box::new(move |event| {
+                let Event::Key(KeyboardEvent {
+                    state: KeyState::Down,
+                    key,
+                    modifiers,
+                    ..
+                }) = event
+                else {
+                    return EventPropagation::Continue;
+                };
+
+                // ArrowLeft/ArrowRight move between the menus of the bar
+                if let (Some(ctx), Some(position)) = (menubar, position) {
+                    match key {
+                        Key::Named(NamedKey::ArrowLeft) => {
+                            ctx.move_focus(position, false);
+                            return EventPropagation::Stop;
+                        }
+                        Key::Named(NamedKey::ArrowRight) => {
+                            ctx.move_focus(position, true);
+                            return EventPropagation::Stop;
+                        }
+                        _ => {}
+                    }
+                }
+
+                if nav.handle_key(key, modifiers) {
+                    EventPropagation::Stop
+                } else {
+                    EventPropagation::Continue
+                }
+            }),
+        );
+
+        let trigger = trigger
             .style(move |s| {
                 s.with_shadcn_theme(move |s, t| {
                     let open = is_open.get();
                     let base = s
-                        .padding_left(12.0)
-                        .padding_right(12.0)
-                        .padding_top(6.0)
-                        .padding_bottom(6.0)
-                        .font_size(14.0)
+                        .padding_horiz(t.space(3.0))
+                        .padding_vert(t.space(1.5))
+                        .font_size(t.font_size.sm)
                         .font_weight(floem::text::Weight::MEDIUM)
                         .color(t.foreground)
                         .border_radius(t.radius)
-                        .cursor(CursorStyle::Pointer);
+                        .cursor(CursorStyle::Pointer)
+                        .focusable(true)
+                        .focus(|s| s.background(t.accent).color(t.accent_foreground));
                     if open {
                         base.background(t.accent).color(t.accent_foreground)
                     } else {
@@ -156,12 +269,14 @@ impl<C: IntoView + 'static> IntoView for MenubarMenu<C> {
                 })
             })
             .on_click_stop(move |_| {
-                is_open.update(|v| *v = !*v);
+                trigger_id.request_focus();
+                nav.toggle();
             });
 
-        // Dropdown content
+        // Dropdown content - built in the menu's scope so items can register with it
         let dropdown = if let Some(content) = self.content {
-            floem::views::Container::new(content)
+            nav.provide()
+                .enter(move || floem::views::Container::new(content))
                 .style(move |s| {
                     s.with_shadcn_theme(move |s, t| {
                         let open = is_open.get();
@@ -171,14 +286,12 @@ impl<C: IntoView + 'static> IntoView for MenubarMenu<C> {
                             .inset_left(0.0)
                             .margin_top(4.0)
                             .min_width(180.0)
-                            .padding_top(4.0)
-                            .padding_bottom(4.0)
+                            .padding_vert(t.space(1.0))
                             .background(t.popover)
                             .border(1.0)
                             .border_color(t.border)
                             .border_radius(t.radius)
-                            .box_shadow_blur(8.0)
-                            .box_shadow_color(t.foreground.with_alpha(0.1))
+                            .apply_shadow(t.shadow.md)
                             .z_index(100)
                             .display(floem::style::Display::Flex)
                             .flex_direction(floem::style::FlexDirection::Column);
@@ -213,7 +326,7 @@ impl<C: IntoView + 'static> IntoView for MenubarMenu<C> {
                 }
             })
             .on_click_stop(move |_| {
-                is_open.set(false);
+                nav.close();
             });
 
         Box::new(
@@ -262,11 +375,9 @@ impl IntoView for MenubarTrigger {
 
         Box::new(floem::views::Label::with_id(self.id, label).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.padding_left(12.0)
-                    .padding_right(12.0)
-                    .padding_top(6.0)
-                    .padding_bottom(6.0)
-                    .font_size(14.0)
+                s.padding_horiz(t.space(3.0))
+                    .padding_vert(t.space(1.5))
+                    .font_size(t.font_size.sm)
                     .font_weight(floem::text::Weight::MEDIUM)
                     .color(t.foreground)
                     .border_radius(t.radius)
@@ -383,12 +494,13 @@ impl IntoView for MenubarItem {
         let label = self.label;
         let shortcut = self.shortcut;
         let disabled = self.disabled;
-        let on_select = self.on_select;
+        let on_select: Option<Rc<dyn Fn()>> = self.on_select.map(Rc::from);
+        let entry = MenuNavEntry::register(label.clone(), disabled, on_select.clone());
 
         // Label
         let label_view = floem::views::Label::new(label).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
-                let base = s.font_size(14.0).flex_grow(1.0);
+                let base = s.font_size(t.font_size.sm).flex_grow(1.0);
                 if disabled {
                     base.color(t.muted_foreground)
                 } else {
@@ -402,7 +514,7 @@ impl IntoView for MenubarItem {
             floem::views::Label::new(sc)
                 .style(|s| {
                     s.with_shadcn_theme(move |s, t| {
-                        s.font_size(12.0)
+                        s.font_size(t.font_size.xs)
                             .color(t.muted_foreground)
                             .margin_left(24.0)
                     })
@@ -414,12 +526,11 @@ impl IntoView for MenubarItem {
 
         let row = floem::views::Stack::horizontal((label_view, shortcut_view)).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
+                let highlighted = entry.is_some_and(|e| e.is_highlighted());
                 let base = s
                     .width_full()
-                    .padding_left(8.0)
-                    .padding_right(8.0)
-                    .padding_top(6.0)
-                    .padding_bottom(6.0)
+                    .padding_horiz(t.space(2.0))
+                    .padding_vert(t.space(1.5))
                     .border_radius(4.0)
                     .cursor(if disabled {
                         CursorStyle::Default
@@ -429,17 +540,19 @@ impl IntoView for MenubarItem {
                 if disabled {
                     base
                 } else {
-                    base.hover(|s| s.background(t.accent))
+                    base.apply_if(highlighted, |s| s.background(t.accent))
+                        .hover(|s| s.background(t.accent))
                 }
             })
         });
 
-        if let Some(handler) = on_select {
-            if !disabled {
-                Box::new(row.on_click_stop(move |_| handler()))
-            } else {
-                Box::new(row)
-            }
+        if disabled {
+            Box::new(row)
+        } else if let Some(entry) = entry {
+            // Runs the handler and closes the menu
+            Box::new(row.on_click_stop(move |_| entry.activate()))
+        } else if let Some(handler) = on_select {
+            Box::new(row.on_click_stop(move |_| handler()))
         } else {
             Box::new(row)
         }
@@ -541,20 +654,25 @@ impl IntoView for MenubarCheckboxItem {
         let label = self.label;
         let checked = self.checked;
         let disabled = self.disabled;
+        let entry = MenuNavEntry::register(
+            label.clone(),
+            disabled,
+            Some(Rc::new(move || checked.update(|v| *v = !*v))),
+        );
 
         // Checkbox indicator
         let check_indicator =
             floem::views::Label::derived(move || if checked.get() { "✓" } else { " " }.to_string())
                 .style(|s| {
                     s.with_shadcn_theme(move |s, t| {
-                        s.width(16.0).font_size(12.0).color(t.foreground)
+                        s.width(16.0).font_size(t.font_size.xs).color(t.foreground)
                     })
                 });
 
         // Label
         let label_view = floem::views::Label::new(label).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
-                let base = s.font_size(14.0).flex_grow(1.0);
+                let base = s.font_size(t.font_size.sm).flex_grow(1.0);
                 if disabled {
                     base.color(t.muted_foreground)
                 } else {
@@ -567,10 +685,8 @@ impl IntoView for MenubarCheckboxItem {
             s.with_shadcn_theme(move |s, t| {
                 let base = s
                     .width_full()
-                    .padding_left(8.0)
-                    .padding_right(8.0)
-                    .padding_top(6.0)
-                    .padding_bottom(6.0)
+                    .padding_horiz(t.space(2.0))
+                    .padding_vert(t.space(1.5))
                     .border_radius(4.0)
                     .gap(8.0)
                     .cursor(if disabled {
@@ -578,16 +694,21 @@ impl IntoView for MenubarCheckboxItem {
                     } else {
                         CursorStyle::Pointer
                     });
+                let highlighted = entry.is_some_and(|e| e.is_highlighted());
                 if disabled {
                     base
                 } else {
-                    base.hover(|s| s.background(t.accent))
+                    base.apply_if(highlighted, |s| s.background(t.accent))
+                        .hover(|s| s.background(t.accent))
                 }
             })
         });
 
         if disabled {
             Box::new(row)
+        } else if let Some(entry) = entry {
+            // Toggles the checkbox and closes the menu
+            Box::new(row.on_click_stop(move |_| entry.activate()))
         } else {
             Box::new(row.on_click_stop(move |_| {
                 checked.update(|v| *v = !*v);
@@ -634,7 +755,7 @@ impl IntoView for MenubarShortcut {
         let keys = self.keys;
 
         Box::new(floem::views::Label::new(keys).style(|s| {
-            s.with_shadcn_theme(move |s, t| s.font_size(12.0).color(t.muted_foreground))
+            s.with_shadcn_theme(move |s, t| s.font_size(t.font_size.xs).color(t.muted_foreground))
         }))
     }
 }
@@ -16,14 +16,19 @@ pub mod collapsible;
 pub mod combobox;
 pub mod command;
 pub mod context_menu;
+pub mod data_table;
 pub mod date_picker;
 pub mod dialog;
 pub mod drawer;
 pub mod dropdown_menu;
+pub mod floating;
+pub mod focus_scope;
+pub mod form;
 pub mod hover_card;
 pub mod input;
 pub mod input_otp;
 pub mod label;
+pub mod menu_nav;
 pub mod menubar;
 pub mod navigation_menu;
 pub mod pagination;
@@ -181,11 +181,9 @@ impl<C: IntoView + 'static> IntoView for NavigationMenuItem<C> {
         let trigger = floem::views::Label::new(format!("{} ▾", label))
             .style(|s| {
                 s.with_shadcn_theme(move |s, t| {
-                    s.padding_left(12.0)
-                        .padding_right(12.0)
-                        .padding_top(8.0)
-                        .padding_bottom(8.0)
-                        .font_size(14.0)
+                    s.padding_horiz(t.space(3.0))
+                        .padding_vert(t.space(2.0))
+                        .font_size(t.font_size.sm)
                         .font_weight(floem::text::Weight::MEDIUM)
                         .color(t.foreground)
                         .border_radius(t.radius)
@@ -214,8 +212,7 @@ impl<C: IntoView + 'static> IntoView for NavigationMenuItem<C> {
                             .border(1.0)
                             .border_color(t.border)
                             .border_radius(t.radius)
-                            .box_shadow_blur(8.0)
-                            .box_shadow_color(t.foreground.with_alpha(0.1))
+                            .apply_shadow(t.shadow.md)
                             .z_index(50)
                             .display(floem::style::Display::Flex)
                             .flex_direction(floem::style::FlexDirection::Column)
@@ -285,11 +282,9 @@ impl IntoView for NavigationMenuTrigger {
         Box::new(
             floem::views::Label::with_id(self.id, format!("{} ▾", label)).style(|s| {
                 s.with_shadcn_theme(move |s, t| {
-                    s.padding_left(12.0)
-                        .padding_right(12.0)
-                        .padding_top(8.0)
-                        .padding_bottom(8.0)
-                        .font_size(14.0)
+                    s.padding_horiz(t.space(3.0))
+                        .padding_vert(t.space(2.0))
+                        .font_size(t.font_size.sm)
                         .font_weight(floem::text::Weight::MEDIUM)
                         .color(t.foreground)
                         .border_radius(t.radius)
@@ -418,7 +413,7 @@ impl IntoView for NavigationMenuLink {
         // Title
         let title = floem::views::Label::new(label).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.font_size(14.0)
+                s.font_size(t.font_size.sm)
                     .font_weight(floem::text::Weight::MEDIUM)
                     .color(t.foreground)
             })
@@ -429,7 +424,9 @@ impl IntoView for NavigationMenuLink {
             floem::views::Label::new(desc)
                 .style(|s| {
                     s.with_shadcn_theme(move |s, t| {
-                        s.font_size(12.0).color(t.muted_foreground).margin_top(2.0)
+                        s.font_size(t.font_size.xs)
+                            .color(t.muted_foreground)
+                            .margin_top(2.0)
                     })
                 })
                 .into_any()
@@ -544,8 +541,7 @@ impl<V: IntoView + 'static> IntoView for NavigationMenuViewport<V> {
                     .border(1.0)
                     .border_color(t.border)
                     .border_radius(t.radius)
-                    .box_shadow_blur(8.0)
-                    .box_shadow_color(t.foreground.with_alpha(0.1))
+                    .apply_shadow(t.shadow.md)
             })
         }))
     }
@@ -12,8 +12,13 @@
 //! let total_pages = 10;
 //!
 //! Pagination::new(page, total_pages);
+//!
+//! // Page count that changes with the data (e.g. a filtered list)
+//! Pagination::dynamic(page, move || items.with(|items| items.len().div_ceil(10)));
 //! ```
 
+use std::rc::Rc;
+
 use floem::prelude::*;
 use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
 use floem::style::CursorStyle;
@@ -30,7 +35,7 @@ use crate::theme::ShadcnThemeExt;
 pub struct Pagination {
     id: ViewId,
     current_page: RwSignal<usize>,
-    total_pages: usize,
+    total_pages: Rc<dyn Fn() -> usize>,
     show_edges: bool,
     siblings: usize,
 }
@@ -38,10 +43,18 @@ pub struct Pagination {
 impl Pagination {
     /// Create a new pagination component
     pub fn new(current_page: RwSignal<usize>, total_pages: usize) -> Self {
+        Self::dynamic(current_page, move || total_pages)
+    }
+
+    /// Create a pagination component whose page count is computed reactively
+    pub fn dynamic(
+        current_page: RwSignal<usize>,
+        total_pages: impl Fn() -> usize + 'static,
+    ) -> Self {
         Self {
             id: ViewId::new(),
             current_page,
-            total_pages,
+            total_pages: Rc::new(total_pages),
             show_edges: true,
             siblings: 1,
         }
@@ -87,9 +100,9 @@ impl IntoView for Pagination {
                     let page = current_page.get();
                     let disabled = page <= 1;
                     let base = s
-                        .width(36.0)
-                        .height(36.0)
-                        .font_size(14.0)
+                        .width(t.control_height.sm)
+                        .height(t.control_height.sm)
+                        .font_size(t.font_size.sm)
                         .display(floem::style::Display::Flex)
                         .items_center()
                         .justify_center()
@@ -118,24 +131,35 @@ impl IntoView for Pagination {
             });
 
         // Page numbers - create a static set of page buttons
-        let page1 = create_page_button(current_page, 1, total_pages, siblings, show_edges);
-        let page2 = create_page_button(current_page, 2, total_pages, siblings, show_edges);
-        let page3 = create_page_button(current_page, 3, total_pages, siblings, show_edges);
-        let page4 = create_page_button(current_page, 4, total_pages, siblings, show_edges);
-        let page5 = create_page_button(current_page, 5, total_pages, siblings, show_edges);
-        let page6 = create_page_button(current_page, 6, total_pages, siblings, show_edges);
-        let page7 = create_page_button(current_page, 7, total_pages, siblings, show_edges);
+        let page_button = |slot| {
+            create_page_button(
+                current_page,
+                slot,
+                total_pages.clone(),
+                siblings,
+                show_edges,
+            )
+        };
+        let page1 = page_button(1);
+        let page2 = page_button(2);
+        let page3 = page_button(3);
+        let page4 = page_button(4);
+        let page5 = page_button(5);
+        let page6 = page_button(6);
+        let page7 = page_button(7);
 
         // Next button
+        let style_total = total_pages.clone();
         let next_btn = floem::views::Label::new("▶")
             .style(move |s| {
+                let total_pages = style_total();
                 s.with_shadcn_theme(move |s, t| {
                     let page = current_page.get();
                     let disabled = page >= total_pages;
                     let base = s
-                        .width(36.0)
-                        .height(36.0)
-                        .font_size(14.0)
+                        .width(t.control_height.sm)
+                        .height(t.control_height.sm)
+                        .font_size(t.font_size.sm)
                         .display(floem::style::Display::Flex)
                         .items_center()
                         .justify_center()
@@ -158,7 +182,7 @@ impl IntoView for Pagination {
             })
             .on_click_stop(move |_| {
                 let page = current_page.get();
-                if page < total_pages {
+                if page < total_pages() {
                     current_page.set(page + 1);
                 }
             });
@@ -167,7 +191,7 @@ impl IntoView for Pagination {
             floem::views::Stack::horizontal((
                 prev_btn, page1, page2, page3, page4, page5, page6, page7, next_btn,
             ))
-            .style(|s| s.gap(4.0).items_center()),
+            .style(|s| s.with_shadcn_theme(|s, t| s.gap(t.space(1.0)).items_center())),
         )
     }
 }
@@ -175,12 +199,15 @@ impl IntoView for Pagination {
 fn create_page_button(
     current_page: RwSignal<usize>,
     page_num: usize,
-    total_pages: usize,
+    total_pages: Rc<dyn Fn() -> usize>,
     siblings: usize,
     _show_edges: bool,
 ) -> impl IntoView {
+    let label_total = total_pages.clone();
+    let style_total = total_pages.clone();
     floem::views::Label::derived(move || {
         let current = current_page.get();
+        let total_pages = label_total();
 
         // Calculate which page number to show in this slot
         let display_page = calculate_display_page(page_num, current, total_pages, siblings);
@@ -194,6 +221,7 @@ fn create_page_button(
         }
     })
     .style(move |s| {
+        let total_pages = style_total();
         s.with_shadcn_theme(move |s, t| {
             let current = current_page.get();
             let display_page = calculate_display_page(page_num, current, total_pages, siblings);
@@ -201,11 +229,10 @@ fn create_page_button(
             let is_ellipsis = display_page == 0;
             let is_hidden = display_page > total_pages;
             let base = s
-                .min_width(36.0)
-                .height(36.0)
-                .padding_left(12.0)
-                .padding_right(12.0)
-                .font_size(14.0)
+                .min_width(t.control_height.sm)
+                .height(t.control_height.sm)
+                .padding_horiz(t.space(3.0))
+                .font_size(t.font_size.sm)
                 .display(floem::style::Display::Flex)
                 .items_center()
                 .justify_center()
@@ -230,6 +257,7 @@ fn create_page_button(
     })
     .on_click_stop(move |_| {
         let current = current_page.get();
+        let total_pages = total_pages();
         let display_page = calculate_display_page(page_num, current, total_pages, siblings);
 
         if display_page > 0 && display_page <= total_pages && display_page != current {
@@ -313,10 +341,12 @@ impl<V: IntoView + 'static> IntoView for PaginationContent<V> {
     fn into_view(self) -> Self::V {
         Box::new(
             floem::views::Container::with_id(self.id, self.child).style(|s| {
-                s.display(floem::style::Display::Flex)
-                    .flex_direction(floem::style::FlexDirection::Row)
-                    .gap(4.0)
-                    .items_center()
+                s.with_shadcn_theme(|s, t| {
+                    s.display(floem::style::Display::Flex)
+                        .flex_direction(floem::style::FlexDirection::Row)
+                        .gap(t.space(1.0))
+                        .items_center()
+                })
             }),
         )
     }
@@ -415,11 +445,10 @@ impl IntoView for PaginationLink {
                 .style(move |s| {
                     s.with_shadcn_theme(move |s, t| {
                         let base = s
-                            .min_width(36.0)
-                            .height(36.0)
-                            .padding_left(12.0)
-                            .padding_right(12.0)
-                            .font_size(14.0)
+                            .min_width(t.control_height.sm)
+                            .height(t.control_height.sm)
+                            .padding_horiz(t.space(3.0))
+                            .font_size(t.font_size.sm)
                             .display(floem::style::Display::Flex)
                             .items_center()
                             .justify_center()
@@ -493,7 +522,8 @@ impl IntoView for PaginationPrevious {
 
         Box::new(
             floem::views::Stack::horizontal((
-                floem::views::Label::new("◀").style(|s| s.font_size(12.0)),
+                floem::views::Label::new("◀")
+                    .style(|s| s.with_shadcn_theme(|s, t| s.font_size(t.font_size.xs))),
                 floem::views::Label::new(label),
             ))
             .style(move |s| {
@@ -501,11 +531,10 @@ impl IntoView for PaginationPrevious {
                     let page = current_page.get();
                     let disabled = page <= 1;
                     let base = s
-                        .gap(4.0)
-                        .padding_left(12.0)
-                        .padding_right(12.0)
-                        .height(36.0)
-                        .font_size(14.0)
+                        .gap(t.space(1.0))
+                        .padding_horiz(t.space(3.0))
+                        .height(t.control_height.sm)
+                        .font_size(t.font_size.sm)
                         .items_center()
                         .border(1.0)
                         .border_color(t.border)
@@ -586,18 +615,18 @@ impl IntoView for PaginationNext {
         Box::new(
             floem::views::Stack::horizontal((
                 floem::views::Label::new(label),
-                floem::views::Label::new("▶").style(|s| s.font_size(12.0)),
+                floem::views::Label::new("▶")
+                    .style(|s| s.with_shadcn_theme(|s, t| s.font_size(t.font_size.xs))),
             ))
             .style(move |s| {
                 s.with_shadcn_theme(move |s, t| {
                     let page = current_page.get();
                     let disabled = page >= total_pages;
                     let base = s
-                        .gap(4.0)
-                        .padding_left(12.0)
-                        .padding_right(12.0)
-                        .height(36.0)
-                        .font_size(14.0)
+                        .gap(t.space(1.0))
+                        .padding_horiz(t.space(3.0))
+                        .height(t.control_height.sm)
+                        .font_size(t.font_size.sm)
                         .items_center()
                         .border(1.0)
                         .border_color(t.border)
@@ -663,9 +692,9 @@ impl IntoView for PaginationEllipsis {
     fn into_view(self) -> Self::V {
         Box::new(floem::views::Label::new("...").style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.min_width(36.0)
-                    .height(36.0)
-                    .font_size(14.0)
+                s.min_width(t.control_height.sm)
+                    .height(t.control_height.sm)
+                    .font_size(t.font_size.sm)
                     .color(t.muted_foreground)
                     .display(floem::style::Display::Flex)
                     .items_center()
@@ -14,6 +14,10 @@
 //!     .trigger(|| label(|| "Click me"))
 //!     .content(|| label(|| "Popover content"));
 //! ```
+//!
+//! The content is rendered in an overlay and positioned by the shared
+//! [`floating`](crate::components::floating) engine: it flips to the opposite
+//! side and shifts along the trigger when it would otherwise leave the window.
 
 use floem::prelude::*;
 use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
@@ -21,6 +25,7 @@ use floem::style::CursorStyle;
 use floem::views::Decorators;
 use floem::{HasViewId, ViewId};
 
+use crate::components::floating::{ARROW_SIZE, Align, Floating, FloatingOptions, Side};
 use crate::theme::ShadcnThemeExt;
 
 /// Popover alignment relative to trigger
@@ -42,13 +47,34 @@ pub enum PopoverSide {
     Right,
 }
 
+impl From<PopoverAlign> for Align {
+    fn from(align: PopoverAlign) -> Self {
+        match align {
+            PopoverAlign::Start => Align::Start,
+            PopoverAlign::Center => Align::Center,
+            PopoverAlign::End => Align::End,
+        }
+    }
+}
+
+impl From<PopoverSide> for Side {
+    fn from(side: PopoverSide) -> Self {
+        match side {
+            PopoverSide::Top => Side::Top,
+            PopoverSide::Bottom => Side::Bottom,
+            PopoverSide::Left => Side::Left,
+            PopoverSide::Right => Side::Right,
+        }
+    }
+}
+
 /// A styled popover builder
 pub struct Popover<T, C> {
     open: RwSignal<bool>,
     trigger: Option<T>,
     content: Option<C>,
-    side: PopoverSide,
-    align: PopoverAlign,
+    options: FloatingOptions,
+    arrow: bool,
 }
 
 impl Popover<(), ()> {
@@ -58,8 +84,8 @@ impl Popover<(), ()> {
             open,
             trigger: None,
             content: None,
-            side: PopoverSide::Bottom,
-            align: PopoverAlign::Center,
+            options: FloatingOptions::new(Side::Bottom, Align::Center),
+            arrow: false,
         }
     }
 }
@@ -71,8 +97,8 @@ impl<T, C> Popover<T, C> {
             open: self.open,
             trigger: Some(trigger),
             content: self.content,
-            side: self.side,
-            align: self.align,
+            options: self.options,
+            arrow: self.arrow,
         }
     }
 
@@ -82,20 +108,46 @@ impl<T, C> Popover<T, C> {
             open: self.open,
             trigger: self.trigger,
             content: Some(content),
-            side: self.side,
-            align: self.align,
+            options: self.options,
+            arrow: self.arrow,
         }
     }
 
     /// Set which side the popover appears on
+    ///
+    /// The popover flips to the opposite side if there is not enough room.
     pub fn side(mut self, side: PopoverSide) -> Self {
-        self.side = side;
+        self.options.side = side.into();
         self
     }
 
     /// Set the alignment of the popover
     pub fn align(mut self, align: PopoverAlign) -> Self {
-        self.align = align;
+        self.options.align = align.into();
+        self
+    }
+
+    /// Set the gap between the trigger and the popover (default 4px)
+    pub fn side_offset(mut self, offset: f64) -> Self {
+        self.options.side_offset = offset;
+        self
+    }
+
+    /// Set the offset along the trigger edge, applied after alignment
+    pub fn align_offset(mut self, offset: f64) -> Self {
+        self.options.align_offset = offset;
+        self
+    }
+
+    /// Keep the popover inside the window by flipping and shifting it (default true)
+    pub fn avoid_collisions(mut self, avoid: bool) -> Self {
+        self.options.avoid_collisions = avoid;
+        self
+    }
+
+    /// Show an arrow pointing at the trigger
+    pub fn arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
         self
     }
 }
@@ -112,79 +164,80 @@ where
         let open = self.open;
         let trigger = self.trigger;
         let content = self.content;
-        let side = self.side;
-        let align = self.align;
+        let arrow = self.arrow;
+        let mut options = self.options;
+        if arrow {
+            // The arrow is drawn in the gap between trigger and content
+            options.side_offset += ARROW_SIZE;
+        }
+        let floating = Floating::new(options);
 
         // Trigger wrapper
         let trigger_view = if let Some(trigger_fn) = trigger {
-            floem::views::Container::new(trigger_fn())
-                .style(|s| s.cursor(CursorStyle::Pointer))
-                .on_click_stop(move |_| {
-                    open.update(|v| *v = !*v);
-                })
+            floating
+                .anchor(
+                    floem::views::Container::new(trigger_fn())
+                        .style(|s| s.cursor(CursorStyle::Pointer))
+                        .on_click_stop(move |_| {
+                            open.update(|v| *v = !*v);
+                        }),
+                )
                 .into_any()
         } else {
             floem::views::Empty::new().into_any()
         };
 
-        // Content panel
+        // Content panel in an Overlay - escapes parent clipping and z-index constraints
         let content_view = if let Some(content_fn) = content {
-            floem::views::Container::new(content_fn())
-                .style(move |s| {
-                    s.with_shadcn_theme(move |s, t| {
-                        let is_open = open.get();
-                        let base = s
-                            .padding(16.0)
-                            .background(t.popover)
-                            .border(1.0)
-                            .border_color(t.border)
-                            .border_radius(t.radius)
-                            .box_shadow_blur(8.0)
-                            .box_shadow_color(t.foreground.with_alpha(0.1))
-                            .position(floem::style::Position::Absolute)
-                            .z_index(50);
-                        // Position based on side (use percentage + margin for offset)
-                        let positioned = match side {
-                            PopoverSide::Top => base.inset_bottom_pct(100.0).margin_bottom(8.0),
-                            PopoverSide::Bottom => base.inset_top_pct(100.0).margin_top(8.0),
-                            PopoverSide::Left => base.inset_right_pct(100.0).margin_right(8.0),
-                            PopoverSide::Right => base.inset_left_pct(100.0).margin_left(8.0),
-                        };
-                        // Alignment
-                        let aligned = match (side, align) {
-                            (PopoverSide::Top | PopoverSide::Bottom, PopoverAlign::Start) => {
-                                positioned.inset_left(0.0)
-                            }
-                            (PopoverSide::Top | PopoverSide::Bottom, PopoverAlign::Center) => {
-                                positioned.inset_left_pct(50.0).margin_left(-50.0)
-                            }
-                            (PopoverSide::Top | PopoverSide::Bottom, PopoverAlign::End) => {
-                                positioned.inset_right(0.0)
-                            }
-                            (PopoverSide::Left | PopoverSide::Right, PopoverAlign::Start) => {
-                                positioned.inset_top(0.0)
-                            }
-                            (PopoverSide::Left | PopoverSide::Right, PopoverAlign::Center) => {
-                                positioned.inset_top_pct(50.0).margin_top(-50.0)
-                            }
-                            (PopoverSide::Left | PopoverSide::Right, PopoverAlign::End) => {
-                                positioned.inset_bottom(0.0)
-                            }
-                        };
-                        if is_open {
-                            aligned
-                        } else {
-                            aligned.display(floem::style::Display::None)
-                        }
-                    })
+            let panel = floem::views::Stack::new((
+                content_fn().into_any(),
+                if arrow {
+                    floating.arrow().into_any()
+                } else {
+                    floem::views::Empty::new().into_any()
+                },
+            ))
+            .style(move |s| {
+                s.with_shadcn_theme(move |s, t| {
+                    floating
+                        .position(s)
+                        .padding(t.space(4.0))
+                        .background(t.popover)
+                        .border(1.0)
+                        .border_color(t.border)
+                        .border_radius(t.radius)
+                        .apply_shadow(t.shadow.md)
+                        .z_index(50)
                 })
+            });
+
+            floem::views::Overlay::new()
+                .child(
+                    floem::views::Stack::new((
+                        floating.window_probe(),
+                        // Backdrop - closes the popover when clicking outside
+                        floem::views::Empty::new()
+                            .style(|s| s.absolute().inset_0())
+                            .on_click_stop(move |_| {
+                                open.set(false);
+                            }),
+                        floating.content(panel),
+                    ))
+                    .style(move |s| {
+                        let is_open = open.get();
+                        s.fixed()
+                            .inset_0()
+                            .width_full()
+                            .height_full()
+                            .apply_if(!is_open, |s| s.hide())
+                    }),
+                )
                 .into_any()
         } else {
             floem::views::Empty::new().into_any()
         };
 
-        floem::views::Container::new(floem::views::Stack::new((trigger_view, content_view)))
-            .style(|s| s.position(floem::style::Position::Relative))
+        floem::views::Stack::new((trigger_view, content_view))
     }
 }
 
@@ -310,13 +363,12 @@ impl<V: IntoView + 'static> IntoView for PopoverContent<V> {
                 s.with_shadcn_theme(move |s, t| {
                     let is_open = open.get();
                     let base = s
-                        .padding(16.0)
+                        .padding(t.space(4.0))
                         .background(t.popover)
                         .border(1.0)
                         .border_color(t.border)
                         .border_radius(t.radius)
-                        .box_shadow_blur(8.0)
-                        .box_shadow_color(t.foreground.with_alpha(0.1))
+                        .apply_shadow(t.shadow.md)
                         .z_index(50);
                     if is_open {
                         base
@@ -24,6 +24,7 @@ use floem::views::Decorators;
 use floem::{HasViewId, ViewId};
 use floem_tailwind::TailwindExt;
 
+use crate::components::focus_scope;
 use crate::theme::ShadcnThemeExt;
 
 // ============================================================================
@@ -127,7 +128,8 @@ impl RadioGroupItem {
         // Indicator: CircleIcon fill-primary size-2 (8px filled dot with primary color)
 
         // Radio circle
-        let radio_circle = floem::views::Container::new(
+        let radio_circle = floem::views::Container::with_id(
+            self.id,
             // Inner dot (visible when selected) - fill-primary size-2
             floem::views::Empty::new().style(move |s| {
                 let val = item_value.clone();
@@ -161,6 +163,7 @@ impl RadioGroupItem {
                     .background(peniko::Color::TRANSPARENT) // transparent background
                     .apply_if(disabled, |s| s.cursor(CursorStyle::Default))
                     .apply_if(!disabled, |s| s.cursor(CursorStyle::Pointer))
+                    .focusable(!disabled)
             })
         });
 
@@ -185,13 +188,14 @@ impl RadioGroupItem {
             .style(|s| s.gap_2().items_center());
 
         if !disabled {
-            container
-                .on_click_stop(move |_| {
-                    if let Some(signal) = selected_signal {
-                        signal.update(|v| *v = item_value_click.clone());
-                    }
-                })
-                .into_any()
+            let select = move || {
+                if let Some(signal) = selected_signal {
+                    signal.update(|v| *v = item_value_click.clone());
+                }
+            };
+            // Reachable with Tab, selected with Enter/Space
+            focus_scope::activate_on_key(self.id, select.clone());
+            container.on_click_stop(move |_| select()).into_any()
         } else {
             container.into_any()
         }
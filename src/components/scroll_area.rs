@@ -20,9 +20,21 @@
 //!     label(|| "Column 2"),
 //!     // ... many columns
 //! ))).horizontal().width(300.0);
+//!
+//! // Virtualized list - only the visible rows are built
+//! let lines = RwSignal::new((0..50_000).map(|i| format!("line {i}")).collect::<Vec<_>>());
+//! VirtualScrollArea::new(lines, |_, line: &String| Label::new(line.clone()))
+//!     .item_height(20.0)
+//!     .height(400.0);
 //! ```
 
+use std::ops::Range;
+use std::rc::Rc;
+
+use floem::kurbo::{Point, Rect};
 use floem::prelude::*;
+use floem::reactive::{Effect, RwSignal, SignalGet, SignalUpdate, SignalWith};
+use floem::style::Style;
 use floem::views::Decorators;
 use floem::{HasViewId, ViewId};
 
@@ -230,56 +242,336 @@ impl<V: IntoView + 'static> IntoView for ScrollAreaWithBar<V> {
         let height = self.height;
         let width = self.width;
 
-        Box::new(floem::views::Scroll::new(self.child).style(move |s| {
-            s.with_shadcn_theme(move |s, t| {
-                let base = s
-                    .border(1.0)
-                    .border_color(t.border)
-                    .border_radius(t.radius)
-                    .flex_grow(1.0)
-                    .flex_basis(0.0);
-
-                match (width, height) {
-                    (Some(w), Some(h)) => base.width(w).height(h),
-                    (Some(w), None) => base.width(w),
-                    (None, Some(h)) => base.height(h),
-                    (None, None) => base,
-                }
-            })
-        }))
+        Box::new(floem::views::Scroll::new(self.child).style(move |s| bar_style(s, width, height)))
     }
 }
 
+/// Frame styling shared by `ScrollAreaWithBar` and `VirtualScrollArea`
+fn bar_style(s: Style, width: Option<f64>, height: Option<f64>) -> Style {
+    s.with_shadcn_theme(move |s, t| {
+        let base = s
+            .border(1.0)
+            .border_color(t.border)
+            .border_radius(t.radius)
+            .flex_grow(1.0)
+            .flex_basis(0.0);
+
+        match (width, height) {
+            (Some(w), Some(h)) => base.width(w).height(h),
+            (Some(w), None) => base.width(w),
+            (None, Some(h)) => base.height(h),
+            (None, None) => base,
+        }
+    })
+}
+
 // ============================================================================
-// VirtualList helper
+// VirtualScrollArea
 // ============================================================================
 
-/// A virtualized list for large datasets (uses floem's virtual_stack)
-pub struct VirtualScrollArea;
+/// Number of rows built above and below the viewport by default
+const DEFAULT_OVERSCAN: usize = 8;
+
+/// Viewport height assumed until the scroll view reports its real size
+const FALLBACK_VIEWPORT_HEIGHT: f64 = 400.0;
+
+/// Row positions of a virtualized list
+///
+/// Fixed-height lists are computed arithmetically; variable-height lists keep
+/// the prefix sums of the row heights so lookups are a binary search.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VirtualLayout {
+    /// `count` rows of `height` each
+    Fixed { count: usize, height: f64 },
+    /// `offsets[i]` is the top of row `i`; the last entry is the total height
+    Variable { offsets: Vec<f64> },
+}
 
-impl VirtualScrollArea {
-    /// Create a hint for using floem's virtual_stack
-    /// Note: Virtual scrolling requires using floem's virtual_stack directly
-    /// with an im::Vector and a closure for rendering items.
-    ///
-    /// Example:
-    /// ```rust
-    /// use floem::views::virtual_stack;
-    /// use im::Vector;
-    ///
-    /// let items: Vector<String> = (0..10000)
-    ///     .map(|i| format!("Item {}", i))
-    ///     .collect();
+impl VirtualLayout {
+    /// Layout for `count` rows of the same height
+    pub fn fixed(count: usize, height: f64) -> Self {
+        Self::Fixed {
+            count,
+            height: height.max(1.0),
+        }
+    }
+
+    /// Layout for rows with the given heights
+    pub fn variable(heights: impl IntoIterator<Item = f64>) -> Self {
+        let mut offsets = vec![0.0];
+        let mut top = 0.0;
+        for height in heights {
+            top += height.max(0.0);
+            offsets.push(top);
+        }
+        Self::Variable { offsets }
+    }
+
+    /// Number of rows
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Fixed { count, .. } => *count,
+            Self::Variable { offsets } => offsets.len() - 1,
+        }
+    }
+
+    /// Returns true if there are no rows
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Height of all rows together
+    pub fn total_height(&self) -> f64 {
+        match self {
+            Self::Fixed { count, height } => *count as f64 * height,
+            Self::Variable { offsets } => offsets.last().copied().unwrap_or(0.0),
+        }
+    }
+
+    /// Top edge of the row at `index` (the total height past the end)
+    pub fn row_top(&self, index: usize) -> f64 {
+        let index = index.min(self.len());
+        match self {
+            Self::Fixed { height, .. } => index as f64 * height,
+            Self::Variable { offsets } => offsets[index],
+        }
+    }
+
+    /// Height of the row at `index` (0 past the end)
+    pub fn row_height(&self, index: usize) -> f64 {
+        if index >= self.len() {
+            return 0.0;
+        }
+        match self {
+            Self::Fixed { height, .. } => *height,
+            Self::Variable { offsets } => offsets[index + 1] - offsets[index],
+        }
+    }
+
+    /// Index of the row containing the vertical position `y`, clamped to the rows
+    pub fn row_at(&self, y: f64) -> usize {
+        let last = self.len().saturating_sub(1);
+        if y <= 0.0 {
+            return 0;
+        }
+        match self {
+            Self::Fixed { height, .. } => ((y / height) as usize).min(last),
+            Self::Variable { offsets } => offsets
+                .partition_point(|&top| top <= y)
+                .saturating_sub(1)
+                .min(last),
+        }
+    }
+
+    /// Rows intersecting the viewport `top..top + height`, widened by `overscan` rows
+    pub fn visible_range(&self, top: f64, height: f64, overscan: usize) -> Range<usize> {
+        if self.is_empty() {
+            return 0..0;
+        }
+        let start = self.row_at(top).saturating_sub(overscan);
+        let end = (self.row_at(top + height) + 1 + overscan).min(self.len());
+        start..end
+    }
+
+    /// Rectangle of the row at `index`, spanning `width`
+    pub fn row_rect(&self, index: usize, width: f64) -> Rect {
+        let top = self.row_top(index);
+        Rect::new(0.0, top, width, top + self.row_height(index))
+    }
+}
+
+/// A virtualized, themed scroll area for large lists
+///
+/// Only the rows in (and slightly around) the viewport are built, and rows
+/// that stay in view while scrolling are kept. Rows can have a fixed height
+/// or a height computed per item.
+#[allow(clippy::type_complexity)]
+pub struct VirtualScrollArea<T: 'static> {
+    id: ViewId,
+    items: RwSignal<Vec<T>>,
+    row: Rc<dyn Fn(usize, &T) -> Box<dyn View>>,
+    item_height: f64,
+    height_fn: Option<Rc<dyn Fn(usize, &T) -> f64>>,
+    overscan: usize,
+    scroll_request: RwSignal<Option<usize>>,
+    selected: Option<RwSignal<Option<usize>>>,
+    width: Option<f64>,
+    height: Option<f64>,
+}
+
+impl<T: 'static> VirtualScrollArea<T> {
+    /// Create a virtual scroll area over `items`, building each row with `row`
     ///
-    /// virtual_stack(
-    ///     floem::views::VirtualDirection::Vertical,
-    ///     floem::views::VirtualItemSize::Fixed(Box::new(|| 32.0)),
-    ///     move || items.clone(),
-    ///     move |item| item.clone(),
-    ///     move |item| label(move || item.clone()),
-    /// )
-    /// ```
-    pub fn usage_hint() -> &'static str {
-        "Use floem::views::virtual_stack for virtualized scrolling of large lists"
+    /// `row` receives the item's index and a reference to the item.
+    pub fn new<V: IntoView + 'static>(
+        items: RwSignal<Vec<T>>,
+        row: impl Fn(usize, &T) -> V + 'static,
+    ) -> Self {
+        Self {
+            id: ViewId::new(),
+            items,
+            row: Rc::new(move |index, item| row(index, item).into_any()),
+            item_height: 32.0,
+            height_fn: None,
+            overscan: DEFAULT_OVERSCAN,
+            scroll_request: RwSignal::new(None),
+            selected: None,
+            width: None,
+            height: None,
+        }
+    }
+
+    /// Set a fixed height for every row (default: 32)
+    pub fn item_height(mut self, height: f64) -> Self {
+        self.item_height = height;
+        self.height_fn = None;
+        self
+    }
+
+    /// Compute each row's height from its index and item
+    pub fn variable_height(mut self, height: impl Fn(usize, &T) -> f64 + 'static) -> Self {
+        self.height_fn = Some(Rc::new(height));
+        self
+    }
+
+    /// Set how many rows are built beyond each edge of the viewport
+    pub fn overscan(mut self, rows: usize) -> Self {
+        self.overscan = rows;
+        self
+    }
+
+    /// Keep the item at the selected index scrolled into view
+    pub fn selected(mut self, selected: RwSignal<Option<usize>>) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    /// Set fixed width
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set fixed height
+    pub fn height(mut self, height: f64) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Get the signal that scrolls a row to the top of the viewport when set
+    pub fn scroll_to_index_signal(&self) -> RwSignal<Option<usize>> {
+        self.scroll_request
+    }
+
+    /// Scroll the row at `index` to the top of the viewport
+    pub fn scroll_to_index(&self, index: usize) {
+        self.scroll_request.set(Some(index));
+    }
+}
+
+impl<T: 'static> HasViewId for VirtualScrollArea<T> {
+    fn view_id(&self) -> ViewId {
+        self.id
+    }
+}
+
+impl<T: 'static> IntoView for VirtualScrollArea<T> {
+    type V = Box<dyn View>;
+    type Intermediate = Self;
+
+    fn into_intermediate(self) -> Self::Intermediate {
+        self
+    }
+
+    fn into_view(self) -> Self::V {
+        let items = self.items;
+        let row = self.row;
+        let item_height = self.item_height;
+        let height_fn = self.height_fn;
+        let overscan = self.overscan;
+        let scroll_request = self.scroll_request;
+        let selected = self.selected;
+        let width = self.width;
+        let height = self.height;
+
+        // Row positions, recomputed when the items change
+        let layout = RwSignal::new(VirtualLayout::fixed(0, item_height));
+        let revision = RwSignal::new(0u64);
+        Effect::new(move |_| {
+            let next = items.with(|items| match &height_fn {
+                Some(height_fn) => VirtualLayout::variable(
+                    items
+                        .iter()
+                        .enumerate()
+                        .map(|(index, item)| height_fn(index, item)),
+                ),
+                None => VirtualLayout::fixed(items.len(), item_height),
+            });
+            layout.set(next);
+            revision.update(|r| *r += 1);
+        });
+
+        let viewport = RwSignal::new(Rect::new(
+            0.0,
+            0.0,
+            width.unwrap_or(0.0),
+            height.unwrap_or(FALLBACK_VIEWPORT_HEIGHT),
+        ));
+
+        // Visible rows, keyed by index so rows that stay in view are kept
+        // while scrolling. Changing the items rebuilds them all.
+        let window = floem::views::dyn_stack(
+            move || {
+                let viewport = viewport.get();
+                // `revision` changes whenever the layout does
+                let revision = revision.get();
+                let range = layout.with_untracked(|layout| {
+                    layout.visible_range(viewport.y0, viewport.height(), overscan)
+                });
+                range.map(move |index| (revision, index))
+            },
+            |key| *key,
+            move |(_, index)| {
+                let (top, row_height) = layout
+                    .with_untracked(|layout| (layout.row_top(index), layout.row_height(index)));
+                let view =
+                    items.with_untracked(|items| items.get(index).map(|item| row(index, item)));
+                floem::views::Container::new(view.unwrap_or_else(|| ().into_any())).style(
+                    move |s| {
+                        s.absolute()
+                            .inset_top(top)
+                            .inset_left(0.0)
+                            .width_full()
+                            .height(row_height)
+                    },
+                )
+            },
+        )
+        .style(|s| s.size_full());
+
+        // Full-height content so the scrollbar reflects the whole list
+        let content = floem::views::Container::new(window)
+            .style(move |s| s.width_full().height(layout.with(|l| l.total_height())));
+
+        let scroll = floem::views::Scroll::new(content)
+            .on_scroll(move |new_viewport| {
+                viewport.set(new_viewport);
+            })
+            .scroll_to(move || {
+                let index = scroll_request.get()?;
+                let top = layout.with_untracked(|layout| layout.row_top(index));
+                Some(Point::new(0.0, top))
+            })
+            .ensure_visible(move || {
+                let index = selected.and_then(|selected| selected.get());
+                match index {
+                    Some(index) => layout.with(|layout| layout.row_rect(index, 1.0)),
+                    // Nothing selected: ask for the current viewport so it stays put
+                    None => viewport.get_untracked(),
+                }
+            })
+            .style(move |s| bar_style(s, width, height));
+
+        Box::new(scroll)
     }
 }
@@ -19,15 +19,22 @@
 //!     ]);
 //! ```
 
+use std::rc::Rc;
+
 use floem::prelude::*;
-use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
+use floem::reactive::{Effect, RwSignal, SignalGet, SignalUpdate};
 use floem::style::CursorStyle;
 use floem::views::Decorators;
 use floem::{HasViewId, ViewId};
 use floem_tailwind::TailwindExt;
 
+use super::floating::{Align, Floating, FloatingOptions, Side};
+use super::menu_nav::MenuNav;
 use crate::theme::ShadcnThemeExt;
 
+/// Maximum number of items rendered in the dropdown
+const MAX_ITEMS: usize = 10;
+
 // ============================================================================
 // SelectItemData (data structure)
 // ============================================================================
@@ -122,10 +129,29 @@ impl IntoView for Select {
         let disabled = self.disabled;
         let is_open = RwSignal::new(false);
 
-        // Track trigger position (window coords via on_move) and size (via on_resize)
-        // for positioning the Overlay dropdown below the trigger.
-        let trigger_origin = RwSignal::new(floem::kurbo::Point::ZERO);
-        let trigger_size = RwSignal::new(floem::kurbo::Size::ZERO);
+        // Keyboard navigation: every rendered item is navigable and selects its value
+        let nav = MenuNav::new(is_open);
+        for item in items.iter().take(MAX_ITEMS) {
+            let value = item.value.clone();
+            nav.register(
+                item.label.clone(),
+                item.disabled,
+                Some(Rc::new(move || selected.set(Some(value.clone())))),
+            );
+        }
+        // Opening highlights the currently selected item
+        let items_for_nav = items.clone();
+        Effect::new(move |_| {
+            let index = selected
+                .get()
+                .and_then(|val| items_for_nav.iter().position(|i| i.value == val));
+            nav.selected_index.set(index);
+        });
+
+        // Places the Overlay dropdown below the trigger (sideOffset=6), flipping
+        // above it when there is not enough room
+        let floating =
+            Floating::new(FloatingOptions::new(Side::Bottom, Align::Start).side_offset(6.0));
 
         let items_for_trigger = items.clone();
 
@@ -150,27 +176,31 @@ impl IntoView for Select {
                 s.with_shadcn_theme(move |s, t| {
                     let has_value = selected.get().is_some();
                     // data-[placeholder]:text-muted-foreground
-                    s.flex_grow(1.0).text_sm().color(if has_value {
-                        t.foreground
-                    } else {
-                        t.muted_foreground
-                    })
+                    s.flex_grow(1.0)
+                        .font_size(t.font_size.sm)
+                        .color(if has_value {
+                            t.foreground
+                        } else {
+                            t.muted_foreground
+                        })
                 })
             }),
             // ChevronDown icon - size-4 opacity-50
             floem::views::Label::new("▼").style(|s| {
                 s.with_shadcn_theme(move |s, t| {
-                    s.font_size(10.0).color(t.muted_foreground).flex_shrink(0.0)
+                    s.font_size(t.font_size.xs - 2.0) // 10px glyph
+                        .color(t.muted_foreground)
+                        .flex_shrink(0.0)
                 })
             }),
         ))
         .style(move |s| {
             s.with_shadcn_theme(move |s, t| {
                 s.min_width(120.0) // min-w-[8rem]
-                    .h_9() // h-9 = 36px
-                    .px_3() // px-3 = 12px
-                    .py_2() // py-2 = 8px
-                    .gap_2() // gap-2 = 8px
+                    .height(t.control_height.sm) // h-9 = 36px
+                    .padding_horiz(t.space(3.0)) // px-3 = 12px
+                    .padding_vert(t.space(2.0)) // py-2 = 8px
+                    .gap(t.space(2.0)) // gap-2 = 8px
                     .items_center()
                     .border_1() // border
                     .border_color(t.input) // border-input
@@ -180,39 +210,44 @@ impl IntoView for Select {
                     .apply_if(disabled, |s| s.cursor(CursorStyle::Default))
                     .apply_if(!disabled, |s| {
                         s.cursor(CursorStyle::Pointer)
+                            .focusable(true)
                             .hover(|s| s.border_color(t.ring))
+                            // focus-visible:border-ring
+                            .focus(|s| {
+                                s.border_color(t.ring).apply_if(t.is_high_contrast(), |s| {
+                                    s.outline(t.ring_width).outline_color(t.ring)
+                                })
+                            })
                     })
             })
-        })
-        // Track trigger position (window coords) and size for dropdown placement
-        .on_move(move |origin| {
-            trigger_origin.set(origin);
-        })
-        .on_resize(move |rect| {
-            trigger_size.set(rect.size());
         });
+        // Track trigger position (window coords) and size for dropdown placement
+        let trigger = floating.anchor(trigger);
 
         let trigger = if !disabled {
+            let trigger_id = trigger.id();
+            nav.attach(trigger_id);
             trigger
                 .on_click_stop(move |_| {
-                    is_open.update(|v| *v = !*v);
+                    trigger_id.request_focus();
+                    nav.toggle();
                 })
                 .into_any()
         } else {
             trigger.into_any()
         };
 
-        // Build items (up to 10)
-        let item0 = create_select_item(0, items.clone(), selected, is_open);
-        let item1 = create_select_item(1, items.clone(), selected, is_open);
-        let item2 = create_select_item(2, items.clone(), selected, is_open);
-        let item3 = create_select_item(3, items.clone(), selected, is_open);
-        let item4 = create_select_item(4, items.clone(), selected, is_open);
-        let item5 = create_select_item(5, items.clone(), selected, is_open);
-        let item6 = create_select_item(6, items.clone(), selected, is_open);
-        let item7 = create_select_item(7, items.clone(), selected, is_open);
-        let item8 = create_select_item(8, items.clone(), selected, is_open);
-        let item9 = create_select_item(9, items.clone(), selected, is_open);
+        // Build items (up to MAX_ITEMS)
+        let item0 = create_select_item(0, items.clone(), selected, nav);
+        let item1 = create_select_item(1, items.clone(), selected, nav);
+        let item2 = create_select_item(2, items.clone(), selected, nav);
+        let item3 = create_select_item(3, items.clone(), selected, nav);
+        let item4 = create_select_item(4, items.clone(), selected, nav);
+        let item5 = create_select_item(5, items.clone(), selected, nav);
+        let item6 = create_select_item(6, items.clone(), selected, nav);
+        let item7 = create_select_item(7, items.clone(), selected, nav);
+        let item8 = create_select_item(8, items.clone(), selected, nav);
+        let item9 = create_select_item(9, items.clone(), selected, nav);
 
         let items_container = floem::views::Stack::vertical((
             item0, item1, item2, item3, item4, item5, item6, item7, item8, item9,
@@ -222,6 +257,7 @@ impl IntoView for Select {
         // Dropdown in Overlay - escapes parent clipping and z-index constraints
         let dropdown_overlay = Overlay::new().child(
             floem::views::Stack::new((
+                floating.window_probe(),
                 // Backdrop - closes dropdown when clicking outside
                 floem::views::Empty::new()
                     .style(move |s| {
@@ -229,28 +265,27 @@ impl IntoView for Select {
                         // Transparent backdrop - just for click handling
                     })
                     .on_click_stop(move |_| {
-                        is_open.set(false);
+                        nav.close();
                     }),
-                // Dropdown content - positioned relative to trigger using window coordinates
-                floem::views::Container::new(items_container).style(move |s| {
-                    s.with_shadcn_theme(move |s, t| {
-                        let origin = trigger_origin.get();
-                        let size = trigger_size.get();
-                        // Position below the trigger using window coordinates
-                        s.absolute()
-                            .inset_left(origin.x)
-                            .inset_top(origin.y + size.height + 6.0) // 6px gap (sideOffset=6)
-                            .min_width(size.width.max(120.0))
-                            .p_1() // p-1 = 4px (viewport padding)
-                            .background(t.popover)
-                            .color(t.popover_foreground)
-                            .border_1()
-                            .border_color(t.border)
-                            .rounded_md()
-                            .shadow_md() // shadow-md
-                            .z_index(100)
-                    })
-                }),
+                // Dropdown content - positioned next to the trigger using window coordinates
+                floating.content(
+                    floem::views::Container::new(items_container).style(move |s| {
+                        s.with_shadcn_theme(move |s, t| {
+                            let width = floating.anchor_rect().width();
+                            floating
+                                .position(s)
+                                .min_width(width.max(120.0))
+                                .padding(t.space(1.0)) // p-1 = 4px (viewport padding)
+                                .background(t.popover)
+                                .color(t.popover_foreground)
+                                .border_1()
+                                .border_color(t.border)
+                                .rounded_md()
+                                .shadow_md() // shadow-md
+                                .z_index(100)
+                        })
+                    }),
+                ),
             ))
             .style(move |s| {
                 let open = is_open.get();
@@ -271,12 +306,11 @@ fn create_select_item(
     index: usize,
     items: Vec<SelectItemData>,
     selected: RwSignal<Option<String>>,
-    is_open: RwSignal<bool>,
+    nav: MenuNav,
 ) -> impl IntoView {
     let items_for_label = items.clone();
     let items_for_style = items.clone();
-    let items_for_click = items.clone();
-    let items_for_handler = items;
+    let items_for_click = items;
 
     // shadcn/ui SelectItem (v4 new-york):
     // py-1.5 pr-8 pl-2 text-sm rounded-sm
@@ -291,7 +325,7 @@ fn create_select_item(
                     .map(|i| i.label.clone())
                     .unwrap_or_default()
             })
-            .style(|s| s.text_sm().flex_grow(1.0)),
+            .style(|s| s.with_shadcn_theme(|s, t| s.font_size(t.font_size.sm).flex_grow(1.0))),
             // Check icon (at end via flex)
             floem::views::Label::new("✓").style(move |s| {
                 let items = items_for_label.clone();
@@ -300,8 +334,8 @@ fn create_select_item(
                     let is_selected = item_opt
                         .map(|i| Some(i.value.clone()) == selected.get())
                         .unwrap_or(false);
-                    s.size_4() // size-4 = 16px
-                        .text_sm()
+                    s.size(t.space(4.0), t.space(4.0)) // size-4 = 16px
+                        .font_size(t.font_size.sm)
                         .color(t.foreground)
                         .items_center()
                         .justify_center()
@@ -310,25 +344,21 @@ fn create_select_item(
                 })
             }),
         ))
-        .style(|s| s.width_full().items_center().gap_2()),
+        .style(|s| s.with_shadcn_theme(|s, t| s.width_full().items_center().gap(t.space(2.0)))),
     )
     .style(move |s| {
         let items = items_for_click.clone();
         s.with_shadcn_theme(move |s, t| {
             let item_opt = items.get(index);
             let is_visible = item_opt.is_some();
-            let is_selected = item_opt
-                .map(|i| Some(i.value.clone()) == selected.get())
-                .unwrap_or(false);
             let is_disabled = item_opt.map(|i| i.disabled).unwrap_or(false);
+            let is_highlighted = nav.is_highlighted(index);
 
             // py-1.5 = 6px, pl-2 = 8px, pr-2 = 8px (check at end via flex)
             let base = s
                 .width_full()
-                .padding_top(6.0) // py-1.5 = 6px
-                .padding_bottom(6.0)
-                .padding_left(8.0) // pl-2 = 8px
-                .padding_right(8.0) // pr-2 = 8px
+                .padding_vert(t.space(1.5)) // py-1.5 = 6px
+                .padding_horiz(t.space(2.0)) // pl-2 pr-2 = 8px
                 .items_center()
                 .rounded_sm() // rounded-sm = 3px
                 .cursor(if is_disabled {
@@ -339,8 +369,9 @@ fn create_select_item(
 
             if !is_visible {
                 base.display(floem::style::Display::None)
-            } else if is_selected {
-                // Selected state
+            } else if is_highlighted {
+                // Keyboard-highlighted state (focus:bg-accent); the selected
+                // item is marked by its check icon
                 base.background(t.accent).color(t.accent_foreground)
             } else if is_disabled {
                 // Disabled state - opacity-50
@@ -353,12 +384,8 @@ fn create_select_item(
         })
     })
     .on_click_stop(move |_| {
-        if let Some(item) = items_for_handler.get(index) {
-            if !item.disabled {
-                selected.set(Some(item.value.clone()));
-                is_open.set(false);
-            }
-        }
+        // Selects the value and closes the dropdown unless the item is disabled
+        nav.activate(index);
     })
 }
 
@@ -407,10 +434,10 @@ impl<V: IntoView + 'static> IntoView for SelectTrigger<V> {
             floem::views::Container::with_id(self.id, self.child)
                 .style(|s| {
                     s.with_shadcn_theme(move |s, t| {
-                        s.h_9() // h-9 = 36px
-                            .px_3() // px-3 = 12px
-                            .py_2() // py-2 = 8px
-                            .gap_2() // gap-2
+                        s.height(t.control_height.sm) // h-9 = 36px
+                            .padding_horiz(t.space(3.0)) // px-3 = 12px
+                            .padding_vert(t.space(2.0)) // py-2 = 8px
+                            .gap(t.space(2.0)) // gap-2
                             .items_center()
                             .border_1() // border
                             .border_color(t.input) // border-input
@@ -479,8 +506,8 @@ impl<V: IntoView + 'static> IntoView for SelectContent<V> {
                         .inset_top_pct(100.0)
                         .inset_left(0.0)
                         .inset_right(0.0)
-                        .margin_top(6.0) // sideOffset=6
-                        .p_1() // p-1 = 4px
+                        .margin_top(t.space(1.5)) // sideOffset=6
+                        .padding(t.space(1.0)) // p-1 = 4px
                         .background(t.popover) // bg-popover
                         .color(t.popover_foreground) // text-popover-foreground
                         .border_1() // border
@@ -578,7 +605,9 @@ impl IntoView for SelectItem {
             floem::views::Container::new(
                 floem::views::Stack::horizontal((
                     // Label text
-                    floem::views::Label::new(label).style(|s| s.text_sm().flex_grow(1.0)),
+                    floem::views::Label::new(label).style(|s| {
+                        s.with_shadcn_theme(|s, t| s.font_size(t.font_size.sm).flex_grow(1.0))
+                    }),
                     // Check icon (at end via flex)
                     floem::views::Label::new("✓").style(move |s| {
                         let val = value.clone();
@@ -586,8 +615,8 @@ impl IntoView for SelectItem {
                             let is_selected = selected
                                 .map(|sig| sig.get() == Some(val.clone()))
                                 .unwrap_or(false);
-                            s.size_4() // size-4 = 16px
-                                .text_sm()
+                            s.size(t.space(4.0), t.space(4.0)) // size-4 = 16px
+                                .font_size(t.font_size.sm)
                                 .color(t.foreground)
                                 .items_center()
                                 .justify_center()
@@ -596,7 +625,9 @@ impl IntoView for SelectItem {
                         })
                     }),
                 ))
-                .style(|s| s.width_full().items_center().gap_2()),
+                .style(|s| {
+                    s.with_shadcn_theme(|s, t| s.width_full().items_center().gap(t.space(2.0)))
+                }),
             )
             .style(move |s| {
                 let val = value_for_style.clone();
@@ -608,11 +639,9 @@ impl IntoView for SelectItem {
                     // py-1.5 = 6px, pl-2 = 8px, pr-2 = 8px (check at end via flex)
                     let base = s
                         .width_full()
-                        .padding_top(6.0) // py-1.5 = 6px
-                        .padding_bottom(6.0)
-                        .padding_left(8.0) // pl-2 = 8px
-                        .padding_right(8.0) // pr-2 = 8px
-                        .gap_2() // gap-2 = 8px
+                        .padding_vert(t.space(1.5)) // py-1.5 = 6px
+                        .padding_horiz(t.space(2.0)) // pl-2 pr-2 = 8px
+                        .gap(t.space(2.0)) // gap-2 = 8px
                         .items_center()
                         .rounded_sm() // rounded-sm
                         .cursor(if disabled {
@@ -685,10 +714,9 @@ impl IntoView for SelectLabel {
         // text-muted-foreground px-2 py-1.5 text-xs
         Box::new(floem::views::Label::with_id(self.id, self.text).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.px_2() // px-2 = 8px
-                    .padding_top(6.0) // py-1.5 = 6px
-                    .padding_bottom(6.0)
-                    .text_xs() // text-xs = 12px
+                s.padding_horiz(t.space(2.0)) // px-2 = 8px
+                    .padding_vert(t.space(1.5)) // py-1.5 = 6px
+                    .font_size(t.font_size.xs) // text-xs = 12px
                     .color(t.muted_foreground) // text-muted-foreground
             })
         }))
@@ -737,10 +765,8 @@ impl IntoView for SelectSeparator {
                 s.width_full()
                     .height(1.0) // h-px
                     .background(t.border) // bg-border
-                    .margin_left(-4.0) // -mx-1
-                    .margin_right(-4.0) // -mx-1
-                    .margin_top(4.0) // my-1
-                    .margin_bottom(4.0) // my-1
+                    .margin_horiz(-t.space(1.0)) // -mx-1
+                    .margin_vert(t.space(1.0)) // my-1
             })
         }))
     }
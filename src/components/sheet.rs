@@ -17,6 +17,13 @@
 //!     ))
 //! ).side(SheetSide::Right));
 //! ```
+//!
+//! While open, focus is trapped inside the sheet: the first focusable element
+//! is focused, Tab/Shift+Tab cycle within the sheet and Escape closes it
+//! (unless vetoed with `Sheet::on_escape_key_down`). Focus returns to the
+//! `SheetTrigger` that opened it.
+
+use std::rc::Rc;
 
 use floem::prelude::*;
 use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
@@ -25,6 +32,7 @@ use floem::views::{Decorators, Overlay};
 use floem::{HasViewId, ViewId};
 use floem_tailwind::TailwindExt;
 
+use crate::components::focus_scope::{self, FocusScope};
 use crate::theme::ShadcnThemeExt;
 
 /// Which side the sheet slides in from
@@ -46,6 +54,7 @@ pub struct Sheet<V> {
     id: ViewId,
     open: RwSignal<bool>,
     content: V,
+    on_escape: Option<Rc<dyn Fn() -> bool>>,
 }
 
 impl<V: IntoView + 'static> Sheet<V> {
@@ -55,8 +64,17 @@ impl<V: IntoView + 'static> Sheet<V> {
             id: ViewId::new(),
             open,
             content,
+            on_escape: None,
         }
     }
+
+    /// Set a handler that runs when Escape is pressed while the sheet is open
+    ///
+    /// Return `false` to keep the sheet open.
+    pub fn on_escape_key_down(mut self, handler: impl Fn() -> bool + 'static) -> Self {
+        self.on_escape = Some(Rc::new(handler));
+        self
+    }
 }
 
 impl<V: IntoView + 'static> HasViewId for Sheet<V> {
@@ -89,8 +107,16 @@ impl<V: IntoView + 'static> IntoView for Sheet<V> {
                 open.update(|v| *v = false);
             });
 
-        // Content wrapper
-        let content_wrapper = floem::views::Container::new(self.content);
+        // Content wrapper - built in the focus scope so closers and inputs
+        // register with it; focusable so Escape works without focusable children
+        let focus = FocusScope::new(open);
+        focus.set_on_escape(self.on_escape);
+        let content = self.content;
+        let content_wrapper = focus
+            .provide()
+            .enter(move || floem::views::Container::new(content))
+            .style(|s| s.focusable(true));
+        focus.attach(content_wrapper.id());
 
         // Use Overlay with fixed positioning
         let sheet_overlay = Overlay::new()
@@ -112,6 +138,58 @@ impl<V: IntoView + 'static> IntoView for Sheet<V> {
     }
 }
 
+// ============================================================================
+// SheetTrigger
+// ============================================================================
+
+/// Element that opens a sheet when clicked
+///
+/// Focus returns to the trigger when the sheet closes.
+pub struct SheetTrigger<V> {
+    id: ViewId,
+    open: RwSignal<bool>,
+    child: V,
+}
+
+impl<V: IntoView + 'static> SheetTrigger<V> {
+    /// Create a new sheet trigger
+    pub fn new(open: RwSignal<bool>, child: V) -> Self {
+        Self {
+            id: ViewId::new(),
+            open,
+            child,
+        }
+    }
+}
+
+impl<V: IntoView + 'static> HasViewId for SheetTrigger<V> {
+    fn view_id(&self) -> ViewId {
+        self.id
+    }
+}
+
+impl<V: IntoView + 'static> IntoView for SheetTrigger<V> {
+    type V = Box<dyn View>;
+    type Intermediate = Self;
+
+    fn into_intermediate(self) -> Self::Intermediate {
+        self
+    }
+
+    fn into_view(self) -> Self::V {
+        let open = self.open;
+        let id = self.id;
+        Box::new(
+            floem::views::Container::with_id(id, self.child)
+                .style(|s| s.cursor(CursorStyle::Pointer).focusable(true))
+                .on_click_stop(move |_| {
+                    focus_scope::remember_opener(id);
+                    open.update(|v| *v = true);
+                }),
+        )
+    }
+}
+
 // ============================================================================
 // SheetContent
 // ============================================================================
@@ -163,12 +241,12 @@ impl<V: IntoView + 'static> IntoView for SheetContent<V> {
                     let base = s
                         .background(t.background)
                         .border_color(t.border)
-                        .padding(24.0)
+                        .padding(t.space(6.0))
                         .position(floem::style::Position::Absolute)
                         .z_index(50)
                         .display(floem::style::Display::Flex)
                         .flex_direction(floem::style::FlexDirection::Column)
-                        .gap(16.0);
+                        .gap(t.space(4.0));
                     match side {
                         SheetSide::Top => base
                             .inset_top(0.0)
@@ -187,13 +265,13 @@ impl<V: IntoView + 'static> IntoView for SheetContent<V> {
                             .inset_bottom(0.0)
                             .inset_left(0.0)
                             .border_right(1.0)
-                            .width(320.0),
+                            .width(t.space(80.0)),
                         SheetSide::Right => base
                             .inset_top(0.0)
                             .inset_bottom(0.0)
                             .inset_right(0.0)
                             .border_left(1.0)
-                            .width(320.0),
+                            .width(t.space(80.0)),
                     }
                 })
             }),
@@ -238,9 +316,11 @@ impl<V: IntoView + 'static> IntoView for SheetHeader<V> {
     fn into_view(self) -> Self::V {
         Box::new(
             floem::views::Container::with_id(self.id, self.child).style(|s| {
-                s.display(floem::style::Display::Flex)
-                    .flex_direction(floem::style::FlexDirection::Column)
-                    .gap(4.0)
+                s.with_shadcn_theme(|s, t| {
+                    s.display(floem::style::Display::Flex)
+                        .flex_direction(floem::style::FlexDirection::Column)
+                        .gap(t.space(1.0))
+                })
             }),
         )
     }
@@ -284,7 +364,7 @@ impl IntoView for SheetTitle {
         let text = self.text;
         Box::new(floem::views::Label::with_id(self.id, text).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.font_size(18.0)
+                s.font_size(t.font_size.lg)
                     .font_weight(floem::text::Weight::SEMIBOLD)
                     .color(t.foreground)
             })
@@ -329,7 +409,7 @@ impl IntoView for SheetDescription {
     fn into_view(self) -> Self::V {
         let text = self.text;
         Box::new(floem::views::Label::with_id(self.id, text).style(|s| {
-            s.with_shadcn_theme(move |s, t| s.font_size(14.0).color(t.muted_foreground))
+            s.with_shadcn_theme(move |s, t| s.font_size(t.font_size.sm).color(t.muted_foreground))
         }))
     }
 }
@@ -371,11 +451,13 @@ impl<V: IntoView + 'static> IntoView for SheetFooter<V> {
     fn into_view(self) -> Self::V {
         Box::new(
             floem::views::Container::with_id(self.id, self.child).style(|s| {
-                s.display(floem::style::Display::Flex)
-                    .flex_direction(floem::style::FlexDirection::Row)
-                    .gap(8.0)
-                    .justify_end()
-                    .margin_top(16.0)
+                s.with_shadcn_theme(|s, t| {
+                    s.display(floem::style::Display::Flex)
+                        .flex_direction(floem::style::FlexDirection::Row)
+                        .gap(t.space(2.0))
+                        .justify_end()
+                        .margin_top(t.space(4.0))
+                })
             }),
         )
     }
@@ -419,12 +501,16 @@ impl<V: IntoView + 'static> IntoView for SheetClose<V> {
 
     fn into_view(self) -> Self::V {
         let open = self.open;
+        let close = move || open.update(|v| *v = false);
+
+        // Reachable with Tab inside the sheet, activated with Enter/Space
+        FocusScope::register_current(self.id);
+        focus_scope::activate_on_key(self.id, close);
+
         Box::new(
             floem::views::Container::with_id(self.id, self.child)
-                .style(|s| s.cursor(CursorStyle::Pointer))
-                .on_click_stop(move |_| {
-                    open.update(|v| *v = false);
-                }),
+                .style(|s| s.cursor(CursorStyle::Pointer).focusable(true))
+                .on_click_stop(move |_| close()),
         )
     }
 }
@@ -3,6 +3,7 @@
 //! Based on shadcn/ui Sidebar component for navigation.
 //! Active state is controlled via the `is_active` prop on `SidebarMenuButton`,
 //! following the same pattern as shadcn/ui.
+//! Colors come from the `sidebar_*` theme tokens, like upstream's `--sidebar-*`.
 //!
 //! # Example
 //!
@@ -52,13 +53,13 @@
 use std::rc::Rc;
 
 use floem::prelude::*;
-use floem::views::Scroll;
 use floem::style::CursorStyle;
 use floem::text::Weight;
 use floem::view::ParentView;
+use floem::views::Scroll;
 use floem::{HasViewId, ViewId};
 
-use crate::theme::ShadcnThemeExt;
+use crate::theme::{DEFAULT_SPACING, ShadcnTheme, ShadcnThemeExt};
 
 // ============================================================================
 // Sidebar - Main container
@@ -157,8 +158,9 @@ impl IntoView for Sidebar {
                         .flex_shrink(0.)
                         .height_full()
                         .border_right(1.0)
-                        .border_color(t.border)
-                        .background(t.background)
+                        .border_color(t.sidebar_border)
+                        .background(t.sidebar)
+                        .color(t.sidebar_foreground)
                 })
             }),
         )
@@ -205,9 +207,9 @@ impl IntoView for SidebarHeader {
         floem::views::Stem::with_id(self.id).style(|s| {
             s.with_shadcn_theme(|s, t| {
                 s.width_full()
-                    .padding(16.0)
+                    .padding(t.space(4.0))
                     .border_bottom(1.0)
-                    .border_color(t.border)
+                    .border_color(t.sidebar_border)
             })
         })
     }
@@ -255,18 +257,15 @@ impl IntoView for SidebarContent {
         let inner = floem::views::Stem::with_id(self.id).style(|s| {
             s.width_full()
                 .flex_direction(floem::style::FlexDirection::Column)
-                .padding(8.0)
-                .gap(8.0)
+                .with_shadcn_theme(|s, t| s.padding(t.space(2.0)).gap(t.space(2.0)))
         });
 
-        Box::new(
-            Scroll::new(inner).style(|s| {
-                s.flex_grow(1.0)
-                    .flex_basis(0.0)
-                    .min_height(0.0)
-                    .width_full()
-            }),
-        )
+        Box::new(Scroll::new(inner).style(|s| {
+            s.flex_grow(1.0)
+                .flex_basis(0.0)
+                .min_height(0.0)
+                .width_full()
+        }))
     }
 }
 
@@ -312,9 +311,9 @@ impl IntoView for SidebarFooter {
         floem::views::Stem::with_id(self.id).style(|s| {
             s.with_shadcn_theme(|s, t| {
                 s.width_full()
-                    .padding(16.0)
+                    .padding(t.space(4.0))
                     .border_top(1.0)
-                    .border_color(t.border)
+                    .border_color(t.sidebar_border)
             })
         })
     }
@@ -362,7 +361,7 @@ impl IntoView for SidebarGroup {
         floem::views::Stem::with_id(self.id).style(|s| {
             s.flex_direction(floem::style::FlexDirection::Column)
                 .width_full()
-                .gap(4.0)
+                .with_shadcn_theme(|s, t| s.gap(t.space(1.0)))
         })
     }
 }
@@ -408,13 +407,12 @@ impl IntoView for SidebarGroupLabel {
         Box::new(
             floem::views::Label::derived(move || text.clone()).style(|s| {
                 s.with_shadcn_theme(|s, t| {
-                    s.font_size(11.0)
+                    s.font_size(t.font_size.xs - 1.0) // 11px
                         .font_weight(Weight::SEMIBOLD)
-                        .color(t.muted_foreground)
-                        .padding_left(8.0)
-                        .padding_right(8.0)
-                        .padding_top(8.0)
-                        .padding_bottom(4.0)
+                        .color(t.sidebar_foreground.multiply_alpha(0.7))
+                        .padding_horiz(t.space(2.0))
+                        .padding_top(t.space(2.0))
+                        .padding_bottom(t.space(1.0))
                 })
             }),
         )
@@ -506,10 +504,10 @@ impl IntoView for SidebarGroupAction {
     fn into_view(self) -> Self::V {
         floem::views::Stem::with_id(self.id).style(|s| {
             s.with_shadcn_theme(|s, t| {
-                s.padding(4.0)
+                s.padding(t.space(1.0))
                     .border_radius(t.radius_sm)
                     .cursor(CursorStyle::Pointer)
-                    .hover(|s| s.background(t.accent))
+                    .hover(|s| s.background(t.sidebar_accent))
             })
         })
     }
@@ -557,7 +555,7 @@ impl IntoView for SidebarMenu {
         floem::views::Stem::with_id(self.id).style(|s| {
             s.flex_direction(floem::style::FlexDirection::Column)
                 .width_full()
-                .gap(2.0)
+                .with_shadcn_theme(|s, t| s.gap(t.space(0.5)))
         })
     }
 }
@@ -632,6 +630,12 @@ impl SidebarMenuButtonSize {
             SidebarMenuButtonSize::Lg => 48.0,
         }
     }
+
+    /// The height for this size variant in `theme`, following its spacing
+    /// unit (and so the inherited [`Density`](crate::theme::Density))
+    pub fn height_in(&self, theme: &ShadcnTheme) -> f32 {
+        theme.space(self.height() / DEFAULT_SPACING)
+    }
 }
 
 /// Sidebar menu button (the actual clickable item)
@@ -712,33 +716,33 @@ impl IntoView for SidebarMenuButton {
 
     fn into_view(self) -> Self::V {
         let is_active = self.is_active.clone();
-        let height = self.size.height();
+        let size = self.size;
         floem::views::Stem::with_id(self.id).style(move |s| {
             let is_active = is_active.clone();
             s.with_shadcn_theme(move |s, t| {
                 let active = is_active.as_ref().as_ref().map(|f| f()).unwrap_or(false);
                 let base = s
                     .width_full()
-                    .height(height)
+                    .height(size.height_in(t))
                     .flex_direction(floem::style::FlexDirection::Row)
                     .items_center()
-                    .gap(8.0)
-                    .padding_left(12.0)
-                    .padding_right(12.0)
+                    .gap(t.space(2.0))
+                    .padding_horiz(t.space(3.0))
                     .border_radius(t.radius_sm)
-                    .font_size(14.0)
+                    .font_size(t.font_size.sm)
                     .cursor(CursorStyle::Pointer)
                     .transition(
                         floem::style::Background,
                         floem::style::Transition::linear(millis(100)),
                     )
-                    .hover(move |s| s.background(t.accent));
+                    .hover(move |s| s.background(t.sidebar_accent));
                 if active {
-                    base.background(t.accent)
-                        .color(t.accent_foreground)
+                    base.background(t.sidebar_accent)
+                        .color(t.sidebar_accent_foreground)
                         .font_weight(Weight::MEDIUM)
                 } else {
-                    base.background(peniko::Color::TRANSPARENT).color(t.foreground)
+                    base.background(peniko::Color::TRANSPARENT)
+                        .color(t.sidebar_foreground)
                 }
             })
         })
@@ -786,7 +790,7 @@ impl IntoView for SidebarMenuButtonWithLabel {
     fn into_view(self) -> Self::V {
         let label = self.label.clone();
         let is_active: Rc<Option<Box<dyn Fn() -> bool>>> = Rc::new(self.is_active);
-        let height = self.size.height();
+        let size = self.size;
 
         Box::new(
             floem::views::Container::with_id(
@@ -799,25 +803,24 @@ impl IntoView for SidebarMenuButtonWithLabel {
                     let active = is_active.as_ref().as_ref().map(|f| f()).unwrap_or(false);
                     let base = s
                         .width_full()
-                        .height(height)
+                        .height(size.height_in(t))
                         .items_center()
-                        .padding_left(12.0)
-                        .padding_right(12.0)
+                        .padding_horiz(t.space(3.0))
                         .border_radius(t.radius_sm)
-                        .font_size(14.0)
+                        .font_size(t.font_size.sm)
                         .cursor(CursorStyle::Pointer)
                         .transition(
                             floem::style::Background,
                             floem::style::Transition::linear(millis(100)),
                         )
-                        .hover(move |s| s.background(t.accent));
+                        .hover(move |s| s.background(t.sidebar_accent));
                     if active {
-                        base.background(t.accent)
-                            .color(t.accent_foreground)
+                        base.background(t.sidebar_accent)
+                            .color(t.sidebar_accent_foreground)
                             .font_weight(Weight::MEDIUM)
                     } else {
                         base.background(peniko::Color::TRANSPARENT)
-                            .color(t.foreground)
+                            .color(t.sidebar_foreground)
                     }
                 })
             }),
@@ -864,9 +867,8 @@ impl IntoView for SidebarSeparator {
             s.with_shadcn_theme(|s, t| {
                 s.width_full()
                     .height(1.0)
-                    .margin_top(8.0)
-                    .margin_bottom(8.0)
-                    .background(t.border)
+                    .margin_vert(t.space(2.0))
+                    .background(t.sidebar_border)
             })
         }))
     }
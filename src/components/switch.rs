@@ -24,6 +24,7 @@ use floem::views::Decorators;
 use floem::{HasViewId, ViewId};
 use floem_tailwind::TailwindExt;
 
+use crate::components::focus_scope;
 use crate::theme::ShadcnThemeExt;
 
 /// A styled switch (toggle) builder
@@ -91,7 +92,7 @@ impl Switch {
 
         // The track (background)
         // h-[1.15rem] ≈ 18px, w-8 = 32px
-        let track = floem::views::Container::new(thumb).style(move |s| {
+        let track = floem::views::Container::with_id(self.id, thumb).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
                 let is_checked = checked.get();
                 s.height(18.0) // h-[1.15rem] ≈ 18px
@@ -116,7 +117,10 @@ impl Switch {
         });
 
         let track = if !disabled {
+            // Reachable with Tab, toggled with Enter/Space
+            focus_scope::activate_on_key(self.id, move || checked.update(|c| *c = !*c));
             track
+                .style(|s| s.focusable(true))
                 .on_click_stop(move |_| {
                     checked.update(|c| *c = !*c);
                 })
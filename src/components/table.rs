@@ -83,7 +83,7 @@ impl IntoView for Table {
                 s.width_full()
                     .grid()
                     .grid_auto_flow(GridAutoFlow::Row)
-                    .font_size(14.0)
+                    .font_size(t.font_size.sm)
                     .border(1.0)
                     .border_color(t.border)
                     .border_radius(t.radius)
@@ -319,9 +319,9 @@ impl IntoView for TableHead {
         Box::new(floem::views::Label::with_id(self.id, text).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
                 let base = s
-                    .height(40.0) // h-10
-                    .padding_horiz(8.0) // px-2
-                    .font_size(14.0) // text-sm
+                    .height(t.control_height.md) // h-10
+                    .padding_horiz(t.space(2.0)) // px-2
+                    .font_size(t.font_size.sm) // text-sm
                     .font_weight(floem::text::Weight::MEDIUM) // font-medium
                     .color(t.foreground) // text-foreground
                     .items_center(); // align-middle vertically
@@ -384,9 +384,9 @@ impl<V: IntoView + 'static> IntoView for TableHeadCustom<V> {
             floem::views::Container::with_id(self.id, self.child).style(move |s| {
                 s.with_shadcn_theme(move |s, t| {
                     let base = s
-                        .height(40.0) // h-10
-                        .padding_horiz(8.0) // px-2
-                        .font_size(14.0) // text-sm
+                        .height(t.control_height.md) // h-10
+                        .padding_horiz(t.space(2.0)) // px-2
+                        .font_size(t.font_size.sm) // text-sm
                         .font_weight(floem::text::Weight::MEDIUM) // font-medium
                         .color(t.foreground) // text-foreground
                         .items_center(); // align-middle vertically
@@ -450,8 +450,8 @@ impl IntoView for TableCell {
         Box::new(floem::views::Label::with_id(self.id, text).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
                 let base = s
-                    .padding(8.0) // p-2
-                    .font_size(14.0) // text-sm
+                    .padding(t.space(2.0)) // p-2
+                    .font_size(t.font_size.sm) // text-sm
                     .color(t.foreground) // text-foreground
                     .items_center(); // align-middle
                 if let Some(w) = width {
@@ -513,8 +513,8 @@ impl<V: IntoView + 'static> IntoView for TableCellCustom<V> {
             floem::views::Container::with_id(self.id, self.child).style(move |s| {
                 s.with_shadcn_theme(move |s, t| {
                     let base = s
-                        .padding(8.0) // p-2
-                        .font_size(14.0) // text-sm
+                        .padding(t.space(2.0)) // p-2
+                        .font_size(t.font_size.sm) // text-sm
                         .color(t.foreground) // text-foreground
                         .items_center(); // align-middle
                     if let Some(w) = width {
@@ -567,8 +567,8 @@ impl IntoView for TableCaption {
 
         Box::new(floem::views::Label::with_id(self.id, text).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.margin_top(16.0) // mt-4
-                    .font_size(14.0) // text-sm
+                s.margin_top(t.space(4.0)) // mt-4
+                    .font_size(t.font_size.sm) // text-sm
                     .color(t.muted_foreground) // text-muted-foreground
                     .width_full()
             })
@@ -188,7 +188,7 @@ impl Tab {
                         .border_radius(6.0) // rounded-md
                         .border(1.0) // border
                         .border_color(peniko::Color::TRANSPARENT) // border-transparent
-                        .font_size(14.0)
+                        .font_size(t.font_size.sm)
                         .font_weight(Weight::MEDIUM)
                         .cursor(CursorStyle::Pointer)
                         .transition(
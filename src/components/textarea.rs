@@ -5,6 +5,7 @@
 //! # Example
 //!
 //! ```rust
+//! use floem::reactive::RwSignal;
 //! use floem_shadcn::components::textarea::Textarea;
 //!
 //! // Basic textarea
@@ -15,6 +16,10 @@
 //!     .placeholder("Enter your message...")
 //!     .rows(5)
 //!     .on_change(|text| println!("Text changed: {}", text));
+//!
+//! // Locked while a form is submitting
+//! let submitting = RwSignal::new(false);
+//! let textarea = Textarea::new("").disabled(move || submitting.get());
 //! ```
 
 use floem::prelude::*;
@@ -22,6 +27,7 @@ use floem::views::Decorators;
 use floem::{HasViewId, ViewId};
 use floem_tailwind::TailwindExt;
 
+use crate::components::focus_scope::FocusScope;
 use crate::text::TextArea as TextAreaView;
 use crate::theme::ShadcnThemeExt;
 
@@ -34,6 +40,8 @@ pub struct Textarea {
     rows: u32,
     on_change: Option<Box<dyn Fn(&str)>>,
     resizable: bool,
+    read_only_fn: Option<Box<dyn Fn() -> bool>>,
+    disabled_fn: Option<Box<dyn Fn() -> bool>>,
 }
 
 impl Textarea {
@@ -46,6 +54,8 @@ impl Textarea {
             rows: 3,
             on_change: None,
             resizable: false,
+            read_only_fn: None,
+            disabled_fn: None,
         }
     }
 
@@ -73,9 +83,26 @@ impl Textarea {
         self
     }
 
+    /// Reject edits while `read_only` returns true (reactive)
+    ///
+    /// The text can still be selected, scrolled and copied.
+    pub fn read_only(mut self, read_only: impl Fn() -> bool + 'static) -> Self {
+        self.read_only_fn = Some(Box::new(read_only));
+        self
+    }
+
+    /// Disable the textarea while `disabled` returns true (reactive)
+    pub fn disabled(mut self, disabled: impl Fn() -> bool + 'static) -> Self {
+        self.disabled_fn = Some(Box::new(disabled));
+        self
+    }
+
     /// Build the textarea view
     pub fn build(self) -> impl IntoView {
-        let min_height = (self.rows as f64) * 24.0 + 16.0; // line height * rows + padding
+        let rows = self.rows as f32;
+
+        // Take part in the focus trap of an enclosing dialog, sheet or drawer
+        FocusScope::register_current(self.id);
 
         // Use our custom multi-line TextArea, passing our ViewId for proper HasViewId impl
         let mut textarea =
@@ -86,21 +113,29 @@ impl Textarea {
                 on_change(text);
             });
         }
+        if let Some(read_only) = self.read_only_fn {
+            textarea = textarea.read_only(read_only);
+        }
+        if let Some(disabled) = self.disabled_fn {
+            textarea = textarea.disabled(disabled);
+        }
 
         textarea.style(move |s| {
-            s.min_height(min_height)
-                .w_full()
+            s.w_full()
                 .rounded_md()
                 .border_1()
-                .px_3()
-                .py_2()
-                .text_sm()
-                .with_shadcn_theme(|s, t| {
+                .with_shadcn_theme(move |s, t| {
                     let ring = t.ring;
-                    s.border_color(t.input)
+                    let ring_width = t.ring_width;
+                    // 24px per row plus 8px padding top and bottom
+                    s.min_height(rows * t.space(6.0) + t.space(4.0))
+                        .padding_horiz(t.space(3.0))
+                        .padding_vert(t.space(2.0))
+                        .font_size(t.font_size.sm)
+                        .border_color(t.input)
                         .background(t.background)
                         .color(t.foreground)
-                        .focus(move |s| s.outline(2.0).outline_color(ring))
+                        .focus(move |s| s.outline(ring_width).outline_color(ring))
                         .disabled(|s| s.background(t.muted).color(t.muted_foreground))
                 })
         })
@@ -155,16 +155,18 @@ impl IntoView for ToastContainer {
                     .collect();
 
                 floem::views::Stack::vertical_from_iter(views)
-                    .style(|s| s.gap(8.0))
+                    .style(|s| s.with_shadcn_theme(|s, t| s.gap(t.space(2.0))))
                     .into_any()
             },
         )
         .style(|s| {
-            s.absolute()
-                .inset_bottom(16.0)
-                .inset_right(16.0)
-                .flex_col()
-                .gap(8.0)
+            s.with_shadcn_theme(|s, t| {
+                s.absolute()
+                    .inset_bottom(t.space(4.0))
+                    .inset_right(t.space(4.0))
+                    .flex_col()
+                    .gap(t.space(2.0))
+            })
         });
 
         // Use Overlay with fixed positioning
@@ -225,7 +227,7 @@ impl IntoView for Toast {
         // Title
         let title_view = floem::views::Label::new(title).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
-                s.font_size(14.0)
+                s.font_size(t.font_size.sm)
                     .font_weight(floem::text::Weight::SEMIBOLD)
                     .color(match variant {
                         ToastVariant::Default => t.foreground,
@@ -240,7 +242,7 @@ impl IntoView for Toast {
             floem::views::Label::new(desc)
                 .style(move |s| {
                     s.with_shadcn_theme(move |s, t| {
-                        s.font_size(13.0)
+                        s.font_size(t.font_size.sm - 1.0) // 13px
                             .color(match variant {
                                 ToastVariant::Default => t.muted_foreground,
                                 ToastVariant::Success => t.muted_foreground,
@@ -248,7 +250,7 @@ impl IntoView for Toast {
                                     t.destructive_foreground.with_alpha(0.9)
                                 }
                             })
-                            .margin_top(2.0)
+                            .margin_top(t.space(0.5))
                     })
                 })
                 .into_any()
@@ -259,7 +261,7 @@ impl IntoView for Toast {
         // Close button
         let close_btn = floem::views::Label::new("×").style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.font_size(18.0)
+                s.font_size(t.font_size.lg)
                     .color(t.muted_foreground)
                     .cursor(CursorStyle::Pointer)
                     .hover(|s| s.color(t.foreground))
@@ -282,13 +284,12 @@ impl IntoView for Toast {
                     let base = s
                         .min_width(300.0)
                         .max_width(420.0)
-                        .padding(16.0)
+                        .padding(t.space(4.0))
                         .border(1.0)
                         .border_radius(t.radius)
-                        .box_shadow_blur(8.0)
-                        .box_shadow_color(t.foreground.with_alpha(0.1))
+                        .apply_shadow(t.shadow.lg)
                         .items_start()
-                        .gap(8.0)
+                        .gap(t.space(2.0))
                         .pointer_events_auto(); // Enable clicks on toast (parent overlay has pointer-events: none)
                     match variant {
                         ToastVariant::Default | ToastVariant::Success => {
@@ -352,13 +353,11 @@ impl IntoView for ToastAction {
 
         let btn = floem::views::Label::new(text).style(|s| {
             s.with_shadcn_theme(move |s, t| {
-                s.font_size(13.0)
+                s.font_size(t.font_size.sm - 1.0) // 13px
                     .font_weight(floem::text::Weight::MEDIUM)
                     .color(t.foreground)
-                    .padding_left(12.0)
-                    .padding_right(12.0)
-                    .padding_top(6.0)
-                    .padding_bottom(6.0)
+                    .padding_horiz(t.space(3.0))
+                    .padding_vert(t.space(1.5))
                     .border(1.0)
                     .border_color(t.border)
                     .border_radius(t.radius)
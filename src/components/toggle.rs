@@ -127,17 +127,15 @@ impl IntoView for Toggle {
         let label = floem::views::Label::new(text).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
                 let is_pressed = pressed.get();
-                // Size-based padding and font
+                // Size-based padding (in spacing units) and font
                 let (px, py, font_size) = match size {
-                    ToggleSize::Sm => (8.0, 6.0, 12.0),
-                    ToggleSize::Default => (12.0, 8.0, 14.0),
-                    ToggleSize::Lg => (16.0, 10.0, 16.0),
+                    ToggleSize::Sm => (2.0, 1.5, t.font_size.xs),
+                    ToggleSize::Default => (3.0, 2.0, t.font_size.sm),
+                    ToggleSize::Lg => (4.0, 2.5, t.font_size.base),
                 };
                 let base = s
-                    .padding_left(px)
-                    .padding_right(px)
-                    .padding_top(py)
-                    .padding_bottom(py)
+                    .padding_horiz(t.space(px))
+                    .padding_vert(t.space(py))
                     .font_size(font_size)
                     .font_weight(floem::text::Weight::MEDIUM)
                     .border_radius(t.radius)
@@ -277,17 +275,15 @@ impl<V: IntoView + 'static> IntoView for ToggleCustom<V> {
         let container = floem::views::Container::new(self.child).style(move |s| {
             s.with_shadcn_theme(move |s, t| {
                 let is_pressed = pressed.get();
-                // Size-based padding
+                // Size-based padding (in spacing units)
                 let (px, py) = match size {
-                    ToggleSize::Sm => (8.0, 6.0),
-                    ToggleSize::Default => (12.0, 8.0),
-                    ToggleSize::Lg => (16.0, 10.0),
+                    ToggleSize::Sm => (2.0, 1.5),
+                    ToggleSize::Default => (3.0, 2.0),
+                    ToggleSize::Lg => (4.0, 2.5),
                 };
                 let base = s
-                    .padding_left(px)
-                    .padding_right(px)
-                    .padding_top(py)
-                    .padding_bottom(py)
+                    .padding_horiz(t.space(px))
+                    .padding_vert(t.space(py))
                     .border_radius(t.radius)
                     .cursor(if disabled {
                         CursorStyle::Default
@@ -284,11 +284,9 @@ impl IntoView for ToggleGroupItem {
                     .map(|sig| sig.get().as_ref() == Some(&val))
                     .unwrap_or(false);
                 let base = s
-                    .padding_left(12.0)
-                    .padding_right(12.0)
-                    .padding_top(8.0)
-                    .padding_bottom(8.0)
-                    .font_size(14.0)
+                    .padding_horiz(t.space(3.0))
+                    .padding_vert(t.space(2.0))
+                    .font_size(t.font_size.sm)
                     .font_weight(floem::text::Weight::MEDIUM)
                     .border_radius(t.radius)
                     .cursor(if disabled {
@@ -387,11 +385,9 @@ impl IntoView for ToggleGroupItemMultiple {
                     .map(|sig| sig.get().contains(&val))
                     .unwrap_or(false);
                 let base = s
-                    .padding_left(12.0)
-                    .padding_right(12.0)
-                    .padding_top(8.0)
-                    .padding_bottom(8.0)
-                    .font_size(14.0)
+                    .padding_horiz(t.space(3.0))
+                    .padding_vert(t.space(2.0))
+                    .font_size(t.font_size.sm)
                     .font_weight(floem::text::Weight::MEDIUM)
                     .border_radius(t.radius)
                     .cursor(if disabled {
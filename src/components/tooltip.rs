@@ -1,6 +1,8 @@
 //! Tooltip component with shadcn styling
 //!
-//! Wraps floem's built-in tooltip with shadcn-style theming.
+//! Shows a small label in an overlay after hovering a view. The tooltip is
+//! placed above the view by default and flips or shifts to stay inside the
+//! window, using the shared [`floating`](crate::components::floating) engine.
 //!
 //! # Example
 //!
@@ -13,16 +15,26 @@
 //! ```
 
 use floem::prelude::*;
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
 use floem::views::Decorators;
 
+use crate::components::floating::{Align, Floating, FloatingOptions, Side};
 use crate::theme::ShadcnThemeExt;
 
+/// Delay before the tooltip appears, matching shadcn's `delayDuration`
+const TOOLTIP_DELAY_MS: u64 = 700;
+
 /// Extension trait for adding styled tooltips to views
 pub trait TooltipExt: IntoView + Sized + 'static {
     /// Add a styled tooltip to this view
     fn tooltip_styled(self, text: impl Into<String>) -> impl IntoView {
+        self.tooltip_styled_on(Side::Top, text)
+    }
+
+    /// Add a styled tooltip on the given side of this view
+    fn tooltip_styled_on(self, side: Side, text: impl Into<String>) -> impl IntoView {
         let text = text.into();
-        floem::views::TooltipExt::tooltip(self, move || tooltip_content(text.clone()))
+        floating_tooltip(self, side, move || tooltip_content(text.clone()))
     }
 
     /// Add a styled tooltip with custom content
@@ -30,26 +42,69 @@ pub trait TooltipExt: IntoView + Sized + 'static {
         self,
         content: impl Fn() -> V + 'static,
     ) -> impl IntoView {
-        floem::views::TooltipExt::tooltip(self, content)
+        floating_tooltip(self, Side::Top, content)
     }
 }
 
 impl<T: IntoView + Sized + 'static> TooltipExt for T {}
 
+/// Wrap `target` so `content` floats next to it while it is hovered
+fn floating_tooltip<V: IntoView + 'static>(
+    target: impl IntoView + 'static,
+    side: Side,
+    content: impl Fn() -> V + 'static,
+) -> impl IntoView {
+    let floating = Floating::new(FloatingOptions::new(side, Align::Center));
+    let visible = RwSignal::new(false);
+    // Bumped on every enter/leave so a pending show is dropped after leaving
+    let generation = RwSignal::new(0u64);
+
+    let anchor = floating
+        .anchor(floem::views::Container::new(target))
+        .on_event_cont(floem::event::EventListener::PointerEnter, move |_| {
+            generation.update(|g| *g += 1);
+            let current = generation.get_untracked();
+            floem::action::exec_after(millis(TOOLTIP_DELAY_MS), move |_| {
+                if generation.get_untracked() == current {
+                    visible.set(true);
+                }
+            });
+        })
+        .on_event_cont(floem::event::EventListener::PointerLeave, move |_| {
+            generation.update(|g| *g += 1);
+            visible.set(false);
+        });
+
+    let tip =
+        floem::views::Container::new(content()).style(move |s| floating.position(s).z_index(100));
+
+    let overlay = floem::views::Overlay::new().child(
+        floem::views::Stack::new((floating.window_probe(), floating.content(tip))).style(
+            move |s| {
+                let is_visible = visible.get();
+                s.apply_if(!is_visible, |s| s.hide())
+            },
+        ),
+    );
+
+    floem::views::Stack::new((anchor, overlay))
+}
+
 /// Create styled tooltip content
 fn tooltip_content(text: String) -> impl IntoView {
     floem::views::Label::new(text).style(|s| {
-        s.padding_left(8.0)
-            .padding_right(8.0)
-            .padding_top(4.0)
-            .padding_bottom(4.0)
-            .border(1.0)
-            .border_radius(4.0)
-            .font_size(12.0)
-            .with_shadcn_theme(|s, t| {
-                s.background(t.popover)
-                    .color(t.popover_foreground)
-                    .border_color(t.border)
-            })
+        s.border(1.0).with_shadcn_theme(|s, t| {
+            s.padding_horiz(t.space(2.0))
+                .padding_vert(t.space(1.0))
+                .border_radius(t.radius_sm)
+                .font_size(t.font_size.xs)
+                .background(t.popover)
+                .color(t.popover_foreground)
+                .border_color(t.border)
+        })
     })
 }
+
+fn millis(ms: u64) -> std::time::Duration {
+    std::time::Duration::from_millis(ms)
+}
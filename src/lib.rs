@@ -62,6 +62,7 @@ pub mod prelude {
         ContextMenu, ContextMenuContent, ContextMenuGroup, ContextMenuItem, ContextMenuLabel,
         ContextMenuSeparator,
     };
+    pub use crate::components::data_table::{ColumnSort, DataColumn, DataTable, SortDirection};
     pub use crate::components::date_picker::{DatePicker, DateRangePicker};
     pub use crate::components::dialog::{
         Dialog, DialogClose, DialogContent, DialogContext, DialogDescription, DialogFooter,
@@ -75,6 +76,9 @@ pub mod prelude {
         DropdownMenu, DropdownMenuContent, DropdownMenuGroup, DropdownMenuItem,
         DropdownMenuItemCustom, DropdownMenuLabel, DropdownMenuSeparator, DropdownMenuShortcut,
     };
+    pub use crate::components::form::{
+        AsyncValidation, FieldState, Form, FormValue, Rules, ValidateOn,
+    };
     pub use crate::components::hover_card::{
         HoverCard, HoverCardAlign, HoverCardContent, HoverCardSide, HoverCardTrigger,
     };
@@ -105,7 +109,7 @@ pub mod prelude {
         resizable_horizontal, resizable_vertical,
     };
     pub use crate::components::scroll_area::{
-        ScrollArea, ScrollAreaWithBar, ScrollOrientation, VirtualScrollArea,
+        ScrollArea, ScrollAreaWithBar, ScrollOrientation, VirtualLayout, VirtualScrollArea,
     };
     pub use crate::components::select::{
         Select, SelectContent, SelectGroup, SelectItem, SelectItemData, SelectLabel,
@@ -114,7 +118,7 @@ pub mod prelude {
     pub use crate::components::separator::{Separator, SeparatorOrientation};
     pub use crate::components::sheet::{
         Sheet, SheetClose, SheetContent, SheetDescription, SheetFooter, SheetHeader, SheetSide,
-        SheetTitle,
+        SheetTitle, SheetTrigger,
     };
     pub use crate::components::sidebar::{
         Sidebar, SidebarContent, SidebarFooter, SidebarGroup, SidebarGroupAction,
@@ -141,7 +145,10 @@ pub mod prelude {
     };
     pub use crate::components::tooltip::TooltipExt;
     pub use crate::styled::ShadcnStyleExt;
-    pub use crate::theme::{ShadcnTheme, ShadcnThemeExt, ShadcnThemeProp, ThemeMode};
+    pub use crate::theme::{
+        Density, ShadcnTheme, ShadcnThemeExt, ShadcnThemeProp, ThemeMode, ThemePair, ThemePreset,
+        ThemeProvider, ThemeState,
+    };
 
     // Re-export ParentView for .child() API
     pub use floem::view::ParentView;
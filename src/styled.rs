@@ -54,6 +54,19 @@ pub trait ShadcnStyleExt: Sized {
     fn bg_accent_foreground(self) -> Self;
     fn bg_destructive(self) -> Self;
     fn bg_destructive_foreground(self) -> Self;
+    fn bg_chart_1(self) -> Self;
+    fn bg_chart_2(self) -> Self;
+    fn bg_chart_3(self) -> Self;
+    fn bg_chart_4(self) -> Self;
+    fn bg_chart_5(self) -> Self;
+    fn bg_sidebar(self) -> Self;
+    fn bg_sidebar_foreground(self) -> Self;
+    fn bg_sidebar_primary(self) -> Self;
+    fn bg_sidebar_primary_foreground(self) -> Self;
+    fn bg_sidebar_accent(self) -> Self;
+    fn bg_sidebar_accent_foreground(self) -> Self;
+    fn bg_sidebar_border(self) -> Self;
+    fn bg_sidebar_ring(self) -> Self;
 
     // === Text colors ===
 
@@ -73,6 +86,19 @@ pub trait ShadcnStyleExt: Sized {
     fn text_accent_foreground(self) -> Self;
     fn text_destructive(self) -> Self;
     fn text_destructive_foreground(self) -> Self;
+    fn text_chart_1(self) -> Self;
+    fn text_chart_2(self) -> Self;
+    fn text_chart_3(self) -> Self;
+    fn text_chart_4(self) -> Self;
+    fn text_chart_5(self) -> Self;
+    fn text_sidebar(self) -> Self;
+    fn text_sidebar_foreground(self) -> Self;
+    fn text_sidebar_primary(self) -> Self;
+    fn text_sidebar_primary_foreground(self) -> Self;
+    fn text_sidebar_accent(self) -> Self;
+    fn text_sidebar_accent_foreground(self) -> Self;
+    fn text_sidebar_border(self) -> Self;
+    fn text_sidebar_ring(self) -> Self;
 
     // === Border colors ===
 
@@ -84,12 +110,15 @@ pub trait ShadcnStyleExt: Sized {
     fn border_destructive(self) -> Self;
     fn border_muted(self) -> Self;
     fn border_accent(self) -> Self;
+    fn border_sidebar_border(self) -> Self;
+    fn border_sidebar_ring(self) -> Self;
 
     // === Outline colors ===
 
     fn outline_ring(self) -> Self;
     fn outline_primary(self) -> Self;
     fn outline_destructive(self) -> Self;
+    fn outline_sidebar_ring(self) -> Self;
 
     // === Border radius (from theme) ===
 
@@ -166,6 +195,58 @@ impl ShadcnStyleExt for Style {
         self.with_context::<ShadcnThemeProp>(|s, t| s.background(t.destructive_foreground))
     }
 
+    fn bg_chart_1(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.background(t.chart_1))
+    }
+
+    fn bg_chart_2(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.background(t.chart_2))
+    }
+
+    fn bg_chart_3(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.background(t.chart_3))
+    }
+
+    fn bg_chart_4(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.background(t.chart_4))
+    }
+
+    fn bg_chart_5(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.background(t.chart_5))
+    }
+
+    fn bg_sidebar(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.background(t.sidebar))
+    }
+
+    fn bg_sidebar_foreground(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.background(t.sidebar_foreground))
+    }
+
+    fn bg_sidebar_primary(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.background(t.sidebar_primary))
+    }
+
+    fn bg_sidebar_primary_foreground(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.background(t.sidebar_primary_foreground))
+    }
+
+    fn bg_sidebar_accent(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.background(t.sidebar_accent))
+    }
+
+    fn bg_sidebar_accent_foreground(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.background(t.sidebar_accent_foreground))
+    }
+
+    fn bg_sidebar_border(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.background(t.sidebar_border))
+    }
+
+    fn bg_sidebar_ring(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.background(t.sidebar_ring))
+    }
+
     // === Text colors ===
 
     fn text_background(self) -> Self {
@@ -232,6 +313,58 @@ impl ShadcnStyleExt for Style {
         self.with_context::<ShadcnThemeProp>(|s, t| s.color(t.destructive_foreground))
     }
 
+    fn text_chart_1(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.color(t.chart_1))
+    }
+
+    fn text_chart_2(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.color(t.chart_2))
+    }
+
+    fn text_chart_3(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.color(t.chart_3))
+    }
+
+    fn text_chart_4(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.color(t.chart_4))
+    }
+
+    fn text_chart_5(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.color(t.chart_5))
+    }
+
+    fn text_sidebar(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.color(t.sidebar))
+    }
+
+    fn text_sidebar_foreground(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.color(t.sidebar_foreground))
+    }
+
+    fn text_sidebar_primary(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.color(t.sidebar_primary))
+    }
+
+    fn text_sidebar_primary_foreground(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.color(t.sidebar_primary_foreground))
+    }
+
+    fn text_sidebar_accent(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.color(t.sidebar_accent))
+    }
+
+    fn text_sidebar_accent_foreground(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.color(t.sidebar_accent_foreground))
+    }
+
+    fn text_sidebar_border(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.color(t.sidebar_border))
+    }
+
+    fn text_sidebar_ring(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.color(t.sidebar_ring))
+    }
+
     // === Border colors ===
 
     fn border_border(self) -> Self {
@@ -266,6 +399,14 @@ impl ShadcnStyleExt for Style {
         self.with_context::<ShadcnThemeProp>(|s, t| s.border_color(t.accent))
     }
 
+    fn border_sidebar_border(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.border_color(t.sidebar_border))
+    }
+
+    fn border_sidebar_ring(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.border_color(t.sidebar_ring))
+    }
+
     // === Outline colors ===
 
     fn outline_ring(self) -> Self {
@@ -280,6 +421,10 @@ impl ShadcnStyleExt for Style {
         self.with_context::<ShadcnThemeProp>(|s, t| s.outline_color(t.destructive))
     }
 
+    fn outline_sidebar_ring(self) -> Self {
+        self.with_context::<ShadcnThemeProp>(|s, t| s.outline_color(t.sidebar_ring))
+    }
+
     // === Border radius ===
 
     fn rounded_radius(self) -> Self {
@@ -19,18 +19,27 @@ use floem_editor_core::{
 };
 use lapce_xi_rope::{Delta, Rope, RopeDelta};
 use ui_events::pointer::{PointerButton, PointerButtonEvent, PointerState, PointerUpdate};
+use unicode_segmentation::UnicodeSegmentation;
 
-use super::TextLayoutLines;
+use super::history::{EditRecord, UndoHistory};
+use super::kill_ring::KillRing;
+use super::{TextLayoutLineBuilder, TextLayoutLines};
+
+/// The character drawn for each grapheme in password mode.
+pub const PASSWORD_BULLET: char = '\u{2022}';
 
 /// A document model for text editing with visual line support.
 ///
 /// `Document` provides:
 /// - Rope-based text buffer storage
-/// - Cursor and selection management
+/// - Cursor and selection management, including multiple cursors
 /// - Text layout synchronization with visual lines
 /// - Edit commands (insert, delete, newline)
 /// - Movement commands (left, right, up, down with visual line support)
 /// - Mouse click handling (single, double, triple click)
+/// - Grouped undo/redo history, with compound edits undone in one step
+/// - An Emacs-style kill ring and mark
+/// - A password mode that masks the text
 #[derive(Clone)]
 #[allow(clippy::type_complexity)]
 pub struct Document {
@@ -39,13 +48,28 @@ pub struct Document {
     width: RwSignal<f64>,
     active: RwSignal<bool>,
     cursor: RwSignal<SelRegion>,
+    extra_cursors: RwSignal<Vec<SelRegion>>,
     horiz: RwSignal<Option<ColPosition>>,
     text_color: RwSignal<Color>,
     font_size: RwSignal<f32>,
     line_height: RwSignal<LineHeightValue>,
     font_weight: RwSignal<Weight>,
     font_family: RwSignal<Vec<FamilyOwned>>,
+    password: RwSignal<bool>,
     on_update: Rc<RefCell<Vec<Box<dyn Fn(&str)>>>>,
+    history: Rc<RefCell<UndoHistory>>,
+    compound: Rc<RefCell<Option<CompoundEdit>>>,
+    kill_ring: Rc<RefCell<KillRing>>,
+    mark: RwSignal<Option<usize>>,
+}
+
+/// The state from before a compound edit started
+struct CompoundEdit {
+    text: Rope,
+    cursors: Vec<SelRegion>,
+    /// The range edited so far, as its start, its end in `text` and its end
+    /// in the current text
+    edited: Option<(usize, usize, usize)>,
 }
 
 impl Document {
@@ -67,6 +91,7 @@ impl Document {
             buffer,
             text_layouts: Rc::new(RefCell::new(TextLayoutLines::builder().build())),
             cursor,
+            extra_cursors: RwSignal::new(Vec::new()),
             active,
             width,
             horiz,
@@ -75,7 +100,12 @@ impl Document {
             line_height,
             font_weight,
             font_family,
+            password: RwSignal::new(false),
             on_update: Rc::new(RefCell::new(Vec::new())),
+            history: Rc::new(RefCell::new(UndoHistory::default())),
+            compound: Rc::new(RefCell::new(None)),
+            kill_ring: Rc::new(RefCell::new(KillRing::default())),
+            mark: RwSignal::new(None),
         }
     }
 
@@ -90,7 +120,7 @@ impl Document {
         self.buffer
     }
 
-    /// Returns the cursor signal for reactive access.
+    /// Returns the primary cursor signal for reactive access.
     pub fn cursor(&self) -> RwSignal<SelRegion> {
         self.cursor
     }
@@ -180,6 +210,55 @@ impl Document {
         }
     }
 
+    /// Returns true if the document is in password mode.
+    pub fn is_password(&self) -> bool {
+        self.password.get_untracked()
+    }
+
+    /// Turns password mode on or off and rebuilds layouts.
+    ///
+    /// In password mode every grapheme is drawn as a bullet, copy and cut do
+    /// nothing, and word-wise movement and deletion treat the whole text as a
+    /// single word, so they don't reveal where the words of the password are.
+    pub fn set_password(&self, password: bool) {
+        if self.password.get_untracked() == password {
+            return;
+        }
+        self.password.set(password);
+        let width = self.width.get_untracked();
+        if width > 0.0 {
+            self.rebuild_layouts(width);
+        }
+    }
+
+    /// Lays out one raw line of the buffer and pushes it to `builder`.
+    /// In password mode the line is laid out as one bullet per grapheme.
+    fn push_line(
+        &self,
+        builder: &mut TextLayoutLineBuilder,
+        line: &str,
+        attrs: &AttrsList,
+        width: f64,
+    ) {
+        if !self.password.get_untracked() {
+            let mut text_layout = TextLayout::new_with_text(line, attrs.clone(), None);
+            text_layout.set_size(width as f32, f32::MAX);
+            builder.push_text_layout(&text_layout);
+            return;
+        }
+
+        let content = line.trim_end_matches(['\n', '\r']);
+        let mut boundaries: Vec<usize> = content.grapheme_indices(true).map(|(i, _)| i).collect();
+        let graphemes = boundaries.len();
+        boundaries.push(content.len());
+
+        let mut masked: String = std::iter::repeat_n(PASSWORD_BULLET, graphemes).collect();
+        masked.push_str(&line[content.len()..]);
+        let mut text_layout = TextLayout::new_with_text(&masked, attrs.clone(), None);
+        text_layout.set_size(width as f32, f32::MAX);
+        builder.push_masked_text_layout(&text_layout, PASSWORD_BULLET, &boundaries);
+    }
+
     /// Rebuilds text layouts with current settings.
     fn rebuild_layouts(&self, width: f64) {
         let text_color = self.text_color.get_untracked();
@@ -208,17 +287,164 @@ impl Document {
 
         self.buffer.with_untracked(|buffer| {
             for line in buffer.text().lines_raw(0..buffer.text().len()) {
-                let mut text_layout = TextLayout::new_with_text(&line, attrs.clone(), None);
-                text_layout.set_size(width as f32, f32::MAX);
-                builder.push_text_layout(&text_layout);
+                self.push_line(&mut builder, &line, &attrs, width);
             }
         });
         *self.text_layouts.borrow_mut() = builder.build();
     }
 
-    /// Inserts text at the current cursor position.
+    /// Returns the signal holding the secondary cursors, excluding the primary one.
+    pub fn extra_cursors(&self) -> RwSignal<Vec<SelRegion>> {
+        self.extra_cursors
+    }
+
+    /// Returns all cursor regions, primary first.
+    pub fn regions(&self) -> Vec<SelRegion> {
+        let mut regions = vec![self.cursor.get_untracked()];
+        regions.extend(self.extra_cursors.get_untracked());
+        regions
+    }
+
+    /// Returns all cursor regions as a `Selection`.
+    pub fn selection(&self) -> Selection {
+        let mut selection = Selection::new();
+        for region in self.regions() {
+            selection.add_region(region);
+        }
+        selection
+    }
+
+    /// Returns true if there is more than one cursor.
+    pub fn has_multiple_cursors(&self) -> bool {
+        self.extra_cursors
+            .with_untracked(|extras| !extras.is_empty())
+    }
+
+    /// Returns all cursor regions sorted by position.
+    fn sorted_regions(&self) -> Vec<SelRegion> {
+        let mut regions = self.regions();
+        regions.sort_by_key(|r| (r.min(), r.max()));
+        regions
+    }
+
+    /// Sets the primary cursor and the secondary cursors.
+    /// Secondary cursors that overlap the primary or each other are dropped.
+    fn set_cursors(&self, primary: SelRegion, extras: Vec<SelRegion>) {
+        let mut kept: Vec<SelRegion> = Vec::with_capacity(extras.len());
+        for region in extras {
+            if !regions_overlap(&primary, &region)
+                && !kept.iter().any(|r| regions_overlap(r, &region))
+            {
+                kept.push(region);
+            }
+        }
+
+        self.cursor.set(primary);
+        if self.extra_cursors.with_untracked(|extras| *extras != kept) {
+            self.extra_cursors.set(kept);
+        }
+    }
+
+    /// Adds a caret at `offset` and makes it the primary cursor.
+    /// The previous primary cursor is kept as a secondary cursor.
+    pub fn add_cursor(&self, offset: usize) {
+        let mut extras = self.extra_cursors.get_untracked();
+        extras.push(self.cursor.get_untracked());
+        self.set_cursors(SelRegion::caret(offset, CursorAffinity::Forward), extras);
+        self.horiz.set(None);
+    }
+
+    /// Removes all secondary cursors, keeping only the primary one, and
+    /// clears the mark along with the selection it made.
+    /// Returns true if there was anything to remove.
+    pub fn collapse_cursors(&self) -> bool {
+        if self.mark.get_untracked().is_some() {
+            self.mark.set(None);
+            self.set_offset(self.cursor.get_untracked().end, false);
+            return true;
+        }
+        if !self.has_multiple_cursors() {
+            return false;
+        }
+        self.extra_cursors.set(Vec::new());
+        true
+    }
+
+    /// Selects the next occurrence of the primary selection as a new cursor.
+    ///
+    /// If the primary cursor is a caret, the word under it is selected first.
+    /// The search continues after the primary selection and wraps around,
+    /// skipping occurrences that are already selected.
+    /// Returns true if the selection changed.
+    pub fn select_next_occurrence(&self) -> bool {
+        let primary = self.cursor.get_untracked();
+        if primary.is_caret() {
+            let (start, end) = self.word_at(primary.start);
+            if start == end {
+                return false;
+            }
+            self.set_cursors(
+                SelRegion::new(start, end, CursorAffinity::Forward, None),
+                self.extra_cursors.get_untracked(),
+            );
+            self.horiz.set(None);
+            return true;
+        }
+
+        let text = self.text();
+        let needle = &text[primary.min()..primary.max()];
+        let regions = self.regions();
+        let candidates: Vec<SelRegion> = text
+            .match_indices(needle)
+            .map(|(start, _)| {
+                SelRegion::new(start, start + needle.len(), CursorAffinity::Forward, None)
+            })
+            .filter(|candidate| !regions.iter().any(|r| regions_overlap(r, candidate)))
+            .collect();
+
+        let Some(next) = candidates
+            .iter()
+            .find(|candidate| candidate.min() >= primary.max())
+            .or_else(|| candidates.first())
+            .copied()
+        else {
+            return false;
+        };
+
+        let mut extras = self.extra_cursors.get_untracked();
+        extras.push(primary);
+        self.set_cursors(next, extras);
+        self.horiz.set(None);
+        true
+    }
+
+    /// Inserts text at every cursor position, replacing any selected text.
     pub fn insert_text(&self, text: &str) {
-        self.edit([(self.cursor.get_untracked(), text)], EditType::InsertChars);
+        self.insert_at_each(text, EditType::InsertChars);
+    }
+
+    fn insert_at_each(&self, text: &str, edit_type: EditType) {
+        let regions = self.sorted_regions();
+        self.edit(regions.into_iter().map(|region| (region, text)), edit_type);
+    }
+
+    /// Deletes the range returned by `range_of` for every cursor.
+    /// Empty ranges are skipped and overlapping ranges are merged.
+    fn delete_each(&self, range_of: impl Fn(SelRegion) -> (usize, usize)) {
+        let ranges = merge_ranges(self.regions().into_iter().map(range_of).collect());
+        if ranges.is_empty() {
+            return;
+        }
+
+        self.edit(
+            ranges.into_iter().map(|(start, end)| {
+                (
+                    SelRegion::new(start, end, CursorAffinity::Forward, None),
+                    "",
+                )
+            }),
+            EditType::Delete,
+        );
     }
 
     /// Performs an edit operation with the given edits.
@@ -232,11 +458,178 @@ impl Document {
                 s,
             )
         });
+        let cursors_before = self.regions();
         let delta = self
             .buffer
             .try_update(|b| b.edit(edits, edit_type))
             .unwrap();
         self.apply_delta(&delta);
+        self.record_edit(&delta, edit_type, cursors_before);
+    }
+
+    /// Records an applied edit in the undo history.
+    fn record_edit(
+        &self,
+        delta: &(Rope, RopeDelta, InvalLines),
+        edit_type: EditType,
+        cursors_before: Vec<SelRegion>,
+    ) {
+        let (rope, rope_delta, _) = delta;
+        if rope_delta.is_identity() {
+            return;
+        }
+
+        // With several cursors this covers everything from the first to the
+        // last change, which is still a single contiguous replacement.
+        let (iv, new_len) = rope_delta.summary();
+        if let Some(compound) = self.compound.borrow_mut().as_mut() {
+            compound.edited = Some(match compound.edited {
+                None => (iv.start, iv.end, iv.start + new_len),
+                Some((start, old_end, new_end)) => {
+                    let end = new_end.max(iv.end);
+                    (
+                        start.min(iv.start),
+                        old_end + (end - new_end),
+                        end - (iv.end - iv.start) + new_len,
+                    )
+                }
+            });
+            return;
+        }
+        let removed = rope.slice_to_cow(iv.start..iv.end).into_owned();
+        let inserted = self.buffer.with_untracked(|b| {
+            b.text()
+                .slice_to_cow(iv.start..iv.start + new_len)
+                .into_owned()
+        });
+
+        self.history.borrow_mut().record(EditRecord {
+            start: iv.start,
+            removed,
+            inserted,
+            cursors_before,
+            cursors_after: self.regions(),
+            edit_type,
+        });
+    }
+
+    /// Replaces `start..end` with `text` without recording it in the history.
+    fn replace_untracked(&self, start: usize, end: usize, text: &str) {
+        let edits = [(Selection::region(start, end, CursorAffinity::Forward), text)];
+        let delta = self
+            .buffer
+            .try_update(|b| b.edit(edits, EditType::Other))
+            .unwrap();
+        self.apply_delta(&delta);
+    }
+
+    /// Restores cursors saved in the history, primary first.
+    fn restore_cursors(&self, regions: &[SelRegion]) {
+        if let Some((primary, extras)) = regions.split_first() {
+            self.set_cursors(*primary, extras.to_vec());
+        }
+        self.horiz.set(None);
+    }
+
+    /// Starts a compound edit: every edit until
+    /// [`end_compound_edit`](Self::end_compound_edit) is undone and redone as
+    /// a single step. Does nothing if a compound edit is already open.
+    pub fn begin_compound_edit(&self) {
+        let mut compound = self.compound.borrow_mut();
+        if compound.is_none() {
+            *compound = Some(CompoundEdit {
+                text: self.buffer.with_untracked(|b| b.text().clone()),
+                cursors: self.regions(),
+                edited: None,
+            });
+        }
+    }
+
+    /// Ends the open compound edit and records it in the history.
+    pub fn end_compound_edit(&self) {
+        let Some(before) = self.compound.borrow_mut().take() else {
+            return;
+        };
+        let Some((start, old_end, new_end)) = before.edited else {
+            return;
+        };
+        let removed = before.text.slice_to_cow(start..old_end);
+        let inserted = self
+            .buffer
+            .with_untracked(|b| b.text().slice_to_cow(start..new_end).into_owned());
+        let Some((offset, removed, inserted)) = replaced_range(&removed, &inserted) else {
+            return;
+        };
+        let start = start + offset;
+
+        let mut history = self.history.borrow_mut();
+        history.close_group();
+        history.record(EditRecord {
+            start,
+            removed,
+            inserted,
+            cursors_before: before.cursors,
+            cursors_after: self.regions(),
+            edit_type: EditType::Other,
+        });
+        history.close_group();
+    }
+
+    /// Reverts the most recent undo group and restores the cursors from before it.
+    /// Returns true if anything was undone.
+    pub fn undo(&self) -> bool {
+        self.end_compound_edit();
+        let Some(record) = self.history.borrow_mut().pop_undo() else {
+            return false;
+        };
+
+        self.replace_untracked(
+            record.start,
+            record.start + record.inserted.len(),
+            &record.removed,
+        );
+        self.restore_cursors(&record.cursors_before);
+        self.history.borrow_mut().push_redo(record);
+        true
+    }
+
+    /// Re-applies the most recently undone group.
+    /// Returns true if anything was redone.
+    pub fn redo(&self) -> bool {
+        self.end_compound_edit();
+        let Some(record) = self.history.borrow_mut().pop_redo() else {
+            return false;
+        };
+
+        self.replace_untracked(
+            record.start,
+            record.start + record.removed.len(),
+            &record.inserted,
+        );
+        self.restore_cursors(&record.cursors_after);
+        self.history.borrow_mut().push_undo(record);
+        true
+    }
+
+    /// Returns true if there is an edit that can be undone.
+    pub fn can_undo(&self) -> bool {
+        self.history.borrow().can_undo()
+    }
+
+    /// Returns true if there is an undone edit that can be redone.
+    pub fn can_redo(&self) -> bool {
+        self.history.borrow().can_redo()
+    }
+
+    /// Ends the current undo group so the next edit is undone separately,
+    /// even if it would otherwise be coalesced with the previous one.
+    pub fn commit_undo_group(&self) {
+        self.history.borrow_mut().close_group();
+    }
+
+    /// Discards all undo and redo history.
+    pub fn clear_history(&self) {
+        self.history.borrow_mut().clear();
     }
 
     /// Applies a delta to the document, updating layouts and cursor.
@@ -261,6 +654,10 @@ impl Document {
         let attrs = AttrsList::new(attrs);
 
         let (rope, rope_delta, inval_lines) = delta;
+        self.kill_ring.borrow_mut().interrupt();
+        if self.mark.get_untracked().is_some() {
+            self.mark.set(None);
+        }
         {
             let mut text_layouts = self.text_layouts.borrow_mut();
 
@@ -269,9 +666,7 @@ impl Document {
                 let start = buffer.offset_of_line(inval_lines.start_line);
                 let end = buffer.offset_of_line(inval_lines.start_line + inval_lines.new_count);
                 for line in buffer.text().lines_raw(start..end) {
-                    let mut text_layout = TextLayout::new_with_text(&line, attrs.clone(), None);
-                    text_layout.set_size(width as f32, f32::MAX);
-                    builder.push_text_layout(&text_layout);
+                    self.push_line(&mut builder, &line, &attrs, width);
                 }
             });
 
@@ -283,14 +678,23 @@ impl Document {
             text_layouts.apply_delta(lines_delta);
         }
 
-        self.cursor.update(|c| {
-            // Wrap in Selection to use apply_delta, then extract first region
-            let selection = Selection::region(c.start, c.end, c.affinity);
-            let new_selection = selection.apply_delta(rope_delta, true, InsertDrift::Default);
-            if let Some(region) = new_selection.regions().first() {
-                *c = *region;
-            }
-        });
+        // Transform every cursor through the delta individually
+        let transform = |region: SelRegion| {
+            Selection::region(region.start, region.end, region.affinity)
+                .apply_delta(rope_delta, true, InsertDrift::Default)
+                .regions()
+                .first()
+                .copied()
+                .unwrap_or(region)
+        };
+        let primary = transform(self.cursor.get_untracked());
+        let extras = self
+            .extra_cursors
+            .get_untracked()
+            .into_iter()
+            .map(transform)
+            .collect();
+        self.set_cursors(primary, extras);
 
         self.buffer.with_untracked(|buffer| {
             let text = &buffer.text().slice_to_cow(..);
@@ -300,44 +704,104 @@ impl Document {
         });
     }
 
-    /// Runs a movement command.
-    /// If `modify` is true, extends the selection instead of moving the cursor.
+    /// Returns the offset a word-wise move backward from `offset` ends at.
+    /// In password mode the whole text is one word.
+    fn word_backward(&self, offset: usize) -> usize {
+        if self.password.get_untracked() {
+            return 0;
+        }
+        self.buffer
+            .with_untracked(|b| b.move_word_backward(offset, Mode::Insert))
+    }
+
+    /// Returns the offset a word-wise move forward from `offset` ends at.
+    /// In password mode the whole text is one word.
+    fn word_forward(&self, offset: usize) -> usize {
+        if self.password.get_untracked() {
+            return self.buffer.with_untracked(|b| b.text().len());
+        }
+        self.buffer.with_untracked(|b| b.move_word_forward(offset))
+    }
+
+    /// Returns the range of the word at `offset`.
+    /// In password mode the whole text is one word.
+    fn word_at(&self, offset: usize) -> (usize, usize) {
+        if self.password.get_untracked() {
+            return (0, self.buffer.with_untracked(|b| b.text().len()));
+        }
+        self.buffer.with_untracked(|b| b.select_word(offset))
+    }
+
+    /// Runs a movement command on every cursor.
+    /// If `modify` is true, extends the selections instead of moving the cursors.
     pub fn run_move_command(&self, command: &MoveCommand, modify: bool) {
+        // While the mark is set, moving the cursor selects from it
+        let modify = modify || self.mark.get_untracked().is_some();
+        let primary = self.move_region(self.cursor.get_untracked(), command, modify, true);
+        let extras = self
+            .extra_cursors
+            .get_untracked()
+            .into_iter()
+            .map(|region| self.move_region(region, command, modify, false))
+            .collect();
+        self.set_cursors(primary, extras);
+    }
+
+    /// Returns the column to keep when moving `offset` vertically.
+    /// Only the primary cursor remembers its column across moves.
+    fn horiz_of(&self, lines: &TextLayoutLines, offset: usize, primary: bool) -> ColPosition {
+        if primary {
+            if let Some(horiz) = self.horiz.get_untracked() {
+                return horiz;
+            }
+        }
+        let point = lines.point_of_offset(offset);
+        if primary {
+            self.horiz.set(Some(ColPosition::Col(point.x)));
+        }
+        ColPosition::Col(point.x)
+    }
+
+    /// Computes where a single cursor region ends up after a movement command.
+    fn move_region(
+        &self,
+        region: SelRegion,
+        command: &MoveCommand,
+        modify: bool,
+        primary: bool,
+    ) -> SelRegion {
+        let set_horiz = |horiz: Option<ColPosition>| {
+            if primary {
+                self.horiz.set(horiz);
+            }
+        };
+
         match command {
             MoveCommand::Left => {
-                let region = self.cursor.get_untracked();
                 let new_offset = if modify || region.is_caret() {
                     self.buffer
                         .with_untracked(|b| b.move_left(region.end, Mode::Insert, 1))
                 } else {
                     region.min()
                 };
-                self.set_offset(new_offset, modify);
-                self.horiz.set(None);
+                set_horiz(None);
+                moved_region(region, new_offset, modify)
             }
             MoveCommand::Right => {
-                let region = self.cursor.get_untracked();
                 let new_offset = if modify || region.is_caret() {
                     self.buffer
                         .with_untracked(|b| b.move_right(region.end, Mode::Insert, 1))
                 } else {
                     region.max()
                 };
-                self.set_offset(new_offset, modify);
-                self.horiz.set(None);
+                set_horiz(None);
+                moved_region(region, new_offset, modify)
             }
             MoveCommand::Up => {
-                let region = self.cursor.get_untracked();
                 let offset = region.end;
                 let lines = self.text_layouts.borrow();
                 let vline = lines.vline_of_offset(offset);
-                let horiz = if let Some(horiz) = self.horiz.get_untracked() {
-                    horiz
-                } else {
-                    let point = lines.point_of_offset(offset);
-                    self.horiz.set(Some(ColPosition::Col(point.x)));
-                    ColPosition::Col(point.x)
-                };
+                let horiz = self.horiz_of(&lines, offset, primary);
                 let new_offset = if vline == 0 {
                     0
                 } else {
@@ -360,21 +824,13 @@ impl Document {
                         }
                     }
                 };
-                drop(lines);
-                self.set_offset(new_offset, modify);
+                moved_region(region, new_offset, modify)
             }
             MoveCommand::Down => {
-                let region = self.cursor.get_untracked();
                 let offset = region.end;
                 let lines = self.text_layouts.borrow();
                 let vline = lines.vline_of_offset(offset);
-                let horiz = if let Some(horiz) = self.horiz.get_untracked() {
-                    horiz
-                } else {
-                    let point = lines.point_of_offset(offset);
-                    self.horiz.set(Some(ColPosition::Col(point.x)));
-                    ColPosition::Col(point.x)
-                };
+                let horiz = self.horiz_of(&lines, offset, primary);
 
                 let last_vline = lines.vline_of_offset(lines.utf8_len());
                 let new_offset = if last_vline == vline {
@@ -399,20 +855,17 @@ impl Document {
                         }
                     }
                 };
-                drop(lines);
-                self.set_offset(new_offset, modify);
+                moved_region(region, new_offset, modify)
             }
             MoveCommand::LineStart => {
-                let region = self.cursor.get_untracked();
                 let lines = self.text_layouts.borrow();
                 let vline = lines.vline_of_offset(region.end);
                 let new_offset = lines.offset_of_vline(vline);
                 drop(lines);
-                self.set_offset(new_offset, modify);
-                self.horiz.set(Some(ColPosition::Start));
+                set_horiz(Some(ColPosition::Start));
+                moved_region(region, new_offset, modify)
             }
             MoveCommand::LineEnd => {
-                let region = self.cursor.get_untracked();
                 let lines = self.text_layouts.borrow();
                 let vline = lines.vline_of_offset(region.end);
                 let last_vline = lines.vline_of_offset(lines.utf8_len());
@@ -427,175 +880,159 @@ impl Document {
                     next_vline_offset.saturating_sub(1).min(doc_len)
                 };
                 drop(lines);
-                self.set_offset(new_offset, modify);
-                self.horiz.set(Some(ColPosition::End));
+                set_horiz(Some(ColPosition::End));
+                moved_region(region, new_offset, modify)
             }
             MoveCommand::DocumentStart => {
-                self.set_offset(0, modify);
-                self.horiz.set(None);
+                set_horiz(None);
+                moved_region(region, 0, modify)
             }
             MoveCommand::DocumentEnd => {
                 let doc_len = self.text_layouts.borrow().utf8_len();
-                self.set_offset(doc_len, modify);
-                self.horiz.set(None);
+                set_horiz(None);
+                moved_region(region, doc_len, modify)
             }
             MoveCommand::WordBackward => {
-                let region = self.cursor.get_untracked();
-                let new_offset = self
-                    .buffer
-                    .with_untracked(|b| b.move_word_backward(region.end, Mode::Insert));
-                self.set_offset(new_offset, modify);
-                self.horiz.set(None);
+                let new_offset = self.word_backward(region.end);
+                set_horiz(None);
+                moved_region(region, new_offset, modify)
             }
             MoveCommand::WordForward => {
-                let region = self.cursor.get_untracked();
-                let new_offset = self
-                    .buffer
-                    .with_untracked(|b| b.move_word_forward(region.end));
-                self.set_offset(new_offset, modify);
-                self.horiz.set(None);
+                let new_offset = self.word_forward(region.end);
+                set_horiz(None);
+                moved_region(region, new_offset, modify)
             }
-            _ => {}
+            _ => region,
         }
     }
 
-    /// Runs an edit command.
+    /// Runs an edit command at every cursor.
     pub fn run_edit_command(&self, command: &EditCommand) {
         match command {
             EditCommand::InsertNewLine => {
-                self.edit(
-                    [(self.cursor.get_untracked(), "\n")],
-                    EditType::InsertNewline,
-                );
+                self.insert_at_each("\n", EditType::InsertNewline);
             }
             EditCommand::InsertTab => {
-                self.edit([(self.cursor.get_untracked(), "\t")], EditType::InsertChars);
+                self.insert_at_each("\t", EditType::InsertChars);
             }
             EditCommand::DeleteBackward => {
-                let region = self.cursor.get_untracked();
-                let region = if region.is_caret() {
-                    let new_offset = self
-                        .buffer
-                        .with_untracked(|b| b.move_left(region.start, Mode::Insert, 1));
-                    SelRegion::new(region.start, new_offset, CursorAffinity::Forward, None)
-                } else {
-                    region
-                };
-                self.edit([(region, "")], EditType::Delete);
+                self.delete_each(|region| {
+                    if region.is_caret() {
+                        let new_offset = self
+                            .buffer
+                            .with_untracked(|b| b.move_left(region.start, Mode::Insert, 1));
+                        (new_offset, region.start)
+                    } else {
+                        (region.min(), region.max())
+                    }
+                });
             }
             EditCommand::DeleteForward => {
-                let region = self.cursor.get_untracked();
-                let region = if region.is_caret() {
-                    let new_offset = self
-                        .buffer
-                        .with_untracked(|b| b.move_right(region.start, Mode::Insert, 1));
-                    SelRegion::new(region.start, new_offset, CursorAffinity::Forward, None)
-                } else {
-                    region
-                };
-                self.edit([(region, "")], EditType::Delete);
+                self.delete_each(|region| {
+                    if region.is_caret() {
+                        let new_offset = self
+                            .buffer
+                            .with_untracked(|b| b.move_right(region.start, Mode::Insert, 1));
+                        (region.start, new_offset)
+                    } else {
+                        (region.min(), region.max())
+                    }
+                });
             }
             EditCommand::DeleteWordBackward => {
-                let region = self.cursor.get_untracked();
-                let region = if region.is_caret() {
-                    let new_offset = self
-                        .buffer
-                        .with_untracked(|b| b.move_word_backward(region.start, Mode::Insert));
-                    SelRegion::new(region.start, new_offset, CursorAffinity::Forward, None)
-                } else {
-                    region
-                };
-                self.edit([(region, "")], EditType::Delete);
+                self.delete_each(|region| {
+                    if region.is_caret() {
+                        (self.word_backward(region.start), region.start)
+                    } else {
+                        (region.min(), region.max())
+                    }
+                });
             }
             EditCommand::DeleteWordForward => {
-                let region = self.cursor.get_untracked();
-                let region = if region.is_caret() {
-                    let new_offset = self
-                        .buffer
-                        .with_untracked(|b| b.move_word_forward(region.start));
-                    SelRegion::new(region.start, new_offset, CursorAffinity::Forward, None)
-                } else {
-                    region
-                };
-                self.edit([(region, "")], EditType::Delete);
+                self.delete_each(|region| {
+                    if region.is_caret() {
+                        (region.start, self.word_forward(region.start))
+                    } else {
+                        (region.min(), region.max())
+                    }
+                });
             }
             EditCommand::DeleteToBeginningOfLine => {
-                let region = self.cursor.get_untracked();
-                let lines = self.text_layouts.borrow();
-                let vline = lines.vline_of_offset(region.end);
-                let line_start = lines.offset_of_vline(vline);
-                drop(lines);
-
-                if region.end > line_start {
-                    let delete_region =
-                        SelRegion::new(line_start, region.end, CursorAffinity::Forward, None);
-                    self.edit([(delete_region, "")], EditType::Delete);
-                }
+                self.delete_each(|region| {
+                    let lines = self.text_layouts.borrow();
+                    let vline = lines.vline_of_offset(region.end);
+                    let line_start = lines.offset_of_vline(vline);
+                    (line_start.min(region.end), region.end)
+                });
             }
             _ => {}
         }
     }
 
     /// Sets the cursor offset, optionally extending the selection.
+    /// Any secondary cursors are removed.
     pub fn set_offset(&self, offset: usize, modify: bool) {
-        let region = self.cursor.get_untracked();
-        let region = if modify {
-            SelRegion::new(region.start, offset, CursorAffinity::Forward, None)
-        } else {
-            SelRegion::caret(offset, CursorAffinity::Forward)
-        };
-        self.cursor.set(region);
+        let region = moved_region(self.cursor.get_untracked(), offset, modify);
+        self.set_cursors(region, Vec::new());
     }
 
     /// Selects all text in the document.
     pub fn select_all(&self) {
         let doc_len = self.text_layouts.borrow().utf8_len();
         let region = SelRegion::new(0, doc_len, CursorAffinity::Forward, None);
-        self.cursor.set(region);
+        self.set_cursors(region, Vec::new());
+    }
+
+    /// Returns the selected text of every cursor, joined by newlines in
+    /// document order. Returns `None` if nothing is selected.
+    fn selected_text(&self) -> Option<String> {
+        let regions: Vec<SelRegion> = self
+            .sorted_regions()
+            .into_iter()
+            .filter(|r| !r.is_caret())
+            .collect();
+        if regions.is_empty() {
+            return None;
+        }
+
+        let text = self.buffer.with_untracked(|b| {
+            regions
+                .iter()
+                .map(|r| b.text().slice_to_cow(r.min()..r.max()).into_owned())
+                .collect::<Vec<_>>()
+                .join("\n")
+        });
+
+        if text.is_empty() { None } else { Some(text) }
     }
 
     /// Copies the selected text to the clipboard.
-    /// Returns true if text was copied.
+    /// With multiple cursors, the selections are joined by newlines.
+    /// Returns true if text was copied; never copies in password mode.
     pub fn copy(&self) -> bool {
-        let region = self.cursor.get_untracked();
-        if region.is_caret() {
+        if self.password.get_untracked() {
             return false;
         }
-
-        let start = region.min();
-        let end = region.max();
-        let text = self
-            .buffer
-            .with_untracked(|b| b.text().slice_to_cow(start..end).into_owned());
-
-        if text.is_empty() {
+        let Some(text) = self.selected_text() else {
             return false;
-        }
+        };
 
         floem::Clipboard::set_contents(text).is_ok()
     }
 
     /// Cuts the selected text to the clipboard.
-    /// Returns true if text was cut.
+    /// Returns true if text was cut; never cuts in password mode.
     pub fn cut(&self) -> bool {
-        let region = self.cursor.get_untracked();
-        if region.is_caret() {
+        if self.password.get_untracked() {
             return false;
         }
-
-        let start = region.min();
-        let end = region.max();
-        let text = self
-            .buffer
-            .with_untracked(|b| b.text().slice_to_cow(start..end).into_owned());
-
-        if text.is_empty() {
+        let Some(text) = self.selected_text() else {
             return false;
-        }
+        };
 
         if floem::Clipboard::set_contents(text).is_ok() {
             // Delete the selected text
-            self.edit([(region, "")], EditType::Delete);
+            self.delete_each(|region| (region.min(), region.max()));
             true
         } else {
             false
@@ -619,13 +1056,170 @@ impl Document {
             return false;
         }
 
-        let region = self.cursor.get_untracked();
-        self.edit([(region, content.as_str())], EditType::InsertChars);
+        self.paste_text(&content);
         true
     }
 
+    /// Inserts pasted text at every cursor.
+    /// If the text has exactly one line per cursor, each cursor gets its own line.
+    fn paste_text(&self, content: &str) {
+        let regions = self.sorted_regions();
+        let lines: Vec<&str> = content.lines().collect();
+        if regions.len() > 1 && lines.len() == regions.len() {
+            self.edit(regions.into_iter().zip(lines), EditType::InsertChars);
+        } else {
+            self.edit(
+                regions.into_iter().map(|region| (region, content)),
+                EditType::InsertChars,
+            );
+        }
+    }
+
+    /// Kills from each cursor to the end of its line into the kill ring, like
+    /// Emacs' `kill-line`. At the end of a line the line break is killed, and
+    /// selected text is killed as a whole. A kill made where the previous one
+    /// left the cursors is appended to the same kill ring entry.
+    /// Returns true if anything was killed. In password mode the text is
+    /// deleted without being added to the kill ring.
+    pub fn kill_line(&self) -> bool {
+        let ranges = merge_ranges(
+            self.regions()
+                .into_iter()
+                .map(|region| self.kill_range(region))
+                .collect(),
+        );
+        if ranges.is_empty() {
+            return false;
+        }
+
+        let killed = self.buffer.with_untracked(|b| {
+            ranges
+                .iter()
+                .map(|&(start, end)| b.text().slice_to_cow(start..end).into_owned())
+                .collect::<Vec<_>>()
+                .join("\n")
+        });
+        let append = self.kill_ring.borrow().continues_kill(&self.regions());
+        self.delete_each(|region| self.kill_range(region));
+
+        if !self.password.get_untracked() {
+            self.kill_ring
+                .borrow_mut()
+                .kill(killed, append, self.regions());
+        }
+        true
+    }
+
+    /// Returns the range `kill_line` removes for a cursor.
+    fn kill_range(&self, region: SelRegion) -> (usize, usize) {
+        if !region.is_caret() {
+            return (region.min(), region.max());
+        }
+        self.buffer.with_untracked(|b| {
+            let line = b.line_of_offset(region.end);
+            let line_end = b.line_end_offset(line, true);
+            if region.end < line_end {
+                (region.end, line_end)
+            } else {
+                (region.end, b.offset_of_line(line + 1))
+            }
+        })
+    }
+
+    /// Inserts the most recent kill ring entry at every cursor, like Emacs'
+    /// `yank`. If `filter_newlines` is true, newlines are removed (for
+    /// single-line input). Returns true if text was inserted.
+    pub fn yank(&self, filter_newlines: bool) -> bool {
+        let Some(mut content) = self.kill_ring.borrow().current().map(str::to_string) else {
+            return false;
+        };
+        if filter_newlines {
+            content.retain(|c| c != '\n' && c != '\r');
+        }
+        if content.is_empty() {
+            return false;
+        }
+
+        let start = self.cursor.get_untracked().min();
+        self.commit_undo_group();
+        self.paste_text(&content);
+        self.commit_undo_group();
+
+        // Yank-pop only replaces the text yanked by a single cursor
+        let range =
+            (!self.has_multiple_cursors()).then(|| (start, self.cursor.get_untracked().end));
+        self.kill_ring.borrow_mut().set_last_yank(range);
+        true
+    }
+
+    /// Replaces the text inserted by the previous [`yank`](Self::yank) or
+    /// `yank_pop` with the next older kill ring entry, like Emacs'
+    /// `yank-pop`. Following yanks insert that entry.
+    /// Returns false unless the cursor is still at the end of a yank.
+    pub fn yank_pop(&self, filter_newlines: bool) -> bool {
+        let cursor = self.cursor.get_untracked();
+        let mut ring = self.kill_ring.borrow_mut();
+        let Some((start, end)) = ring.last_yank() else {
+            return false;
+        };
+        if !cursor.is_caret() || cursor.end != end || self.has_multiple_cursors() {
+            return false;
+        }
+        let Some(mut content) = ring.rotate().map(str::to_string) else {
+            return false;
+        };
+        drop(ring);
+        if filter_newlines {
+            content.retain(|c| c != '\n' && c != '\r');
+        }
+
+        self.commit_undo_group();
+        self.edit(
+            [(
+                SelRegion::new(start, end, CursorAffinity::Forward, None),
+                content.as_str(),
+            )],
+            EditType::Other,
+        );
+        self.commit_undo_group();
+
+        let end = start + content.len();
+        self.set_offset(end, false);
+        self.kill_ring
+            .borrow_mut()
+            .set_last_yank(Some((start, end)));
+        true
+    }
+
+    /// Returns the kill ring entries, most recent first.
+    pub fn kill_ring(&self) -> Vec<String> {
+        self.kill_ring
+            .borrow()
+            .entries()
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Sets the mark at the cursor, like Emacs' `set-mark-command`. Until
+    /// the next edit or click, moving the cursor selects from the mark.
+    /// Setting the mark again where it already is clears it.
+    pub fn set_mark(&self) {
+        let offset = self.cursor.get_untracked().end;
+        let mark = (self.mark.get_untracked() != Some(offset)).then_some(offset);
+        self.set_offset(offset, false);
+        self.mark.set(mark);
+    }
+
+    /// Returns the offset of the mark, if it is set.
+    pub fn mark(&self) -> Option<usize> {
+        self.mark.get_untracked()
+    }
+
     /// Handles pointer down events.
     pub fn pointer_down(&self, event: &PointerButtonEvent) {
+        if self.mark.get_untracked().is_some() {
+            self.mark.set(None);
+        }
         if event.button == Some(PointerButton::Primary) {
             self.left_click(&event.state);
         } else if event.button == Some(PointerButton::Secondary) {
@@ -654,6 +1248,12 @@ impl Document {
         let lines = self.text_layouts.borrow();
         let pos = state.logical_point();
         let new_offset = lines.offset_of_point(pos);
+        drop(lines);
+        if state.modifiers.alt() {
+            // Alt+click adds another cursor
+            self.add_cursor(new_offset);
+            return;
+        }
         let shift = state.modifiers.shift();
         self.set_offset(new_offset, shift);
         self.horiz.set(None);
@@ -663,10 +1263,13 @@ impl Document {
         let lines = self.text_layouts.borrow();
         let pos = state.logical_point();
         let mouse_offset = lines.offset_of_point(pos);
-        let (start, end) = self.buffer.with_untracked(|b| b.select_word(mouse_offset));
+        drop(lines);
+        let (start, end) = self.word_at(mouse_offset);
 
-        self.cursor
-            .set(SelRegion::new(start, end, CursorAffinity::Forward, None));
+        self.set_cursors(
+            SelRegion::new(start, end, CursorAffinity::Forward, None),
+            Vec::new(),
+        );
         self.horiz.set(None);
     }
 
@@ -679,25 +1282,27 @@ impl Document {
         let start = lines.offset_of_vline(vline);
         let end = lines.offset_of_vline(vline + 1);
 
-        self.cursor
-            .set(SelRegion::new(start, end, CursorAffinity::Forward, None));
+        self.set_cursors(
+            SelRegion::new(start, end, CursorAffinity::Forward, None),
+            Vec::new(),
+        );
         self.horiz.set(None);
     }
 
     /// Handles pointer move events (for drag selection).
+    /// Only the primary cursor is extended.
     pub fn pointer_move(&self, event: &PointerUpdate) {
         if self.active.get_untracked() {
             let lines = self.text_layouts.borrow();
             let pos = event.current.logical_point();
             let offset = lines.offset_of_point(pos);
+            drop(lines);
             let cursor = self.cursor.get_untracked();
             if cursor.end != offset {
-                self.cursor.set(SelRegion::new(
-                    cursor.start,
-                    offset,
-                    CursorAffinity::Forward,
-                    None,
-                ));
+                self.set_cursors(
+                    SelRegion::new(cursor.start, offset, CursorAffinity::Forward, None),
+                    self.extra_cursors.get_untracked(),
+                );
             }
         }
     }
@@ -708,6 +1313,61 @@ impl Document {
     }
 }
 
+/// Returns true if two cursor regions overlap or start at the same offset.
+fn regions_overlap(a: &SelRegion, b: &SelRegion) -> bool {
+    a.min() == b.min() || (a.min() < b.max() && b.min() < a.max())
+}
+
+/// Moves a region's end to `offset`, or collapses it to a caret there.
+fn moved_region(region: SelRegion, offset: usize, modify: bool) -> SelRegion {
+    if modify {
+        SelRegion::new(region.start, offset, CursorAffinity::Forward, None)
+    } else {
+        SelRegion::caret(offset, CursorAffinity::Forward)
+    }
+}
+
+/// Returns the single replacement that turns `before` into `after`, as the
+/// start offset, the removed text and the inserted text.
+/// Returns `None` if the texts are equal.
+fn replaced_range(before: &str, after: &str) -> Option<(usize, String, String)> {
+    if before == after {
+        return None;
+    }
+    let prefix = before
+        .char_indices()
+        .zip(after.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(before.len().min(after.len()), |((i, _), _)| i);
+    let suffix = before[prefix..]
+        .chars()
+        .rev()
+        .zip(after[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum::<usize>();
+    Some((
+        prefix,
+        before[prefix..before.len() - suffix].to_string(),
+        after[prefix..after.len() - suffix].to_string(),
+    ))
+}
+
+/// Sorts ranges, drops empty ones and merges those that overlap or touch.
+fn merge_ranges(mut ranges: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+    ranges.retain(|(start, end)| start < end);
+    ranges.sort();
+
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2115,4 +2775,526 @@ mod tests {
 
         assert_eq!(doc.text(), " world");
     }
+
+    // ==========================================================================
+    // Undo/redo tests
+    // ==========================================================================
+
+    #[test]
+    fn test_undo_coalesces_consecutive_typing() {
+        let doc = Document::new("");
+        doc.insert_text("h");
+        doc.insert_text("e");
+        doc.insert_text("y");
+        assert_eq!(doc.text(), "hey");
+
+        assert!(doc.undo());
+        assert_eq!(
+            doc.text(),
+            "",
+            "Consecutive typing should undo as one group"
+        );
+        assert!(!doc.can_undo());
+    }
+
+    #[test]
+    fn test_undo_restores_cursor_and_selection() {
+        let doc = Document::new("hello world");
+        doc.set_offset(6, false);
+        doc.set_offset(11, true);
+        doc.insert_text("there");
+        assert_eq!(doc.text(), "hello there");
+
+        doc.undo();
+        assert_eq!(doc.text(), "hello world");
+        let cursor = doc.cursor().get_untracked();
+        assert_eq!(cursor.start, 6, "Selection anchor should be restored");
+        assert_eq!(cursor.end, 11, "Selection end should be restored");
+    }
+
+    #[test]
+    fn test_compound_edit_undoes_as_one_step() {
+        let doc = Document::new("hello world");
+        doc.begin_compound_edit();
+        doc.set_offset(0, false);
+        doc.set_offset(5, true);
+        doc.run_edit_command(&EditCommand::DeleteBackward);
+        doc.insert_text("h");
+        doc.insert_text("i");
+        doc.set_offset(doc.text().len(), false);
+        doc.insert_text("!");
+        doc.end_compound_edit();
+        assert_eq!(doc.text(), "hi world!");
+
+        assert!(doc.undo());
+        assert_eq!(doc.text(), "hello world");
+        assert!(!doc.can_undo());
+        let cursor = doc.cursor().get_untracked();
+        assert_eq!(cursor.end, 0, "Cursors from before the compound edit");
+
+        assert!(doc.redo());
+        assert_eq!(doc.text(), "hi world!");
+    }
+
+    #[test]
+    fn test_compound_edit_covers_edits_before_earlier_ones() {
+        let doc = Document::new("abc def");
+        doc.begin_compound_edit();
+        doc.set_offset(7, false);
+        doc.insert_text("!");
+        doc.set_offset(0, false);
+        doc.insert_text(">");
+        doc.end_compound_edit();
+        assert_eq!(doc.text(), ">abc def!");
+
+        assert!(doc.undo());
+        assert_eq!(doc.text(), "abc def");
+        assert!(doc.redo());
+        assert_eq!(doc.text(), ">abc def!");
+    }
+
+    #[test]
+    fn test_redo_reapplies_undone_edit() {
+        let doc = Document::new("");
+        doc.insert_text("abc");
+        doc.undo();
+        assert!(doc.can_redo());
+
+        assert!(doc.redo());
+        assert_eq!(doc.text(), "abc");
+        assert_eq!(doc.cursor().get_untracked().end, 3);
+        assert!(!doc.can_redo());
+    }
+
+    #[test]
+    fn test_new_edit_clears_redo() {
+        let doc = Document::new("");
+        doc.insert_text("abc");
+        doc.undo();
+        doc.insert_text("x");
+        assert!(!doc.can_redo(), "A new edit should discard the redo stack");
+        assert!(!doc.redo());
+        assert_eq!(doc.text(), "x");
+    }
+
+    #[test]
+    fn test_cursor_move_breaks_undo_group() {
+        let doc = Document::new("");
+        doc.insert_text("ab");
+        doc.set_offset(0, false);
+        doc.insert_text("x");
+        assert_eq!(doc.text(), "xab");
+
+        doc.undo();
+        assert_eq!(doc.text(), "ab");
+        doc.undo();
+        assert_eq!(doc.text(), "");
+    }
+
+    #[test]
+    fn test_undo_coalesces_backspaces() {
+        let doc = Document::new("hello");
+        doc.set_offset(5, false);
+        doc.run_edit_command(&EditCommand::DeleteBackward);
+        doc.run_edit_command(&EditCommand::DeleteBackward);
+        assert_eq!(doc.text(), "hel");
+
+        doc.undo();
+        assert_eq!(doc.text(), "hello");
+        assert_eq!(doc.cursor().get_untracked().end, 5);
+    }
+
+    #[test]
+    fn test_newline_starts_new_undo_group() {
+        let doc = Document::new("");
+        doc.insert_text("a");
+        doc.run_edit_command(&EditCommand::InsertNewLine);
+        doc.insert_text("b");
+
+        doc.undo();
+        assert_eq!(doc.text(), "a\n");
+        doc.undo();
+        assert_eq!(doc.text(), "a");
+    }
+
+    // ==========================================================================
+    // Multi-cursor tests
+    // ==========================================================================
+
+    #[test]
+    fn test_multi_cursor_insert_text() {
+        let doc = Document::new("ab\ncd");
+        doc.set_width(200.0);
+        doc.set_offset(0, false);
+        doc.add_cursor(3);
+        doc.insert_text("x");
+
+        assert_eq!(doc.text(), "xab\nxcd");
+        let mut ends: Vec<usize> = doc.regions().iter().map(|r| r.end).collect();
+        ends.sort();
+        assert_eq!(
+            ends,
+            vec![1, 5],
+            "Every cursor should move past its insertion"
+        );
+    }
+
+    #[test]
+    fn test_multi_cursor_delete_backward() {
+        let doc = Document::new("ab\ncd");
+        doc.set_width(200.0);
+        doc.set_offset(2, false);
+        doc.add_cursor(5);
+        doc.run_edit_command(&EditCommand::DeleteBackward);
+
+        assert_eq!(doc.text(), "a\nc");
+        assert_eq!(doc.regions().len(), 2);
+    }
+
+    #[test]
+    fn test_multi_cursor_move_applies_to_all() {
+        let doc = Document::new("abc\ndef");
+        doc.set_width(200.0);
+        doc.set_offset(0, false);
+        doc.add_cursor(4);
+        doc.run_move_command(&MoveCommand::LineEnd, false);
+
+        let mut ends: Vec<usize> = doc.regions().iter().map(|r| r.end).collect();
+        ends.sort();
+        assert_eq!(ends, vec![3, 7]);
+    }
+
+    #[test]
+    fn test_multi_cursors_merge_when_they_meet() {
+        let doc = Document::new("abc");
+        doc.set_width(200.0);
+        doc.set_offset(1, false);
+        doc.add_cursor(2);
+        doc.run_move_command(&MoveCommand::DocumentStart, false);
+
+        assert!(
+            !doc.has_multiple_cursors(),
+            "Cursors at the same offset should collapse into one"
+        );
+    }
+
+    #[test]
+    fn test_set_offset_clears_extra_cursors() {
+        let doc = Document::new("hello");
+        doc.add_cursor(3);
+        assert!(doc.has_multiple_cursors());
+
+        doc.set_offset(1, false);
+        assert!(!doc.has_multiple_cursors());
+        assert_eq!(doc.cursor().get_untracked().end, 1);
+    }
+
+    #[test]
+    fn test_collapse_cursors() {
+        let doc = Document::new("hello");
+        assert!(
+            !doc.collapse_cursors(),
+            "Nothing to collapse with one cursor"
+        );
+
+        doc.add_cursor(3);
+        assert!(doc.collapse_cursors());
+        assert_eq!(doc.regions().len(), 1);
+        assert_eq!(
+            doc.cursor().get_untracked().end,
+            3,
+            "The most recently added cursor stays"
+        );
+    }
+
+    #[test]
+    fn test_select_next_occurrence() {
+        let doc = Document::new("foo bar foo baz foo");
+        doc.set_offset(1, false);
+
+        // First use selects the word under the caret
+        assert!(doc.select_next_occurrence());
+        let cursor = doc.cursor().get_untracked();
+        assert_eq!((cursor.min(), cursor.max()), (0, 3));
+        assert_eq!(doc.regions().len(), 1);
+
+        // Subsequent uses add the following occurrences
+        assert!(doc.select_next_occurrence());
+        let cursor = doc.cursor().get_untracked();
+        assert_eq!((cursor.min(), cursor.max()), (8, 11));
+        assert!(doc.select_next_occurrence());
+        assert_eq!(doc.regions().len(), 3);
+
+        // All occurrences selected: nothing more to add
+        assert!(!doc.select_next_occurrence());
+
+        doc.insert_text("qux");
+        assert_eq!(doc.text(), "qux bar qux baz qux");
+    }
+
+    #[test]
+    fn test_select_next_occurrence_wraps_around() {
+        let doc = Document::new("ab ab ab");
+        doc.set_offset(6, false);
+        doc.set_offset(8, true);
+
+        assert!(doc.select_next_occurrence());
+        let cursor = doc.cursor().get_untracked();
+        assert_eq!(
+            (cursor.min(), cursor.max()),
+            (0, 2),
+            "Search should wrap to the start of the document"
+        );
+    }
+
+    #[test]
+    fn test_alt_click_adds_cursor() {
+        let doc = Document::new("hello world");
+        doc.set_width(200.0);
+        doc.set_offset(0, false);
+
+        let lines = doc.text_layouts().borrow();
+        let point = lines.point_of_offset(6);
+        drop(lines);
+
+        let mut state = create_pointer_state(point.x, point.glyph_top, 1);
+        state.modifiers = ui_events::keyboard::Modifiers::ALT;
+        doc.single_click(&state);
+
+        let mut ends: Vec<usize> = doc.regions().iter().map(|r| r.end).collect();
+        ends.sort();
+        assert_eq!(ends, vec![0, 6]);
+
+        // A plain click goes back to a single cursor
+        let state = create_pointer_state(point.x, point.glyph_top, 1);
+        doc.single_click(&state);
+        assert_eq!(doc.regions().len(), 1);
+    }
+
+    #[test]
+    fn test_multi_cursor_undo_restores_all_cursors() {
+        let doc = Document::new("a\nb");
+        doc.set_width(200.0);
+        doc.set_offset(1, false);
+        doc.add_cursor(3);
+        doc.insert_text("1");
+        doc.insert_text("2");
+        assert_eq!(doc.text(), "a12\nb12");
+
+        assert!(doc.undo());
+        assert_eq!(
+            doc.text(),
+            "a\nb",
+            "Multi-cursor typing should undo as one group"
+        );
+        let mut ends: Vec<usize> = doc.regions().iter().map(|r| r.end).collect();
+        ends.sort();
+        assert_eq!(ends, vec![1, 3]);
+
+        assert!(doc.redo());
+        assert_eq!(doc.text(), "a12\nb12");
+        assert_eq!(doc.regions().len(), 2);
+    }
+
+    #[test]
+    fn test_multi_cursor_paste_distributes_lines() {
+        let doc = Document::new("a\nb");
+        doc.set_width(200.0);
+        doc.set_offset(1, false);
+        doc.add_cursor(3);
+        doc.paste_text("1\n2");
+        assert_eq!(doc.text(), "a1\nb2");
+
+        doc.paste_text("x");
+        assert_eq!(doc.text(), "a1x\nb2x");
+    }
+
+    #[test]
+    fn test_password_layout_keeps_real_offsets() {
+        // "é" is 2 bytes and "👍🏽" is 8 bytes, but each is one grapheme
+        let doc = Document::new("aé👍🏽");
+        doc.set_width(200.0);
+        doc.set_password(true);
+        assert!(doc.is_password());
+
+        let lines = doc.text_layouts().borrow();
+        assert_eq!(lines.utf8_len(), 11);
+        let bullet = lines.point_of_offset(1).x;
+        assert!(bullet > 0.0);
+        assert!((lines.point_of_offset(3).x - 2.0 * bullet).abs() < 0.01);
+        assert!((lines.point_of_offset(11).x - 3.0 * bullet).abs() < 0.01);
+        assert_eq!(lines.offset_of_point(Point::new(bullet * 1.5, 0.0)), 1);
+    }
+
+    #[test]
+    fn test_password_layout_follows_edits() {
+        let doc = Document::new("");
+        doc.set_width(200.0);
+        doc.set_password(true);
+        doc.insert_text("pä");
+        doc.insert_text("ss");
+
+        let lines = doc.text_layouts().borrow();
+        assert_eq!(lines.utf8_len(), 5);
+        let bullet = lines.point_of_offset(1).x;
+        assert!((lines.point_of_offset(5).x - 4.0 * bullet).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_password_disables_copy_and_cut() {
+        let doc = Document::new("secret");
+        doc.set_width(200.0);
+        doc.set_password(true);
+        doc.select_all();
+
+        assert!(!doc.copy());
+        assert!(!doc.cut());
+        assert_eq!(doc.text(), "secret");
+    }
+
+    #[test]
+    fn test_password_word_movement_spans_field() {
+        let doc = Document::new("correct horse");
+        doc.set_width(200.0);
+        doc.set_password(true);
+        doc.set_offset(3, false);
+
+        doc.run_move_command(&MoveCommand::WordForward, false);
+        assert_eq!(doc.cursor().get_untracked().end, 13);
+
+        doc.run_move_command(&MoveCommand::WordBackward, false);
+        assert_eq!(doc.cursor().get_untracked().end, 0);
+    }
+
+    #[test]
+    fn test_password_delete_word_clears_field() {
+        let doc = Document::new("correct horse");
+        doc.set_width(200.0);
+        doc.set_password(true);
+        doc.set_offset(13, false);
+
+        doc.run_edit_command(&EditCommand::DeleteWordBackward);
+        assert_eq!(doc.text(), "");
+    }
+
+    #[test]
+    fn test_password_double_click_selects_all() {
+        let doc = Document::new("correct horse");
+        doc.set_width(200.0);
+        doc.set_password(true);
+
+        let point = doc.text_layouts().borrow().point_of_offset(1);
+        doc.double_click(&create_pointer_state(point.x, point.glyph_top, 2));
+
+        let cursor = doc.cursor().get_untracked();
+        assert_eq!((cursor.min(), cursor.max()), (0, 13));
+    }
+
+    #[test]
+    fn test_leaving_password_mode_restores_words() {
+        let doc = Document::new("correct horse");
+        doc.set_width(200.0);
+        doc.set_password(true);
+        doc.set_password(false);
+        doc.set_offset(0, false);
+
+        doc.run_move_command(&MoveCommand::WordForward, false);
+        assert!(doc.cursor().get_untracked().end < 13);
+    }
+
+    #[test]
+    fn test_kill_line_appends_consecutive_kills() {
+        let doc = Document::new("one\ntwo\nthree");
+        assert!(doc.kill_line());
+        assert_eq!(doc.text(), "\ntwo\nthree");
+        assert!(
+            doc.kill_line(),
+            "At the end of a line the line break is killed"
+        );
+        assert!(doc.kill_line());
+        assert_eq!(doc.text(), "\nthree");
+        assert_eq!(doc.kill_ring(), ["one\ntwo"]);
+
+        doc.insert_text("x");
+        doc.run_move_command(&MoveCommand::Left, false);
+        assert!(doc.kill_line());
+        assert_eq!(
+            doc.kill_ring(),
+            ["x", "one\ntwo"],
+            "An edit starts a new entry"
+        );
+
+        doc.set_offset(doc.text().len(), false);
+        assert!(!doc.kill_line());
+    }
+
+    #[test]
+    fn test_yank_and_yank_pop() {
+        let doc = Document::new("alpha\nbeta\n");
+        doc.kill_line();
+        doc.set_offset(1, false);
+        doc.kill_line();
+        assert_eq!(doc.text(), "\n\n");
+
+        doc.set_offset(0, false);
+        assert!(doc.yank(false));
+        assert_eq!(doc.text(), "beta\n\n");
+        assert!(doc.yank_pop(false));
+        assert_eq!(doc.text(), "alpha\n\n");
+        assert_eq!(doc.cursor().get_untracked().end, 5);
+        assert!(doc.yank_pop(false), "Yank-pop wraps around the ring");
+        assert_eq!(doc.text(), "beta\n\n");
+
+        doc.undo();
+        assert_eq!(doc.text(), "alpha\n\n");
+        assert!(
+            !doc.yank_pop(false),
+            "Only the text just yanked is replaced"
+        );
+
+        doc.undo();
+        doc.undo();
+        assert_eq!(doc.text(), "\n\n");
+    }
+
+    #[test]
+    fn test_set_mark_selects_with_moves() {
+        let doc = Document::new("hello world");
+        doc.set_width(200.0);
+        doc.set_mark();
+        assert_eq!(doc.mark(), Some(0));
+        doc.run_move_command(&MoveCommand::Right, false);
+        doc.run_move_command(&MoveCommand::Right, false);
+        let cursor = doc.cursor().get_untracked();
+        assert_eq!((cursor.min(), cursor.max()), (0, 2));
+
+        assert!(doc.kill_line(), "The selection is killed as a whole");
+        assert_eq!(doc.text(), "llo world");
+        assert_eq!(doc.mark(), None, "Edits clear the mark");
+
+        doc.set_mark();
+        doc.set_mark();
+        assert_eq!(doc.mark(), None, "Setting the mark twice clears it");
+        doc.set_mark();
+        assert!(doc.collapse_cursors());
+        assert_eq!(doc.mark(), None);
+    }
+
+    #[test]
+    fn test_password_kill_skips_kill_ring() {
+        let doc = Document::new("secret");
+        doc.set_password(true);
+        assert!(doc.kill_line());
+        assert_eq!(doc.text(), "");
+        assert!(doc.kill_ring().is_empty());
+        assert!(!doc.yank(false));
+    }
+
+    #[test]
+    fn test_merge_ranges() {
+        assert_eq!(
+            merge_ranges(vec![(5, 7), (0, 2), (2, 3), (6, 9), (4, 4)]),
+            vec![(0, 3), (5, 9)]
+        );
+    }
 }
@@ -0,0 +1,617 @@
+//! Find and replace for [`TextArea`](super::TextArea)
+//!
+//! The find bar opens over the top-right corner of the text area with
+//! Cmd/Ctrl+F, or with a replace row using Ctrl+H (Cmd+Alt+F on macOS).
+//! Enter and Shift+Enter in the query go to the next and previous match,
+//! which is selected and scrolled into view. Escape closes the bar.
+//!
+//! Matches are searched line by line straight from the rope, so they never
+//! span a line break.
+
+use floem::prelude::*;
+use floem::reactive::SignalTrack;
+use floem::views::{Decorators, Label, Stack};
+use floem::{HasViewId, ViewId};
+use floem_editor_core::{cursor::CursorAffinity, editor::EditType, selection::SelRegion};
+use floem_tailwind::TailwindExt;
+use lapce_xi_rope::Rope;
+use regex::{Regex, RegexBuilder};
+
+use super::{Command, Document, Keymap};
+use crate::components::button::Button;
+use crate::components::input::Input;
+use crate::components::tooltip::TooltipExt;
+use crate::theme::ShadcnThemeExt;
+
+/// How the query matches text
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct FindOptions {
+    pub case_sensitive: bool,
+    pub whole_word: bool,
+    pub regex: bool,
+}
+
+/// Builds the regex that finds `query`
+fn search_regex(query: &str, options: FindOptions) -> Result<Regex, regex::Error> {
+    let pattern = if options.regex {
+        query.to_string()
+    } else {
+        regex::escape(query)
+    };
+    let pattern = if options.whole_word {
+        format!(r"\b(?:{pattern})\b")
+    } else {
+        pattern
+    };
+    RegexBuilder::new(&pattern)
+        .case_insensitive(!options.case_sensitive)
+        .multi_line(true)
+        .build()
+}
+
+/// Strips the line break from a raw rope line
+fn line_content(line: &str) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+/// Returns the byte ranges of every non-empty match of `query` in `text`,
+/// searching one line at a time. An empty query matches nothing.
+pub(crate) fn find_matches(
+    text: &Rope,
+    query: &str,
+    options: FindOptions,
+) -> Result<Vec<(usize, usize)>, regex::Error> {
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    let regex = search_regex(query, options)?;
+    let mut matches = Vec::new();
+    let mut line_start = 0;
+    for line in text.lines_raw(..) {
+        matches.extend(
+            regex
+                .find_iter(line_content(&line))
+                .filter(|m| !m.is_empty())
+                .map(|m| (line_start + m.start(), line_start + m.end())),
+        );
+        line_start += line.len();
+    }
+    Ok(matches)
+}
+
+/// Find bar state for a text area
+#[derive(Clone, Copy)]
+pub(crate) struct Find {
+    doc: RwSignal<Document>,
+    text_area: ViewId,
+    read_only: RwSignal<bool>,
+    open: RwSignal<bool>,
+    show_replace: RwSignal<bool>,
+    /// Bumped to move focus to the query input
+    focus: RwSignal<u64>,
+    query: RwSignal<String>,
+    replacement: RwSignal<String>,
+    options: RwSignal<FindOptions>,
+    matches: RwSignal<Vec<(usize, usize)>>,
+    /// Whether the query is an invalid regex
+    invalid: RwSignal<bool>,
+}
+
+impl Find {
+    pub fn new(doc: RwSignal<Document>, text_area: ViewId, read_only: RwSignal<bool>) -> Self {
+        let find = Self {
+            doc,
+            text_area,
+            read_only,
+            open: RwSignal::new(false),
+            show_replace: RwSignal::new(false),
+            focus: RwSignal::new(0),
+            query: RwSignal::new(String::new()),
+            replacement: RwSignal::new(String::new()),
+            options: RwSignal::new(FindOptions::default()),
+            matches: RwSignal::new(Vec::new()),
+            invalid: RwSignal::new(false),
+        };
+
+        // Search again whenever the query, the options or the text change,
+        // and jump to the nearest match when the query or options change
+        Effect::new(move |prev: Option<Option<(String, FindOptions)>>| {
+            if !find.open.get() {
+                find.matches.set(Vec::new());
+                return None;
+            }
+            let query = find.query.get();
+            let options = find.options.get();
+            let doc = find.doc.get_untracked();
+            let found = doc
+                .buffer()
+                .with(|buffer| find_matches(buffer.text(), &query, options));
+
+            match found {
+                Ok(matches) => {
+                    find.invalid.set(false);
+                    find.matches.set(matches);
+                }
+                Err(_) => {
+                    find.invalid.set(true);
+                    find.matches.set(Vec::new());
+                }
+            }
+
+            let search = Some((query, options));
+            if prev.flatten() != search {
+                find.select_from(doc.cursor().get_untracked().min());
+            }
+            search
+        });
+
+        find
+    }
+
+    /// Opens the find bar, with the replace row if `replace`, and focuses
+    /// the query. A selection within one line becomes the query.
+    pub fn open(&self, replace: bool) {
+        let doc = self.doc.get_untracked();
+        let cursor = doc.cursor().get_untracked();
+        if !cursor.is_caret() {
+            let selected = doc.buffer().with_untracked(|buffer| {
+                buffer
+                    .text()
+                    .slice_to_cow(cursor.min()..cursor.max())
+                    .into_owned()
+            });
+            if !selected.contains('\n') {
+                self.query.set(selected);
+            }
+        }
+        self.show_replace.set(replace);
+        self.open.set(true);
+        self.focus.update(|n| *n += 1);
+    }
+
+    /// Closes the find bar and returns focus to the text area
+    pub fn close(&self) {
+        self.open.set(false);
+        self.text_area.request_focus();
+        self.text_area.request_paint();
+    }
+
+    pub fn is_open(&self) -> RwSignal<bool> {
+        self.open
+    }
+
+    /// Matches to highlight, sorted by position
+    pub fn matches(&self) -> RwSignal<Vec<(usize, usize)>> {
+        self.matches
+    }
+
+    /// The index of the match that is selected in the document
+    fn current(&self) -> Option<usize> {
+        let cursor = self.doc.with(|doc| doc.cursor().get());
+        self.matches
+            .with(|matches| matches.binary_search(&(cursor.min(), cursor.max())).ok())
+    }
+
+    /// Selects the first match starting at or after `offset`, wrapping around
+    fn select_from(&self, offset: usize) {
+        let found = self.matches.with_untracked(|matches| {
+            matches
+                .iter()
+                .find(|m| m.0 >= offset)
+                .or(matches.first())
+                .copied()
+        });
+        if let Some(found) = found {
+            self.select(found);
+        }
+    }
+
+    fn select(&self, (start, end): (usize, usize)) {
+        let doc = self.doc.get_untracked();
+        doc.set_offset(start, false);
+        doc.set_offset(end, true);
+        self.text_area.request_paint();
+    }
+
+    /// Selects the next match after the selection, wrapping around
+    pub fn next(&self) {
+        let cursor = self.doc.get_untracked().cursor().get_untracked();
+        let found = self.matches.with_untracked(|matches| {
+            matches
+                .iter()
+                .find(|m| m.0 >= cursor.max() && *m != &(cursor.min(), cursor.max()))
+                .or(matches.first())
+                .copied()
+        });
+        if let Some(found) = found {
+            self.select(found);
+        }
+    }
+
+    /// Selects the previous match before the selection, wrapping around
+    pub fn previous(&self) {
+        let cursor = self.doc.get_untracked().cursor().get_untracked();
+        let found = self.matches.with_untracked(|matches| {
+            matches
+                .iter()
+                .rev()
+                .find(|m| m.1 <= cursor.min())
+                .or(matches.last())
+                .copied()
+        });
+        if let Some(found) = found {
+            self.select(found);
+        }
+    }
+
+    /// The replacement text for the match at `start`, with `$1`-style
+    /// groups expanded in regex mode
+    fn replacement_for(&self, regex: &Regex, text: &Rope, start: usize) -> String {
+        let replacement = self.replacement.get_untracked();
+        if !self.options.get_untracked().regex {
+            return replacement;
+        }
+        let line_start = text.offset_of_line(text.line_of_offset(start));
+        let line_end = text.offset_of_line(text.line_of_offset(start) + 1);
+        let line = text.slice_to_cow(line_start..line_end);
+        let mut expanded = String::new();
+        if let Some(captures) = regex.captures_at(line_content(&line), start - line_start) {
+            captures.expand(&replacement, &mut expanded);
+        }
+        expanded
+    }
+
+    /// Replaces the selected match and selects the next one. If no match is
+    /// selected, only selects the next one.
+    pub fn replace_one(&self) {
+        if self.read_only.get_untracked() {
+            return;
+        }
+        let Some(index) = self.current() else {
+            self.next();
+            return;
+        };
+        let (start, end) = self.matches.with_untracked(|matches| matches[index]);
+        let doc = self.doc.get_untracked();
+        let Ok(regex) = search_regex(&self.query.get_untracked(), self.options.get_untracked())
+        else {
+            return;
+        };
+        let replacement = doc
+            .buffer()
+            .with_untracked(|buffer| self.replacement_for(&regex, buffer.text(), start));
+
+        doc.commit_undo_group();
+        doc.edit(
+            [(
+                SelRegion::new(start, end, CursorAffinity::Forward, None),
+                replacement.as_str(),
+            )],
+            EditType::Other,
+        );
+        doc.commit_undo_group();
+        self.select_from(start + replacement.len());
+        self.text_area.request_layout();
+    }
+
+    /// Replaces every match as a single undoable edit
+    pub fn replace_all(&self) {
+        if self.read_only.get_untracked() {
+            return;
+        }
+        let matches = self.matches.get_untracked();
+        if matches.is_empty() {
+            return;
+        }
+        let doc = self.doc.get_untracked();
+        let Ok(regex) = search_regex(&self.query.get_untracked(), self.options.get_untracked())
+        else {
+            return;
+        };
+        let replacements: Vec<(SelRegion, String)> = doc.buffer().with_untracked(|buffer| {
+            matches
+                .iter()
+                .map(|&(start, end)| {
+                    (
+                        SelRegion::new(start, end, CursorAffinity::Forward, None),
+                        self.replacement_for(&regex, buffer.text(), start),
+                    )
+                })
+                .collect()
+        });
+
+        doc.commit_undo_group();
+        doc.edit(
+            replacements
+                .iter()
+                .map(|(region, replacement)| (*region, replacement.as_str())),
+            EditType::Other,
+        );
+        doc.commit_undo_group();
+        self.text_area.request_layout();
+    }
+}
+
+/// The find bar shown over a text area
+pub(crate) fn find_bar(find: Find) -> impl IntoView {
+    // Escape closes and Shift+Enter goes back; Enter is `on_enter`
+    let mut replace_keymap = Keymap::single_line();
+    replace_keymap
+        .bind("esc", Command::from_name("close-find"))
+        .expect("valid key chord");
+    let mut keymap = replace_keymap.clone();
+    keymap
+        .bind("shift+enter", Command::from_name("find-previous"))
+        .expect("valid key chord");
+
+    let query_input = Input::new()
+        .placeholder("Find")
+        .value(move || find.query.get())
+        .on_update(move |text| find.query.set(text.to_string()))
+        .on_enter(move |_| find.next())
+        .keymap(keymap)
+        .command("close-find", move |_| find.close())
+        .command("find-previous", move |_| find.previous());
+    let query_id = query_input.view_id();
+
+    Effect::new(move |_| {
+        find.focus.track();
+        if find.open.get_untracked() {
+            query_id.request_focus();
+        }
+    });
+
+    let replace_input = Input::new()
+        .placeholder("Replace")
+        .value(move || find.replacement.get())
+        .on_update(move |text| find.replacement.set(text.to_string()))
+        .on_enter(move |_| find.replace_one())
+        .read_only(move || find.read_only.get())
+        .keymap(replace_keymap)
+        .command("close-find", move |_| find.close());
+
+    let count = Label::derived(move || {
+        if find.invalid.get() {
+            return "Invalid".to_string();
+        }
+        let total = find.matches.with(|matches| matches.len());
+        match find.current() {
+            _ if total == 0 => "No results".to_string(),
+            Some(index) => format!("{} of {total}", index + 1),
+            None => format!("{total} found"),
+        }
+    })
+    .style(|s| {
+        s.with_shadcn_theme(|s, t| {
+            s.min_width(t.space(16.0))
+                .font_size(t.font_size.xs)
+                .color(t.muted_foreground)
+        })
+    });
+
+    let find_row = Stack::horizontal((
+        query_input.style(|s| s.with_shadcn_theme(|s, t| s.width(t.space(45.0)))),
+        option_toggle("Aa", "Match case", find, |o| &mut o.case_sensitive),
+        option_toggle("ab", "Whole word", find, |o| &mut o.whole_word),
+        option_toggle(".*", "Regular expression", find, |o| &mut o.regex),
+        count,
+        bar_button("↑", move || find.previous()),
+        bar_button("↓", move || find.next()),
+        bar_button("×", move || find.close()),
+    ))
+    .style(|s| s.with_shadcn_theme(|s, t| s.items_center().gap(t.space(1.0))));
+
+    let replace_row = Stack::horizontal((
+        replace_input.style(|s| s.with_shadcn_theme(|s, t| s.width(t.space(45.0)))),
+        Button::new("Replace")
+            .sm()
+            .outline()
+            .on_click_stop(move |_| find.replace_one()),
+        Button::new("All")
+            .sm()
+            .outline()
+            .on_click_stop(move |_| find.replace_all()),
+    ))
+    .style(move |s| {
+        s.apply_if(!find.show_replace.get(), |s| s.hide())
+            .with_shadcn_theme(|s, t| s.items_center().gap(t.space(1.0)))
+    });
+
+    Stack::vertical((find_row, replace_row))
+        // Keep clicks from moving the text area's cursor
+        .on_event_stop(floem::event::EventListener::PointerDown, |_| {})
+        .style(move |s| {
+            s.absolute()
+                .apply_if(!find.open.get(), |s| s.hide())
+                .with_shadcn_theme(|s, t| {
+                    s.inset_top(t.space(1.0))
+                        .inset_right(t.space(1.0))
+                        .gap(t.space(1.0))
+                        .padding(t.space(1.0))
+                        .background(t.popover)
+                        .color(t.popover_foreground)
+                        .border(1.0)
+                        .border_color(t.border)
+                        .border_radius(t.radius)
+                        .apply_shadow(t.shadow.md)
+                        .z_index(10)
+                })
+        })
+}
+
+/// A ghost button that toggles one of the find options
+fn option_toggle(
+    text: &'static str,
+    tooltip: &'static str,
+    find: Find,
+    option: fn(&mut FindOptions) -> &mut bool,
+) -> impl IntoView {
+    let active = move || *option(&mut find.options.get());
+    Button::new(Label::new(text).style(move |s| {
+        let active = active();
+        s.with_shadcn_theme(move |s, t| {
+            s.padding_horiz(t.space(1.0))
+                .border_radius(t.radius_sm)
+                .apply_if(active, |s| {
+                    s.background(t.accent).color(t.accent_foreground)
+                })
+                .apply_if(!active, |s| s.color(t.muted_foreground))
+        })
+    }))
+    .ghost()
+    .sm()
+    .on_click_stop(move |_| {
+        find.options.update(|options| {
+            let value = option(options);
+            *value = !*value;
+        })
+    })
+    .tooltip_styled(tooltip)
+}
+
+fn bar_button(text: &'static str, on_click: impl Fn() + 'static) -> impl IntoView {
+    Button::new(text)
+        .ghost()
+        .sm()
+        .on_click_stop(move |_| on_click())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_matches_options() {
+        let text = Rope::from("Cat cat scatter CAT");
+        let options = FindOptions::default();
+        assert_eq!(
+            find_matches(&text, "cat", options).unwrap(),
+            [(0, 3), (4, 7), (9, 12), (16, 19)]
+        );
+
+        let case_sensitive = FindOptions {
+            case_sensitive: true,
+            ..options
+        };
+        assert_eq!(
+            find_matches(&text, "cat", case_sensitive).unwrap(),
+            [(4, 7), (9, 12)]
+        );
+
+        let whole_word = FindOptions {
+            whole_word: true,
+            ..options
+        };
+        assert_eq!(
+            find_matches(&text, "cat", whole_word).unwrap(),
+            [(0, 3), (4, 7), (16, 19)]
+        );
+
+        assert!(find_matches(&text, "", options).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_find_matches_regex() {
+        let regex = FindOptions {
+            regex: true,
+            ..FindOptions::default()
+        };
+        assert_eq!(
+            find_matches(&"a1 b22 c".into(), r"\d+", regex).unwrap(),
+            [(1, 2), (4, 6)]
+        );
+        assert_eq!(
+            find_matches(&"a.b".into(), ".", FindOptions::default()).unwrap(),
+            [(1, 2)],
+            "Without regex the query is literal"
+        );
+        assert!(
+            find_matches(&"aaa".into(), "x*", regex).unwrap().is_empty(),
+            "Empty matches are skipped"
+        );
+        assert!(find_matches(&"abc".into(), "(", regex).is_err());
+    }
+
+    #[test]
+    fn test_find_matches_line_by_line() {
+        let regex = FindOptions {
+            regex: true,
+            ..FindOptions::default()
+        };
+        let text = Rope::from("ab\r\nab\nb");
+        assert_eq!(
+            find_matches(&text, "b$", regex).unwrap(),
+            [(1, 2), (5, 6), (7, 8)],
+            "Offsets count the line breaks"
+        );
+        assert!(
+            find_matches(&text, r"b\s+a", regex).unwrap().is_empty(),
+            "Matches don't span lines"
+        );
+    }
+
+    fn find_in(text: &str) -> (Find, RwSignal<Document>) {
+        let doc = RwSignal::new(Document::new(text));
+        let find = Find::new(doc, ViewId::new(), RwSignal::new(false));
+        find.open(true);
+        (find, doc)
+    }
+
+    fn selection(doc: RwSignal<Document>) -> (usize, usize) {
+        let cursor = doc.get_untracked().cursor().get_untracked();
+        (cursor.min(), cursor.max())
+    }
+
+    #[test]
+    fn test_find_navigates_and_replaces() {
+        let (find, doc) = find_in("one two one two one");
+        find.query.set("one".into());
+        assert_eq!(find.matches.get_untracked().len(), 3);
+        assert_eq!(selection(doc), (0, 3), "Typing selects the nearest match");
+
+        find.next();
+        assert_eq!(selection(doc), (8, 11));
+        find.previous();
+        find.previous();
+        assert_eq!(selection(doc), (16, 19), "Previous wraps around");
+
+        find.replacement.set("1".into());
+        find.replace_one();
+        assert_eq!(doc.get_untracked().text(), "one two one two 1");
+        assert_eq!(selection(doc), (0, 3), "Replacing selects the next match");
+
+        find.replace_all();
+        assert_eq!(doc.get_untracked().text(), "1 two 1 two 1");
+        assert!(find.matches.get_untracked().is_empty());
+
+        doc.get_untracked().undo();
+        assert_eq!(
+            doc.get_untracked().text(),
+            "one two one two 1",
+            "Replace all is undone in one step"
+        );
+    }
+
+    #[test]
+    fn test_find_regex_replacement_and_read_only() {
+        let (find, doc) = find_in("a@b c@d");
+        find.options.set(FindOptions {
+            regex: true,
+            ..FindOptions::default()
+        });
+        find.query.set(r"(\w)@(\w)".into());
+        find.replacement.set("$2@$1".into());
+
+        find.read_only.set(true);
+        find.replace_all();
+        assert_eq!(doc.get_untracked().text(), "a@b c@d");
+
+        find.read_only.set(false);
+        find.replace_all();
+        assert_eq!(doc.get_untracked().text(), "b@a d@c");
+
+        find.query.set("(".into());
+        assert!(find.invalid.get_untracked());
+    }
+}
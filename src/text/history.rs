@@ -0,0 +1,154 @@
+//! Undo/redo history for text documents.
+//!
+//! Each edit applied through `Document::edit` is recorded as a replacement of a
+//! byte range together with the cursors before and after the edit. Consecutive
+//! edits of the same kind (typing, backspacing) that continue where the previous
+//! one stopped are merged into a single undo group.
+
+use floem_editor_core::{editor::EditType, selection::SelRegion};
+
+/// Maximum number of undo groups kept per document.
+const MAX_UNDO_DEPTH: usize = 500;
+
+/// A single recorded edit: `removed` was replaced by `inserted` at `start`.
+///
+/// Cursors are stored primary first, so multi-cursor edits restore every cursor.
+#[derive(Clone, Debug)]
+pub(crate) struct EditRecord {
+    pub start: usize,
+    pub removed: String,
+    pub inserted: String,
+    pub cursors_before: Vec<SelRegion>,
+    pub cursors_after: Vec<SelRegion>,
+    pub edit_type: EditType,
+}
+
+impl EditRecord {
+    /// Tries to merge `next` into this record so both are undone together.
+    /// Returns false (leaving `self` untouched) if the edits should stay separate.
+    ///
+    /// Typing and deleting merge when the cursors did not move in between and
+    /// the range touched by `next` overlaps or borders the text produced by this
+    /// record. This covers plain typing and backspacing as well as the wider
+    /// replacements recorded for edits made with several cursors.
+    fn merge(&mut self, next: &EditRecord) -> bool {
+        if self.edit_type != next.edit_type
+            || !matches!(next.edit_type, EditType::InsertChars | EditType::Delete)
+            || self.cursors_after != next.cursors_before
+        {
+            return false;
+        }
+
+        // All offsets below are in the text between the two edits
+        let a = self.start;
+        let a_end = a + self.inserted.len();
+        let b = next.start;
+        let b_end = b + next.removed.len();
+        if b > a_end || b_end < a {
+            return false;
+        }
+        let lo = a.min(b);
+
+        // Reassemble the text covered by both ranges
+        let mut covered = String::new();
+        if b < a {
+            covered.push_str(&next.removed[..a - b]);
+        }
+        covered.push_str(&self.inserted);
+        if b_end > a_end {
+            covered.push_str(&next.removed[a_end - b..]);
+        }
+
+        let removed = format!(
+            "{}{}{}",
+            &covered[..a - lo],
+            self.removed,
+            &covered[a_end - lo..]
+        );
+        let inserted = format!(
+            "{}{}{}",
+            &covered[..b - lo],
+            next.inserted,
+            &covered[b_end - lo..]
+        );
+
+        self.start = lo;
+        self.removed = removed;
+        self.inserted = inserted;
+        self.cursors_after = next.cursors_after.clone();
+        true
+    }
+}
+
+/// Undo and redo stacks for a document.
+#[derive(Default)]
+pub(crate) struct UndoHistory {
+    undo: Vec<EditRecord>,
+    redo: Vec<EditRecord>,
+    /// When set, the next recorded edit starts a new group even if it could merge.
+    group_closed: bool,
+}
+
+impl UndoHistory {
+    /// Records a new edit, merging it into the previous group when possible.
+    /// Any pending redo entries are discarded.
+    pub fn record(&mut self, record: EditRecord) {
+        self.redo.clear();
+
+        if !self.group_closed {
+            if let Some(last) = self.undo.last_mut() {
+                if last.merge(&record) {
+                    return;
+                }
+            }
+        }
+
+        self.group_closed = false;
+        self.undo.push(record);
+        if self.undo.len() > MAX_UNDO_DEPTH {
+            self.undo.remove(0);
+        }
+    }
+
+    /// Forces the next recorded edit into a new undo group.
+    pub fn close_group(&mut self) {
+        self.group_closed = true;
+    }
+
+    /// Pops the most recent group so it can be reverted.
+    pub fn pop_undo(&mut self) -> Option<EditRecord> {
+        let record = self.undo.pop()?;
+        self.group_closed = true;
+        Some(record)
+    }
+
+    /// Pops the most recently undone group so it can be re-applied.
+    pub fn pop_redo(&mut self) -> Option<EditRecord> {
+        self.redo.pop()
+    }
+
+    /// Pushes a reverted group onto the redo stack.
+    pub fn push_redo(&mut self, record: EditRecord) {
+        self.redo.push(record);
+    }
+
+    /// Pushes a re-applied group back onto the undo stack without clearing redo.
+    pub fn push_undo(&mut self, record: EditRecord) {
+        self.undo.push(record);
+        self.group_closed = true;
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.group_closed = false;
+    }
+}
@@ -3,13 +3,21 @@
 //! This module provides common types and utilities used by both TextInput and TextArea.
 
 use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
 
+use floem::action::exec_after;
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
 use floem_editor_core::command::{EditCommand, MoveCommand};
 use ui_events::keyboard::{Key, Modifiers, NamedKey};
 
 /// Cursor blink interval in milliseconds
 pub const CURSOR_BLINK_INTERVAL_MS: u64 = 500;
 
+/// How long a multi-key sequence waits for its next key, in milliseconds
+pub const SEQUENCE_TIMEOUT_MS: u64 = 1500;
+
 /// A command that can be executed on a text editor
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Command {
@@ -23,18 +31,281 @@ pub enum Command {
     Cut,
     /// Paste text from clipboard
     Paste,
+    /// Undo the last edit group
+    Undo,
+    /// Redo the last undone edit group
+    Redo,
+    /// Select the next occurrence of the selection as an additional cursor
+    SelectNextOccurrence,
+    /// Remove all cursors except the primary one
+    CollapseCursors,
+    /// Kill to the end of the line into the kill ring
+    KillLine,
+    /// Insert the most recent kill
+    Yank,
+    /// Replace the text just yanked with an older kill
+    YankPop,
+    /// Set the mark, so moving the cursor selects from it
+    SetMark,
+    /// Open the find bar of a text area
+    Find,
+    /// Open the find bar of a text area with replace
+    Replace,
+    /// A user-defined command, run by the callback registered with
+    /// [`TextArea::command`](super::TextArea::command) or
+    /// [`TextInput::command`](super::TextInput::command)
+    Custom(String),
+}
+
+impl Command {
+    /// Returns true if the command changes the text.
+    /// These are rejected by read-only editors.
+    pub fn is_edit(&self) -> bool {
+        matches!(
+            self,
+            Command::Edit(_)
+                | Command::Cut
+                | Command::Paste
+                | Command::Undo
+                | Command::Redo
+                | Command::KillLine
+                | Command::Yank
+                | Command::YankPop
+        )
+    }
+
+    /// Look up a command by name
+    ///
+    /// Built-in commands use kebab-case names such as `move-left`,
+    /// `delete-word-backward` or `select-all` (see [`Command::builtin`]); any
+    /// other name is a [`Command::Custom`].
+    pub fn from_name(name: &str) -> Command {
+        Command::builtin(name).unwrap_or_else(|| Command::Custom(name.to_string()))
+    }
+
+    /// Look up a built-in command by its kebab-case name
+    pub fn builtin(name: &str) -> Option<Command> {
+        let command = match name {
+            "move-left" => Command::Move(MoveCommand::Left),
+            "move-right" => Command::Move(MoveCommand::Right),
+            "move-up" => Command::Move(MoveCommand::Up),
+            "move-down" => Command::Move(MoveCommand::Down),
+            "line-start" => Command::Move(MoveCommand::LineStart),
+            "line-end" => Command::Move(MoveCommand::LineEnd),
+            "document-start" => Command::Move(MoveCommand::DocumentStart),
+            "document-end" => Command::Move(MoveCommand::DocumentEnd),
+            "word-backward" => Command::Move(MoveCommand::WordBackward),
+            "word-forward" => Command::Move(MoveCommand::WordForward),
+            "insert-newline" => Command::Edit(EditCommand::InsertNewLine),
+            "insert-tab" => Command::Edit(EditCommand::InsertTab),
+            "delete-backward" => Command::Edit(EditCommand::DeleteBackward),
+            "delete-forward" => Command::Edit(EditCommand::DeleteForward),
+            "delete-word-backward" => Command::Edit(EditCommand::DeleteWordBackward),
+            "delete-word-forward" => Command::Edit(EditCommand::DeleteWordForward),
+            "delete-to-beginning-of-line" => Command::Edit(EditCommand::DeleteToBeginningOfLine),
+            "delete-to-end-of-line" => Command::Edit(EditCommand::DeleteToEndOfLine),
+            "select-all" => Command::SelectAll,
+            "copy" => Command::Copy,
+            "cut" => Command::Cut,
+            "paste" => Command::Paste,
+            "undo" => Command::Undo,
+            "redo" => Command::Redo,
+            "select-next-occurrence" => Command::SelectNextOccurrence,
+            "collapse-cursors" => Command::CollapseCursors,
+            "kill-line" => Command::KillLine,
+            "yank" => Command::Yank,
+            "yank-pop" => Command::YankPop,
+            "set-mark" => Command::SetMark,
+            "find" => Command::Find,
+            "replace" => Command::Replace,
+            _ => return None,
+        };
+        Some(command)
+    }
 }
 
 /// A key press with modifiers
-#[derive(Clone, PartialEq, Eq, Hash)]
+///
+/// Parses from key-chord strings such as `ctrl+shift+k`, `alt+backspace`
+/// or `mod+enter`, where `mod` is Cmd on macOS and Ctrl elsewhere.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct KeyPress {
     pub key: Key,
     pub modifiers: Modifiers,
 }
 
+/// An error found while parsing a key chord
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyChordError {
+    /// The chord has no key
+    Empty,
+    /// A modifier that isn't `ctrl`, `shift`, `alt`, `meta` or `mod`
+    UnknownModifier(String),
+    /// A key name that isn't a single character or a known named key
+    UnknownKey(String),
+}
+
+impl fmt::Display for KeyChordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyChordError::Empty => write!(f, "empty key chord"),
+            KeyChordError::UnknownModifier(name) => write!(f, "unknown modifier `{name}`"),
+            KeyChordError::UnknownKey(name) => write!(f, "unknown key `{name}`"),
+        }
+    }
+}
+
+impl std::error::Error for KeyChordError {}
+
+impl fmt::Display for KeyPress {
+    /// Formats the key press as a chord that parses back, e.g. `ctrl+shift+k`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let modifiers = [
+            (Modifiers::CONTROL, "ctrl"),
+            (Modifiers::ALT, "alt"),
+            (Modifiers::SHIFT, "shift"),
+            (Modifiers::META, "meta"),
+        ];
+        for (modifier, name) in modifiers {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+        match &self.key {
+            Key::Character(c) if &**c == " " => f.write_str("space"),
+            Key::Character(c) => f.write_str(&c.to_lowercase()),
+            Key::Named(named) => f.write_str(&format!("{named:?}").to_lowercase()),
+        }
+    }
+}
+
+impl KeyPress {
+    /// Parse a space-separated sequence of chords such as `ctrl+k ctrl+c`
+    pub fn parse_sequence(sequence: &str) -> Result<Vec<KeyPress>, KeyChordError> {
+        let keys = sequence
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        if keys.is_empty() {
+            return Err(KeyChordError::Empty);
+        }
+        Ok(keys)
+    }
+
+    /// Returns true for keys that only change modifiers, like Shift or Ctrl
+    fn is_modifier(&self) -> bool {
+        matches!(
+            self.key,
+            Key::Named(
+                NamedKey::Alt
+                    | NamedKey::AltGraph
+                    | NamedKey::CapsLock
+                    | NamedKey::Control
+                    | NamedKey::Hyper
+                    | NamedKey::Meta
+                    | NamedKey::Shift
+                    | NamedKey::Super
+            )
+        )
+    }
+
+    /// This key press, followed by the same press without shift
+    fn with_unshifted(self) -> Vec<KeyPress> {
+        let mut unshifted = self.clone();
+        unshifted.modifiers.set(Modifiers::SHIFT, false);
+        if unshifted == self {
+            vec![self]
+        } else {
+            vec![self, unshifted]
+        }
+    }
+}
+
+impl FromStr for KeyPress {
+    type Err = KeyChordError;
+
+    fn from_str(chord: &str) -> Result<Self, Self::Err> {
+        let chord = chord.trim();
+        // `ctrl++` binds the plus key itself
+        let (prefix, key) = match chord.strip_suffix("++") {
+            Some(prefix) => (Some(prefix), "+"),
+            None => match chord.rsplit_once('+') {
+                Some((prefix, key)) => (Some(prefix), key),
+                None => (None, chord),
+            },
+        };
+
+        let mut modifiers = Modifiers::default();
+        for name in prefix.into_iter().flat_map(|p| p.split('+')) {
+            modifiers |= parse_modifier(name.trim())?;
+        }
+        let key = parse_key(key.trim())?;
+
+        Ok(KeyPress { key, modifiers })
+    }
+}
+
+fn parse_modifier(name: &str) -> Result<Modifiers, KeyChordError> {
+    match name.to_ascii_lowercase().as_str() {
+        "ctrl" | "control" => Ok(Modifiers::CONTROL),
+        "shift" => Ok(Modifiers::SHIFT),
+        "alt" | "option" | "opt" => Ok(Modifiers::ALT),
+        "meta" | "cmd" | "command" | "super" | "win" => Ok(Modifiers::META),
+        #[cfg(target_os = "macos")]
+        "mod" | "cmdorctrl" => Ok(Modifiers::META),
+        #[cfg(not(target_os = "macos"))]
+        "mod" | "cmdorctrl" => Ok(Modifiers::CONTROL),
+        "" => Err(KeyChordError::Empty),
+        _ => Err(KeyChordError::UnknownModifier(name.to_string())),
+    }
+}
+
+fn parse_key(name: &str) -> Result<Key, KeyChordError> {
+    let mut chars = name.chars();
+    match (chars.next(), chars.next()) {
+        (None, _) => return Err(KeyChordError::Empty),
+        (Some(c), None) => return Ok(Key::Character(c.to_lowercase().to_string().into())),
+        _ => {}
+    }
+
+    let named = match name.to_ascii_lowercase().as_str() {
+        "space" => return Ok(Key::Character(" ".into())),
+        "enter" | "return" => NamedKey::Enter,
+        "tab" => NamedKey::Tab,
+        "escape" | "esc" => NamedKey::Escape,
+        "backspace" => NamedKey::Backspace,
+        "delete" | "del" => NamedKey::Delete,
+        "insert" | "ins" => NamedKey::Insert,
+        "home" => NamedKey::Home,
+        "end" => NamedKey::End,
+        "pageup" => NamedKey::PageUp,
+        "pagedown" => NamedKey::PageDown,
+        "up" | "arrowup" => NamedKey::ArrowUp,
+        "down" | "arrowdown" => NamedKey::ArrowDown,
+        "left" | "arrowleft" => NamedKey::ArrowLeft,
+        "right" | "arrowright" => NamedKey::ArrowRight,
+        "f1" => NamedKey::F1,
+        "f2" => NamedKey::F2,
+        "f3" => NamedKey::F3,
+        "f4" => NamedKey::F4,
+        "f5" => NamedKey::F5,
+        "f6" => NamedKey::F6,
+        "f7" => NamedKey::F7,
+        "f8" => NamedKey::F8,
+        "f9" => NamedKey::F9,
+        "f10" => NamedKey::F10,
+        "f11" => NamedKey::F11,
+        "f12" => NamedKey::F12,
+        _ => return Err(KeyChordError::UnknownKey(name.to_string())),
+    };
+    Ok(Key::Named(named))
+}
+
 /// Builder for creating keymaps with various binding sets.
 pub struct KeymapBuilder {
     keymaps: HashMap<KeyPress, Command>,
+    sequences: HashMap<Vec<KeyPress>, Command>,
+    sequence_timeout: Duration,
 }
 
 impl KeymapBuilder {
@@ -42,12 +313,42 @@ impl KeymapBuilder {
     pub fn new() -> Self {
         Self {
             keymaps: HashMap::new(),
+            sequences: HashMap::new(),
+            sequence_timeout: Duration::from_millis(SEQUENCE_TIMEOUT_MS),
+        }
+    }
+
+    /// Bind a sequence of key presses, like Emacs' `Ctrl+X Ctrl+S`, to a
+    /// command.
+    /// A sequence of one key press is a plain binding.
+    pub fn with_sequence(
+        mut self,
+        keys: impl IntoIterator<Item = KeyPress>,
+        command: Command,
+    ) -> Self {
+        let mut keys: Vec<KeyPress> = keys.into_iter().collect();
+        match keys.len() {
+            0 => {}
+            1 => {
+                self.keymaps.insert(keys.remove(0), command);
+            }
+            _ => {
+                self.sequences.insert(keys, command);
+            }
         }
+        self
+    }
+
+    /// Set how long a sequence waits for its next key press before it is
+    /// dropped. Defaults to [`SEQUENCE_TIMEOUT_MS`].
+    pub fn with_sequence_timeout(mut self, timeout: Duration) -> Self {
+        self.sequence_timeout = timeout;
+        self
     }
 
     /// Add common bindings shared by all text editors.
     /// Includes: basic left/right navigation, backspace/delete, word navigation,
-    /// word deletion, select all, clipboard operations.
+    /// word deletion, select all, clipboard operations, undo/redo.
     pub fn with_common_bindings(mut self) -> Self {
         #[cfg(target_os = "macos")]
         let cmd_or_ctrl = Modifiers::META;
@@ -208,6 +509,40 @@ impl KeymapBuilder {
             Command::Paste,
         );
 
+        // =======================================================================
+        // Undo/Redo (Cmd/Ctrl+Z, Cmd/Ctrl+Shift+Z, Ctrl+Y on non-macOS)
+        // =======================================================================
+        self.keymaps.insert(
+            KeyPress {
+                key: Key::Character("z".into()),
+                modifiers: cmd_or_ctrl,
+            },
+            Command::Undo,
+        );
+        // Shift may or may not uppercase the logical key depending on platform
+        self.keymaps.insert(
+            KeyPress {
+                key: Key::Character("z".into()),
+                modifiers: cmd_or_ctrl | Modifiers::SHIFT,
+            },
+            Command::Redo,
+        );
+        self.keymaps.insert(
+            KeyPress {
+                key: Key::Character("Z".into()),
+                modifiers: cmd_or_ctrl | Modifiers::SHIFT,
+            },
+            Command::Redo,
+        );
+        #[cfg(not(target_os = "macos"))]
+        self.keymaps.insert(
+            KeyPress {
+                key: Key::Character("y".into()),
+                modifiers: Modifiers::CONTROL,
+            },
+            Command::Redo,
+        );
+
         self
     }
 
@@ -376,6 +711,9 @@ impl KeymapBuilder {
     /// Add Unix Emacs-style keybindings (Ctrl+letter).
     /// If `multiline` is true, includes Ctrl+N/P for up/down and uses LineStart/LineEnd.
     /// If `multiline` is false, uses DocumentStart/DocumentEnd.
+    /// Ctrl+K, Ctrl+Y, Alt+Y and Ctrl+Space use the document's kill ring and
+    /// mark. Ctrl+Y only yanks where it isn't already bound: on Linux it stays
+    /// Redo unless rebound with `keymap.bind("ctrl+y", Command::Yank)`.
     #[cfg(any(target_os = "macos", target_os = "linux"))]
     pub fn with_emacs_bindings(mut self, multiline: bool) -> Self {
         // Ctrl+H -> Delete backward (backspace)
@@ -440,7 +778,30 @@ impl KeymapBuilder {
                 key: Key::Character("k".into()),
                 modifiers: Modifiers::CONTROL,
             },
-            Command::Edit(EditCommand::DeleteToEndOfLine),
+            Command::KillLine,
+        );
+        // Ctrl+Y -> Yank the last kill, unless it already redoes
+        self.keymaps
+            .entry(KeyPress {
+                key: Key::Character("y".into()),
+                modifiers: Modifiers::CONTROL,
+            })
+            .or_insert(Command::Yank);
+        // Alt+Y -> Replace the yank with an older kill
+        self.keymaps.insert(
+            KeyPress {
+                key: Key::Character("y".into()),
+                modifiers: Modifiers::ALT,
+            },
+            Command::YankPop,
+        );
+        // Ctrl+Space -> Set the mark
+        self.keymaps.insert(
+            KeyPress {
+                key: Key::Character(" ".into()),
+                modifiers: Modifiers::CONTROL,
+            },
+            Command::SetMark,
         );
 
         if multiline {
@@ -465,6 +826,58 @@ impl KeymapBuilder {
         self
     }
 
+    /// Add multi-cursor bindings: Cmd/Ctrl+D selects the next occurrence and
+    /// Escape collapses back to a single cursor.
+    /// Chords that are already bound keep their command, so on Linux the
+    /// Emacs Ctrl+D still deletes forward.
+    pub fn with_multi_cursor_bindings(mut self) -> Self {
+        #[cfg(target_os = "macos")]
+        let cmd_or_ctrl = Modifiers::META;
+        #[cfg(not(target_os = "macos"))]
+        let cmd_or_ctrl = Modifiers::CONTROL;
+
+        self.keymaps
+            .entry(KeyPress {
+                key: Key::Character("d".into()),
+                modifiers: cmd_or_ctrl,
+            })
+            .or_insert(Command::SelectNextOccurrence);
+        self.keymaps
+            .entry(KeyPress {
+                key: Key::Named(NamedKey::Escape),
+                modifiers: Modifiers::default(),
+            })
+            .or_insert(Command::CollapseCursors);
+
+        self
+    }
+
+    /// Add find bindings: Cmd/Ctrl+F finds, and Ctrl+H (Cmd+Alt+F on macOS)
+    /// finds and replaces.
+    /// Chords that are already bound keep their command, so on Linux the
+    /// Emacs Ctrl+F and Ctrl+H still move forward and delete backward.
+    pub fn with_find_bindings(mut self) -> Self {
+        #[cfg(target_os = "macos")]
+        let (cmd_or_ctrl, replace) = (Modifiers::META, ("f", Modifiers::META | Modifiers::ALT));
+        #[cfg(not(target_os = "macos"))]
+        let (cmd_or_ctrl, replace) = (Modifiers::CONTROL, ("h", Modifiers::CONTROL));
+
+        self.keymaps
+            .entry(KeyPress {
+                key: Key::Character("f".into()),
+                modifiers: cmd_or_ctrl,
+            })
+            .or_insert(Command::Find);
+        self.keymaps
+            .entry(KeyPress {
+                key: Key::Character(replace.0.into()),
+                modifiers: replace.1,
+            })
+            .or_insert(Command::Replace);
+
+        self
+    }
+
     /// No-op on Windows where Emacs bindings are not standard.
     #[cfg(not(any(target_os = "macos", target_os = "linux")))]
     pub fn with_emacs_bindings(self, _multiline: bool) -> Self {
@@ -475,6 +888,8 @@ impl KeymapBuilder {
     pub fn build(self) -> Keymap {
         Keymap {
             keymaps: self.keymaps,
+            sequences: self.sequences,
+            sequence_timeout: self.sequence_timeout,
         }
     }
 }
@@ -486,8 +901,26 @@ impl Default for KeymapBuilder {
 }
 
 /// A keymap containing key bindings for a text editor.
+#[derive(Clone)]
 pub struct Keymap {
     pub keymaps: HashMap<KeyPress, Command>,
+    /// Bindings for sequences of two or more key presses
+    pub sequences: HashMap<Vec<KeyPress>, Command>,
+    /// How long a sequence waits for its next key press
+    pub sequence_timeout: Duration,
+}
+
+/// The result of feeding a key press to [`Keymap::resolve`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeymapMatch {
+    /// The key press completed a binding
+    Command(Command),
+    /// The key press started or continued a sequence
+    Pending,
+    /// The key press didn't continue the pending sequence, which was dropped
+    Cancelled,
+    /// Nothing is bound to the key press
+    None,
 }
 
 impl Keymap {
@@ -497,6 +930,7 @@ impl Keymap {
             .with_common_bindings()
             .with_single_line_bindings()
             .with_emacs_bindings(false)
+            .with_multi_cursor_bindings()
             .build()
     }
 
@@ -506,10 +940,11 @@ impl Keymap {
             .with_common_bindings()
             .with_multi_line_bindings()
             .with_emacs_bindings(true)
+            .with_multi_cursor_bindings()
+            .with_find_bindings()
             .build()
     }
 
-
     /// Create a keymap for chat-style input where Shift+Enter inserts newline
     /// and plain Enter is not bound (to allow external handling for send).
     pub fn chat_mode() -> Self {
@@ -517,6 +952,7 @@ impl Keymap {
             .with_common_bindings()
             .with_multi_line_bindings()
             .with_emacs_bindings(true)
+            .with_multi_cursor_bindings()
             .build();
         // Remove plain Enter binding
         keymap.keymaps.remove(&KeyPress {
@@ -533,6 +969,92 @@ impl Keymap {
         );
         keymap
     }
+
+    /// Bind a key chord such as `ctrl+shift+k`, or a space-separated
+    /// sequence such as `ctrl+k ctrl+c`, to a command, replacing any existing
+    /// binding
+    ///
+    /// ```rust,ignore
+    /// let mut keymap = Keymap::multi_line();
+    /// keymap.bind("mod+enter", Command::from_name("submit"))?;
+    /// keymap.bind("ctrl+x ctrl+s", Command::from_name("save"))?;
+    /// ```
+    pub fn bind(&mut self, chord: &str, command: Command) -> Result<(), KeyChordError> {
+        for keys in sequence_variants(KeyPress::parse_sequence(chord)?) {
+            if let [keypress] = keys.as_slice() {
+                self.keymaps.insert(keypress.clone(), command.clone());
+            } else {
+                self.sequences.insert(keys, command.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the binding for a key chord or sequence
+    pub fn unbind(&mut self, chord: &str) -> Result<(), KeyChordError> {
+        for keys in sequence_variants(KeyPress::parse_sequence(chord)?) {
+            if let [keypress] = keys.as_slice() {
+                self.keymaps.remove(keypress);
+            } else {
+                self.sequences.remove(&keys);
+            }
+        }
+        Ok(())
+    }
+
+    /// Feed a key press to the keymap, given the keys pressed so far in a
+    /// sequence
+    ///
+    /// `pending` is extended while the keys are a prefix of a bound sequence
+    /// and cleared once a sequence completes or fails. A key that starts a
+    /// sequence shadows a plain binding for the same key. Modifier keys
+    /// pressed during a sequence are ignored.
+    pub fn resolve(
+        &self,
+        pending: &mut Vec<KeyPress>,
+        key: &Key,
+        modifiers: &Modifiers,
+    ) -> KeymapMatch {
+        let keypress = KeyPress {
+            key: key.clone(),
+            modifiers: *modifiers,
+        };
+        if !pending.is_empty() && keypress.is_modifier() {
+            return KeymapMatch::Pending;
+        }
+
+        let candidates: Vec<Vec<KeyPress>> = keypress
+            .with_unshifted()
+            .into_iter()
+            .map(|keypress| pending.iter().cloned().chain([keypress]).collect())
+            .collect();
+        for keys in &candidates {
+            if let Some(command) = self.sequences.get(keys) {
+                pending.clear();
+                return KeymapMatch::Command(command.clone());
+            }
+        }
+        for keys in candidates {
+            let is_prefix = self
+                .sequences
+                .keys()
+                .any(|sequence| sequence.len() > keys.len() && sequence.starts_with(&keys));
+            if is_prefix {
+                *pending = keys;
+                return KeymapMatch::Pending;
+            }
+        }
+
+        if !pending.is_empty() {
+            pending.clear();
+            return KeymapMatch::Cancelled;
+        }
+        match self.get(key, modifiers) {
+            Some(command) => KeymapMatch::Command(command.clone()),
+            None => KeymapMatch::None,
+        }
+    }
+
     /// Look up a command for a key press, trying with and without shift modifier.
     pub fn get(&self, key: &Key, modifiers: &Modifiers) -> Option<&Command> {
         let keypress = KeyPress {
@@ -547,3 +1069,91 @@ impl Keymap {
         })
     }
 }
+
+/// Every combination of [`chord_variants`] for the keys of a sequence
+fn sequence_variants(keys: Vec<KeyPress>) -> Vec<Vec<KeyPress>> {
+    keys.into_iter()
+        .fold(vec![Vec::new()], |sequences, keypress| {
+            let variants = chord_variants(keypress);
+            sequences
+                .iter()
+                .flat_map(|sequence| {
+                    variants.iter().map(move |variant| {
+                        let mut sequence = sequence.clone();
+                        sequence.push(variant.clone());
+                        sequence
+                    })
+                })
+                .collect()
+        })
+}
+
+/// Shift may or may not uppercase the logical key depending on platform,
+/// so shifted letters are bound in both cases
+fn chord_variants(keypress: KeyPress) -> Vec<KeyPress> {
+    let upper = match &keypress.key {
+        Key::Character(c) if keypress.modifiers.shift() => {
+            let upper = c.to_uppercase();
+            (*upper != **c).then(|| KeyPress {
+                key: Key::Character(upper.into()),
+                modifiers: keypress.modifiers,
+            })
+        }
+        _ => None,
+    };
+    std::iter::once(keypress).chain(upper).collect()
+}
+
+/// The pending key sequence of a text editor, dropped after the keymap's
+/// sequence timeout
+#[derive(Clone, Copy)]
+pub(crate) struct PendingKeys {
+    keys: RwSignal<Vec<KeyPress>>,
+    // Bumped on every change so a stale timeout is ignored
+    generation: RwSignal<u64>,
+}
+
+impl PendingKeys {
+    pub(crate) fn new() -> Self {
+        Self {
+            keys: RwSignal::new(Vec::new()),
+            generation: RwSignal::new(0),
+        }
+    }
+
+    pub(crate) fn keys(&self) -> RwSignal<Vec<KeyPress>> {
+        self.keys
+    }
+
+    /// Feed a key press to `keymap`, updating the pending keys
+    pub(crate) fn resolve(&self, keymap: &Keymap, key: &Key, modifiers: &Modifiers) -> KeymapMatch {
+        let mut keys = self.keys.get_untracked();
+        let was_pending = !keys.is_empty();
+        let result = keymap.resolve(&mut keys, key, modifiers);
+        if keys.is_empty() {
+            if was_pending {
+                self.clear();
+            }
+            return result;
+        }
+
+        self.keys.set(keys);
+        self.generation.update(|g| *g += 1);
+        let current = self.generation.get_untracked();
+        let (keys, generation) = (self.keys, self.generation);
+        exec_after(keymap.sequence_timeout, move |_| {
+            if generation.get_untracked() == current {
+                keys.set(Vec::new());
+            }
+        });
+        result
+    }
+
+    /// Drop the pending keys
+    pub(crate) fn clear(&self) {
+        self.generation.update(|g| *g += 1);
+        if !self.keys.get_untracked().is_empty() {
+            self.keys.set(Vec::new());
+        }
+    }
+}
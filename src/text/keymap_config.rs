@@ -0,0 +1,242 @@
+//! Loading key bindings from TOML and JSON files (requires the `serde` feature)
+//!
+//! A keymap file maps key chords to command names:
+//!
+//! ```toml
+//! [bindings]
+//! "mod+enter" = "custom:submit"
+//! "ctrl+shift+k" = "delete-to-end-of-line"
+//! "ctrl+x ctrl+s" = "custom:save"
+//! "ctrl+y" = "none"
+//! ```
+//!
+//! The JSON form is `{"bindings": {"mod+enter": "custom:submit"}}`. Built-in
+//! command names are listed on [`Command::builtin`]; names with a `custom:`
+//! prefix become [`Command::Custom`] commands, so a misspelled built-in name
+//! is reported instead of binding a command that does nothing. `none`
+//! removes a binding.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use serde::de::{MapAccess, Visitor};
+
+use super::keymap::{Command, KeyChordError, KeyPress, Keymap};
+
+/// The command name that removes a binding
+const UNBIND: &str = "none";
+
+/// The prefix of custom command names
+const CUSTOM_PREFIX: &str = "custom:";
+
+/// An error found while loading a keymap file
+#[derive(Debug)]
+pub enum KeymapError {
+    Io(PathBuf, std::io::Error),
+    Toml(toml::de::Error),
+    Json(serde_json::Error),
+    /// A binding whose key chord or sequence couldn't be parsed
+    InvalidChord {
+        /// The 1-based line of the binding, when it could be found
+        line: Option<usize>,
+        key: String,
+        error: KeyChordError,
+    },
+    /// A binding to a name that isn't a built-in command or `custom:`-prefixed
+    UnknownCommand {
+        /// The 1-based line of the binding, when it could be found
+        line: Option<usize>,
+        key: String,
+        command: String,
+    },
+    /// The file extension is not `toml` or `json`
+    UnsupportedFormat(PathBuf),
+}
+
+impl fmt::Display for KeymapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeymapError::Io(path, err) => write!(f, "{}: {err}", path.display()),
+            KeymapError::Toml(err) => write!(f, "invalid keymap TOML: {err}"),
+            KeymapError::Json(err) => write!(f, "invalid keymap JSON: {err}"),
+            KeymapError::InvalidChord { line, key, error } => {
+                if let Some(line) = line {
+                    write!(f, "line {line}: ")?;
+                }
+                write!(f, "invalid key `{key}`: {error}")
+            }
+            KeymapError::UnknownCommand { line, key, command } => {
+                if let Some(line) = line {
+                    write!(f, "line {line}: ")?;
+                }
+                write!(
+                    f,
+                    "unknown command `{command}` for `{key}` \
+                     (custom commands need the `{CUSTOM_PREFIX}` prefix)"
+                )
+            }
+            KeymapError::UnsupportedFormat(path) => write!(
+                f,
+                "{}: keymap files must end in .toml or .json",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for KeymapError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            KeymapError::Io(_, err) => Some(err),
+            KeymapError::Toml(err) => Some(err),
+            KeymapError::Json(err) => Some(err),
+            KeymapError::InvalidChord { error, .. } => Some(error),
+            KeymapError::UnknownCommand { .. } | KeymapError::UnsupportedFormat(_) => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KeymapFile {
+    #[serde(default)]
+    bindings: Bindings,
+}
+
+/// Bindings in file order, so later entries win
+#[derive(Default)]
+struct Bindings(Vec<(String, String)>);
+
+impl<'de> Deserialize<'de> for Bindings {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BindingsVisitor;
+
+        impl<'de> Visitor<'de> for BindingsVisitor {
+            type Value = Bindings;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a table of key chords to command names")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Bindings, A::Error> {
+                let mut bindings = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    bindings.push(entry);
+                }
+                Ok(Bindings(bindings))
+            }
+        }
+
+        deserializer.deserialize_map(BindingsVisitor)
+    }
+}
+
+impl Keymap {
+    /// Add the bindings from a TOML keymap file
+    ///
+    /// Nothing is changed if any binding is invalid.
+    pub fn extend_from_toml(&mut self, text: &str) -> Result<(), KeymapError> {
+        let file: KeymapFile = toml::from_str(text).map_err(KeymapError::Toml)?;
+        self.apply_bindings(text, file.bindings)
+    }
+
+    /// Add the bindings from a JSON keymap file
+    ///
+    /// Nothing is changed if any binding is invalid.
+    pub fn extend_from_json(&mut self, text: &str) -> Result<(), KeymapError> {
+        let file: KeymapFile = serde_json::from_str(text).map_err(KeymapError::Json)?;
+        self.apply_bindings(text, file.bindings)
+    }
+
+    /// Add the bindings from a `.toml` or `.json` keymap file
+    ///
+    /// ```rust,ignore
+    /// let mut keymap = Keymap::multi_line();
+    /// if let Err(err) = keymap.extend_from_file("keymap.toml") {
+    ///     eprintln!("{err}");
+    /// }
+    /// ```
+    pub fn extend_from_file(&mut self, path: impl AsRef<Path>) -> Result<(), KeymapError> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_ascii_lowercase());
+        let extend: fn(&mut Keymap, &str) -> Result<(), KeymapError> = match extension.as_deref() {
+            Some("toml") => Keymap::extend_from_toml,
+            Some("json") => Keymap::extend_from_json,
+            _ => return Err(KeymapError::UnsupportedFormat(path.to_path_buf())),
+        };
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| KeymapError::Io(path.to_path_buf(), err))?;
+        extend(self, &text)
+    }
+
+    fn apply_bindings(&mut self, source: &str, bindings: Bindings) -> Result<(), KeymapError> {
+        // Validate every binding before touching the keymap
+        let mut resolved = Vec::with_capacity(bindings.0.len());
+        for (key, name) in bindings.0 {
+            if let Err(error) = KeyPress::parse_sequence(&key) {
+                return Err(KeymapError::InvalidChord {
+                    line: find_line(source, &key),
+                    key,
+                    error,
+                });
+            }
+            let command = if name == UNBIND {
+                None
+            } else if let Some(command) = parse_command(&name) {
+                Some(command)
+            } else {
+                return Err(KeymapError::UnknownCommand {
+                    line: find_line(source, &key),
+                    key,
+                    command: name,
+                });
+            };
+            resolved.push((key, command));
+        }
+
+        for (key, command) in resolved {
+            let result = match command {
+                Some(command) => self.bind(&key, command),
+                None => self.unbind(&key),
+            };
+            debug_assert!(result.is_ok());
+        }
+        Ok(())
+    }
+}
+
+/// The command for a name in a keymap file: a built-in command name or a
+/// `custom:`-prefixed custom command
+fn parse_command(name: &str) -> Option<Command> {
+    match name.strip_prefix(CUSTOM_PREFIX) {
+        Some("") => None,
+        Some(custom) => Some(Command::Custom(custom.to_string())),
+        None => Command::builtin(name),
+    }
+}
+
+/// The 1-based line where `key` is defined, found by looking for the key
+/// (quoted or bare) followed by `=` or `:`, or else for the quoted key
+/// anywhere (e.g. single-line JSON)
+fn find_line(source: &str, key: &str) -> Option<usize> {
+    let quoted = format!("\"{key}\"");
+    let candidates = [quoted.clone(), format!("'{key}'"), key.to_string()];
+    source
+        .lines()
+        .position(|line| {
+            let line = line
+                .trim_start()
+                .trim_start_matches(['{', ','])
+                .trim_start();
+            candidates.iter().any(|candidate| {
+                line.strip_prefix(candidate.as_str())
+                    .is_some_and(|rest| rest.trim_start().starts_with(['=', ':']))
+            })
+        })
+        .or_else(|| source.lines().position(|line| line.contains(&quoted)))
+        .map(|index| index + 1)
+}
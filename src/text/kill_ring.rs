@@ -0,0 +1,82 @@
+//! Emacs-style kill ring for text documents.
+//!
+//! Text killed with `Document::kill_line` is pushed to the front of the ring,
+//! or appended to the front entry when the previous kill left the cursors
+//! where they still are. `Document::yank` inserts the current entry and
+//! `Document::yank_pop` replaces the text just yanked with the next older one.
+
+use std::collections::VecDeque;
+
+use floem_editor_core::selection::SelRegion;
+
+/// Maximum number of entries kept per document.
+const MAX_KILL_RING_LEN: usize = 120;
+
+#[derive(Default)]
+pub(crate) struct KillRing {
+    /// Killed text, most recent first
+    entries: VecDeque<String>,
+    /// Index of the entry the next yank inserts
+    yank_index: usize,
+    /// Cursors after the last kill, while the next kill appends to it
+    last_kill: Option<Vec<SelRegion>>,
+    /// The range inserted by the last yank, while yank-pop can replace it
+    last_yank: Option<(usize, usize)>,
+}
+
+impl KillRing {
+    /// Returns the killed text, most recent first.
+    pub fn entries(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(String::as_str)
+    }
+
+    /// Returns true if a kill with these cursors continues the last kill.
+    pub fn continues_kill(&self, cursors: &[SelRegion]) -> bool {
+        self.last_kill.as_deref() == Some(cursors)
+    }
+
+    /// Adds killed text, appending it to the most recent entry if `append`.
+    /// `cursors` are the cursors after the kill.
+    pub fn kill(&mut self, text: String, append: bool, cursors: Vec<SelRegion>) {
+        match self.entries.front_mut() {
+            Some(front) if append => front.push_str(&text),
+            _ => {
+                self.entries.push_front(text);
+                self.entries.truncate(MAX_KILL_RING_LEN);
+            }
+        }
+        self.yank_index = 0;
+        self.last_kill = Some(cursors);
+    }
+
+    /// Returns the entry the next yank inserts.
+    pub fn current(&self) -> Option<&str> {
+        self.entries.get(self.yank_index).map(String::as_str)
+    }
+
+    /// Records the range inserted by a yank.
+    pub fn set_last_yank(&mut self, range: Option<(usize, usize)>) {
+        self.last_yank = range;
+    }
+
+    /// Returns the range inserted by the last yank, if nothing changed since.
+    pub fn last_yank(&self) -> Option<(usize, usize)> {
+        self.last_yank
+    }
+
+    /// Moves on to the next older entry, wrapping around, and returns it.
+    pub fn rotate(&mut self) -> Option<&str> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        self.yank_index = (self.yank_index + 1) % self.entries.len();
+        self.current()
+    }
+
+    /// Ends the current run of kills and the current yank.
+    /// Called for every edit that isn't itself a kill or yank.
+    pub fn interrupt(&mut self) {
+        self.last_kill = None;
+        self.last_yank = None;
+    }
+}
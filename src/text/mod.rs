@@ -1,12 +1,24 @@
 mod document;
+mod find;
+mod history;
 mod keymap;
+#[cfg(feature = "serde")]
+mod keymap_config;
+mod kill_ring;
 mod style_utils;
 mod text_area;
 mod text_input;
 mod text_layout_lines;
+mod vim;
 
-pub use document::Document;
-pub use keymap::{CURSOR_BLINK_INTERVAL_MS, Command, KeyPress, Keymap, KeymapBuilder};
+pub use document::{Document, PASSWORD_BULLET};
+pub use floem_editor_core::mode::{Mode, VisualMode};
+pub use keymap::{
+    CURSOR_BLINK_INTERVAL_MS, Command, KeyChordError, KeyPress, Keymap, KeymapBuilder, KeymapMatch,
+    SEQUENCE_TIMEOUT_MS,
+};
+#[cfg(feature = "serde")]
+pub use keymap_config::KeymapError;
 pub use style_utils::{
     Padding, TextStyles, apply_styles_to_document, extract_padding, extract_text_styles,
     get_glyph_dimensions, is_cursor_visible,
@@ -3,6 +3,7 @@
 //! A multi-line text area with visual line support, cursor/selection rendering,
 //! and keyboard/mouse handling.
 
+use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
 use floem::{
@@ -11,7 +12,7 @@ use floem::{
     context::{ComputeLayoutCx, PaintCx},
     event::{Event, EventListener, EventPropagation},
     kurbo::{Point, Rect, Size},
-    reactive::{Effect, RwSignal, SignalGet, SignalTrack, SignalUpdate, SignalWith},
+    reactive::{Effect, ReadSignal, RwSignal, SignalGet, SignalTrack, SignalUpdate, SignalWith},
     style::{CursorStyle as StyleCursorStyle, Style},
     taffy::{Dimension, Overflow},
     views::{Decorators, Empty, Scroll},
@@ -19,24 +20,30 @@ use floem::{
 
 use crate::theme::ShadcnThemeProp;
 use floem_editor_core::buffer::rope_text::RopeText;
+use floem_editor_core::mode::Mode;
 use ui_events::{
     keyboard::{Key, KeyState, KeyboardEvent, Modifiers},
     pointer::PointerEvent,
 };
 
-#[cfg(test)]
-use super::KeyPress;
+use super::find::{Find, find_bar};
+use super::keymap::PendingKeys;
+use super::vim::Vim;
 use super::{
-    CURSOR_BLINK_INTERVAL_MS, Command, Document, Keymap, apply_styles_to_document, extract_padding,
-    extract_text_styles, get_glyph_dimensions, is_cursor_visible,
+    CURSOR_BLINK_INTERVAL_MS, Command, Document, KeyPress, Keymap, KeymapMatch, TextLayoutLines,
+    apply_styles_to_document, extract_padding, extract_text_styles, get_glyph_dimensions,
+    is_cursor_visible,
 };
 #[cfg(test)]
+use super::{KeyChordError, KeymapBuilder};
+#[cfg(test)]
 use ui_events::keyboard::NamedKey;
 
 /// Size of the resize handle grip area
 const RESIZE_HANDLE_SIZE: f64 = 16.0;
 
 /// A multi-line text area view
+#[allow(clippy::type_complexity)]
 pub struct TextArea {
     id: ViewId,
     scroll_id: ViewId,
@@ -66,6 +73,21 @@ pub struct TextArea {
     min_size: RwSignal<Size>,
     /// Maximum size for resize (None = no limit)
     max_size: RwSignal<Option<Size>>,
+    /// Whether edits are rejected; selection and copy still work
+    read_only: RwSignal<bool>,
+    /// Whether the text area ignores all interaction and can't be focused
+    disabled: RwSignal<bool>,
+    /// Key bindings
+    keymap: RwSignal<Keymap>,
+    /// Callbacks for [`Command::Custom`] commands, by name
+    commands: RwSignal<HashMap<String, Box<dyn Fn(&Document)>>>,
+    /// Keys typed so far in a multi-key sequence
+    pending_keys: PendingKeys,
+    /// Vim modal editing, off by default
+    vim: Vim,
+    /// The find and replace bar
+    find: Find,
+    find_id: ViewId,
 }
 
 impl Default for TextArea {
@@ -91,6 +113,7 @@ impl TextArea {
     }
 
     /// Creates a new text editor with the given initial text, ViewId, and custom Keymap
+    #[allow(clippy::type_complexity)]
     pub fn with_text_id_and_keymap(text: impl Into<String>, id: ViewId, keymap: Keymap) -> Self {
         let child_height = RwSignal::new(0.0);
         let padding = RwSignal::new((0.0, 0.0, 0.0, 0.0));
@@ -108,6 +131,13 @@ impl TextArea {
         let resize_start_size = RwSignal::new(Size::ZERO);
         let min_size = RwSignal::new(Size::new(50.0, 30.0));
         let max_size = RwSignal::new(None);
+        let read_only = RwSignal::new(false);
+        let disabled = RwSignal::new(false);
+        let keymap = RwSignal::new(keymap);
+        let commands: RwSignal<HashMap<String, Box<dyn Fn(&Document)>>> =
+            RwSignal::new(HashMap::new());
+        let pending_keys = PendingKeys::new();
+        let vim = Vim::new();
 
         // Capture cursor signal directly for reactive tracking in ensure_visible
         let cursor_signal = doc_signal.get_untracked().cursor();
@@ -157,15 +187,21 @@ impl TextArea {
             });
         let scroll_id = scroll_view.id();
 
-        id.set_children_vec(vec![scroll_view.into_any()]);
+        let find = Find::new(doc_signal, id, read_only);
+        let find_view = find_bar(find).into_any();
+        let find_id = find_view.id();
+
+        id.set_children_vec(vec![scroll_view.into_any(), find_view]);
 
         // Set up event handlers
-        let keymap = std::sync::Arc::new(keymap);
-        let keymap_clone = keymap.clone();
 
+        let pointer_vim = vim.clone();
         id.add_event_listener(
             EventListener::PointerDown,
             Box::new(move |event| {
+                if disabled.get_untracked() {
+                    return EventPropagation::Continue;
+                }
                 if let Event::Pointer(PointerEvent::Down(pointer_event)) = event {
                     let pos = pointer_event.state.position;
 
@@ -195,6 +231,7 @@ impl TextArea {
                     id.request_active();
                     id.request_focus();
                     doc_signal.get_untracked().pointer_down(&adjusted);
+                    pointer_vim.pointer_down();
                     last_cursor_action.set(Instant::now());
                 }
                 EventPropagation::Stop
@@ -263,6 +300,7 @@ impl TextArea {
             }),
         );
 
+        let key_vim = vim.clone();
         id.add_event_listener(
             EventListener::KeyDown,
             Box::new(move |event| {
@@ -275,15 +313,43 @@ impl TextArea {
                 else {
                     return EventPropagation::Continue;
                 };
+                if disabled.get_untracked() {
+                    return EventPropagation::Continue;
+                }
+
+                // Vim sees keys first, outside of keymap sequences
+                if pending_keys.keys().with_untracked(|keys| keys.is_empty())
+                    && key_vim.handle_key(
+                        &doc_signal.get_untracked(),
+                        key,
+                        modifiers,
+                        read_only.get_untracked(),
+                    )
+                {
+                    id.request_layout();
+                    scroll_id.request_layout();
+                    last_cursor_action.set(Instant::now());
+                    return EventPropagation::Stop;
+                }
 
-                // Try to find command
-                let command = keymap_clone.get(key, modifiers);
+                // Try to find command, following multi-key sequences
+                let command =
+                    match keymap.with_untracked(|k| pending_keys.resolve(k, key, modifiers)) {
+                        KeymapMatch::Command(command) => Some(command),
+                        KeymapMatch::Pending | KeymapMatch::Cancelled => {
+                            return EventPropagation::Stop;
+                        }
+                        KeymapMatch::None => None,
+                    };
 
                 let document = doc_signal.get_untracked();
 
                 if let Some(command) = command {
+                    if command.is_edit() && read_only.get_untracked() {
+                        return EventPropagation::Stop;
+                    }
                     let shift_held = modifiers.shift();
-                    match command {
+                    match &command {
                         Command::Edit(edit_cmd) => {
                             document.run_edit_command(edit_cmd);
                             id.request_layout();
@@ -306,6 +372,61 @@ impl TextArea {
                                 id.request_layout();
                             }
                         }
+                        Command::Undo => {
+                            if document.undo() {
+                                id.request_layout();
+                            }
+                        }
+                        Command::Redo => {
+                            if document.redo() {
+                                id.request_layout();
+                            }
+                        }
+                        Command::SelectNextOccurrence => {
+                            if document.select_next_occurrence() {
+                                id.request_paint();
+                            }
+                        }
+                        Command::CollapseCursors => {
+                            if document.collapse_cursors() {
+                                id.request_paint();
+                            } else if find.is_open().get_untracked() {
+                                find.close();
+                            } else {
+                                // Let Escape reach parent handlers (e.g. dialogs)
+                                return EventPropagation::Continue;
+                            }
+                        }
+                        Command::KillLine => {
+                            if document.kill_line() {
+                                id.request_layout();
+                            }
+                        }
+                        Command::Yank => {
+                            if document.yank(false) {
+                                id.request_layout();
+                            }
+                        }
+                        Command::YankPop => {
+                            if document.yank_pop(false) {
+                                id.request_layout();
+                            }
+                        }
+                        Command::SetMark => {
+                            document.set_mark();
+                            id.request_paint();
+                        }
+                        Command::Find => find.open(false),
+                        Command::Replace => find.open(true),
+                        Command::Custom(name) => {
+                            let handled = commands.with_untracked(|commands| {
+                                commands.get(name).map(|callback| callback(&document))
+                            });
+                            if handled.is_none() {
+                                return EventPropagation::Continue;
+                            }
+                            id.request_layout();
+                        }
                     }
                     last_cursor_action.set(Instant::now());
                     return EventPropagation::Stop;
@@ -322,6 +443,9 @@ impl TextArea {
                     if !matches!(key, Key::Character(_)) {
                         return EventPropagation::Continue;
                     }
+                    if read_only.get_untracked() {
+                        return EventPropagation::Stop;
+                    }
                     if let Key::Character(c) = key {
                         document.insert_text(c);
                         id.request_layout();
@@ -332,9 +456,24 @@ impl TextArea {
             }),
         );
 
+        id.add_event_listener(
+            EventListener::FocusLost,
+            Box::new(move |_| {
+                pending_keys.clear();
+                EventPropagation::Continue
+            }),
+        );
+
+        let ime_vim = vim.clone();
         id.add_event_listener(
             EventListener::ImeCommit,
             Box::new(move |event| {
+                if read_only.get_untracked()
+                    || disabled.get_untracked()
+                    || (ime_vim.is_enabled() && ime_vim.mode().get_untracked() != Mode::Insert)
+                {
+                    return EventPropagation::Stop;
+                }
                 if let Event::ImeCommit(text) = event {
                     doc_signal.get_untracked().insert_text(text);
                     id.request_layout();
@@ -361,6 +500,14 @@ impl TextArea {
             resize_start_size,
             min_size,
             max_size,
+            read_only,
+            disabled,
+            keymap,
+            commands,
+            pending_keys,
+            vim,
+            find,
+            find_id,
         }
     }
 
@@ -390,6 +537,41 @@ impl TextArea {
         self
     }
 
+    /// Replaces the key bindings, [`Keymap::multi_line`] by default
+    pub fn keymap(self, keymap: Keymap) -> Self {
+        self.keymap.set(keymap);
+        self
+    }
+
+    /// Signal holding the keys typed so far in a multi-key sequence
+    ///
+    /// Empty unless a sequence such as `ctrl+k ctrl+c` is in progress. Each
+    /// [`KeyPress`] displays as a chord, e.g. for a status bar.
+    pub fn pending_keys(&self) -> RwSignal<Vec<KeyPress>> {
+        self.pending_keys.keys()
+    }
+
+    /// Registers the callback run by the [`Command::Custom`] command `name`
+    ///
+    /// Bind the command with [`Keymap::bind`], or as `custom:<name>` in a
+    /// keymap file. Keys bound to unregistered commands are passed on to
+    /// parent views. Callbacks run even when the text area is read-only.
+    ///
+    /// ```rust,ignore
+    /// let mut keymap = Keymap::multi_line();
+    /// keymap.bind("mod+enter", Command::from_name("submit"))?;
+    ///
+    /// TextArea::new()
+    ///     .keymap(keymap)
+    ///     .command("submit", move |doc| send(doc.text()))
+    /// ```
+    pub fn command(self, name: impl Into<String>, callback: impl Fn(&Document) + 'static) -> Self {
+        self.commands.update(|commands| {
+            commands.insert(name.into(), Box::new(callback));
+        });
+        self
+    }
+
     /// Sets the editor content reactively
     pub fn value(self, set_value: impl Fn() -> String + 'static) -> Self {
         let doc = self.doc;
@@ -412,6 +594,8 @@ impl TextArea {
                     )],
                     EditType::Other,
                 );
+                // Externally set values start a fresh history
+                doc.clear_history();
             });
         });
         self
@@ -454,6 +638,93 @@ impl TextArea {
     pub fn is_resizing(&self) -> bool {
         self.is_resizing.get_untracked()
     }
+
+    /// Rejects edits reactively while `read_only` returns true
+    ///
+    /// The text can still be selected, navigated, scrolled and copied.
+    pub fn read_only(self, read_only: impl Fn() -> bool + 'static) -> Self {
+        let signal = self.read_only;
+        Effect::new(move |_| signal.set(read_only()));
+        self
+    }
+
+    /// Disables the text area reactively while `disabled` returns true
+    ///
+    /// A disabled text area ignores pointer and keyboard input, can't be
+    /// focused or resized and matches the `disabled` style selector. It loses
+    /// focus when it becomes disabled.
+    pub fn disabled(self, disabled: impl Fn() -> bool + 'static) -> Self {
+        let signal = self.disabled;
+        let id = self.id;
+        Effect::new(move |_| {
+            let disabled = disabled();
+            if signal.get_untracked() == disabled {
+                return;
+            }
+            signal.set(disabled);
+            if disabled {
+                id.clear_focus();
+            }
+            id.request_style();
+        });
+        self
+    }
+
+    /// Enables Vim modal editing reactively while `vim` returns true
+    ///
+    /// The text area starts in normal mode and draws a block cursor there.
+    /// Keys Vim doesn't use, such as Ctrl+C, still go to the keymap. When
+    /// read-only, motions and yanks work but edits are ignored.
+    ///
+    /// ```rust,ignore
+    /// let vim = RwSignal::new(true);
+    /// let editor = TextArea::new().vim(move || vim.get());
+    /// let mode = editor.vim_mode();
+    /// label(move || format!("{:?}", mode.get()))
+    /// ```
+    pub fn vim(self, vim: impl Fn() -> bool + 'static) -> Self {
+        let state = self.vim.clone();
+        let doc = self.doc;
+        let id = self.id;
+        Effect::new(move |_| {
+            let enabled = vim();
+            state.set_enabled(&doc.get_untracked(), enabled);
+            id.request_paint();
+        });
+        self
+    }
+
+    /// Signal holding the Vim mode
+    ///
+    /// [`Mode::Insert`] while Vim editing is disabled.
+    pub fn vim_mode(&self) -> ReadSignal<Mode> {
+        self.vim.mode()
+    }
+
+    /// Returns the contents of a Vim register: `"` for the unnamed register,
+    /// `0` for the last yank, `a` to `z`, or `+` for the clipboard
+    pub fn vim_register(&self, name: char) -> Option<String> {
+        self.vim.register(name)
+    }
+
+    /// Signal that is true while the find bar is open
+    ///
+    /// The bar opens with Cmd/Ctrl+F, or Ctrl+H (Cmd+Alt+F on macOS) to
+    /// also replace. Setting the signal opens or closes it without moving
+    /// focus.
+    pub fn find_open(&self) -> RwSignal<bool> {
+        self.find.is_open()
+    }
+
+    /// Returns true if the text area rejects edits
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.get_untracked()
+    }
+
+    /// Returns true if the text area is disabled
+    pub fn is_disabled(&self) -> bool {
+        self.disabled.get_untracked()
+    }
 }
 
 impl View for TextArea {
@@ -463,10 +734,16 @@ impl View for TextArea {
 
     fn view_style(&self) -> Option<Style> {
         let resize_size = self.resize_size;
+        let disabled = self.disabled.get_untracked();
         Some(
             Style::new()
-                .cursor(StyleCursorStyle::Text)
-                .focusable(true)
+                .cursor(if disabled {
+                    StyleCursorStyle::Default
+                } else {
+                    StyleCursorStyle::Text
+                })
+                .focusable(!disabled)
+                .set_disabled(disabled)
                 .set(floem::style::OverflowX, Overflow::Hidden) // Hidden to enable text wrapping
                 .set(floem::style::OverflowY, Overflow::Scroll)
                 .apply_if(resize_size.get().is_some(), move |s| {
@@ -520,6 +797,7 @@ impl View for TextArea {
         }
 
         cx.compute_view_layout(self.scroll_id);
+        cx.compute_view_layout(self.find_id);
 
         None
     }
@@ -533,6 +811,7 @@ impl View for TextArea {
         let text_styles = extract_text_styles(&style.builtin());
         let theme = style.get(ShadcnThemeProp);
         let selection_color = theme.primary.multiply_alpha(0.2);
+        let block_cursor = self.vim.block_cursor();
 
         cx.save();
         cx.clip(
@@ -549,64 +828,96 @@ impl View for TextArea {
 
         let min_vline = lines.vline_of_height(viewport.y0).saturating_sub(1);
         let max_vline = lines.vline_of_height(viewport.y1) + 1;
+        let visible = (min_vline, max_vline);
+        let origin = Point::new(padding.3, padding.0 - viewport.y0);
 
-        // Draw cursor/selection
-        if cx.is_focused(self.id) {
+        // Highlight find matches on the visible lines
+        if self.find.is_open().get_untracked() {
             let cursor = doc.cursor().get_untracked();
-            if cursor.is_caret() {
-                let elapsed_ms = self
-                    .last_cursor_action
+            let selected = (cursor.min(), cursor.max());
+            let min_offset = lines.offset_of_vline(min_vline);
+            let max_offset = lines.offset_of_vline(max_vline + 1);
+            self.find.matches().with_untracked(|matches| {
+                let first = matches.partition_point(|m| m.1 < min_offset);
+                for &range in matches[first..].iter().take_while(|m| m.0 <= max_offset) {
+                    let alpha = if range == selected { 0.4 } else { 0.15 };
+                    fill_range(
+                        cx,
+                        &lines,
+                        range,
+                        visible,
+                        origin,
+                        theme.primary.multiply_alpha(alpha),
+                    );
+                }
+            });
+        }
+
+        // Draw cursors/selections for every region
+        if cx.is_focused(self.id) {
+            let regions = doc.regions();
+            let cursor_visible = is_cursor_visible(
+                self.last_cursor_action
                     .get_untracked()
                     .elapsed()
-                    .as_millis();
-
-                if is_cursor_visible(elapsed_ms) {
-                    let p = lines.point_of_offset(cursor.end);
-                    let (cursor_top, cursor_height) = get_glyph_dimensions(
-                        p.glyph_top,
-                        p.glyph_bottom,
-                        lines.default_glyph_top(),
-                        lines.default_glyph_height(),
-                    );
-                    let rect = Rect::from_origin_size(
-                        (p.x + padding.3 - 1.0, cursor_top + padding.0 - viewport.y0),
-                        (2.0, cursor_height),
-                    );
-                    cx.fill(&rect, text_styles.text_color, 0.0);
+                    .as_millis(),
+            );
+
+            for cursor in regions.iter() {
+                if cursor.is_caret() {
+                    if cursor_visible {
+                        let p = lines.point_of_offset(cursor.end);
+                        let (cursor_top, cursor_height) = get_glyph_dimensions(
+                            p.glyph_top,
+                            p.glyph_bottom,
+                            lines.default_glyph_top(),
+                            lines.default_glyph_height(),
+                        );
+                        if block_cursor {
+                            // Cover the character under the cursor, or half a
+                            // line height at the end of a line
+                            let next = doc
+                                .buffer()
+                                .with_untracked(|b| b.next_grapheme_offset(cursor.end, 1, b.len()));
+                            let next_x = lines.point_of_offset(next).x;
+                            let width = if next_x > p.x {
+                                next_x - p.x
+                            } else {
+                                cursor_height / 2.0
+                            };
+                            let rect = Rect::from_origin_size(
+                                (p.x + padding.3, cursor_top + padding.0 - viewport.y0),
+                                (width, cursor_height),
+                            );
+                            cx.fill(&rect, text_styles.text_color.multiply_alpha(0.5), 0.0);
+                        } else {
+                            let rect = Rect::from_origin_size(
+                                (p.x + padding.3 - 1.0, cursor_top + padding.0 - viewport.y0),
+                                (2.0, cursor_height),
+                            );
+                            cx.fill(&rect, text_styles.text_color, 0.0);
+                        }
+                    }
+                    continue;
                 }
 
+                // Draw selection
+                fill_range(
+                    cx,
+                    &lines,
+                    (cursor.min(), cursor.max()),
+                    visible,
+                    origin,
+                    selection_color,
+                );
+            }
+
+            if regions.iter().any(|r| r.is_caret()) {
                 // Schedule repaint for cursor blink
                 let id = self.id;
                 exec_after(Duration::from_millis(CURSOR_BLINK_INTERVAL_MS), move |_| {
                     id.request_paint();
                 });
-            } else {
-                // Draw selection
-                let start_vline = lines.vline_of_offset(cursor.min());
-                let end_vline = lines.vline_of_offset(cursor.max());
-                let start_offset = lines.offset_of_vline(start_vline.max(min_vline));
-                let end_offset = lines.offset_of_vline(end_vline.min(max_vline));
-
-                for line in lines.visual_lines(start_offset..end_offset + 1) {
-                    let x0 = if line.line_i == start_vline {
-                        lines.point_of_offset(cursor.min()).x
-                    } else {
-                        0.0
-                    };
-                    let x1 = if line.line_i == end_vline {
-                        lines.point_of_offset(cursor.max()).x
-                    } else {
-                        line.line_w as f64
-                    };
-                    let rect = Rect::from_origin_size(
-                        (
-                            x0 + padding.3,
-                            line.line_top as f64 + padding.0 - viewport.y0,
-                        ),
-                        (x1 - x0, line.line_height as f64),
-                    );
-                    cx.fill(&rect, selection_color, 0.0);
-                }
             }
         }
 
@@ -618,6 +929,7 @@ impl View for TextArea {
 
         cx.restore();
         cx.paint_view(self.scroll_id);
+        cx.paint_view(self.find_id);
 
         // Draw resize handle if enabled
         if self.resize_enabled.get_untracked() {
@@ -651,6 +963,43 @@ impl View for TextArea {
     }
 }
 
+/// Fills the byte range `start..end` on the visual lines `visible`, with
+/// line coordinates offset by `origin`
+fn fill_range(
+    cx: &mut PaintCx,
+    lines: &TextLayoutLines,
+    (start, end): (usize, usize),
+    (min_vline, max_vline): (usize, usize),
+    origin: Point,
+    color: floem::peniko::Color,
+) {
+    let start_vline = lines.vline_of_offset(start);
+    let end_vline = lines.vline_of_offset(end);
+    if end_vline < min_vline || start_vline > max_vline {
+        return;
+    }
+    let start_offset = lines.offset_of_vline(start_vline.max(min_vline));
+    let end_offset = lines.offset_of_vline(end_vline.min(max_vline));
+
+    for line in lines.visual_lines(start_offset..end_offset + 1) {
+        let x0 = if line.line_i == start_vline {
+            lines.point_of_offset(start).x
+        } else {
+            0.0
+        };
+        let x1 = if line.line_i == end_vline {
+            lines.point_of_offset(end).x
+        } else {
+            line.line_w as f64
+        };
+        let rect = Rect::from_origin_size(
+            (x0 + origin.x, line.line_top as f64 + origin.y),
+            (x1 - x0, line.line_height as f64),
+        );
+        cx.fill(&rect, color, 0.0);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1837,6 +2186,99 @@ mod tests {
         assert_eq!(keymap.keymaps.get(&key), Some(&Command::SelectAll));
     }
 
+    #[test]
+    fn test_keypressmap_undo_redo() {
+        let keymap = Keymap::multi_line();
+
+        #[cfg(target_os = "macos")]
+        let modifier = Modifiers::META;
+        #[cfg(not(target_os = "macos"))]
+        let modifier = Modifiers::CONTROL;
+
+        // Cmd/Ctrl+Z -> Undo
+        let key = KeyPress {
+            key: Key::Character("z".into()),
+            modifiers: modifier,
+        };
+        assert_eq!(keymap.keymaps.get(&key), Some(&Command::Undo));
+
+        // Cmd/Ctrl+Shift+Z -> Redo
+        let key = KeyPress {
+            key: Key::Character("Z".into()),
+            modifiers: modifier | Modifiers::SHIFT,
+        };
+        assert_eq!(keymap.keymaps.get(&key), Some(&Command::Redo));
+
+        // Ctrl+Y -> Redo, even with the Emacs yank binding on Linux
+        #[cfg(not(target_os = "macos"))]
+        for keymap in [keymap, Keymap::single_line(), Keymap::chat_mode()] {
+            let key = KeyPress {
+                key: Key::Character("y".into()),
+                modifiers: Modifiers::CONTROL,
+            };
+            assert_eq!(keymap.keymaps.get(&key), Some(&Command::Redo));
+        }
+    }
+
+    #[test]
+    fn test_keypressmap_multi_cursor() {
+        let keymap = Keymap::multi_line();
+
+        #[cfg(target_os = "macos")]
+        let modifier = Modifiers::META;
+        #[cfg(not(target_os = "macos"))]
+        let modifier = Modifiers::CONTROL;
+
+        // Cmd/Ctrl+D -> SelectNextOccurrence, unless Linux's Emacs Ctrl+D
+        // already deletes forward
+        let key = KeyPress {
+            key: Key::Character("d".into()),
+            modifiers: modifier,
+        };
+        #[cfg(not(target_os = "linux"))]
+        assert_eq!(
+            keymap.keymaps.get(&key),
+            Some(&Command::SelectNextOccurrence)
+        );
+        #[cfg(target_os = "linux")]
+        assert_eq!(
+            keymap.keymaps.get(&key),
+            Some(&Command::Edit(EditCommand::DeleteForward))
+        );
+
+        // Escape -> CollapseCursors
+        let key = KeyPress {
+            key: Key::Named(NamedKey::Escape),
+            modifiers: Modifiers::default(),
+        };
+        assert_eq!(keymap.keymaps.get(&key), Some(&Command::CollapseCursors));
+    }
+
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
+    #[test]
+    fn test_keypressmap_multi_cursor_keeps_emacs_delete() {
+        let key = KeyPress {
+            key: Key::Character("d".into()),
+            modifiers: Modifiers::CONTROL,
+        };
+        let delete_forward = Some(Command::Edit(EditCommand::DeleteForward));
+
+        for keymap in [
+            Keymap::single_line(),
+            Keymap::multi_line(),
+            Keymap::chat_mode(),
+        ] {
+            assert_eq!(keymap.keymaps.get(&key).cloned(), delete_forward);
+        }
+
+        // Adding the multi-cursor bindings last keeps the Emacs binding too
+        let keymap = KeymapBuilder::new()
+            .with_emacs_bindings(true)
+            .with_multi_cursor_bindings()
+            .build();
+        assert_eq!(keymap.keymaps.get(&key).cloned(), delete_forward);
+    }
+
     #[cfg(target_os = "macos")]
     #[test]
     fn test_keypressmap_macos_line_navigation() {
@@ -1983,6 +2425,158 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_key_chord_parsing() {
+        assert_eq!(
+            "ctrl+shift+k".parse::<KeyPress>(),
+            Ok(KeyPress {
+                key: Key::Character("k".into()),
+                modifiers: Modifiers::CONTROL | Modifiers::SHIFT,
+            })
+        );
+        assert_eq!(
+            "Alt+Backspace".parse::<KeyPress>(),
+            Ok(KeyPress {
+                key: Key::Named(NamedKey::Backspace),
+                modifiers: Modifiers::ALT,
+            })
+        );
+        assert_eq!(
+            "ctrl++".parse::<KeyPress>(),
+            Ok(KeyPress {
+                key: Key::Character("+".into()),
+                modifiers: Modifiers::CONTROL,
+            })
+        );
+        assert_eq!(
+            "f5".parse::<KeyPress>(),
+            Ok(KeyPress {
+                key: Key::Named(NamedKey::F5),
+                modifiers: Modifiers::default(),
+            })
+        );
+
+        assert_eq!("".parse::<KeyPress>(), Err(KeyChordError::Empty));
+        assert_eq!("ctrl+".parse::<KeyPress>(), Err(KeyChordError::Empty));
+        assert_eq!(
+            "hyper+k".parse::<KeyPress>(),
+            Err(KeyChordError::UnknownModifier("hyper".into()))
+        );
+        assert_eq!(
+            "ctrl+foo".parse::<KeyPress>(),
+            Err(KeyChordError::UnknownKey("foo".into()))
+        );
+    }
+
+    #[test]
+    fn test_keymap_bind_and_unbind() {
+        let mut keymap = Keymap::multi_line();
+        keymap
+            .bind("ctrl+shift+k", Command::from_name("delete-to-end-of-line"))
+            .unwrap();
+        keymap
+            .bind("alt+t", Command::from_name("insert-template"))
+            .unwrap();
+
+        // Shifted letters match whether or not shift uppercased the key
+        for key in ["k", "K"] {
+            assert_eq!(
+                keymap.get(
+                    &Key::Character(key.into()),
+                    &(Modifiers::CONTROL | Modifiers::SHIFT)
+                ),
+                Some(&Command::Edit(EditCommand::DeleteToEndOfLine))
+            );
+        }
+        assert_eq!(
+            keymap.get(&Key::Character("t".into()), &Modifiers::ALT),
+            Some(&Command::Custom("insert-template".into()))
+        );
+
+        keymap.unbind("alt+t").unwrap();
+        assert_eq!(
+            keymap.get(&Key::Character("t".into()), &Modifiers::ALT),
+            None
+        );
+        assert!(keymap.bind("ctrl+nope", Command::Copy).is_err());
+    }
+
+    #[test]
+    fn test_keymap_resolves_sequences() {
+        let mut keymap = Keymap::multi_line();
+        keymap
+            .bind("ctrl+k ctrl+c", Command::from_name("comment"))
+            .unwrap();
+        let ctrl = |c: &str| (Key::Character(c.into()), Modifiers::CONTROL);
+        let mut pending = Vec::new();
+
+        let (key, mods) = ctrl("k");
+        assert_eq!(
+            keymap.resolve(&mut pending, &key, &mods),
+            KeymapMatch::Pending
+        );
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].to_string(), "ctrl+k");
+
+        // Modifier keys don't break the sequence
+        assert_eq!(
+            keymap.resolve(
+                &mut pending,
+                &Key::Named(NamedKey::Control),
+                &Modifiers::CONTROL
+            ),
+            KeymapMatch::Pending
+        );
+
+        let (key, mods) = ctrl("c");
+        assert_eq!(
+            keymap.resolve(&mut pending, &key, &mods),
+            KeymapMatch::Command(Command::Custom("comment".into()))
+        );
+        assert!(pending.is_empty());
+
+        // A key that doesn't continue the sequence cancels it
+        let (key, mods) = ctrl("k");
+        keymap.resolve(&mut pending, &key, &mods);
+        let left = Key::Named(NamedKey::ArrowLeft);
+        let none = Modifiers::default();
+        assert_eq!(
+            keymap.resolve(&mut pending, &left, &none),
+            KeymapMatch::Cancelled
+        );
+        assert!(pending.is_empty());
+
+        // Plain bindings still work outside a sequence
+        assert_eq!(
+            keymap.resolve(&mut pending, &left, &none),
+            KeymapMatch::Command(Command::Move(MoveCommand::Left))
+        );
+    }
+
+    #[test]
+    fn test_keymap_builder_sequences() {
+        let ctrl_x = KeyPress {
+            key: Key::Character("x".into()),
+            modifiers: Modifiers::CONTROL,
+        };
+        let ctrl_s = KeyPress {
+            key: Key::Character("s".into()),
+            modifiers: Modifiers::CONTROL,
+        };
+        let keymap = KeymapBuilder::new()
+            .with_sequence([ctrl_x.clone(), ctrl_s.clone()], Command::from_name("save"))
+            .build();
+        assert_eq!(
+            keymap.sequences.get(&vec![ctrl_x, ctrl_s]),
+            Some(&Command::Custom("save".into()))
+        );
+        assert_eq!(
+            KeyPress::parse_sequence("ctrl+x  ctrl+s").map(|keys| keys.len()),
+            Ok(2)
+        );
+        assert_eq!(KeyPress::parse_sequence(" "), Err(KeyChordError::Empty));
+    }
+
     // ==========================================================================
     // TextArea integration tests for key bindings
     // ==========================================================================
@@ -2178,6 +2772,85 @@ mod tests {
         assert_eq!(cursor.max(), 11, "Selection should end at document length");
     }
 
+    #[test]
+    fn test_textarea_undo_redo_keys() {
+        let textarea = TextArea::new().style(|s| s.size(200.0, 100.0));
+        let doc_signal = textarea.doc();
+
+        let mut harness = HeadlessHarness::new_with_size(textarea, 200.0, 100.0);
+        harness.click(10.0, 10.0);
+
+        for c in ['a', 'b', 'c'] {
+            harness.dispatch_event(create_key_event(
+                Key::Character(c.to_string().into()),
+                Modifiers::default(),
+            ));
+        }
+        assert_eq!(doc_signal.get_untracked().text(), "abc");
+
+        #[cfg(target_os = "macos")]
+        let modifier = Modifiers::META;
+        #[cfg(not(target_os = "macos"))]
+        let modifier = Modifiers::CONTROL;
+
+        harness.dispatch_event(create_key_event(Key::Character("z".into()), modifier));
+        assert_eq!(
+            doc_signal.get_untracked().text(),
+            "",
+            "Cmd/Ctrl+Z should undo the typed group"
+        );
+
+        harness.dispatch_event(create_key_event(
+            Key::Character("z".into()),
+            modifier | Modifiers::SHIFT,
+        ));
+        assert_eq!(
+            doc_signal.get_untracked().text(),
+            "abc",
+            "Cmd/Ctrl+Shift+Z should redo the typed group"
+        );
+    }
+
+    #[test]
+    fn test_textarea_select_next_occurrence_keys() {
+        let textarea = TextArea::with_text("foo bar foo").style(|s| s.size(200.0, 100.0));
+        let doc_signal = textarea.doc();
+
+        let mut harness = HeadlessHarness::new_with_size(textarea, 200.0, 100.0);
+        harness.click(10.0, 10.0);
+        doc_signal.get_untracked().set_offset(0, false);
+
+        #[cfg(target_os = "macos")]
+        let modifier = Modifiers::META;
+        #[cfg(not(target_os = "macos"))]
+        let modifier = Modifiers::CONTROL;
+
+        // First press selects the word, second adds the next occurrence
+        harness.dispatch_event(create_key_event(Key::Character("d".into()), modifier));
+        harness.dispatch_event(create_key_event(Key::Character("d".into()), modifier));
+        assert_eq!(doc_signal.get_untracked().regions().len(), 2);
+
+        harness.dispatch_event(create_key_event(
+            Key::Character("x".into()),
+            Modifiers::default(),
+        ));
+        assert_eq!(
+            doc_signal.get_untracked().text(),
+            "x bar x",
+            "Typing should replace every selected occurrence"
+        );
+
+        harness.dispatch_event(create_key_event(
+            Key::Named(NamedKey::Escape),
+            Modifiers::default(),
+        ));
+        assert_eq!(
+            doc_signal.get_untracked().regions().len(),
+            1,
+            "Escape should collapse to a single cursor"
+        );
+    }
+
     #[cfg(target_os = "macos")]
     #[test]
     fn test_textarea_cmd_left_line_start() {
@@ -2608,6 +3281,370 @@ mod tests {
         assert!(scroll_id != ViewId::new(), "scroll_id should be valid");
     }
 
+    // ==========================================================================
+    // Read-only and disabled tests
+    // ==========================================================================
+
+    #[test]
+    fn test_textarea_read_only_rejects_edits() {
+        let textarea = TextArea::with_text("hello")
+            .read_only(|| true)
+            .style(|s| s.size(200.0, 100.0));
+        let doc_signal = textarea.doc();
+        assert!(textarea.is_read_only());
+
+        let mut harness = HeadlessHarness::new_with_size(textarea, 200.0, 100.0);
+        harness.click(10.0, 10.0);
+        doc_signal.get_untracked().set_offset(5, false);
+
+        harness.dispatch_event(create_key_event(
+            Key::Character("x".into()),
+            Modifiers::default(),
+        ));
+        harness.dispatch_event(create_key_event(
+            Key::Named(NamedKey::Backspace),
+            Modifiers::default(),
+        ));
+        assert_eq!(doc_signal.get_untracked().text(), "hello");
+    }
+
+    #[test]
+    fn test_textarea_read_only_allows_selection() {
+        let textarea = TextArea::with_text("hello")
+            .read_only(|| true)
+            .style(|s| s.size(200.0, 100.0));
+        let id = textarea.id;
+        let doc_signal = textarea.doc();
+
+        let mut harness = HeadlessHarness::new_with_size(textarea, 200.0, 100.0);
+        harness.click(10.0, 10.0);
+        assert!(
+            harness.is_focused(id),
+            "Read-only text areas can be focused"
+        );
+        doc_signal.get_untracked().set_offset(0, false);
+
+        harness.dispatch_event(create_key_event(
+            Key::Named(NamedKey::ArrowRight),
+            Modifiers::SHIFT,
+        ));
+        let cursor = doc_signal.get_untracked().cursor().get_untracked();
+        assert_eq!((cursor.min(), cursor.max()), (0, 1));
+    }
+
+    #[test]
+    fn test_textarea_read_only_is_reactive() {
+        let read_only = RwSignal::new(true);
+        let textarea = TextArea::new()
+            .read_only(move || read_only.get())
+            .style(|s| s.size(200.0, 100.0));
+        let doc_signal = textarea.doc();
+
+        let mut harness = HeadlessHarness::new_with_size(textarea, 200.0, 100.0);
+        harness.click(10.0, 10.0);
+        let type_a = || create_key_event(Key::Character("a".into()), Modifiers::default());
+
+        harness.dispatch_event(type_a());
+        assert_eq!(doc_signal.get_untracked().text(), "");
+
+        read_only.set(false);
+        harness.dispatch_event(type_a());
+        assert_eq!(doc_signal.get_untracked().text(), "a");
+    }
+
+    #[test]
+    fn test_textarea_disabled_cannot_be_focused() {
+        let textarea = TextArea::new()
+            .disabled(|| true)
+            .style(|s| s.size(200.0, 100.0));
+        let id = textarea.id;
+        let doc_signal = textarea.doc();
+        assert!(textarea.is_disabled());
+
+        let mut harness = HeadlessHarness::new_with_size(textarea, 200.0, 100.0);
+        harness.click(10.0, 10.0);
+        assert!(
+            !harness.is_focused(id),
+            "Disabled text areas can't be focused"
+        );
+
+        harness.dispatch_event(create_key_event(
+            Key::Character("a".into()),
+            Modifiers::default(),
+        ));
+        assert_eq!(doc_signal.get_untracked().text(), "");
+    }
+
+    #[test]
+    fn test_textarea_disabling_clears_focus() {
+        let disabled = RwSignal::new(false);
+        let textarea = TextArea::new()
+            .disabled(move || disabled.get())
+            .style(|s| s.size(200.0, 100.0));
+        let id = textarea.id;
+
+        let mut harness = HeadlessHarness::new_with_size(textarea, 200.0, 100.0);
+        harness.click(10.0, 10.0);
+        assert!(harness.is_focused(id));
+
+        disabled.set(true);
+        harness.rebuild();
+        assert!(!harness.is_focused(id), "Disabling should drop focus");
+    }
+
+    // ==========================================================================
+    // Custom command tests
+    // ==========================================================================
+
+    #[test]
+    fn test_textarea_custom_command() {
+        let mut keymap = Keymap::multi_line();
+        keymap
+            .bind("alt+t", Command::from_name("insert-template"))
+            .unwrap();
+        let textarea = TextArea::with_text("")
+            .keymap(keymap)
+            .command("insert-template", |doc| doc.insert_text("// TODO"))
+            .style(|s| s.size(200.0, 100.0));
+        let doc_signal = textarea.doc();
+
+        let mut harness = HeadlessHarness::new_with_size(textarea, 200.0, 100.0);
+        harness.click(10.0, 10.0);
+        harness.dispatch_event(create_key_event(Key::Character("t".into()), Modifiers::ALT));
+        assert_eq!(doc_signal.get_untracked().text(), "// TODO");
+    }
+
+    #[test]
+    fn test_textarea_custom_command_replaces_builtin_binding() {
+        let submitted = RwSignal::new(None);
+        let mut keymap = Keymap::multi_line();
+        keymap.bind("enter", Command::from_name("submit")).unwrap();
+        let textarea = TextArea::with_text("hello")
+            .keymap(keymap)
+            .command("submit", move |doc| submitted.set(Some(doc.text())))
+            .style(|s| s.size(200.0, 100.0));
+        let doc_signal = textarea.doc();
+
+        let mut harness = HeadlessHarness::new_with_size(textarea, 200.0, 100.0);
+        harness.click(10.0, 10.0);
+        harness.dispatch_event(create_key_event(
+            Key::Named(NamedKey::Enter),
+            Modifiers::default(),
+        ));
+        assert_eq!(submitted.get_untracked().as_deref(), Some("hello"));
+        assert_eq!(doc_signal.get_untracked().text(), "hello");
+    }
+
+    #[test]
+    fn test_textarea_key_sequence() {
+        let mut keymap = Keymap::multi_line();
+        keymap
+            .bind("ctrl+k ctrl+t", Command::from_name("insert-template"))
+            .unwrap();
+        let textarea = TextArea::with_text("")
+            .keymap(keymap)
+            .command("insert-template", |doc| doc.insert_text("// TODO"))
+            .style(|s| s.size(200.0, 100.0));
+        let doc_signal = textarea.doc();
+        let pending_keys = textarea.pending_keys();
+
+        let mut harness = HeadlessHarness::new_with_size(textarea, 200.0, 100.0);
+        harness.click(10.0, 10.0);
+        harness.dispatch_event(create_key_event(
+            Key::Character("k".into()),
+            Modifiers::CONTROL,
+        ));
+        assert_eq!(
+            pending_keys
+                .get_untracked()
+                .iter()
+                .map(|k| k.to_string())
+                .collect::<Vec<_>>(),
+            ["ctrl+k"]
+        );
+        assert_eq!(
+            doc_signal.get_untracked().text(),
+            "",
+            "Ctrl+K starts the sequence instead of killing the line"
+        );
+
+        harness.dispatch_event(create_key_event(
+            Key::Character("t".into()),
+            Modifiers::CONTROL,
+        ));
+        assert!(pending_keys.get_untracked().is_empty());
+        assert_eq!(doc_signal.get_untracked().text(), "// TODO");
+    }
+
+    // ==========================================================================
+    // Kill ring tests
+    // ==========================================================================
+
+    #[test]
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
+    fn test_textarea_kill_and_yank() {
+        #[allow(unused_mut)]
+        let mut keymap = Keymap::multi_line();
+        // Ctrl+Y redoes on Linux unless yanking is opted in to
+        #[cfg(target_os = "linux")]
+        keymap.bind("ctrl+y", Command::Yank).unwrap();
+
+        let textarea = TextArea::with_text("ab\ncd")
+            .keymap(keymap)
+            .style(|s| s.size(200.0, 100.0));
+        let doc_signal = textarea.doc();
+
+        let mut harness = HeadlessHarness::new_with_size(textarea, 200.0, 100.0);
+        harness.click(10.0, 10.0);
+        doc_signal.get_untracked().set_offset(0, false);
+        for _ in 0..2 {
+            harness.dispatch_event(create_key_event(
+                Key::Character("k".into()),
+                Modifiers::CONTROL,
+            ));
+        }
+        assert_eq!(doc_signal.get_untracked().text(), "cd");
+
+        harness.dispatch_event(create_key_event(
+            Key::Named(NamedKey::End),
+            Modifiers::default(),
+        ));
+        harness.dispatch_event(create_key_event(
+            Key::Character("y".into()),
+            Modifiers::CONTROL,
+        ));
+        assert_eq!(
+            doc_signal.get_untracked().text(),
+            "cdab\n",
+            "Consecutive kills are yanked together"
+        );
+    }
+
+    // ==========================================================================
+    // Find tests
+    // ==========================================================================
+
+    #[test]
+    #[cfg(not(target_os = "linux"))]
+    fn test_textarea_ctrl_f_opens_find_bar() {
+        #[cfg(target_os = "macos")]
+        let modifier = Modifiers::META;
+        #[cfg(not(target_os = "macos"))]
+        let modifier = Modifiers::CONTROL;
+
+        let textarea = TextArea::with_text("hello").style(|s| s.size(300.0, 100.0));
+        let find_open = textarea.find_open();
+        let doc_signal = textarea.doc();
+
+        let mut harness = HeadlessHarness::new_with_size(textarea, 300.0, 100.0);
+        harness.click(10.0, 10.0);
+        harness.dispatch_event(create_key_event(Key::Character("f".into()), modifier));
+        assert!(find_open.get_untracked());
+        assert_eq!(doc_signal.get_untracked().text(), "hello");
+    }
+
+    #[test]
+    fn test_keypressmap_find_with_emacs_bindings() {
+        let keymap = Keymap::multi_line();
+        let ctrl = |key: &str| KeyPress {
+            key: Key::Character(key.into()),
+            modifiers: Modifiers::CONTROL,
+        };
+
+        // On Linux, find doesn't take over the Emacs chords
+        #[cfg(target_os = "linux")]
+        {
+            assert_eq!(
+                keymap.keymaps.get(&ctrl("f")),
+                Some(&Command::Move(MoveCommand::Right))
+            );
+            assert_eq!(
+                keymap.keymaps.get(&ctrl("h")),
+                Some(&Command::Edit(EditCommand::DeleteBackward))
+            );
+        }
+        #[cfg(target_os = "macos")]
+        {
+            let cmd_f = KeyPress {
+                key: Key::Character("f".into()),
+                modifiers: Modifiers::META,
+            };
+            assert_eq!(keymap.keymaps.get(&cmd_f), Some(&Command::Find));
+            assert_eq!(
+                keymap.keymaps.get(&ctrl("f")),
+                Some(&Command::Move(MoveCommand::Right))
+            );
+        }
+        #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+        {
+            assert_eq!(keymap.keymaps.get(&ctrl("f")), Some(&Command::Find));
+            assert_eq!(keymap.keymaps.get(&ctrl("h")), Some(&Command::Replace));
+        }
+    }
+
+    // ==========================================================================
+    // Vim mode tests
+    // ==========================================================================
+
+    fn type_chars(harness: &mut HeadlessHarness, chars: &str) {
+        for c in chars.chars() {
+            harness.dispatch_event(create_key_event(
+                Key::Character(c.to_string().into()),
+                Modifiers::default(),
+            ));
+        }
+    }
+
+    #[test]
+    fn test_textarea_vim_mode() {
+        let textarea = TextArea::with_text("hello world")
+            .vim(|| true)
+            .style(|s| s.size(200.0, 100.0));
+        let doc_signal = textarea.doc();
+        let mode = textarea.vim_mode();
+        assert_eq!(mode.get_untracked(), Mode::Normal);
+
+        let mut harness = HeadlessHarness::new_with_size(textarea, 200.0, 100.0);
+        harness.click(10.0, 10.0);
+        type_chars(&mut harness, "0dw");
+        assert_eq!(doc_signal.get_untracked().text(), "world");
+
+        type_chars(&mut harness, "A");
+        assert_eq!(mode.get_untracked(), Mode::Insert);
+        type_chars(&mut harness, "!");
+        harness.dispatch_event(create_key_event(
+            Key::Named(NamedKey::Escape),
+            Modifiers::default(),
+        ));
+        assert_eq!(mode.get_untracked(), Mode::Normal);
+        assert_eq!(doc_signal.get_untracked().text(), "world!");
+
+        type_chars(&mut harness, "u");
+        assert_eq!(doc_signal.get_untracked().text(), "world");
+        type_chars(&mut harness, "u");
+        assert_eq!(doc_signal.get_untracked().text(), "hello world");
+    }
+
+    #[test]
+    fn test_textarea_vim_can_be_toggled() {
+        let vim = RwSignal::new(true);
+        let textarea = TextArea::with_text("")
+            .vim(move || vim.get())
+            .style(|s| s.size(200.0, 100.0));
+        let doc_signal = textarea.doc();
+        let mode = textarea.vim_mode();
+
+        let mut harness = HeadlessHarness::new_with_size(textarea, 200.0, 100.0);
+        harness.click(10.0, 10.0);
+        type_chars(&mut harness, "x");
+        assert_eq!(doc_signal.get_untracked().text(), "");
+
+        vim.set(false);
+        assert_eq!(mode.get_untracked(), Mode::Insert);
+        type_chars(&mut harness, "x");
+        assert_eq!(doc_signal.get_untracked().text(), "x");
+    }
+
     // ==========================================================================
     // Resize handle tests
     // ==========================================================================
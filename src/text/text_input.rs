@@ -3,6 +3,7 @@
 //! A single-line text input with horizontal scrolling, cursor/selection rendering,
 //! and keyboard/mouse handling. Reuses Document for text editing logic.
 
+use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
 use floem::{
@@ -22,9 +23,11 @@ use ui_events::{
     pointer::PointerEvent,
 };
 
+use super::keymap::PendingKeys;
 use super::{
-    CURSOR_BLINK_INTERVAL_MS, Command, Document, Keymap, apply_styles_to_document, extract_padding,
-    extract_text_styles, get_glyph_dimensions, is_cursor_visible,
+    CURSOR_BLINK_INTERVAL_MS, Command, Document, KeyPress, Keymap, KeymapMatch,
+    apply_styles_to_document, extract_padding, extract_text_styles, get_glyph_dimensions,
+    is_cursor_visible,
 };
 
 /// A single-line text input view
@@ -43,6 +46,16 @@ pub struct TextInput {
     placeholder: RwSignal<Option<String>>,
     /// Callback for Enter key
     on_enter: RwSignal<Option<Box<dyn Fn(&str)>>>,
+    /// Whether edits are rejected; selection and copy still work
+    read_only: RwSignal<bool>,
+    /// Whether the input ignores all interaction and can't be focused
+    disabled: RwSignal<bool>,
+    /// Key bindings
+    keymap: RwSignal<Keymap>,
+    /// Callbacks for [`Command::Custom`] commands, by name
+    commands: RwSignal<HashMap<String, Box<dyn Fn(&Document)>>>,
+    /// Keys typed so far in a multi-key sequence
+    pending_keys: PendingKeys,
 }
 
 impl TextInput {
@@ -67,6 +80,12 @@ impl TextInput {
         let last_cursor_action = RwSignal::new(Instant::now());
         let placeholder = RwSignal::new(None);
         let on_enter: RwSignal<Option<Box<dyn Fn(&str)>>> = RwSignal::new(None);
+        let read_only = RwSignal::new(false);
+        let disabled = RwSignal::new(false);
+        let keymap = RwSignal::new(Keymap::single_line());
+        let commands: RwSignal<HashMap<String, Box<dyn Fn(&Document)>>> =
+            RwSignal::new(HashMap::new());
+        let pending_keys = PendingKeys::new();
 
         // Capture cursor signal for reactive tracking
         let cursor_signal = doc_signal.get_untracked().cursor();
@@ -102,12 +121,12 @@ impl TextInput {
         });
 
         // Set up event handlers
-        let keymap = std::sync::Arc::new(Keymap::single_line());
-        let keymap_clone = keymap.clone();
-
         id.add_event_listener(
             EventListener::PointerDown,
             Box::new(move |event| {
+                if disabled.get_untracked() {
+                    return EventPropagation::Continue;
+                }
                 if let Event::Pointer(PointerEvent::Down(pointer_event)) = event {
                     let padding = padding.get_untracked();
                     let offset = scroll_offset.get_untracked();
@@ -169,9 +188,22 @@ impl TextInput {
                 else {
                     return EventPropagation::Continue;
                 };
+                if disabled.get_untracked() {
+                    return EventPropagation::Continue;
+                }
 
-                // Handle Enter key specially
-                if key == &Key::Named(NamedKey::Enter) {
+                // Try to find command, following multi-key sequences
+                let command =
+                    match keymap.with_untracked(|k| pending_keys.resolve(k, key, modifiers)) {
+                        KeymapMatch::Command(command) => Some(command),
+                        KeymapMatch::Pending | KeymapMatch::Cancelled => {
+                            return EventPropagation::Stop;
+                        }
+                        KeymapMatch::None => None,
+                    };
+
+                // Handle Enter key specially unless it's bound
+                if command.is_none() && key == &Key::Named(NamedKey::Enter) {
                     on_enter.with_untracked(|cb| {
                         if let Some(callback) = cb {
                             let text = doc_signal.get_untracked().text();
@@ -181,14 +213,14 @@ impl TextInput {
                     return EventPropagation::Stop;
                 }
 
-                // Try to find command
-                let command = keymap_clone.get(key, modifiers);
-
                 let document = doc_signal.get_untracked();
 
                 if let Some(command) = command {
+                    if command.is_edit() && read_only.get_untracked() {
+                        return EventPropagation::Stop;
+                    }
                     let shift_held = modifiers.shift();
-                    match command {
+                    match &command {
                         Command::Edit(edit_cmd) => {
                             document.run_edit_command(edit_cmd);
                             id.request_layout();
@@ -215,6 +247,60 @@ impl TextInput {
                                 id.request_layout();
                             }
                         }
+                        Command::Undo => {
+                            if document.undo() {
+                                id.request_layout();
+                            }
+                        }
+                        Command::Redo => {
+                            if document.redo() {
+                                id.request_layout();
+                            }
+                        }
+                        Command::SelectNextOccurrence => {
+                            if document.select_next_occurrence() {
+                                id.request_paint();
+                            }
+                        }
+                        Command::CollapseCursors => {
+                            if !document.collapse_cursors() {
+                                // Let Escape reach parent handlers (e.g. dialogs)
+                                return EventPropagation::Continue;
+                            }
+                            id.request_paint();
+                        }
+                        Command::KillLine => {
+                            if document.kill_line() {
+                                id.request_layout();
+                            }
+                        }
+                        Command::Yank => {
+                            if document.yank(true) {
+                                id.request_layout();
+                            }
+                        }
+                        Command::YankPop => {
+                            if document.yank_pop(true) {
+                                id.request_layout();
+                            }
+                        }
+                        Command::SetMark => {
+                            document.set_mark();
+                            id.request_paint();
+                        }
+                        // Only text areas have a find bar
+                        Command::Find | Command::Replace => {
+                            return EventPropagation::Continue;
+                        }
+                        Command::Custom(name) => {
+                            let handled = commands.with_untracked(|commands| {
+                                commands.get(name).map(|callback| callback(&document))
+                            });
+                            if handled.is_none() {
+                                return EventPropagation::Continue;
+                            }
+                            id.request_layout();
+                        }
                     }
                     last_cursor_action.set(Instant::now());
                     return EventPropagation::Stop;
@@ -226,7 +312,7 @@ impl TextInput {
                 #[cfg(target_os = "macos")]
                 mods.set(Modifiers::ALT, false);
 
-                if mods.is_empty() {
+                if mods.is_empty() && !read_only.get_untracked() {
                     if let Key::Character(c) = key {
                         // Filter out newlines for single-line input
                         let filtered: String =
@@ -242,9 +328,20 @@ impl TextInput {
             }),
         );
 
+        id.add_event_listener(
+            EventListener::FocusLost,
+            Box::new(move |_| {
+                pending_keys.clear();
+                EventPropagation::Continue
+            }),
+        );
+
         id.add_event_listener(
             EventListener::ImeCommit,
             Box::new(move |event| {
+                if read_only.get_untracked() || disabled.get_untracked() {
+                    return EventPropagation::Stop;
+                }
                 if let Event::ImeCommit(text) = event {
                     // Filter out newlines from IME input
                     let filtered: String = text
@@ -270,6 +367,11 @@ impl TextInput {
             last_cursor_action,
             placeholder,
             on_enter,
+            read_only,
+            disabled,
+            keymap,
+            commands,
+            pending_keys,
         }
     }
 
@@ -296,6 +398,43 @@ impl TextInput {
         self
     }
 
+    /// Replaces the key bindings, [`Keymap::single_line`] by default
+    ///
+    /// A binding for Enter takes precedence over [`on_enter`](Self::on_enter).
+    pub fn keymap(self, keymap: Keymap) -> Self {
+        self.keymap.set(keymap);
+        self
+    }
+
+    /// Signal holding the keys typed so far in a multi-key sequence
+    ///
+    /// Empty unless a sequence such as `ctrl+k ctrl+c` is in progress. Each
+    /// [`KeyPress`] displays as a chord, e.g. for a status bar.
+    pub fn pending_keys(&self) -> RwSignal<Vec<KeyPress>> {
+        self.pending_keys.keys()
+    }
+
+    /// Registers the callback run by the [`Command::Custom`] command `name`
+    ///
+    /// Bind the command with [`Keymap::bind`], or as `custom:<name>` in a
+    /// keymap file. Keys bound to unregistered commands are passed on to
+    /// parent views. Callbacks run even when the input is read-only.
+    ///
+    /// ```rust,ignore
+    /// let mut keymap = Keymap::single_line();
+    /// keymap.bind("ctrl+t", Command::from_name("insert-date"))?;
+    ///
+    /// TextInput::new()
+    ///     .keymap(keymap)
+    ///     .command("insert-date", |doc| doc.insert_text(&today()))
+    /// ```
+    pub fn command(self, name: impl Into<String>, callback: impl Fn(&Document) + 'static) -> Self {
+        self.commands.update(|commands| {
+            commands.insert(name.into(), Box::new(callback));
+        });
+        self
+    }
+
     /// Sets the editor content reactively
     pub fn value(self, set_value: impl Fn() -> String + 'static) -> Self {
         let doc = self.doc;
@@ -323,11 +462,77 @@ impl TextInput {
                     )],
                     EditType::Other,
                 );
+                // Externally set values start a fresh history
+                doc.clear_history();
             });
         });
         self
     }
 
+    /// Masks the text reactively, as for passwords
+    ///
+    /// While `password` returns true, every grapheme is drawn as a bullet,
+    /// copy and cut are disabled and word-wise movement jumps over the whole
+    /// text. See [`Document::set_password`].
+    pub fn password(self, password: impl Fn() -> bool + 'static) -> Self {
+        let doc = self.doc;
+        let id = self.id;
+        Effect::new(move |_| {
+            let password = password();
+            let doc = doc.get_untracked();
+            if doc.is_password() == password {
+                return;
+            }
+            doc.set_password(password);
+            // Bullets and plain text have different widths; let the scroll
+            // offset follow the cursor to its new position
+            doc.cursor().update(|_| {});
+            id.request_paint();
+        });
+        self
+    }
+
+    /// Rejects edits reactively while `read_only` returns true
+    ///
+    /// The text can still be selected, navigated and copied.
+    pub fn read_only(self, read_only: impl Fn() -> bool + 'static) -> Self {
+        let signal = self.read_only;
+        Effect::new(move |_| signal.set(read_only()));
+        self
+    }
+
+    /// Disables the input reactively while `disabled` returns true
+    ///
+    /// A disabled input ignores pointer and keyboard input, can't be focused
+    /// and matches the `disabled` style selector. It loses focus when it
+    /// becomes disabled.
+    pub fn disabled(self, disabled: impl Fn() -> bool + 'static) -> Self {
+        let signal = self.disabled;
+        let id = self.id;
+        Effect::new(move |_| {
+            let disabled = disabled();
+            if signal.get_untracked() == disabled {
+                return;
+            }
+            signal.set(disabled);
+            if disabled {
+                id.clear_focus();
+            }
+            id.request_style();
+        });
+        self
+    }
+
+    /// Returns true if the input rejects edits
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.get_untracked()
+    }
+
+    /// Returns true if the input is disabled
+    pub fn is_disabled(&self) -> bool {
+        self.disabled.get_untracked()
+    }
+
     /// Returns the current text content
     pub fn text(&self) -> String {
         self.doc.get_untracked().text()
@@ -346,7 +551,17 @@ impl View for TextInput {
     }
 
     fn view_style(&self) -> Option<Style> {
-        Some(Style::new().cursor(StyleCursorStyle::Text).focusable(true))
+        let disabled = self.disabled.get_untracked();
+        Some(
+            Style::new()
+                .cursor(if disabled {
+                    StyleCursorStyle::Default
+                } else {
+                    StyleCursorStyle::Text
+                })
+                .focusable(!disabled)
+                .set_disabled(disabled),
+        )
     }
 
     fn compute_layout(&mut self, _cx: &mut ComputeLayoutCx) -> Option<Rect> {
@@ -442,59 +657,64 @@ impl View for TextInput {
             );
         }
 
-        // Always draw cursor/selection when focused (even when empty)
+        // Always draw cursors/selections when focused (even when empty)
         if cx.is_focused(self.id) {
-            let cursor = doc.cursor().get_untracked();
-            if cursor.is_caret() {
-                let elapsed_ms = self
-                    .last_cursor_action
+            let regions = doc.regions();
+            let caret_visible = is_cursor_visible(
+                self.last_cursor_action
                     .get_untracked()
                     .elapsed()
-                    .as_millis();
+                    .as_millis(),
+            );
 
-                if is_cursor_visible(elapsed_ms) {
-                    let p = lines.point_of_offset(cursor.end);
-                    let (cursor_top, cursor_height) = get_glyph_dimensions(
+            for cursor in &regions {
+                if cursor.is_caret() {
+                    if caret_visible {
+                        let p = lines.point_of_offset(cursor.end);
+                        let (cursor_top, cursor_height) = get_glyph_dimensions(
+                            p.glyph_top,
+                            p.glyph_bottom,
+                            lines.default_glyph_top(),
+                            lines.default_glyph_height(),
+                        );
+                        let rect = Rect::from_origin_size(
+                            (
+                                p.x + padding.3 - scroll_offset - 1.0,
+                                cursor_top + padding.0 + y_offset,
+                            ),
+                            (2.0, cursor_height),
+                        );
+                        cx.fill(&rect, text_styles.text_color, 0.0);
+                    }
+                } else {
+                    // Draw selection
+                    let start_x = lines.point_of_offset(cursor.min()).x;
+                    let end_x = lines.point_of_offset(cursor.max()).x;
+                    let p = lines.point_of_offset(cursor.min());
+                    let (sel_top, sel_height) = get_glyph_dimensions(
                         p.glyph_top,
                         p.glyph_bottom,
                         lines.default_glyph_top(),
                         lines.default_glyph_height(),
                     );
+
                     let rect = Rect::from_origin_size(
                         (
-                            p.x + padding.3 - scroll_offset - 1.0,
-                            cursor_top + padding.0 + y_offset,
+                            start_x + padding.3 - scroll_offset,
+                            sel_top + padding.0 + y_offset,
                         ),
-                        (2.0, cursor_height),
+                        (end_x - start_x, sel_height),
                     );
-                    cx.fill(&rect, text_styles.text_color, 0.0);
+                    cx.fill(&rect, selection_color, 0.0);
                 }
+            }
 
+            if regions.iter().any(|cursor| cursor.is_caret()) {
                 // Schedule repaint for cursor blink
                 let id = self.id;
                 exec_after(Duration::from_millis(CURSOR_BLINK_INTERVAL_MS), move |_| {
                     id.request_paint();
                 });
-            } else {
-                // Draw selection
-                let start_x = lines.point_of_offset(cursor.min()).x;
-                let end_x = lines.point_of_offset(cursor.max()).x;
-                let p = lines.point_of_offset(cursor.min());
-                let (sel_top, sel_height) = get_glyph_dimensions(
-                    p.glyph_top,
-                    p.glyph_bottom,
-                    lines.default_glyph_top(),
-                    lines.default_glyph_height(),
-                );
-
-                let rect = Rect::from_origin_size(
-                    (
-                        start_x + padding.3 - scroll_offset,
-                        sel_top + padding.0 + y_offset,
-                    ),
-                    (end_x - start_x, sel_height),
-                );
-                cx.fill(&rect, selection_color, 0.0);
             }
         }
 
@@ -386,6 +386,34 @@ impl TextLayoutLineBuilder {
     }
 
     pub fn push_text_layout(&mut self, text_layout: &TextLayout) {
+        self.push_mapped_text_layout(text_layout, None);
+    }
+
+    /// Pushes the layout of masked text, such as a password, where each
+    /// character of the layout stands for one grapheme of the real text.
+    ///
+    /// `boundaries` holds the byte offsets where the graphemes of the real
+    /// line start, followed by its length. Glyph offsets are remapped, so the
+    /// built lines measure offsets in the real text rather than in the mask.
+    pub fn push_masked_text_layout(
+        &mut self,
+        text_layout: &TextLayout,
+        mask: char,
+        boundaries: &[usize],
+    ) {
+        let mask_len = mask.len_utf8();
+        let len = boundaries.last().copied().unwrap_or(0);
+        let map = |offset: usize| boundaries.get(offset / mask_len).copied().unwrap_or(len);
+        self.push_mapped_text_layout(text_layout, Some(&map));
+    }
+
+    /// Pushes a text layout, converting its byte offsets with `map` if given.
+    fn push_mapped_text_layout(
+        &mut self,
+        text_layout: &TextLayout,
+        map: Option<&dyn Fn(usize) -> usize>,
+    ) {
+        let offset = |offset: usize| map.map_or(offset, |map| map(offset));
         let metrics = text_layout.metrics();
         let mut captured_default = self.default_glyph_height > 16.0; // already have a real value
         for buffer_line in text_layout.lines() {
@@ -412,19 +440,14 @@ impl TextLayoutLineBuilder {
                     // we use the start offset of the next line
                     // and the start offset of the current line
                     // to get the utf8 len of this line
-                    next.glyphs
-                        .first()
-                        .map(|g| g.start)
-                        .unwrap_or(0)
-                        .saturating_sub(line.glyphs.first().map(|g| g.start).unwrap_or(0))
+                    offset(next.glyphs.first().map(|g| g.start).unwrap_or(0))
+                        .saturating_sub(offset(line.glyphs.first().map(|g| g.start).unwrap_or(0)))
                 } else {
                     // if there's no next line, this means this is the last visual line
                     // we use the total text len and the start glyph offset
                     // to get the utf8 len of this line
-                    buffer_line
-                        .text()
-                        .len()
-                        .saturating_sub(line.glyphs.first().map(|g| g.start).unwrap_or(0))
+                    offset(buffer_line.text().len())
+                        .saturating_sub(offset(line.glyphs.first().map(|g| g.start).unwrap_or(0)))
                 };
 
                 // Add the line ending byte length to the last visual line
@@ -438,6 +461,20 @@ impl TextLayoutLineBuilder {
                     };
                 }
 
+                let remapped;
+                let line = match map {
+                    Some(map) => {
+                        let mut line = line.clone();
+                        for glyph in line.glyphs.iter_mut() {
+                            glyph.start = map(glyph.start);
+                            glyph.end = map(glyph.end);
+                        }
+                        remapped = line;
+                        &remapped
+                    }
+                    None => line,
+                };
+
                 leaf.push_layout_line(
                     line,
                     utf8_len,
@@ -0,0 +1,1501 @@
+//! Vim-style modal editing for [`TextArea`](super::TextArea)
+//!
+//! Supported commands:
+//! - Normal, insert (`i a I A o O`) and visual (`v`, `V`) modes
+//! - Motions `h j k l w b e 0 ^ $ gg G` and the arrow keys, with counts
+//! - Operators `d c y`, doubled for whole lines (`dd`), and `x X s D C Y`
+//! - `p P` to paste, `u` and Ctrl+R to undo and redo, `.` to repeat the
+//!   last change
+//! - Registers `"a` to `"z` (uppercase appends), `"0` (the last yank), `"_`
+//!   (discards) and `"+` (the system clipboard)
+//!
+//! Keys with Ctrl, Alt or Meta that Vim doesn't use fall through to the
+//! text area's keymap, as does everything typed in insert mode.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use floem::reactive::{ReadSignal, RwSignal, SignalGet, SignalUpdate, SignalWith};
+use floem_editor_core::{
+    cursor::CursorAffinity,
+    editor::EditType,
+    mode::{Mode, VisualMode},
+    selection::SelRegion,
+};
+use lapce_xi_rope::Rope;
+use ui_events::keyboard::{Key, Modifiers, NamedKey};
+
+use super::Document;
+
+/// Escape, as a control character
+const ESCAPE: char = '\u{1b}';
+/// Ctrl+R, as a control character
+const CTRL_R: char = '\u{12}';
+/// The register used when none is named
+const UNNAMED: char = '"';
+/// The largest count a command takes
+const MAX_COUNT: usize = 999_999;
+
+/// Vim editing state for a text area
+#[derive(Clone)]
+pub(crate) struct Vim {
+    enabled: RwSignal<bool>,
+    mode: RwSignal<Mode>,
+    state: Rc<RefCell<VimState>>,
+}
+
+#[derive(Default)]
+struct VimState {
+    /// Keys of the command being typed
+    pending: Vec<char>,
+    /// The other end of the selection in visual mode
+    anchor: usize,
+    /// The cursor in visual mode
+    head: usize,
+    /// Column kept by vertical motions, in chars
+    col: Option<usize>,
+    registers: HashMap<char, Register>,
+    last_change: Option<Change>,
+    /// The change being typed in insert mode
+    insert: Option<InsertSession>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Register {
+    text: String,
+    linewise: bool,
+}
+
+/// A change that `.` repeats
+#[derive(Clone)]
+struct Change {
+    command: NormalCommand,
+    inserted: Option<String>,
+}
+
+struct InsertSession {
+    /// The command that entered insert mode
+    command: NormalCommand,
+    start: usize,
+    len_before: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct NormalCommand {
+    register: Option<char>,
+    count: Option<usize>,
+    action: Action,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Action {
+    Move(Motion),
+    Operate(Operator, Target),
+    /// `x`, or `X` when `before`
+    DeleteChar {
+        before: bool,
+    },
+    Paste {
+        before: bool,
+    },
+    Insert(InsertAt),
+    Visual(VisualMode),
+    /// An operator applied to the visual selection
+    VisualOperate(Operator),
+    /// `o` in visual mode
+    SwapAnchor,
+    Escape,
+    Undo,
+    Redo,
+    Repeat,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operator {
+    Delete,
+    Change,
+    Yank,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Target {
+    Motion(Motion),
+    /// The operator key doubled, e.g. `dd`
+    Lines,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum InsertAt {
+    Cursor,
+    AfterCursor,
+    LineStart,
+    LineEnd,
+    LineBelow,
+    LineAbove,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Motion {
+    Left,
+    Right,
+    Up,
+    Down,
+    WordForward,
+    WordBackward,
+    WordEnd,
+    LineStart,
+    FirstNonBlank,
+    LineEnd,
+    FirstLine,
+    LastLine,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum MotionKind {
+    Exclusive,
+    Inclusive,
+    Linewise,
+}
+
+impl Motion {
+    fn kind(self) -> MotionKind {
+        match self {
+            Motion::Up | Motion::Down | Motion::FirstLine | Motion::LastLine => {
+                MotionKind::Linewise
+            }
+            Motion::WordEnd => MotionKind::Inclusive,
+            _ => MotionKind::Exclusive,
+        }
+    }
+}
+
+enum Parse<T> {
+    Incomplete,
+    Invalid,
+    Complete(T),
+}
+
+impl Vim {
+    pub(crate) fn new() -> Self {
+        Self {
+            enabled: RwSignal::new(false),
+            mode: RwSignal::new(Mode::Insert),
+            state: Rc::new(RefCell::new(VimState::default())),
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.enabled.get_untracked()
+    }
+
+    /// Turns Vim mode on in normal mode, or off back to plain insert-style
+    /// editing
+    pub(crate) fn set_enabled(&self, doc: &Document, enabled: bool) {
+        if self.enabled.get_untracked() == enabled {
+            return;
+        }
+        self.enabled.set(enabled);
+        let mut state = self.state.borrow_mut();
+        state.pending.clear();
+        state.insert = None;
+        drop(state);
+        doc.end_compound_edit();
+        if enabled {
+            self.enter_normal(doc);
+        } else {
+            self.mode.set(Mode::Insert);
+        }
+    }
+
+    pub(crate) fn mode(&self) -> ReadSignal<Mode> {
+        self.mode.read_only()
+    }
+
+    /// Returns true if the cursor should be drawn as a block
+    pub(crate) fn block_cursor(&self) -> bool {
+        self.enabled.get_untracked() && self.mode.get_untracked() == Mode::Normal
+    }
+
+    /// The contents of a register; `"` is the unnamed register
+    pub(crate) fn register(&self, name: char) -> Option<String> {
+        if name == '+' || name == '*' {
+            return floem::Clipboard::get_contents().ok();
+        }
+        let name = name.to_ascii_lowercase();
+        self.state
+            .borrow()
+            .registers
+            .get(&name)
+            .map(|r| r.text.clone())
+    }
+
+    /// Leaves visual mode when the pointer moves the cursor
+    pub(crate) fn pointer_down(&self) {
+        if self.is_enabled() && matches!(self.mode.get_untracked(), Mode::Visual(_)) {
+            self.mode.set(Mode::Normal);
+        }
+        self.state.borrow_mut().pending.clear();
+    }
+
+    /// Handles a key press. Returns false if the key should go to the keymap.
+    pub(crate) fn handle_key(
+        &self,
+        doc: &Document,
+        key: &Key,
+        modifiers: &Modifiers,
+        read_only: bool,
+    ) -> bool {
+        if !self.is_enabled() {
+            return false;
+        }
+        let c = key_char(key, modifiers);
+
+        if self.mode.get_untracked() == Mode::Insert {
+            if c != Some(ESCAPE) {
+                return false;
+            }
+            self.finish_insert(doc);
+            return true;
+        }
+
+        let Some(c) = c else {
+            // Unused Ctrl/Alt/Meta chords go to the keymap; other keys are swallowed
+            return !(modifiers.ctrl() || modifiers.alt() || modifiers.meta());
+        };
+
+        let visual = matches!(self.mode.get_untracked(), Mode::Visual(_));
+        let mut state = self.state.borrow_mut();
+        if c == ESCAPE && state.pending.is_empty() && !visual {
+            // Let Escape reach parent handlers (e.g. dialogs)
+            return false;
+        }
+        state.pending.push(c);
+        let parsed = parse(&state.pending, visual);
+        if !matches!(parsed, Parse::Incomplete) {
+            state.pending.clear();
+        }
+        drop(state);
+
+        if let Parse::Complete(command) = parsed {
+            self.run(doc, command, read_only);
+        }
+        true
+    }
+
+    fn run(&self, doc: &Document, command: NormalCommand, read_only: bool) {
+        let changes = match command.action {
+            Action::Operate(op, _) | Action::VisualOperate(op) => op != Operator::Yank,
+            Action::DeleteChar { .. }
+            | Action::Paste { .. }
+            | Action::Insert(_)
+            | Action::Undo
+            | Action::Redo
+            | Action::Repeat => true,
+            _ => false,
+        };
+        if changes && read_only {
+            return;
+        }
+
+        match command.action {
+            Action::Undo => {
+                for _ in 0..command.count.unwrap_or(1) {
+                    if !doc.undo() {
+                        break;
+                    }
+                }
+                self.enter_normal(doc);
+            }
+            Action::Redo => {
+                for _ in 0..command.count.unwrap_or(1) {
+                    if !doc.redo() {
+                        break;
+                    }
+                }
+                self.enter_normal(doc);
+            }
+            Action::Repeat => self.repeat(doc, command.count),
+            Action::Move(motion) if matches!(self.mode.get_untracked(), Mode::Visual(_)) => {
+                let text = rope(doc);
+                let head = self.state.borrow().head;
+                let head = self.motion_target(&text, head, motion, command.count);
+                self.state.borrow_mut().head = clamp_normal(&text, head);
+                self.update_selection(doc);
+            }
+            Action::Move(motion) => {
+                let text = rope(doc);
+                let pos = self.cursor(doc, &text);
+                let target = self.motion_target(&text, pos, motion, command.count);
+                let target = match motion {
+                    Motion::FirstLine | Motion::LastLine => first_non_blank(&text, target),
+                    _ => target,
+                };
+                doc.set_offset(clamp_normal(&text, target), false);
+            }
+            Action::Visual(visual) => self.toggle_visual(doc, visual),
+            Action::SwapAnchor => {
+                let mut state = self.state.borrow_mut();
+                let VimState { anchor, head, .. } = &mut *state;
+                std::mem::swap(anchor, head);
+                drop(state);
+                self.update_selection(doc);
+            }
+            Action::Escape => self.enter_normal(doc),
+            _ => {
+                doc.begin_compound_edit();
+                let enters_insert = self.change(doc, command);
+                if enters_insert {
+                    let text = rope(doc);
+                    self.state.borrow_mut().insert = Some(InsertSession {
+                        command,
+                        start: doc.cursor().get_untracked().end,
+                        len_before: text.len(),
+                    });
+                    self.mode.set(Mode::Insert);
+                } else {
+                    doc.end_compound_edit();
+                    if changes && !matches!(command.action, Action::VisualOperate(_)) {
+                        self.state.borrow_mut().last_change = Some(Change {
+                            command,
+                            inserted: None,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Runs an operator, paste or insert command.
+    /// Returns true if it leaves the editor in insert mode.
+    fn change(&self, doc: &Document, command: NormalCommand) -> bool {
+        let text = rope(doc);
+        let pos = self.cursor(doc, &text);
+        let count = command.count.unwrap_or(1);
+
+        match command.action {
+            Action::Operate(op, target) => {
+                let (start, end, linewise) = match target {
+                    Target::Lines => {
+                        let line = line_of(&text, pos);
+                        let last = line.saturating_add(count - 1).min(line_count(&text) - 1);
+                        let (start, end) = line_range(&text, line, last);
+                        (start, end, true)
+                    }
+                    Target::Motion(motion) => {
+                        self.motion_range(&text, pos, op, motion, command.count)
+                    }
+                };
+                self.operate(doc, &text, op, command.register, start, end, linewise, pos)
+            }
+            Action::VisualOperate(op) => {
+                let state = self.state.borrow();
+                let (anchor, head) = (state.anchor, state.head);
+                drop(state);
+                let (min, max) = (anchor.min(head), anchor.max(head));
+                let linewise = self.mode.get_untracked() == Mode::Visual(VisualMode::Linewise);
+                let (start, end) = if linewise {
+                    line_range(&text, line_of(&text, min), line_of(&text, max))
+                } else {
+                    (min, next_char(&text, max))
+                };
+                let enters_insert =
+                    self.operate(doc, &text, op, command.register, start, end, linewise, min);
+                if !enters_insert {
+                    self.mode.set(Mode::Normal);
+                }
+                enters_insert
+            }
+            Action::DeleteChar { before } => {
+                let (start, end) = if before {
+                    let line_start = line_start(&text, pos);
+                    let mut start = pos;
+                    for _ in 0..count {
+                        if start <= line_start {
+                            break;
+                        }
+                        start = prev_char(&text, start);
+                    }
+                    (start, pos)
+                } else {
+                    let line_end = line_end(&text, pos);
+                    let mut end = pos;
+                    for _ in 0..count {
+                        if end >= line_end {
+                            break;
+                        }
+                        end = next_char(&text, end);
+                    }
+                    (pos, end)
+                };
+                if start < end {
+                    self.operate(
+                        doc,
+                        &text,
+                        Operator::Delete,
+                        command.register,
+                        start,
+                        end,
+                        false,
+                        pos,
+                    );
+                }
+                false
+            }
+            Action::Paste { before } => {
+                self.paste(doc, &text, pos, command.register, count, before);
+                false
+            }
+            Action::Insert(at) => {
+                let offset = match at {
+                    InsertAt::Cursor => pos,
+                    InsertAt::AfterCursor => next_char(&text, pos).min(line_end(&text, pos)),
+                    InsertAt::LineStart => first_non_blank(&text, pos),
+                    InsertAt::LineEnd => line_end(&text, pos),
+                    InsertAt::LineBelow => {
+                        let end = line_end(&text, pos);
+                        replace(doc, end, end, "\n");
+                        end + 1
+                    }
+                    InsertAt::LineAbove => {
+                        let start = line_start(&text, pos);
+                        replace(doc, start, start, "\n");
+                        start
+                    }
+                };
+                doc.set_offset(offset, false);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Applies `op` to `start..end`. Returns true if it enters insert mode.
+    #[allow(clippy::too_many_arguments)]
+    fn operate(
+        &self,
+        doc: &Document,
+        text: &Rope,
+        op: Operator,
+        register: Option<char>,
+        start: usize,
+        end: usize,
+        linewise: bool,
+        pos: usize,
+    ) -> bool {
+        let mut yanked = text.slice_to_cow(start..end).into_owned();
+        if linewise && !yanked.ends_with('\n') {
+            yanked.push('\n');
+        }
+        self.store(register, yanked, linewise, op == Operator::Yank);
+
+        match op {
+            Operator::Yank => {
+                let offset = if linewise && line_of(text, pos) == line_of(text, start) {
+                    pos
+                } else {
+                    start.min(pos)
+                };
+                doc.set_offset(clamp_normal(text, offset), false);
+                false
+            }
+            Operator::Delete => {
+                // Deleting the last lines also removes the newline before them
+                let start = if linewise && end == text.len() && !ends_with_newline(text, start, end)
+                {
+                    start.saturating_sub(1)
+                } else {
+                    start
+                };
+                replace(doc, start, end, "");
+                let text = rope(doc);
+                let offset = if linewise {
+                    first_non_blank(&text, start)
+                } else {
+                    start
+                };
+                doc.set_offset(clamp_normal(&text, offset), false);
+                false
+            }
+            Operator::Change => {
+                // Changing lines keeps one empty line to type into
+                let end = if linewise && ends_with_newline(text, 0, end) {
+                    end - 1
+                } else {
+                    end
+                };
+                replace(doc, start, end, "");
+                doc.set_offset(start, false);
+                true
+            }
+        }
+    }
+
+    fn paste(
+        &self,
+        doc: &Document,
+        text: &Rope,
+        pos: usize,
+        register: Option<char>,
+        count: usize,
+        before: bool,
+    ) {
+        let name = register.unwrap_or(UNNAMED);
+        let register = if name == '+' || name == '*' {
+            floem::Clipboard::get_contents().ok().map(|text| Register {
+                linewise: text.ends_with('\n'),
+                text,
+            })
+        } else {
+            self.state
+                .borrow()
+                .registers
+                .get(&name.to_ascii_lowercase())
+                .cloned()
+        };
+        let Some(register) = register else {
+            return;
+        };
+        let Some(content) = repeated(&register.text, count) else {
+            return;
+        };
+        if content.is_empty() {
+            return;
+        }
+
+        if register.linewise {
+            let line = line_of(text, pos);
+            if before {
+                let at = line_start(text, pos);
+                replace(doc, at, at, &content);
+                let text = rope(doc);
+                doc.set_offset(clamp_normal(&text, first_non_blank(&text, at)), false);
+            } else if line + 1 < line_count(text) {
+                let at = offset_of_line(text, line + 1);
+                replace(doc, at, at, &content);
+                let text = rope(doc);
+                doc.set_offset(clamp_normal(&text, first_non_blank(&text, at)), false);
+            } else {
+                // Pasting below the last line
+                let content = format!("\n{}", content.strip_suffix('\n').unwrap_or(&content));
+                replace(doc, text.len(), text.len(), &content);
+                let text = rope(doc);
+                let at = offset_of_line(&text, line + 1);
+                doc.set_offset(clamp_normal(&text, first_non_blank(&text, at)), false);
+            }
+        } else {
+            let at = if before || line_end(text, pos) == pos {
+                pos
+            } else {
+                next_char(text, pos)
+            };
+            replace(doc, at, at, &content);
+            let end = at + content.len();
+            let text = rope(doc);
+            doc.set_offset(prev_char(&text, end), false);
+        }
+    }
+
+    /// Saves text to a register; `yank` also fills `"0`
+    fn store(&self, register: Option<char>, text: String, linewise: bool, yank: bool) {
+        let name = register.unwrap_or(UNNAMED);
+        if name == '_' {
+            return;
+        }
+        if name == '+' || name == '*' {
+            let _ = floem::Clipboard::set_contents(text);
+            return;
+        }
+
+        let mut state = self.state.borrow_mut();
+        let value = if name.is_ascii_uppercase() {
+            let lower = name.to_ascii_lowercase();
+            match state.registers.get(&lower) {
+                Some(existing) => Register {
+                    text: existing.text.clone() + &text,
+                    linewise: existing.linewise || linewise,
+                },
+                None => Register { text, linewise },
+            }
+        } else {
+            Register { text, linewise }
+        };
+        if name != UNNAMED {
+            state
+                .registers
+                .insert(name.to_ascii_lowercase(), value.clone());
+        }
+        if yank && name == UNNAMED {
+            state.registers.insert('0', value.clone());
+        }
+        state.registers.insert(UNNAMED, value);
+    }
+
+    /// The range an operator acts on, as `(start, end, linewise)`
+    fn motion_range(
+        &self,
+        text: &Rope,
+        pos: usize,
+        op: Operator,
+        motion: Motion,
+        count: Option<usize>,
+    ) -> (usize, usize, bool) {
+        // `cw` on a word changes to the end of the word, like `ce`
+        let motion = match (op, motion, char_at(text, pos)) {
+            (Operator::Change, Motion::WordForward, Some(c)) if !c.is_whitespace() => {
+                Motion::WordEnd
+            }
+            _ => motion,
+        };
+        let target = self.motion_target(text, pos, motion, count);
+        let (min, max) = (pos.min(target), pos.max(target));
+
+        match motion.kind() {
+            MotionKind::Linewise => {
+                let (start, end) = line_range(text, line_of(text, min), line_of(text, max));
+                (start, end, true)
+            }
+            MotionKind::Inclusive => (min, next_char(text, max), false),
+            MotionKind::Exclusive => {
+                // An exclusive motion that ends at the start of a later line
+                // stops at the end of the line before it
+                let end = if max > min
+                    && max == line_start(text, max)
+                    && line_of(text, min) != line_of(text, max)
+                {
+                    max - 1
+                } else {
+                    max
+                };
+                (min, end, false)
+            }
+        }
+    }
+
+    fn motion_target(
+        &self,
+        text: &Rope,
+        pos: usize,
+        motion: Motion,
+        count: Option<usize>,
+    ) -> usize {
+        let n = count.unwrap_or(1);
+        let mut state = self.state.borrow_mut();
+        if !matches!(motion, Motion::Up | Motion::Down) {
+            state.col = None;
+        }
+
+        // Stops once the motion gets stuck, so a huge count ends at the
+        // edge of the text instead of spinning
+        let repeat = |f: &dyn Fn(usize) -> usize| {
+            let mut offset = pos;
+            for _ in 0..n {
+                let next = f(offset);
+                if next == offset {
+                    break;
+                }
+                offset = next;
+            }
+            offset
+        };
+        match motion {
+            Motion::Left => {
+                let start = line_start(text, pos);
+                repeat(&|offset| {
+                    if offset > start {
+                        prev_char(text, offset)
+                    } else {
+                        offset
+                    }
+                })
+            }
+            Motion::Right => {
+                let end = line_end(text, pos);
+                repeat(&|offset| {
+                    if offset < end {
+                        next_char(text, offset)
+                    } else {
+                        offset
+                    }
+                })
+            }
+            Motion::Up | Motion::Down => {
+                let line = line_of(text, pos);
+                let target = if motion == Motion::Up {
+                    line.saturating_sub(n)
+                } else {
+                    line.saturating_add(n).min(line_count(text) - 1)
+                };
+                let col = *state
+                    .col
+                    .get_or_insert_with(|| char_indices(text, line_start(text, pos), pos).count());
+                offset_at_col(text, target, col)
+            }
+            Motion::WordForward => repeat(&|offset| word_forward(text, offset)),
+            Motion::WordBackward => repeat(&|offset| word_backward(text, offset)),
+            Motion::WordEnd => repeat(&|offset| word_end(text, offset)),
+            Motion::LineStart => line_start(text, pos),
+            Motion::FirstNonBlank => first_non_blank(text, pos),
+            Motion::LineEnd => {
+                state.col = Some(usize::MAX);
+                let line = line_of(text, pos)
+                    .saturating_add(n - 1)
+                    .min(line_count(text) - 1);
+                line_end(text, offset_of_line(text, line))
+            }
+            Motion::FirstLine | Motion::LastLine => {
+                let line = match (motion, count) {
+                    (_, Some(n)) => n.saturating_sub(1).min(line_count(text) - 1),
+                    (Motion::FirstLine, None) => 0,
+                    _ => line_count(text) - 1,
+                };
+                offset_of_line(text, line)
+            }
+        }
+    }
+
+    /// Repeats the last change, with `count` replacing its count if given
+    fn repeat(&self, doc: &Document, count: Option<usize>) {
+        let Some(mut change) = self.state.borrow().last_change.clone() else {
+            return;
+        };
+        if count.is_some() {
+            change.command.count = count;
+        }
+
+        doc.begin_compound_edit();
+        let enters_insert = self.change(doc, change.command);
+        if enters_insert {
+            if let Some(inserted) = &change.inserted {
+                let times = match change.command.action {
+                    Action::Insert(_) => change.command.count.unwrap_or(1),
+                    _ => 1,
+                };
+                if let Some(inserted) = repeated(inserted, times) {
+                    doc.insert_text(&inserted);
+                }
+            }
+            self.leave_insert(doc);
+        }
+        doc.end_compound_edit();
+        self.state.borrow_mut().last_change = Some(change);
+    }
+
+    /// Leaves insert mode, recording what was typed for `.`
+    fn finish_insert(&self, doc: &Document) {
+        let session = self.state.borrow_mut().insert.take();
+        if let Some(session) = session {
+            let text = rope(doc);
+            let inserted = text
+                .len()
+                .checked_sub(session.len_before)
+                .map(|added| session.start + added)
+                .filter(|&end| end <= text.len() && is_char_boundary(&text, end))
+                .map(|end| text.slice_to_cow(session.start..end).into_owned());
+
+            // A count on an insert command repeats the typed text
+            let repeats = match (session.command.action, &inserted, session.command.count) {
+                (Action::Insert(_), Some(inserted), Some(count @ 2..)) => {
+                    repeated(inserted, count - 1)
+                }
+                _ => None,
+            };
+            if let Some(repeats) = repeats {
+                doc.insert_text(&repeats);
+            }
+            if !matches!(session.command.action, Action::VisualOperate(_)) {
+                self.state.borrow_mut().last_change = Some(Change {
+                    command: session.command,
+                    inserted,
+                });
+            }
+        }
+        self.leave_insert(doc);
+        doc.end_compound_edit();
+    }
+
+    /// Switches from insert to normal mode, moving the cursor back onto the
+    /// last typed character
+    fn leave_insert(&self, doc: &Document) {
+        let text = rope(doc);
+        let pos = doc.cursor().get_untracked().end.min(text.len());
+        let pos = if pos > line_start(&text, pos) {
+            prev_char(&text, pos)
+        } else {
+            pos
+        };
+        doc.set_offset(clamp_normal(&text, pos), false);
+        self.mode.set(Mode::Normal);
+    }
+
+    fn enter_normal(&self, doc: &Document) {
+        let text = rope(doc);
+        let pos = match self.mode.get_untracked() {
+            Mode::Visual(_) => self.state.borrow().head,
+            _ => doc.cursor().get_untracked().end,
+        };
+        doc.set_offset(clamp_normal(&text, pos.min(text.len())), false);
+        self.mode.set(Mode::Normal);
+    }
+
+    fn toggle_visual(&self, doc: &Document, visual: VisualMode) {
+        match self.mode.get_untracked() {
+            Mode::Visual(current) if current == visual => self.enter_normal(doc),
+            Mode::Visual(_) => {
+                self.mode.set(Mode::Visual(visual));
+                self.update_selection(doc);
+            }
+            _ => {
+                let text = rope(doc);
+                let pos = self.cursor(doc, &text);
+                let mut state = self.state.borrow_mut();
+                state.anchor = pos;
+                state.head = pos;
+                drop(state);
+                self.mode.set(Mode::Visual(visual));
+                self.update_selection(doc);
+            }
+        }
+    }
+
+    /// Selects the visual range in the document
+    fn update_selection(&self, doc: &Document) {
+        let text = rope(doc);
+        let state = self.state.borrow();
+        let (anchor, head) = (state.anchor, state.head);
+        drop(state);
+
+        let (from, to) = if self.mode.get_untracked() == Mode::Visual(VisualMode::Linewise) {
+            let (first, last) = (line_of(&text, anchor), line_of(&text, head));
+            if head >= anchor {
+                (
+                    offset_of_line(&text, first),
+                    line_end(&text, offset_of_line(&text, last)),
+                )
+            } else {
+                (
+                    line_end(&text, offset_of_line(&text, first)),
+                    offset_of_line(&text, last),
+                )
+            }
+        } else if head >= anchor {
+            (anchor, next_char(&text, head))
+        } else {
+            (next_char(&text, anchor), head)
+        };
+        doc.set_offset(from, false);
+        doc.set_offset(to, true);
+    }
+
+    /// The normal-mode cursor, kept on a character
+    fn cursor(&self, doc: &Document, text: &Rope) -> usize {
+        clamp_normal(text, doc.cursor().get_untracked().end.min(text.len()))
+    }
+}
+
+/// Translates a key press into the character Vim sees, if any
+fn key_char(key: &Key, modifiers: &Modifiers) -> Option<char> {
+    let c = match key {
+        Key::Named(NamedKey::Escape) => ESCAPE,
+        Key::Named(NamedKey::ArrowLeft | NamedKey::Backspace) => 'h',
+        Key::Named(NamedKey::ArrowRight) => 'l',
+        Key::Named(NamedKey::ArrowUp) => 'k',
+        Key::Named(NamedKey::ArrowDown | NamedKey::Enter) => 'j',
+        Key::Named(NamedKey::Delete) => 'x',
+        Key::Named(NamedKey::Home) => '0',
+        Key::Named(NamedKey::End) => '$',
+        Key::Character(s) => {
+            let mut chars = s.chars();
+            let (Some(c), None) = (chars.next(), chars.next()) else {
+                return None;
+            };
+            if modifiers.ctrl() {
+                return match c.to_ascii_lowercase() {
+                    'r' => Some(CTRL_R),
+                    '[' => Some(ESCAPE),
+                    _ => None,
+                };
+            }
+            if modifiers.alt() || modifiers.meta() {
+                return None;
+            }
+            if modifiers.shift() {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        }
+        _ => return None,
+    };
+    if matches!(key, Key::Named(_)) && (modifiers.ctrl() || modifiers.alt() || modifiers.meta()) {
+        return None;
+    }
+    Some(c)
+}
+
+/// Parses the keys of a command typed so far
+fn parse(keys: &[char], visual: bool) -> Parse<NormalCommand> {
+    let mut i = 0;
+    let mut register = None;
+    if keys.first() == Some(&'"') {
+        match keys.get(1) {
+            None => return Parse::Incomplete,
+            Some(&name) if name.is_ascii_alphanumeric() || "\"_+*".contains(name) => {
+                register = Some(name);
+                i = 2;
+            }
+            Some(_) => return Parse::Invalid,
+        }
+    }
+    let (count, n) = parse_count(&keys[i..]);
+    i += n;
+    let Some(&c) = keys.get(i) else {
+        return Parse::Incomplete;
+    };
+    let rest = &keys[i + 1..];
+
+    let operator = match c {
+        'd' => Some(Operator::Delete),
+        'c' => Some(Operator::Change),
+        'y' => Some(Operator::Yank),
+        _ => None,
+    };
+    let action = match (c, operator) {
+        (_, Some(op)) if visual => Action::VisualOperate(op),
+        (_, Some(op)) => {
+            let (motion_count, n) = parse_count(rest);
+            let rest = &rest[n..];
+            let target = match rest {
+                [] => return Parse::Incomplete,
+                [k] if *k == c => Target::Lines,
+                _ => match parse_motion(rest) {
+                    Parse::Complete(motion) => Target::Motion(motion),
+                    Parse::Incomplete => return Parse::Incomplete,
+                    Parse::Invalid => return Parse::Invalid,
+                },
+            };
+            let count = match (count, motion_count) {
+                (None, None) => None,
+                (a, b) => Some(a.unwrap_or(1).saturating_mul(b.unwrap_or(1)).min(MAX_COUNT)),
+            };
+            return Parse::Complete(NormalCommand {
+                register,
+                count,
+                action: Action::Operate(op, target),
+            });
+        }
+        ('x', _) if visual => Action::VisualOperate(Operator::Delete),
+        ('s', _) if visual => Action::VisualOperate(Operator::Change),
+        ('o', _) if visual => Action::SwapAnchor,
+        (ESCAPE, _) => Action::Escape,
+        ('x', _) => Action::DeleteChar { before: false },
+        ('X', _) => Action::DeleteChar { before: true },
+        ('s', _) => Action::Operate(Operator::Change, Target::Motion(Motion::Right)),
+        ('D', _) => Action::Operate(Operator::Delete, Target::Motion(Motion::LineEnd)),
+        ('C', _) => Action::Operate(Operator::Change, Target::Motion(Motion::LineEnd)),
+        ('Y', _) => Action::Operate(Operator::Yank, Target::Lines),
+        ('p', _) => Action::Paste { before: false },
+        ('P', _) => Action::Paste { before: true },
+        ('i', _) => Action::Insert(InsertAt::Cursor),
+        ('a', _) => Action::Insert(InsertAt::AfterCursor),
+        ('I', _) => Action::Insert(InsertAt::LineStart),
+        ('A', _) => Action::Insert(InsertAt::LineEnd),
+        ('o', _) => Action::Insert(InsertAt::LineBelow),
+        ('O', _) => Action::Insert(InsertAt::LineAbove),
+        ('v', _) => Action::Visual(VisualMode::Normal),
+        ('V', _) => Action::Visual(VisualMode::Linewise),
+        ('u', _) => Action::Undo,
+        (CTRL_R, _) => Action::Redo,
+        ('.', _) => Action::Repeat,
+        _ => match parse_motion(&keys[i..]) {
+            Parse::Complete(motion) => Action::Move(motion),
+            Parse::Incomplete => return Parse::Incomplete,
+            Parse::Invalid => return Parse::Invalid,
+        },
+    };
+    if matches!(action, Action::Move(_)) || rest.is_empty() {
+        Parse::Complete(NormalCommand {
+            register,
+            count,
+            action,
+        })
+    } else {
+        Parse::Invalid
+    }
+}
+
+/// Parses a count, capped at [`MAX_COUNT`]; a leading `0` is the line-start
+/// motion instead
+fn parse_count(keys: &[char]) -> (Option<usize>, usize) {
+    if keys.first().is_none_or(|c| !('1'..='9').contains(c)) {
+        return (None, 0);
+    }
+    let digits = keys.iter().take_while(|c| c.is_ascii_digit()).count();
+    let count = keys[..digits].iter().fold(0usize, |n, c| {
+        n.saturating_mul(10)
+            .saturating_add(c.to_digit(10).unwrap_or(0) as usize)
+    });
+    (Some(count.min(MAX_COUNT)), digits)
+}
+
+fn parse_motion(keys: &[char]) -> Parse<Motion> {
+    let motion = match keys {
+        [] | ['g'] => return Parse::Incomplete,
+        ['h'] => Motion::Left,
+        ['l'] => Motion::Right,
+        ['k'] => Motion::Up,
+        ['j'] => Motion::Down,
+        ['w'] => Motion::WordForward,
+        ['b'] => Motion::WordBackward,
+        ['e'] => Motion::WordEnd,
+        ['0'] => Motion::LineStart,
+        ['^'] => Motion::FirstNonBlank,
+        ['$'] => Motion::LineEnd,
+        ['g', 'g'] => Motion::FirstLine,
+        ['G'] => Motion::LastLine,
+        _ => return Parse::Invalid,
+    };
+    Parse::Complete(motion)
+}
+
+fn replace(doc: &Document, start: usize, end: usize, text: &Rope) {
+    let edit_type = if text.is_empty() {
+        EditType::Delete
+    } else {
+        EditType::InsertChars
+    };
+    doc.edit(
+        [(
+            SelRegion::new(start, end, CursorAffinity::Forward, None),
+            text,
+        )],
+        edit_type,
+    );
+}
+
+/// The document's text; cloning the rope doesn't copy it
+fn rope(doc: &Document) -> Rope {
+    doc.buffer().with_untracked(|buffer| buffer.text().clone())
+}
+
+/// `text` repeated `count` times, or `None` if that would not fit in memory
+fn repeated(text: &str, count: usize) -> Option<String> {
+    text.len()
+        .checked_mul(count)
+        .filter(|&len| len <= isize::MAX as usize)?;
+    Some(text.repeat(count))
+}
+
+// ============================================================================
+// Text navigation, on byte offsets into the rope
+// ============================================================================
+
+fn next_char(text: &Rope, offset: usize) -> usize {
+    text.next_codepoint_offset(offset).unwrap_or(offset)
+}
+
+fn prev_char(text: &Rope, offset: usize) -> usize {
+    text.prev_codepoint_offset(offset).unwrap_or(offset)
+}
+
+fn char_at(text: &Rope, offset: usize) -> Option<char> {
+    text.iter_chunks(offset..).flat_map(str::chars).next()
+}
+
+/// The characters in `start..end`, with their offsets
+fn char_indices(text: &Rope, start: usize, end: usize) -> impl Iterator<Item = (usize, char)> + '_ {
+    text.iter_chunks(start..end)
+        .flat_map(str::chars)
+        .scan(start, |offset, c| {
+            let at = *offset;
+            *offset += c.len_utf8();
+            Some((at, c))
+        })
+}
+
+/// Returns true if `offset` doesn't split a multi-byte character
+fn is_char_boundary(text: &Rope, offset: usize) -> bool {
+    offset >= text.len() || text.byte_at(offset) & 0xc0 != 0x80
+}
+
+/// Returns true if `start..end` ends with a newline
+fn ends_with_newline(text: &Rope, start: usize, end: usize) -> bool {
+    end > start && char_at(text, prev_char(text, end)) == Some('\n')
+}
+
+fn line_start(text: &Rope, offset: usize) -> usize {
+    text.offset_of_line(text.line_of_offset(offset))
+}
+
+/// The offset of the newline ending the line, or the end of the text
+fn line_end(text: &Rope, offset: usize) -> usize {
+    let next = line_of(text, offset) + 1;
+    if next < line_count(text) {
+        text.offset_of_line(next) - 1
+    } else {
+        text.len()
+    }
+}
+
+fn line_of(text: &Rope, offset: usize) -> usize {
+    text.line_of_offset(offset)
+}
+
+fn line_count(text: &Rope) -> usize {
+    text.line_of_offset(text.len()) + 1
+}
+
+fn offset_of_line(text: &Rope, line: usize) -> usize {
+    if line < line_count(text) {
+        text.offset_of_line(line)
+    } else {
+        text.len()
+    }
+}
+
+fn offset_at_col(text: &Rope, line: usize, col: usize) -> usize {
+    let start = offset_of_line(text, line);
+    let end = line_end(text, start);
+    char_indices(text, start, end)
+        .nth(col)
+        .map_or(end, |(i, _)| i)
+}
+
+fn first_non_blank(text: &Rope, offset: usize) -> usize {
+    let start = line_start(text, offset);
+    let end = line_end(text, start);
+    char_indices(text, start, end)
+        .find(|(_, c)| !c.is_whitespace())
+        .map_or(end, |(i, _)| i)
+}
+
+/// The byte range of lines `first..=last`, including their newlines
+fn line_range(text: &Rope, first: usize, last: usize) -> (usize, usize) {
+    (offset_of_line(text, first), offset_of_line(text, last + 1))
+}
+
+/// Keeps a normal-mode cursor on a character rather than after the last one
+fn clamp_normal(text: &Rope, offset: usize) -> usize {
+    let start = line_start(text, offset);
+    let end = line_end(text, offset);
+    if offset >= end && end > start {
+        prev_char(text, end)
+    } else {
+        offset
+    }
+}
+
+/// Whitespace, punctuation and word characters form separate words
+fn char_class(c: char) -> u8 {
+    if c.is_whitespace() {
+        0
+    } else if c.is_alphanumeric() || c == '_' {
+        2
+    } else {
+        1
+    }
+}
+
+/// `w`: the start of the next word; empty lines count as words
+fn word_forward(text: &Rope, offset: usize) -> usize {
+    let Some(first) = char_at(text, offset) else {
+        return offset;
+    };
+    let mut i = offset;
+    let class = char_class(first);
+    if class != 0 {
+        while char_at(text, i).is_some_and(|c| char_class(c) == class) {
+            i = next_char(text, i);
+        }
+    }
+    while let Some(c) = char_at(text, i) {
+        if char_class(c) != 0 || (c == '\n' && i != offset && line_start(text, i) == i) {
+            break;
+        }
+        i = next_char(text, i);
+    }
+    i
+}
+
+/// `b`: the start of the previous word
+fn word_backward(text: &Rope, offset: usize) -> usize {
+    let mut i = offset;
+    loop {
+        let prev = prev_char(text, i);
+        if prev == i {
+            return 0;
+        }
+        i = prev;
+        if char_at(text, i).is_some_and(|c| char_class(c) != 0) {
+            break;
+        }
+    }
+    let class = char_at(text, i).map_or(0, char_class);
+    loop {
+        let prev = prev_char(text, i);
+        if prev == i || char_at(text, prev).is_none_or(|c| char_class(c) != class) {
+            return i;
+        }
+        i = prev;
+    }
+}
+
+/// `e`: the last character of the current or next word
+fn word_end(text: &Rope, offset: usize) -> usize {
+    let mut i = next_char(text, offset);
+    while char_at(text, i).is_some_and(|c| char_class(c) == 0) {
+        i = next_char(text, i);
+    }
+    let Some(c) = char_at(text, i) else {
+        return prev_char(text, text.len()).max(offset.min(text.len()));
+    };
+    let class = char_class(c);
+    loop {
+        let next = next_char(text, i);
+        if char_at(text, next).is_none_or(|c| char_class(c) != class) {
+            return i;
+        }
+        i = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Feeds `keys` to Vim; `<esc>` is Escape and `<c-r>` is Ctrl+R
+    fn type_keys(vim: &Vim, doc: &Document, keys: &str) {
+        let mut rest = keys;
+        while let Some(c) = rest.chars().next() {
+            let (key, modifiers, len) = if rest.starts_with("<esc>") {
+                (Key::Named(NamedKey::Escape), Modifiers::default(), 5)
+            } else if rest.starts_with("<c-r>") {
+                (Key::Character("r".into()), Modifiers::CONTROL, 5)
+            } else {
+                (
+                    Key::Character(c.to_string().into()),
+                    Modifiers::default(),
+                    c.len_utf8(),
+                )
+            };
+            if !vim.handle_key(doc, &key, &modifiers, false) {
+                // Insert mode: type the character like the text area would
+                if let Key::Character(c) = &key {
+                    doc.insert_text(c);
+                }
+            }
+            rest = &rest[len..];
+        }
+    }
+
+    fn vim(text: &str) -> (Vim, Document) {
+        let doc = Document::new(text);
+        let vim = Vim::new();
+        vim.set_enabled(&doc, true);
+        (vim, doc)
+    }
+
+    fn cursor(doc: &Document) -> usize {
+        doc.cursor().get_untracked().end
+    }
+
+    #[test]
+    fn test_starts_in_normal_mode() {
+        let (vim, doc) = vim("hello");
+        assert_eq!(vim.mode().get_untracked(), Mode::Normal);
+        type_keys(&vim, &doc, "x");
+        assert_eq!(doc.text(), "ello", "Keys are commands, not text");
+    }
+
+    #[test]
+    fn test_word_motions() {
+        let (vim, doc) = vim("foo bar.baz qux");
+        type_keys(&vim, &doc, "w");
+        assert_eq!(cursor(&doc), 4);
+        type_keys(&vim, &doc, "w");
+        assert_eq!(cursor(&doc), 7, "Punctuation is its own word");
+        type_keys(&vim, &doc, "e");
+        assert_eq!(cursor(&doc), 10);
+        type_keys(&vim, &doc, "e");
+        assert_eq!(cursor(&doc), 14);
+        type_keys(&vim, &doc, "2b");
+        assert_eq!(cursor(&doc), 8);
+        type_keys(&vim, &doc, "$");
+        assert_eq!(cursor(&doc), 14, "$ stays on the last character");
+        type_keys(&vim, &doc, "0");
+        assert_eq!(cursor(&doc), 0);
+    }
+
+    #[test]
+    fn test_line_motions() {
+        let (vim, doc) = vim("one\n  two\nthree");
+        type_keys(&vim, &doc, "G");
+        assert_eq!(cursor(&doc), 10);
+        type_keys(&vim, &doc, "gg");
+        assert_eq!(cursor(&doc), 0);
+        type_keys(&vim, &doc, "2G");
+        assert_eq!(cursor(&doc), 6, "G goes to the first non-blank");
+        type_keys(&vim, &doc, "$k");
+        assert_eq!(cursor(&doc), 2, "j and k keep the end of line");
+    }
+
+    #[test]
+    fn test_operators_with_counts() {
+        let (vim, doc) = vim("one two three four");
+        type_keys(&vim, &doc, "2dw");
+        assert_eq!(doc.text(), "three four");
+        type_keys(&vim, &doc, "d2e");
+        assert_eq!(doc.text(), "");
+
+        let (vim, doc) = vim("a\nb\nc\nd");
+        type_keys(&vim, &doc, "j2dd");
+        assert_eq!(doc.text(), "a\nd");
+        type_keys(&vim, &doc, "Gdd");
+        assert_eq!(doc.text(), "a");
+    }
+
+    #[test]
+    fn test_change_and_dot_repeat() {
+        let (vim, doc) = vim("foo foo foo");
+        type_keys(&vim, &doc, "cwbar<esc>");
+        assert_eq!(doc.text(), "bar foo foo");
+        assert_eq!(vim.mode().get_untracked(), Mode::Normal);
+        assert_eq!(
+            cursor(&doc),
+            2,
+            "Escape steps back onto the last typed char"
+        );
+
+        type_keys(&vim, &doc, "w.");
+        assert_eq!(doc.text(), "bar bar foo");
+        type_keys(&vim, &doc, "w.");
+        assert_eq!(doc.text(), "bar bar bar");
+
+        type_keys(&vim, &doc, "0x");
+        type_keys(&vim, &doc, "3.");
+        assert_eq!(doc.text(), "bar bar", "A count replaces the repeated count");
+    }
+
+    #[test]
+    fn test_change_undoes_as_one_step() {
+        let (vim, doc) = vim("hello world");
+        type_keys(&vim, &doc, "cwbye<esc>");
+        assert_eq!(doc.text(), "bye world");
+        type_keys(&vim, &doc, "u");
+        assert_eq!(doc.text(), "hello world");
+        type_keys(&vim, &doc, "<c-r>");
+        assert_eq!(doc.text(), "bye world");
+    }
+
+    #[test]
+    fn test_yank_and_paste_registers() {
+        let (vim, doc) = vim("one\ntwo");
+        type_keys(&vim, &doc, "yyjp");
+        assert_eq!(doc.text(), "one\ntwo\none");
+        assert_eq!(vim.register('0').as_deref(), Some("one\n"));
+
+        type_keys(&vim, &doc, "gg\"ayw");
+        assert_eq!(vim.register('a').as_deref(), Some("one"));
+        type_keys(&vim, &doc, "\"Ayy");
+        assert_eq!(vim.register('a').as_deref(), Some("oneone\n"));
+
+        type_keys(&vim, &doc, "G\"_dd");
+        assert_eq!(doc.text(), "one\ntwo");
+        assert_eq!(
+            vim.register('"').as_deref(),
+            Some("oneone\n"),
+            "The black hole register keeps the unnamed register"
+        );
+
+        type_keys(&vim, &doc, "gg\"ap");
+        assert_eq!(doc.text(), "one\noneone\ntwo");
+    }
+
+    #[test]
+    fn test_charwise_paste() {
+        let (vim, doc) = vim("abc");
+        type_keys(&vim, &doc, "xp");
+        assert_eq!(doc.text(), "bac");
+        assert_eq!(cursor(&doc), 1);
+        type_keys(&vim, &doc, "P");
+        assert_eq!(doc.text(), "baac");
+    }
+
+    #[test]
+    fn test_insert_commands() {
+        let (vim, doc) = vim("  mid");
+        type_keys(&vim, &doc, "A!<esc>");
+        assert_eq!(doc.text(), "  mid!");
+        type_keys(&vim, &doc, "I><esc>");
+        assert_eq!(doc.text(), "  >mid!");
+        type_keys(&vim, &doc, "obelow<esc>");
+        assert_eq!(doc.text(), "  >mid!\nbelow");
+        type_keys(&vim, &doc, "Oabove<esc>");
+        assert_eq!(doc.text(), "  >mid!\nabove\nbelow");
+        type_keys(&vim, &doc, "gg3a-<esc>");
+        assert_eq!(doc.text(), "  >---mid!\nabove\nbelow");
+    }
+
+    #[test]
+    fn test_visual_mode() {
+        let (vim, doc) = vim("hello world");
+        type_keys(&vim, &doc, "vl");
+        assert_eq!(vim.mode().get_untracked(), Mode::Visual(VisualMode::Normal));
+        let selection = doc.cursor().get_untracked();
+        assert_eq!((selection.min(), selection.max()), (0, 2));
+
+        type_keys(&vim, &doc, "ey");
+        assert_eq!(vim.register('"').as_deref(), Some("hello"));
+        assert_eq!(vim.mode().get_untracked(), Mode::Normal);
+
+        type_keys(&vim, &doc, "wvd");
+        assert_eq!(doc.text(), "hello orld");
+
+        let (vim, doc) = vim("a\nb\nc");
+        type_keys(&vim, &doc, "Vjd");
+        assert_eq!(doc.text(), "c");
+    }
+
+    #[test]
+    fn test_huge_counts_stop_at_the_text_edges() {
+        let (vim, doc) = vim("one\ntwo\nthree");
+        type_keys(&vim, &doc, "99999999999999999999999j");
+        assert_eq!(cursor(&doc), 8);
+        type_keys(&vim, &doc, "gg99999999999999999999$");
+        assert_eq!(cursor(&doc), 12);
+        type_keys(&vim, &doc, "gg99999999999999999999x");
+        assert_eq!(doc.text(), "\ntwo\nthree");
+        type_keys(&vim, &doc, "99999999999999999999u");
+        assert_eq!(doc.text(), "one\ntwo\nthree");
+        type_keys(&vim, &doc, "99999999999d99999999999j");
+        assert_eq!(doc.text(), "");
+        type_keys(&vim, &doc, "99999999999999999999<c-r>");
+        assert_eq!(doc.text(), "");
+    }
+
+    #[test]
+    fn test_escape_passes_through_in_normal_mode() {
+        let (vim, doc) = vim("abc");
+        let escape = Key::Named(NamedKey::Escape);
+        assert!(!vim.handle_key(&doc, &escape, &Modifiers::default(), false));
+
+        type_keys(&vim, &doc, "d");
+        assert!(
+            vim.handle_key(&doc, &escape, &Modifiers::default(), false),
+            "Escape cancels a pending command"
+        );
+        type_keys(&vim, &doc, "w");
+        assert_eq!(doc.text(), "abc");
+    }
+
+    #[test]
+    fn test_read_only_allows_motions_and_yanks() {
+        let (vim, doc) = vim("one two");
+        for c in ["w", "y", "w", "d", "d", "i"] {
+            vim.handle_key(&doc, &Key::Character(c.into()), &Modifiers::default(), true);
+        }
+        assert_eq!(doc.text(), "one two");
+        assert_eq!(vim.register('"').as_deref(), Some("two"));
+        assert_eq!(vim.mode().get_untracked(), Mode::Normal);
+    }
+}
@@ -3,6 +3,39 @@
 //! Based on shadcn/ui CSS variables approach with support for light and dark modes.
 //! Integrates with floem's style system via inherited props for automatic theme propagation.
 //! Uses OKLCH color values directly from shadcn/ui for accurate color reproduction.
+//! Every official shadcn/ui color theme is available through [`ThemePreset`], and
+//! themes exported from shadcn/ui can be imported with [`ThemePair::from_css`].
+//! [`ThemeProvider`] follows the OS color scheme and animates theme switches.
+//! [`ThemeGenerator`] derives a whole theme from a single brand color.
+//! Besides colors, themes carry the font size, line height, spacing, control
+//! height and shadow scales that components are sized with, adjusted per
+//! subtree by [`Density`]. High-contrast and forced-colors variants are
+//! marked with [`ThemeContrast`].
+
+#[cfg(feature = "serde")]
+mod config;
+pub mod contrast;
+mod css;
+mod density;
+mod generate;
+mod high_contrast;
+mod presets;
+mod provider;
+mod scale;
+
+#[cfg(feature = "serde")]
+pub use config::{ThemeConfigError, ThemeFile, ThemeOverride, TokenOverrides};
+pub use css::ThemeCssError;
+pub use density::{Density, DensityProp};
+pub use generate::{DEFAULT_NEUTRAL_CHROMA, ThemeGenerator};
+pub use high_contrast::{DEFAULT_RING_WIDTH, HIGH_CONTRAST_RING_WIDTH, ThemeContrast};
+pub use presets::ThemePreset;
+pub use provider::{DEFAULT_THEME_TRANSITION, ThemeProvider, ThemeState};
+pub use scale::{
+    ControlHeightScale, DEFAULT_SPACING, FontSizeScale, LineHeightScale, Shadow, ShadowScale,
+};
+
+use std::rc::Rc;
 
 use floem::prop;
 use floem::style::{Style, StylePropValue};
@@ -24,63 +57,163 @@ fn oklcha(l: f32, c: f32, h: f32, a: f32) -> Color {
     AlphaColor::<Oklch>::new([l, c, h, a]).convert::<Srgb>()
 }
 
-/// Theme mode (light or dark)
+/// Theme mode (light, dark or following the OS)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum ThemeMode {
     #[default]
     Light,
     Dark,
+    /// Follow the OS color scheme; resolved by [`ThemeProvider`]
+    System,
+}
+
+impl ThemeMode {
+    /// Resolve `System` to the OS color scheme `system`
+    ///
+    /// `Light` and `Dark` are returned unchanged. An unknown OS color scheme
+    /// (`system` is itself `System`) resolves to `Light`.
+    pub fn resolve(self, system: ThemeMode) -> ThemeMode {
+        match (self, system) {
+            (ThemeMode::System, ThemeMode::Dark) => ThemeMode::Dark,
+            (ThemeMode::System, _) => ThemeMode::Light,
+            (mode, _) => mode,
+        }
+    }
+
+    /// Returns true for `Dark`
+    pub fn is_dark(self) -> bool {
+        self == ThemeMode::Dark
+    }
 }
 
 /// Design tokens for shadcn-style theming
 ///
 /// All colors follow the shadcn/ui convention with background/foreground pairs.
+/// With the `serde` feature, colors are (de)serialized as CSS color strings.
+///
+/// The size scales default to the Tailwind values shadcn/ui is designed with;
+/// components read them instead of hardcoding pixel sizes.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShadcnTheme {
     pub mode: ThemeMode,
+    /// Contrast level; see [`ShadcnTheme::is_high_contrast`]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub contrast: ThemeContrast,
 
     // Base colors
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub background: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub foreground: Color,
 
     // Card
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub card: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub card_foreground: Color,
 
     // Popover
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub popover: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub popover_foreground: Color,
 
     // Primary (typically used for buttons, links)
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub primary: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub primary_foreground: Color,
 
     // Secondary
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub secondary: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub secondary_foreground: Color,
 
     // Muted (for disabled or subtle elements)
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub muted: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub muted_foreground: Color,
 
     // Accent (for hover states)
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub accent: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub accent_foreground: Color,
 
     // Destructive (for error/delete actions)
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub destructive: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub destructive_foreground: Color,
 
     // Border and input
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub border: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub input: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
     pub ring: Color,
 
+    // Chart palette for data visualisation
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
+    pub chart_1: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
+    pub chart_2: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
+    pub chart_3: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
+    pub chart_4: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
+    pub chart_5: Color,
+
+    // Sidebar
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
+    pub sidebar: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
+    pub sidebar_foreground: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
+    pub sidebar_primary: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
+    pub sidebar_primary_foreground: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
+    pub sidebar_accent: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
+    pub sidebar_accent_foreground: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
+    pub sidebar_border: Color,
+    #[cfg_attr(feature = "serde", serde(with = "config::color"))]
+    pub sidebar_ring: Color,
+
     // Radius values
     pub radius: f32,
     pub radius_sm: f32,
     pub radius_md: f32,
     pub radius_lg: f32,
+
+    /// Width of focus rings in pixels
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "high_contrast::default_ring_width")
+    )]
+    pub ring_width: f32,
+
+    // Size scales
+    /// Spacing unit in pixels; see [`ShadcnTheme::space`]
+    #[cfg_attr(feature = "serde", serde(default = "scale::default_spacing"))]
+    pub spacing: f32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub font_size: FontSizeScale,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub line_height: LineHeightScale,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub control_height: ControlHeightScale,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub shadow: ShadowScale,
 }
 
 impl Default for ShadcnTheme {
@@ -90,88 +223,32 @@ impl Default for ShadcnTheme {
 }
 
 impl ShadcnTheme {
-    /// Create a light theme (shadcn default)
-    /// Colors from: https://ui.shadcn.com/themes (Zinc, default)
+    /// Create a light theme (shadcn default, neutral base color)
     pub fn light() -> Self {
-        Self {
-            mode: ThemeMode::Light,
-
-            background: oklch(1.0, 0.0, 0.0), // --background: oklch(1 0 0)
-            foreground: oklch(0.145, 0.0, 0.0), // --foreground: oklch(0.145 0 0)
-
-            card: oklch(1.0, 0.0, 0.0), // --card: oklch(1 0 0)
-            card_foreground: oklch(0.145, 0.0, 0.0), // --card-foreground: oklch(0.145 0 0)
-
-            popover: oklch(1.0, 0.0, 0.0), // --popover: oklch(1 0 0)
-            popover_foreground: oklch(0.145, 0.0, 0.0), // --popover-foreground: oklch(0.145 0 0)
-
-            primary: oklch(0.205, 0.0, 0.0), // --primary: oklch(0.205 0 0)
-            primary_foreground: oklch(0.985, 0.0, 0.0), // --primary-foreground: oklch(0.985 0 0)
-
-            secondary: oklch(0.97, 0.0, 0.0), // --secondary: oklch(0.97 0 0)
-            secondary_foreground: oklch(0.205, 0.0, 0.0), // --secondary-foreground: oklch(0.205 0 0)
-
-            muted: oklch(0.97, 0.0, 0.0), // --muted: oklch(0.97 0 0)
-            muted_foreground: oklch(0.556, 0.0, 0.0), // --muted-foreground: oklch(0.556 0 0)
-
-            accent: oklch(0.97, 0.0, 0.0), // --accent: oklch(0.97 0 0)
-            accent_foreground: oklch(0.205, 0.0, 0.0), // --accent-foreground: oklch(0.205 0 0)
-
-            destructive: oklch(0.577, 0.245, 27.325), // --destructive: oklch(0.577 0.245 27.325)
-            destructive_foreground: oklch(0.985, 0.0, 0.0), // --destructive-foreground: oklch(0.985 0 0)
-
-            border: oklch(0.922, 0.0, 0.0), // --border: oklch(0.922 0 0)
-            input: oklch(0.922, 0.0, 0.0),  // --input: oklch(0.922 0 0)
-            ring: oklch(0.708, 0.0, 0.0),   // --ring: oklch(0.708 0 0)
-
-            // Radius values (in pixels)
-            radius: 6.0,
-            radius_sm: 4.0,
-            radius_md: 6.0,
-            radius_lg: 8.0,
-        }
+        Self::preset(ThemePreset::Neutral, ThemeMode::Light)
     }
 
-    /// Create a dark theme
-    /// Colors from: https://ui.shadcn.com/themes (Zinc, default)
+    /// Create a dark theme (shadcn default, neutral base color)
     pub fn dark() -> Self {
-        Self {
-            mode: ThemeMode::Dark,
-
-            background: oklch(0.145, 0.0, 0.0), // --background: oklch(0.145 0 0)
-            foreground: oklch(0.985, 0.0, 0.0), // --foreground: oklch(0.985 0 0)
-
-            card: oklch(0.205, 0.0, 0.0), // --card: oklch(0.205 0 0)
-            card_foreground: oklch(0.985, 0.0, 0.0), // --card-foreground: oklch(0.985 0 0)
-
-            popover: oklch(0.205, 0.0, 0.0), // --popover: oklch(0.205 0 0)
-            popover_foreground: oklch(0.985, 0.0, 0.0), // --popover-foreground: oklch(0.985 0 0)
-
-            primary: oklch(0.922, 0.0, 0.0), // --primary: oklch(0.922 0 0)
-            primary_foreground: oklch(0.205, 0.0, 0.0), // --primary-foreground: oklch(0.205 0 0)
-
-            secondary: oklch(0.269, 0.0, 0.0), // --secondary: oklch(0.269 0 0)
-            secondary_foreground: oklch(0.985, 0.0, 0.0), // --secondary-foreground: oklch(0.985 0 0)
-
-            muted: oklch(0.269, 0.0, 0.0), // --muted: oklch(0.269 0 0)
-            muted_foreground: oklch(0.708, 0.0, 0.0), // --muted-foreground: oklch(0.708 0 0)
-
-            accent: oklch(0.269, 0.0, 0.0), // --accent: oklch(0.269 0 0)
-            accent_foreground: oklch(0.985, 0.0, 0.0), // --accent-foreground: oklch(0.985 0 0)
-
-            destructive: oklch(0.704, 0.191, 22.216), // --destructive: oklch(0.704 0.191 22.216)
-            destructive_foreground: oklch(0.985, 0.0, 0.0), // --destructive-foreground: oklch(0.985 0 0)
+        Self::preset(ThemePreset::Neutral, ThemeMode::Dark)
+    }
 
-            border: oklcha(1.0, 0.0, 0.0, 0.1), // --border: oklch(1 0 0 / 10%)
-            input: oklcha(1.0, 0.0, 0.0, 0.1),  // --input: oklch(1 0 0 / 10%)
-            ring: oklch(0.556, 0.0, 0.0),       // --ring: oklch(0.556 0 0)
+    /// The chart palette, in order
+    pub fn charts(&self) -> [Color; 5] {
+        [
+            self.chart_1,
+            self.chart_2,
+            self.chart_3,
+            self.chart_4,
+            self.chart_5,
+        ]
+    }
 
-            // Radius values (same as light)
-            radius: 6.0,
-            radius_sm: 4.0,
-            radius_md: 6.0,
-            radius_lg: 8.0,
-        }
+    /// `n` spacing units in pixels, like Tailwind's `p-2` or `gap-4`
+    ///
+    /// With the default 4px unit, `space(2.0)` is 8px.
+    pub fn space(&self, n: f32) -> f32 {
+        n * self.spacing
     }
 
     /// Adjust the lightness of a color in OKLCH space.
@@ -198,7 +275,7 @@ impl ShadcnTheme {
     /// Get hover color based on theme mode
     pub fn hover_color(&self, color: Color) -> Color {
         match self.mode {
-            ThemeMode::Light => self.darken(color),
+            ThemeMode::Light | ThemeMode::System => self.darken(color),
             ThemeMode::Dark => self.lighten(color),
         }
     }
@@ -206,7 +283,7 @@ impl ShadcnTheme {
     /// Get active/pressed color based on theme mode (more pronounced than hover)
     pub fn active_color(&self, color: Color) -> Color {
         match self.mode {
-            ThemeMode::Light => Self::adjust_lightness(color, -0.10),
+            ThemeMode::Light | ThemeMode::System => Self::adjust_lightness(color, -0.10),
             ThemeMode::Dark => Self::adjust_lightness(color, 0.10),
         }
     }
@@ -214,12 +291,44 @@ impl ShadcnTheme {
     /// Get a more pronounced active color (for primary buttons)
     pub fn strong_active_color(&self, color: Color) -> Color {
         match self.mode {
-            ThemeMode::Light => Self::adjust_lightness(color, -0.15),
+            ThemeMode::Light | ThemeMode::System => Self::adjust_lightness(color, -0.15),
             ThemeMode::Dark => Self::adjust_lightness(color, 0.15),
         }
     }
 }
 
+/// A light and a dark theme that belong together
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThemePair {
+    pub light: ShadcnTheme,
+    pub dark: ShadcnTheme,
+}
+
+impl Default for ThemePair {
+    fn default() -> Self {
+        Self::preset(ThemePreset::Neutral)
+    }
+}
+
+impl ThemePair {
+    /// Both modes of a shadcn/ui preset
+    pub fn preset(preset: ThemePreset) -> Self {
+        Self {
+            light: ShadcnTheme::preset(preset, ThemeMode::Light),
+            dark: ShadcnTheme::preset(preset, ThemeMode::Dark),
+        }
+    }
+
+    /// The theme for the given mode (`System` picks the light theme)
+    pub fn get(&self, mode: ThemeMode) -> ShadcnTheme {
+        match mode {
+            ThemeMode::Light | ThemeMode::System => self.light,
+            ThemeMode::Dark => self.dark,
+        }
+    }
+}
+
 /// Helper to interpolate between two colors
 fn lerp_color(a: Color, b: Color, t: f32) -> Color {
     a.lerp(b, t, HueDirection::default())
@@ -237,6 +346,11 @@ impl StylePropValue for ShadcnTheme {
         Some(ShadcnTheme {
             // Mode switches at midpoint
             mode: if t < 0.5 { self.mode } else { other.mode },
+            contrast: if t < 0.5 {
+                self.contrast
+            } else {
+                other.contrast
+            },
 
             // Interpolate all colors
             background: lerp_color(self.background, other.background, t),
@@ -267,11 +381,42 @@ impl StylePropValue for ShadcnTheme {
             input: lerp_color(self.input, other.input, t),
             ring: lerp_color(self.ring, other.ring, t),
 
+            // Interpolate chart and sidebar colors
+            chart_1: lerp_color(self.chart_1, other.chart_1, t),
+            chart_2: lerp_color(self.chart_2, other.chart_2, t),
+            chart_3: lerp_color(self.chart_3, other.chart_3, t),
+            chart_4: lerp_color(self.chart_4, other.chart_4, t),
+            chart_5: lerp_color(self.chart_5, other.chart_5, t),
+            sidebar: lerp_color(self.sidebar, other.sidebar, t),
+            sidebar_foreground: lerp_color(self.sidebar_foreground, other.sidebar_foreground, t),
+            sidebar_primary: lerp_color(self.sidebar_primary, other.sidebar_primary, t),
+            sidebar_primary_foreground: lerp_color(
+                self.sidebar_primary_foreground,
+                other.sidebar_primary_foreground,
+                t,
+            ),
+            sidebar_accent: lerp_color(self.sidebar_accent, other.sidebar_accent, t),
+            sidebar_accent_foreground: lerp_color(
+                self.sidebar_accent_foreground,
+                other.sidebar_accent_foreground,
+                t,
+            ),
+            sidebar_border: lerp_color(self.sidebar_border, other.sidebar_border, t),
+            sidebar_ring: lerp_color(self.sidebar_ring, other.sidebar_ring, t),
+
             // Interpolate radius values
             radius: lerp_f32(self.radius, other.radius, t),
             radius_sm: lerp_f32(self.radius_sm, other.radius_sm, t),
             radius_md: lerp_f32(self.radius_md, other.radius_md, t),
             radius_lg: lerp_f32(self.radius_lg, other.radius_lg, t),
+            ring_width: lerp_f32(self.ring_width, other.ring_width, t),
+
+            // Interpolate size scales
+            font_size: self.font_size.interpolate(&other.font_size, t),
+            line_height: self.line_height.interpolate(&other.line_height, t),
+            spacing: lerp_f32(self.spacing, other.spacing, t),
+            control_height: self.control_height.interpolate(&other.control_height, t),
+            shadow: self.shadow.interpolate(&other.shadow, t),
         })
     }
 }
@@ -291,8 +436,14 @@ pub trait ShadcnThemeExt {
     /// Set the shadcn theme for this style and all descendants
     fn shadcn_theme(self, theme: ShadcnTheme) -> Self;
 
+    /// Set the density for this style and all descendants
+    fn density(self, density: Density) -> Self;
+
     /// Access the inherited shadcn theme to apply theme-aware styles.
     ///
+    /// The size scales of the theme are already adjusted for the inherited
+    /// [`Density`].
+    ///
     /// Use this when you need multiple theme properties in one style block:
     /// ```rust
     /// s.with_shadcn_theme(|s, t| {
@@ -304,6 +455,13 @@ pub trait ShadcnThemeExt {
     fn with_shadcn_theme(self, f: impl Fn(Self, &ShadcnTheme) -> Self + 'static) -> Self
     where
         Self: Sized;
+
+    /// Set a box shadow from the theme's [`ShadowScale`]
+    ///
+    /// ```rust
+    /// s.with_shadcn_theme(|s, t| s.apply_shadow(t.shadow.md))
+    /// ```
+    fn apply_shadow(self, shadow: Shadow) -> Self;
 }
 
 impl ShadcnThemeExt for Style {
@@ -311,7 +469,24 @@ impl ShadcnThemeExt for Style {
         self.set(ShadcnThemeProp, theme)
     }
 
+    fn density(self, density: Density) -> Self {
+        self.set(DensityProp, density)
+    }
+
     fn with_shadcn_theme(self, f: impl Fn(Self, &ShadcnTheme) -> Self + 'static) -> Self {
-        self.with_context::<ShadcnThemeProp>(f)
+        let f = Rc::new(f);
+        self.with_context::<DensityProp>(move |s, density| {
+            let density = *density;
+            let f = f.clone();
+            s.with_context::<ShadcnThemeProp>(move |s, t| f(s, &t.with_density(density)))
+        })
+    }
+
+    fn apply_shadow(self, shadow: Shadow) -> Self {
+        self.box_shadow_h_offset(shadow.offset_x)
+            .box_shadow_v_offset(shadow.offset_y)
+            .box_shadow_blur(shadow.blur)
+            .box_shadow_spread(shadow.spread)
+            .box_shadow_color(shadow.color)
     }
 }
@@ -0,0 +1,451 @@
+//! Load themes from JSON and TOML files (`serde` feature)
+//!
+//! [`ShadcnTheme`], [`ThemePair`], [`ThemeMode`] and [`ThemePreset`] implement
+//! `Serialize` and `Deserialize`. Colors are written as CSS strings and read
+//! in any format [`ThemePair::from_css`] accepts.
+//!
+//! A [`ThemeOverride`] patches a few tokens on top of a preset. Tokens at the
+//! top level apply to both modes, tokens in `[light]` / `[dark]` to one:
+//!
+//! ```toml
+//! preset = "zinc"
+//! primary = "oklch(0.546 0.245 262.881)"
+//! radius = 8
+//! spacing = 3.5
+//! font_size = { sm = 13, base = 15 }
+//!
+//! [dark]
+//! primary = "#3b82f6"
+//! ```
+//!
+//! [`ThemeFile`] keeps the theme loaded from such a file in a signal and
+//! reloads it on demand.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
+use peniko::Color;
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    ControlHeightScale, FontSizeScale, LineHeightScale, ShadcnTheme, ShadowScale, ThemeCssError,
+    ThemeMode, ThemePair, ThemePreset,
+};
+
+/// `serde(with)` helpers for colors stored as CSS strings
+pub(super) mod color {
+    use peniko::Color;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::theme::css::{format_color, parse_color};
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format_color(*color))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        parse_color(&value).map_err(|reason| {
+            serde::de::Error::custom(format!("invalid color `{value}`: {reason}"))
+        })
+    }
+
+    /// The same for `Option<Color>`
+    pub mod option {
+        use peniko::Color;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(
+            color: &Option<Color>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match color {
+                Some(color) => super::serialize(color, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Color>, D::Error> {
+            #[derive(Deserialize)]
+            struct Wrapper(#[serde(with = "super")] Color);
+
+            Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(color)| color))
+        }
+    }
+}
+
+// ============================================================================
+// Overrides
+// ============================================================================
+
+/// Tokens to replace in a theme; `None` keeps the base value
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TokenOverrides {
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub background: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub foreground: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub card: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub card_foreground: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub popover: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub popover_foreground: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub primary: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub primary_foreground: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub secondary: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub secondary_foreground: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub muted: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub muted_foreground: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub accent: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub accent_foreground: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub destructive: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub destructive_foreground: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub border: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub input: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub ring: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub chart_1: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub chart_2: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub chart_3: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub chart_4: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub chart_5: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub sidebar: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub sidebar_foreground: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub sidebar_primary: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub sidebar_primary_foreground: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub sidebar_accent: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub sidebar_accent_foreground: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub sidebar_border: Option<Color>,
+    #[serde(with = "color::option", skip_serializing_if = "Option::is_none")]
+    pub sidebar_ring: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub radius: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub radius_sm: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub radius_md: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub radius_lg: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ring_width: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spacing: Option<f32>,
+    /// Replaces the whole scale; keys left out use the default scale
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<FontSizeScale>,
+    /// Replaces the whole scale; keys left out use the default scale
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_height: Option<LineHeightScale>,
+    /// Replaces the whole scale; keys left out use the default scale
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub control_height: Option<ControlHeightScale>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow: Option<ShadowScale>,
+}
+
+impl TokenOverrides {
+    /// Replace the overridden tokens in `theme`
+    pub fn apply(&self, theme: &mut ShadcnTheme) {
+        fn set<T: Copy>(target: &mut T, value: Option<T>) {
+            if let Some(value) = value {
+                *target = value;
+            }
+        }
+
+        set(&mut theme.background, self.background);
+        set(&mut theme.foreground, self.foreground);
+        set(&mut theme.card, self.card);
+        set(&mut theme.card_foreground, self.card_foreground);
+        set(&mut theme.popover, self.popover);
+        set(&mut theme.popover_foreground, self.popover_foreground);
+        set(&mut theme.primary, self.primary);
+        set(&mut theme.primary_foreground, self.primary_foreground);
+        set(&mut theme.secondary, self.secondary);
+        set(&mut theme.secondary_foreground, self.secondary_foreground);
+        set(&mut theme.muted, self.muted);
+        set(&mut theme.muted_foreground, self.muted_foreground);
+        set(&mut theme.accent, self.accent);
+        set(&mut theme.accent_foreground, self.accent_foreground);
+        set(&mut theme.destructive, self.destructive);
+        set(
+            &mut theme.destructive_foreground,
+            self.destructive_foreground,
+        );
+        set(&mut theme.border, self.border);
+        set(&mut theme.input, self.input);
+        set(&mut theme.ring, self.ring);
+        set(&mut theme.chart_1, self.chart_1);
+        set(&mut theme.chart_2, self.chart_2);
+        set(&mut theme.chart_3, self.chart_3);
+        set(&mut theme.chart_4, self.chart_4);
+        set(&mut theme.chart_5, self.chart_5);
+        set(&mut theme.sidebar, self.sidebar);
+        set(&mut theme.sidebar_foreground, self.sidebar_foreground);
+        set(&mut theme.sidebar_primary, self.sidebar_primary);
+        set(
+            &mut theme.sidebar_primary_foreground,
+            self.sidebar_primary_foreground,
+        );
+        set(&mut theme.sidebar_accent, self.sidebar_accent);
+        set(
+            &mut theme.sidebar_accent_foreground,
+            self.sidebar_accent_foreground,
+        );
+        set(&mut theme.sidebar_border, self.sidebar_border);
+        set(&mut theme.sidebar_ring, self.sidebar_ring);
+        set(&mut theme.radius, self.radius);
+        set(&mut theme.radius_sm, self.radius_sm);
+        set(&mut theme.radius_md, self.radius_md);
+        set(&mut theme.radius_lg, self.radius_lg);
+        set(&mut theme.ring_width, self.ring_width);
+        set(&mut theme.spacing, self.spacing);
+        set(&mut theme.font_size, self.font_size);
+        set(&mut theme.line_height, self.line_height);
+        set(&mut theme.control_height, self.control_height);
+        set(&mut theme.shadow, self.shadow);
+    }
+}
+
+/// A partial theme: a base preset plus the tokens to change
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawThemeOverride")]
+pub struct ThemeOverride {
+    /// The preset to start from; falls back to the one given when loading
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset: Option<ThemePreset>,
+    /// Tokens changed in both modes
+    #[serde(flatten)]
+    pub shared: TokenOverrides,
+    /// Tokens changed in light mode only
+    #[serde(skip_serializing_if = "is_empty")]
+    pub light: TokenOverrides,
+    /// Tokens changed in dark mode only
+    #[serde(skip_serializing_if = "is_empty")]
+    pub dark: TokenOverrides,
+}
+
+fn is_empty(tokens: &TokenOverrides) -> bool {
+    *tokens == TokenOverrides::default()
+}
+
+/// `ThemeOverride` as written in files; flattened tables can't deny unknown
+/// fields, so leftover keys are collected and rejected in `try_from`
+#[derive(Deserialize)]
+struct RawThemeOverride {
+    #[serde(default)]
+    preset: Option<ThemePreset>,
+    #[serde(flatten)]
+    shared: TokenOverrides,
+    #[serde(default)]
+    light: TokenOverrides,
+    #[serde(default)]
+    dark: TokenOverrides,
+    #[serde(flatten)]
+    unknown: BTreeMap<String, IgnoredAny>,
+}
+
+impl TryFrom<RawThemeOverride> for ThemeOverride {
+    type Error = String;
+
+    fn try_from(raw: RawThemeOverride) -> Result<Self, Self::Error> {
+        if let Some(key) = raw.unknown.keys().next() {
+            return Err(format!("unknown theme token `{key}`"));
+        }
+        Ok(ThemeOverride {
+            preset: raw.preset,
+            shared: raw.shared,
+            light: raw.light,
+            dark: raw.dark,
+        })
+    }
+}
+
+impl ThemeOverride {
+    /// Parse an override from TOML
+    pub fn from_toml(text: &str) -> Result<Self, ThemeConfigError> {
+        toml::from_str(text).map_err(ThemeConfigError::Toml)
+    }
+
+    /// Parse an override from JSON
+    pub fn from_json(text: &str) -> Result<Self, ThemeConfigError> {
+        serde_json::from_str(text).map_err(ThemeConfigError::Json)
+    }
+
+    /// Apply the overrides to `base`, ignoring [`preset`](Self::preset)
+    pub fn apply(&self, base: ThemePair) -> ThemePair {
+        let ThemePair {
+            mut light,
+            mut dark,
+        } = base;
+        self.shared.apply(&mut light);
+        self.shared.apply(&mut dark);
+        self.light.apply(&mut light);
+        self.dark.apply(&mut dark);
+        ThemePair { light, dark }
+    }
+
+    /// Build the themes, starting from [`preset`](Self::preset) or `fallback`
+    pub fn theme(&self, fallback: ThemePreset) -> ThemePair {
+        self.apply(ThemePair::preset(self.preset.unwrap_or(fallback)))
+    }
+}
+
+// ============================================================================
+// ThemeFile
+// ============================================================================
+
+/// An error found while loading a theme file
+#[derive(Debug)]
+pub enum ThemeConfigError {
+    Io(PathBuf, std::io::Error),
+    Toml(toml::de::Error),
+    Json(serde_json::Error),
+    Css(ThemeCssError),
+    /// The file extension is not `toml`, `json` or `css`
+    UnsupportedFormat(PathBuf),
+}
+
+impl fmt::Display for ThemeConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeConfigError::Io(path, err) => write!(f, "{}: {err}", path.display()),
+            ThemeConfigError::Toml(err) => write!(f, "invalid theme TOML: {err}"),
+            ThemeConfigError::Json(err) => write!(f, "invalid theme JSON: {err}"),
+            ThemeConfigError::Css(err) => write!(f, "invalid theme CSS: {err}"),
+            ThemeConfigError::UnsupportedFormat(path) => write!(
+                f,
+                "{}: theme files must end in .toml, .json or .css",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ThemeConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ThemeConfigError::Io(_, err) => Some(err),
+            ThemeConfigError::Toml(err) => Some(err),
+            ThemeConfigError::Json(err) => Some(err),
+            ThemeConfigError::Css(err) => Some(err),
+            ThemeConfigError::UnsupportedFormat(_) => None,
+        }
+    }
+}
+
+/// A theme loaded from a file, reloaded on demand
+///
+/// `.toml` and `.json` files hold a [`ThemeOverride`] applied on top of the
+/// base preset; `.css` files are read with [`ThemePair::from_css`].
+///
+/// ```rust,ignore
+/// let file = ThemeFile::new("theme.toml", ThemePreset::Neutral);
+/// if let Err(err) = file.reload() {
+///     eprintln!("{err}");
+/// }
+///
+/// root.style(move |s| s.shadcn_theme(file.get(mode.get())))
+/// ```
+#[derive(Clone, Copy)]
+pub struct ThemeFile {
+    path: RwSignal<PathBuf>,
+    base: RwSignal<ThemePreset>,
+    theme: RwSignal<ThemePair>,
+}
+
+impl ThemeFile {
+    /// Track `path` without reading it; the theme starts as `base`
+    pub fn new(path: impl Into<PathBuf>, base: ThemePreset) -> Self {
+        Self {
+            path: RwSignal::new(path.into()),
+            base: RwSignal::new(base),
+            theme: RwSignal::new(ThemePair::preset(base)),
+        }
+    }
+
+    /// Track `path` and read it now
+    pub fn load(path: impl Into<PathBuf>, base: ThemePreset) -> Result<Self, ThemeConfigError> {
+        let file = Self::new(path, base);
+        file.reload()?;
+        Ok(file)
+    }
+
+    /// Read the file again and update the theme
+    ///
+    /// On error the previous theme is kept.
+    pub fn reload(&self) -> Result<(), ThemeConfigError> {
+        let path = self.path.get_untracked();
+        let theme = read_theme(&path, self.base.get_untracked())?;
+        self.theme.set(theme);
+        Ok(())
+    }
+
+    /// The file being tracked
+    pub fn path(&self) -> PathBuf {
+        self.path.get_untracked()
+    }
+
+    /// Signal holding the current themes
+    pub fn themes(&self) -> RwSignal<ThemePair> {
+        self.theme
+    }
+
+    /// The current theme for `mode` (tracked)
+    pub fn get(&self, mode: ThemeMode) -> ShadcnTheme {
+        self.theme.get().get(mode)
+    }
+}
+
+fn read_theme(path: &Path, base: ThemePreset) -> Result<ThemePair, ThemeConfigError> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase());
+    let parse: fn(&str) -> Result<ThemeOverride, ThemeConfigError> = match extension.as_deref() {
+        Some("toml") => ThemeOverride::from_toml,
+        Some("json") => ThemeOverride::from_json,
+        Some("css") => {
+            let text = read(path)?;
+            return ThemePair::from_css(&text).map_err(ThemeConfigError::Css);
+        }
+        _ => return Err(ThemeConfigError::UnsupportedFormat(path.to_path_buf())),
+    };
+    Ok(parse(&read(path)?)?.theme(base))
+}
+
+fn read(path: &Path) -> Result<String, ThemeConfigError> {
+    std::fs::read_to_string(path).map_err(|err| ThemeConfigError::Io(path.to_path_buf(), err))
+}
@@ -0,0 +1,340 @@
+//! Color contrast and theme audits
+//!
+//! [`contrast_ratio`] implements the WCAG 2 contrast ratio, from 1:1 (same
+//! color) to 21:1 (black on white). [`apca_contrast`] implements the APCA
+//! lightness contrast (Lc) proposed for WCAG 3.
+//!
+//! [`ShadcnTheme::audit_contrast`] checks every background/foreground pair in
+//! a theme, including the hover and active colors derived from the button
+//! backgrounds, and reports the pairs that fail:
+//!
+//! ```rust
+//! use floem_shadcn::theme::ShadcnTheme;
+//!
+//! let report = ShadcnTheme::dark().audit_contrast();
+//! for failure in report.failures() {
+//!     println!("{failure}");
+//! }
+//! ```
+
+use std::fmt;
+
+use peniko::Color;
+
+use super::ShadcnTheme;
+
+/// WCAG AA minimum for normal text
+pub const WCAG_AA: f32 = 4.5;
+
+/// WCAG minimum for large text and UI components
+pub const WCAG_AA_LARGE: f32 = 3.0;
+
+/// WCAG AAA minimum for normal text
+pub const WCAG_AAA: f32 = 7.0;
+
+/// APCA minimum (absolute Lc) for body text
+pub const APCA_BODY: f32 = 75.0;
+
+/// APCA minimum (absolute Lc) for content text that is not body copy
+pub const APCA_CONTENT: f32 = 60.0;
+
+/// Relative luminance of a color as defined by WCAG 2, ignoring alpha
+pub fn relative_luminance(color: Color) -> f32 {
+    let [r, g, b, _] = color.components;
+    let linear = |c: f32| {
+        let c = c.clamp(0.0, 1.0);
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+}
+
+/// WCAG 2 contrast ratio between two colors, ignoring alpha
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    let la = relative_luminance(a);
+    let lb = relative_luminance(b);
+    let (lighter, darker) = if la > lb { (la, lb) } else { (lb, la) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// APCA lightness contrast (Lc) of `text` on `background`, ignoring alpha
+///
+/// Positive for dark text on a light background, negative for light text on a
+/// dark background. Uses the APCA-W3 0.0.98G constants.
+pub fn apca_contrast(text: Color, background: Color) -> f32 {
+    fn screen_luminance(color: Color) -> f32 {
+        let [r, g, b, _] = color.components;
+        let y = 0.2126729 * r.clamp(0.0, 1.0).powf(2.4)
+            + 0.7151522 * g.clamp(0.0, 1.0).powf(2.4)
+            + 0.0721750 * b.clamp(0.0, 1.0).powf(2.4);
+        // Soft clamp near black
+        if y < 0.022 {
+            y + (0.022 - y).powf(1.414)
+        } else {
+            y
+        }
+    }
+
+    let text = screen_luminance(text);
+    let background = screen_luminance(background);
+    if (background - text).abs() < 0.0005 {
+        return 0.0;
+    }
+    let lc = if background > text {
+        let sapc = (background.powf(0.56) - text.powf(0.57)) * 1.14;
+        if sapc < 0.1 { 0.0 } else { sapc - 0.027 }
+    } else {
+        let sapc = (background.powf(0.65) - text.powf(0.62)) * 1.14;
+        if sapc > -0.1 { 0.0 } else { sapc + 0.027 }
+    };
+    lc * 100.0
+}
+
+/// Blend a translucent color over an opaque background
+pub(super) fn composite(color: Color, background: Color) -> Color {
+    let [r, g, b, a] = color.components;
+    let [br, bg, bb, _] = background.components;
+    let mix = |c: f32, base: f32| c * a + base * (1.0 - a);
+    Color::new([mix(r, br), mix(g, bg), mix(b, bb), 1.0])
+}
+
+// ============================================================================
+// Audit
+// ============================================================================
+
+/// Interaction state of the background in a checked pair
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContrastState {
+    Default,
+    /// Background from [`ShadcnTheme::hover_color`]
+    Hover,
+    /// Background from [`ShadcnTheme::active_color`]
+    Active,
+    /// Background from [`ShadcnTheme::strong_active_color`]
+    StrongActive,
+}
+
+/// Thresholds for [`ShadcnTheme::audit_contrast_with`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastOptions {
+    /// Minimum WCAG 2 contrast ratio
+    pub min_ratio: f32,
+    /// Minimum absolute APCA Lc; `None` skips the APCA check
+    pub min_apca: Option<f32>,
+    /// Also check hover and active backgrounds
+    pub states: bool,
+}
+
+impl Default for ContrastOptions {
+    fn default() -> Self {
+        Self {
+            min_ratio: WCAG_AA,
+            min_apca: None,
+            states: true,
+        }
+    }
+}
+
+impl ContrastOptions {
+    /// Set the minimum WCAG 2 contrast ratio
+    pub fn min_ratio(mut self, ratio: f32) -> Self {
+        self.min_ratio = ratio;
+        self
+    }
+
+    /// Also require an absolute APCA Lc of at least `lc`
+    pub fn apca(mut self, lc: f32) -> Self {
+        self.min_apca = Some(lc);
+        self
+    }
+
+    /// Enable or disable checking hover and active backgrounds
+    pub fn states(mut self, states: bool) -> Self {
+        self.states = states;
+        self
+    }
+}
+
+/// The contrast of one foreground token on one background token
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastCheck {
+    /// Name of the background token (`"primary"`)
+    pub background: &'static str,
+    /// Name of the foreground token (`"primary_foreground"`)
+    pub foreground: &'static str,
+    pub state: ContrastState,
+    /// WCAG 2 contrast ratio
+    pub ratio: f32,
+    /// APCA lightness contrast
+    pub apca: f32,
+    /// Whether the pair meets the thresholds it was audited with
+    pub passes: bool,
+}
+
+impl fmt::Display for ContrastCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} on {}", self.foreground, self.background)?;
+        match self.state {
+            ContrastState::Default => {}
+            ContrastState::Hover => write!(f, " (hover)")?,
+            ContrastState::Active => write!(f, " (active)")?,
+            ContrastState::StrongActive => write!(f, " (strong active)")?,
+        }
+        write!(f, ": {:.2}:1, Lc {:.1}", self.ratio, self.apca)
+    }
+}
+
+/// The result of auditing a theme
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastReport {
+    pub options: ContrastOptions,
+    pub checks: Vec<ContrastCheck>,
+}
+
+impl ContrastReport {
+    /// The pairs below the thresholds
+    pub fn failures(&self) -> impl Iterator<Item = &ContrastCheck> {
+        self.checks.iter().filter(|check| !check.passes)
+    }
+
+    /// Returns true if every pair meets the thresholds
+    pub fn is_passing(&self) -> bool {
+        self.checks.iter().all(|check| check.passes)
+    }
+
+    /// Look up the check for a pair
+    pub fn get(
+        &self,
+        background: &str,
+        foreground: &str,
+        state: ContrastState,
+    ) -> Option<&ContrastCheck> {
+        self.checks.iter().find(|check| {
+            check.background == background && check.foreground == foreground && check.state == state
+        })
+    }
+}
+
+impl fmt::Display for ContrastReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failures: Vec<_> = self.failures().collect();
+        if failures.is_empty() {
+            return write!(f, "all {} pairs pass", self.checks.len());
+        }
+        write!(f, "{} of {} pairs fail", failures.len(), self.checks.len())?;
+        for failure in failures {
+            write!(f, "\n  {failure}")?;
+        }
+        Ok(())
+    }
+}
+
+impl ShadcnTheme {
+    /// Audit every background/foreground pair against WCAG AA (4.5:1)
+    pub fn audit_contrast(&self) -> ContrastReport {
+        self.audit_contrast_with(ContrastOptions::default())
+    }
+
+    /// Audit every background/foreground pair with custom thresholds
+    pub fn audit_contrast_with(&self, options: ContrastOptions) -> ContrastReport {
+        let text_pairs = [
+            ("background", self.background, "foreground", self.foreground),
+            (
+                "background",
+                self.background,
+                "muted_foreground",
+                self.muted_foreground,
+            ),
+            ("card", self.card, "card_foreground", self.card_foreground),
+            (
+                "popover",
+                self.popover,
+                "popover_foreground",
+                self.popover_foreground,
+            ),
+            (
+                "primary",
+                self.primary,
+                "primary_foreground",
+                self.primary_foreground,
+            ),
+            (
+                "secondary",
+                self.secondary,
+                "secondary_foreground",
+                self.secondary_foreground,
+            ),
+            (
+                "muted",
+                self.muted,
+                "muted_foreground",
+                self.muted_foreground,
+            ),
+            (
+                "accent",
+                self.accent,
+                "accent_foreground",
+                self.accent_foreground,
+            ),
+            (
+                "destructive",
+                self.destructive,
+                "destructive_foreground",
+                self.destructive_foreground,
+            ),
+            (
+                "sidebar",
+                self.sidebar,
+                "sidebar_foreground",
+                self.sidebar_foreground,
+            ),
+            (
+                "sidebar_primary",
+                self.sidebar_primary,
+                "sidebar_primary_foreground",
+                self.sidebar_primary_foreground,
+            ),
+            (
+                "sidebar_accent",
+                self.sidebar_accent,
+                "sidebar_accent_foreground",
+                self.sidebar_accent_foreground,
+            ),
+        ];
+        // Backgrounds that change on hover and press
+        let interactive = ["primary", "secondary", "accent", "destructive"];
+
+        let mut checks = Vec::new();
+        for (bg_name, bg, fg_name, fg) in text_pairs {
+            let mut states = vec![(ContrastState::Default, bg)];
+            if options.states && interactive.contains(&bg_name) {
+                states.push((ContrastState::Hover, self.hover_color(bg)));
+                states.push((ContrastState::Active, self.active_color(bg)));
+                if bg_name == "primary" {
+                    states.push((ContrastState::StrongActive, self.strong_active_color(bg)));
+                }
+            }
+            for (state, bg) in states {
+                // Translucent backgrounds sit on the page
+                let bg = composite(bg, self.background);
+                let fg = composite(fg, bg);
+                let ratio = contrast_ratio(bg, fg);
+                let apca = apca_contrast(fg, bg);
+                let passes = ratio >= options.min_ratio
+                    && options.min_apca.is_none_or(|min| apca.abs() >= min);
+                checks.push(ContrastCheck {
+                    background: bg_name,
+                    foreground: fg_name,
+                    state,
+                    ratio,
+                    apca,
+                    passes,
+                });
+            }
+        }
+        ContrastReport { options, checks }
+    }
+}
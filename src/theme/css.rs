@@ -0,0 +1,675 @@
+//! Import themes from shadcn/ui CSS variables
+//!
+//! Reads the `:root { ... }` and `.dark { ... }` blocks of a `globals.css`
+//! file, as written by `shadcn init` or the shadcn theme editor, and builds a
+//! [`ThemePair`]. Blocks may be nested in `@layer base`; everything else in the
+//! file (`@import`, `@theme inline`, rules for other selectors) is ignored.
+//!
+//! Like in a browser, `.dark` only overrides the variables it declares and
+//! inherits the rest from `:root`.
+//!
+//! Supported values:
+//! - `oklch(L C H)` and `oklch(L C H / A)`, with `L` as a number or percentage
+//! - `hsl(H S% L%)`, `hsl(H, S%, L%)`, `hsla(...)` and bare `H S% L%` channels
+//!   (the shadcn/ui v3 format)
+//! - `#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa`
+//! - `rgb(R G B)`, `rgb(R, G, B)` and `rgba(...)`
+//! - `color(srgb R G B)` with channels from 0 to 1
+//! - `white`, `black`, `transparent` and `var(--other)`
+//! - `--radius` and `--spacing` in `rem`, `em` or `px`
+//!
+//! `--destructive-foreground`, `--radius`, `--spacing`, the `--chart-*` colors and the
+//! `--sidebar-*` colors are optional; every other color token is required.
+//! Missing sidebar colors fall back to their page counterparts (`--sidebar` to
+//! `--background`, `--sidebar-accent` to `--accent`, ...).
+
+use std::fmt;
+
+use peniko::Color;
+
+use super::{ShadcnTheme, ShadowScale, ThemeMode, ThemePair, ThemePreset, oklcha};
+
+/// Pixels per `rem`, matching the browser default
+const REM: f32 = 16.0;
+
+/// Maximum depth of `var(--other)` references
+const MAX_VAR_DEPTH: usize = 8;
+
+/// An error found while importing a theme from CSS
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThemeCssError {
+    /// The CSS could not be split into blocks (e.g. unbalanced braces)
+    Syntax { line: usize, message: String },
+    /// The file has no `:root` or `.dark` block
+    MissingBlock { selector: &'static str },
+    /// A required variable is not declared
+    MissingVariable {
+        selector: &'static str,
+        name: String,
+    },
+    /// A variable's value could not be parsed
+    InvalidValue {
+        selector: &'static str,
+        name: String,
+        value: String,
+        line: usize,
+        reason: String,
+    },
+}
+
+impl fmt::Display for ThemeCssError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeCssError::Syntax { line, message } => {
+                write!(f, "line {line}: {message}")
+            }
+            ThemeCssError::MissingBlock { selector } => {
+                write!(f, "no `{selector}` block found")
+            }
+            ThemeCssError::MissingVariable { selector, name } => {
+                write!(f, "`{selector}` does not declare `--{name}`")
+            }
+            ThemeCssError::InvalidValue {
+                selector,
+                name,
+                value,
+                line,
+                reason,
+            } => write!(
+                f,
+                "line {line}: invalid value `{value}` for `--{name}` in `{selector}`: {reason}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ThemeCssError {}
+
+impl ThemePair {
+    /// Build a light and dark theme from the CSS variables in `css`
+    pub fn from_css(css: &str) -> Result<Self, ThemeCssError> {
+        let blocks = scan_blocks(css)?;
+        let root = Scope::collect(&blocks, ":root");
+        let dark = Scope::collect(&blocks, ".dark");
+        if !root.found {
+            return Err(ThemeCssError::MissingBlock { selector: ":root" });
+        }
+        if !dark.found {
+            return Err(ThemeCssError::MissingBlock { selector: ".dark" });
+        }
+
+        let light = build_theme(
+            &Lookup {
+                selector: ":root",
+                scopes: vec![&root],
+            },
+            ThemeMode::Light,
+        )?;
+        let dark = build_theme(
+            &Lookup {
+                selector: ".dark",
+                scopes: vec![&dark, &root],
+            },
+            ThemeMode::Dark,
+        )?;
+        Ok(ThemePair { light, dark })
+    }
+}
+
+// ============================================================================
+// Blocks
+// ============================================================================
+
+/// A `--name: value` declaration
+#[derive(Debug, Clone)]
+struct Declaration {
+    name: String,
+    value: String,
+    line: usize,
+}
+
+/// A `selector { ... }` block with the custom properties declared directly in it
+#[derive(Debug)]
+struct Block {
+    selector: String,
+    declarations: Vec<Declaration>,
+}
+
+/// Split `css` into blocks, tracking line numbers
+fn scan_blocks(css: &str) -> Result<Vec<Block>, ThemeCssError> {
+    let mut blocks = Vec::new();
+    // Open blocks with the line they started on
+    let mut stack: Vec<(Block, usize)> = Vec::new();
+    let mut buffer = String::new();
+    let mut buffer_line = 1;
+    let mut line = 1;
+    let mut quote: Option<char> = None;
+    let mut parens = 0usize;
+
+    let mut chars = css.chars().peekable();
+    while let Some(c) = chars.next() {
+        if let Some(q) = quote {
+            buffer.push(c);
+            if c == q {
+                quote = None;
+            } else if c == '\n' {
+                line += 1;
+            }
+            continue;
+        }
+
+        match c {
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                // Skip the comment, keeping line numbers
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+                buffer.push(' ');
+            }
+            '"' | '\'' => {
+                quote = Some(c);
+                buffer.push(c);
+            }
+            '(' => {
+                parens += 1;
+                buffer.push(c);
+            }
+            ')' => {
+                parens = parens.saturating_sub(1);
+                buffer.push(c);
+            }
+            '{' => {
+                stack.push((
+                    Block {
+                        selector: buffer.trim().to_string(),
+                        declarations: Vec::new(),
+                    },
+                    line,
+                ));
+                buffer.clear();
+            }
+            ';' if parens == 0 => {
+                if let Some((block, _)) = stack.last_mut() {
+                    push_declaration(block, &buffer, buffer_line);
+                }
+                buffer.clear();
+            }
+            '}' => {
+                let Some((mut block, _)) = stack.pop() else {
+                    return Err(ThemeCssError::Syntax {
+                        line,
+                        message: "unexpected `}`".to_string(),
+                    });
+                };
+                push_declaration(&mut block, &buffer, buffer_line);
+                buffer.clear();
+                blocks.push(block);
+            }
+            _ => {
+                if buffer.trim().is_empty() && !c.is_whitespace() {
+                    buffer_line = line;
+                }
+                if c == '\n' {
+                    line += 1;
+                }
+                buffer.push(c);
+            }
+        }
+    }
+
+    if let Some((block, start)) = stack.pop() {
+        return Err(ThemeCssError::Syntax {
+            line: start,
+            message: format!("`{}` block is never closed", block.selector),
+        });
+    }
+    Ok(blocks)
+}
+
+/// Add `--name: value` to `block`; anything else is ignored
+fn push_declaration(block: &mut Block, text: &str, line: usize) {
+    let Some((name, value)) = text.split_once(':') else {
+        return;
+    };
+    let Some(name) = name.trim().strip_prefix("--") else {
+        return;
+    };
+    let value = value.trim().trim_end_matches("!important").trim();
+    block.declarations.push(Declaration {
+        name: name.to_string(),
+        value: value.to_string(),
+        line,
+    });
+}
+
+/// The variables declared by every block matching one selector
+struct Scope {
+    found: bool,
+    declarations: Vec<Declaration>,
+}
+
+impl Scope {
+    fn collect(blocks: &[Block], selector: &str) -> Self {
+        let mut scope = Scope {
+            found: false,
+            declarations: Vec::new(),
+        };
+        for block in blocks {
+            if block.selector.split(',').any(|s| s.trim() == selector) {
+                scope.found = true;
+                scope
+                    .declarations
+                    .extend(block.declarations.iter().cloned());
+            }
+        }
+        scope
+    }
+
+    /// The last declaration of `name`, like the CSS cascade
+    fn get(&self, name: &str) -> Option<&Declaration> {
+        self.declarations.iter().rev().find(|d| d.name == name)
+    }
+}
+
+/// Resolves variables for one theme mode
+struct Lookup<'a> {
+    selector: &'static str,
+    /// Scopes searched in order (`.dark` falls back to `:root`)
+    scopes: Vec<&'a Scope>,
+}
+
+impl Lookup<'_> {
+    fn get(&self, name: &str) -> Option<&Declaration> {
+        self.scopes.iter().find_map(|scope| scope.get(name))
+    }
+
+    /// Look up a variable and follow `var(--other)` references
+    fn resolve(&self, name: &str) -> Result<Option<(Declaration, String)>, ThemeCssError> {
+        let Some(declaration) = self.get(name) else {
+            return Ok(None);
+        };
+        let mut value = declaration.value.clone();
+        for _ in 0..MAX_VAR_DEPTH {
+            let Some((other, fallback)) = var_reference(&value) else {
+                return Ok(Some((declaration.clone(), value)));
+            };
+            value = match (self.get(other), fallback) {
+                (Some(d), _) => d.value.clone(),
+                (None, Some(fallback)) => fallback.to_string(),
+                (None, None) => {
+                    let reason = format!("`--{other}` is not declared");
+                    return Err(self.invalid(declaration, reason));
+                }
+            };
+        }
+        Err(self.invalid(
+            declaration,
+            "too many nested `var()` references".to_string(),
+        ))
+    }
+
+    fn invalid(&self, declaration: &Declaration, reason: String) -> ThemeCssError {
+        ThemeCssError::InvalidValue {
+            selector: self.selector,
+            name: declaration.name.clone(),
+            value: declaration.value.clone(),
+            line: declaration.line,
+            reason,
+        }
+    }
+
+    fn color(&self, name: &str) -> Result<Color, ThemeCssError> {
+        self.optional_color(name)?
+            .ok_or_else(|| ThemeCssError::MissingVariable {
+                selector: self.selector,
+                name: name.to_string(),
+            })
+    }
+
+    fn optional_color(&self, name: &str) -> Result<Option<Color>, ThemeCssError> {
+        let Some((declaration, value)) = self.resolve(name)? else {
+            return Ok(None);
+        };
+        parse_color(&value)
+            .map(Some)
+            .map_err(|reason| self.invalid(&declaration, reason))
+    }
+
+    fn optional_length(&self, name: &str) -> Result<Option<f32>, ThemeCssError> {
+        let Some((declaration, value)) = self.resolve(name)? else {
+            return Ok(None);
+        };
+        parse_length(&value)
+            .map(Some)
+            .map_err(|reason| self.invalid(&declaration, reason))
+    }
+}
+
+/// Split `var(--name)` / `var(--name, fallback)` into name and fallback
+fn var_reference(value: &str) -> Option<(&str, Option<&str>)> {
+    let inner = value.trim().strip_prefix("var(")?.strip_suffix(')')?;
+    let (name, fallback) = match inner.split_once(',') {
+        Some((name, fallback)) => (name, Some(fallback.trim())),
+        None => (inner, None),
+    };
+    Some((name.trim().strip_prefix("--")?, fallback))
+}
+
+fn build_theme(vars: &Lookup, mode: ThemeMode) -> Result<ShadcnTheme, ThemeCssError> {
+    let defaults = ShadcnTheme::preset(ThemePreset::Neutral, mode);
+    let optional = |name: &str, fallback: Color| -> Result<Color, ThemeCssError> {
+        Ok(vars.optional_color(name)?.unwrap_or(fallback))
+    };
+
+    // Derive the radius scale the way shadcn/ui does: lg = --radius,
+    // md = --radius - 2px, sm = --radius - 4px
+    let (radius, radius_sm, radius_md, radius_lg) = match vars.optional_length("radius")? {
+        Some(lg) => {
+            let md = (lg - 2.0).max(0.0);
+            (md, (lg - 4.0).max(0.0), md, lg)
+        }
+        None => (
+            defaults.radius,
+            defaults.radius_sm,
+            defaults.radius_md,
+            defaults.radius_lg,
+        ),
+    };
+
+    let background = vars.color("background")?;
+    let foreground = vars.color("foreground")?;
+    let primary = vars.color("primary")?;
+    let primary_foreground = vars.color("primary-foreground")?;
+    let accent = vars.color("accent")?;
+    let accent_foreground = vars.color("accent-foreground")?;
+    let border = vars.color("border")?;
+    let ring = vars.color("ring")?;
+
+    Ok(ShadcnTheme {
+        mode,
+        contrast: defaults.contrast,
+        background,
+        foreground,
+        card: vars.color("card")?,
+        card_foreground: vars.color("card-foreground")?,
+        popover: vars.color("popover")?,
+        popover_foreground: vars.color("popover-foreground")?,
+        primary,
+        primary_foreground,
+        secondary: vars.color("secondary")?,
+        secondary_foreground: vars.color("secondary-foreground")?,
+        muted: vars.color("muted")?,
+        muted_foreground: vars.color("muted-foreground")?,
+        accent,
+        accent_foreground,
+        destructive: vars.color("destructive")?,
+        // shadcn/ui v4 dropped --destructive-foreground
+        destructive_foreground: optional(
+            "destructive-foreground",
+            defaults.destructive_foreground,
+        )?,
+        border,
+        input: vars.color("input")?,
+        ring,
+        // Older files have no charts; use the shadcn/ui defaults
+        chart_1: optional("chart-1", defaults.chart_1)?,
+        chart_2: optional("chart-2", defaults.chart_2)?,
+        chart_3: optional("chart-3", defaults.chart_3)?,
+        chart_4: optional("chart-4", defaults.chart_4)?,
+        chart_5: optional("chart-5", defaults.chart_5)?,
+        // Files written before the sidebar was added style it like the page
+        sidebar: optional("sidebar", background)?,
+        sidebar_foreground: optional("sidebar-foreground", foreground)?,
+        sidebar_primary: optional("sidebar-primary", primary)?,
+        sidebar_primary_foreground: optional("sidebar-primary-foreground", primary_foreground)?,
+        sidebar_accent: optional("sidebar-accent", accent)?,
+        sidebar_accent_foreground: optional("sidebar-accent-foreground", accent_foreground)?,
+        sidebar_border: optional("sidebar-border", border)?,
+        sidebar_ring: optional("sidebar-ring", ring)?,
+        radius,
+        radius_sm,
+        radius_md,
+        radius_lg,
+        ring_width: defaults.ring_width,
+        font_size: defaults.font_size,
+        line_height: defaults.line_height,
+        // Tailwind v4 declares the spacing unit as --spacing
+        spacing: vars.optional_length("spacing")?.unwrap_or(defaults.spacing),
+        control_height: defaults.control_height,
+        shadow: ShadowScale::with_color(foreground.with_alpha(0.1)),
+    })
+}
+
+// ============================================================================
+// Values
+// ============================================================================
+
+/// Parse a CSS color value
+pub(super) fn parse_color(value: &str) -> Result<Color, String> {
+    let value = value.trim();
+    let lower = value.to_ascii_lowercase();
+
+    match lower.as_str() {
+        "white" => return Ok(Color::new([1.0, 1.0, 1.0, 1.0])),
+        "black" => return Ok(Color::new([0.0, 0.0, 0.0, 1.0])),
+        "transparent" => return Ok(Color::new([0.0, 0.0, 0.0, 0.0])),
+        _ => {}
+    }
+    if let Some(hex) = lower.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+    if let Some((function, args)) = function_args(&lower) {
+        return match function {
+            "oklch" => parse_oklch(args),
+            "hsl" | "hsla" => parse_hsl(args),
+            "rgb" | "rgba" => parse_rgb(args),
+            "color" => parse_color_function(args),
+            _ => Err(format!("unsupported color function `{function}()`")),
+        };
+    }
+    // shadcn/ui v3 stores bare HSL channels: `222.2 47.4% 11.2%`
+    if lower.split_whitespace().count() >= 3 && lower.contains('%') {
+        return parse_hsl(&lower);
+    }
+    Err("expected an oklch(), hsl(), rgb() or hex color".to_string())
+}
+
+/// Split `name(args)` into its parts
+fn function_args(value: &str) -> Option<(&str, &str)> {
+    let open = value.find('(')?;
+    let args = value[open + 1..].strip_suffix(')')?;
+    Some((value[..open].trim(), args))
+}
+
+/// Split color function arguments into channels and an optional alpha
+///
+/// Accepts both `a b c / alpha` and `a, b, c, alpha`.
+fn channels(args: &str) -> Result<(Vec<&str>, Option<&str>), String> {
+    let (main, alpha) = match args.split_once('/') {
+        Some((main, alpha)) => (main, Some(alpha.trim())),
+        None => (args, None),
+    };
+    let mut parts: Vec<&str> = if main.contains(',') {
+        main.split(',').map(str::trim).collect()
+    } else {
+        main.split_whitespace().collect()
+    };
+    let alpha = match (alpha, parts.len()) {
+        (Some(alpha), 3) => Some(alpha),
+        (None, 4) => parts.pop(),
+        (None, 3) => None,
+        _ => return Err(format!("expected 3 channels, found {}", parts.len())),
+    };
+    Ok((parts, alpha))
+}
+
+/// Parse a number, treating `none` as 0
+fn number(text: &str) -> Result<f32, String> {
+    if text == "none" {
+        return Ok(0.0);
+    }
+    text.parse::<f32>()
+        .ok()
+        .filter(|n| n.is_finite())
+        .ok_or_else(|| format!("`{text}` is not a number"))
+}
+
+/// Parse a number or a percentage, where 100% maps to `full`
+fn number_or_percent(text: &str, full: f32) -> Result<f32, String> {
+    match text.strip_suffix('%') {
+        Some(percent) => Ok(number(percent)? / 100.0 * full),
+        None => number(text),
+    }
+}
+
+/// Parse a hue in degrees (`deg`, `turn` and `rad` units are accepted)
+fn hue(text: &str) -> Result<f32, String> {
+    if let Some(deg) = text.strip_suffix("deg") {
+        number(deg)
+    } else if let Some(turn) = text.strip_suffix("turn") {
+        Ok(number(turn)? * 360.0)
+    } else if let Some(rad) = text.strip_suffix("rad") {
+        Ok(number(rad)?.to_degrees())
+    } else {
+        number(text)
+    }
+}
+
+fn alpha(alpha: Option<&str>) -> Result<f32, String> {
+    match alpha {
+        Some(alpha) => Ok(number_or_percent(alpha, 1.0)?.clamp(0.0, 1.0)),
+        None => Ok(1.0),
+    }
+}
+
+fn parse_oklch(args: &str) -> Result<Color, String> {
+    let (parts, a) = channels(args)?;
+    let l = number_or_percent(parts[0], 1.0)?;
+    // In CSS, 100% chroma is 0.4
+    let c = number_or_percent(parts[1], 0.4)?;
+    let h = hue(parts[2])?;
+    Ok(oklcha(l, c, h, alpha(a)?))
+}
+
+fn parse_hsl(args: &str) -> Result<Color, String> {
+    let (parts, a) = channels(args)?;
+    let h = hue(parts[0])?;
+    let s = number_or_percent(parts[1], 1.0)?;
+    let l = number_or_percent(parts[2], 1.0)?;
+    let s = if parts[1].ends_with('%') {
+        s
+    } else {
+        s / 100.0
+    };
+    let l = if parts[2].ends_with('%') {
+        l
+    } else {
+        l / 100.0
+    };
+    let [r, g, b] = hsl_to_rgb(h, s.clamp(0.0, 1.0), l.clamp(0.0, 1.0));
+    Ok(Color::new([r, g, b, alpha(a)?]))
+}
+
+fn hsl_to_rgb(h: f32, s: f32, l: f32) -> [f32; 3] {
+    let h = h.rem_euclid(360.0);
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = l - c / 2.0;
+    let (r, g, b) = match h {
+        h if h < 60.0 => (c, x, 0.0),
+        h if h < 120.0 => (x, c, 0.0),
+        h if h < 180.0 => (0.0, c, x),
+        h if h < 240.0 => (0.0, x, c),
+        h if h < 300.0 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    [r + m, g + m, b + m]
+}
+
+fn parse_rgb(args: &str) -> Result<Color, String> {
+    let (parts, a) = channels(args)?;
+    let mut rgb = [0.0; 3];
+    for (channel, part) in rgb.iter_mut().zip(&parts) {
+        *channel = (number_or_percent(part, 255.0)? / 255.0).clamp(0.0, 1.0);
+    }
+    Ok(Color::new([rgb[0], rgb[1], rgb[2], alpha(a)?]))
+}
+
+fn parse_color_function(args: &str) -> Result<Color, String> {
+    let Some(args) = args.trim().strip_prefix("srgb ") else {
+        return Err("only the `srgb` color space is supported in `color()`".to_string());
+    };
+    let (parts, a) = channels(args)?;
+    let mut rgb = [0.0; 3];
+    for (channel, part) in rgb.iter_mut().zip(&parts) {
+        *channel = number_or_percent(part, 1.0)?;
+    }
+    Ok(Color::new([rgb[0], rgb[1], rgb[2], alpha(a)?]))
+}
+
+fn parse_hex(hex: &str) -> Result<Color, String> {
+    let digits: Option<Vec<u8>> = hex
+        .chars()
+        .map(|c| c.to_digit(16).map(|d| d as u8))
+        .collect();
+    let Some(digits) = digits else {
+        return Err(format!("`#{hex}` is not a hex color"));
+    };
+    let bytes: Vec<u8> = match digits.len() {
+        3 | 4 => digits.iter().map(|d| d * 17).collect(),
+        6 | 8 => digits.chunks(2).map(|p| p[0] * 16 + p[1]).collect(),
+        n => return Err(format!("hex colors have 3, 4, 6 or 8 digits, found {n}")),
+    };
+    let a = bytes.get(3).copied().unwrap_or(255);
+    Ok(Color::from_rgba8(bytes[0], bytes[1], bytes[2], a))
+}
+
+/// Format a color so [`parse_color`] reads it back exactly
+///
+/// Colors that fit in 8 bits per channel are written as hex, everything else
+/// as `color(srgb ...)`.
+#[cfg(feature = "serde")]
+pub(super) fn format_color(color: Color) -> String {
+    let rgba = color.to_rgba8();
+    if Color::from_rgba8(rgba.r, rgba.g, rgba.b, rgba.a) == color {
+        return if rgba.a == 255 {
+            format!("#{:02x}{:02x}{:02x}", rgba.r, rgba.g, rgba.b)
+        } else {
+            format!("#{:02x}{:02x}{:02x}{:02x}", rgba.r, rgba.g, rgba.b, rgba.a)
+        };
+    }
+    let [r, g, b, a] = color.components;
+    if a == 1.0 {
+        format!("color(srgb {r} {g} {b})")
+    } else {
+        format!("color(srgb {r} {g} {b} / {a})")
+    }
+}
+
+/// Parse a length into pixels
+fn parse_length(value: &str) -> Result<f32, String> {
+    let value = value.trim().to_ascii_lowercase();
+    let px = if let Some(rem) = value.strip_suffix("rem") {
+        number(rem.trim())? * REM
+    } else if let Some(em) = value.strip_suffix("em") {
+        number(em.trim())? * REM
+    } else if let Some(px) = value.strip_suffix("px") {
+        number(px.trim())?
+    } else if value == "0" {
+        0.0
+    } else {
+        return Err("expected a length in rem, em or px".to_string());
+    };
+    if px < 0.0 {
+        return Err("length cannot be negative".to_string());
+    }
+    Ok(px)
+}
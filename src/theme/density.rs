@@ -0,0 +1,109 @@
+//! Density modes for data-heavy or touch-friendly layouts
+//!
+//! [`DensityProp`] is an inherited style prop like
+//! [`ShadcnThemeProp`](super::ShadcnThemeProp). Components don't read it
+//! directly: [`with_shadcn_theme`](super::ShadcnThemeExt::with_shadcn_theme)
+//! hands them the theme with its size scales already adjusted by
+//! [`ShadcnTheme::with_density`], so control heights, paddings, gaps and font
+//! sizes follow the density of the nearest ancestor that sets one:
+//!
+//! ```rust
+//! use floem_shadcn::prelude::*;
+//! # use floem::prelude::*;
+//! # use floem::views::Empty;
+//! # fn app_view() -> Empty { Empty::new() }
+//! # fn toolbar() -> Empty { Empty::new() }
+//!
+//! // The whole app is compact, except for the toolbar
+//! app_view().style(|s| s.density(Density::Compact));
+//! toolbar().style(|s| s.density(Density::Comfortable));
+//! ```
+
+use floem::prop;
+use floem::style::StylePropValue;
+
+use super::ShadcnTheme;
+
+/// How tightly components are laid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Density {
+    /// Smaller controls and tighter spacing for data-heavy screens
+    Compact,
+    /// The shadcn/ui defaults
+    #[default]
+    Comfortable,
+    /// Larger controls and more room, for touch screens
+    Spacious,
+}
+
+impl Density {
+    /// Every density, from compact to spacious
+    pub const ALL: [Density; 3] = [Density::Compact, Density::Comfortable, Density::Spacious];
+
+    /// Factor applied to control heights
+    pub fn control_scale(self) -> f32 {
+        match self {
+            Density::Compact => 0.8,
+            Density::Comfortable => 1.0,
+            Density::Spacious => 1.2,
+        }
+    }
+
+    /// Factor applied to the spacing unit (paddings and gaps)
+    pub fn spacing_scale(self) -> f32 {
+        match self {
+            Density::Compact => 0.75,
+            Density::Comfortable => 1.0,
+            Density::Spacious => 1.25,
+        }
+    }
+
+    /// Pixels added to every font size
+    pub fn font_offset(self) -> f32 {
+        match self {
+            Density::Compact => -1.0,
+            Density::Comfortable => 0.0,
+            Density::Spacious => 1.0,
+        }
+    }
+}
+
+impl StylePropValue for Density {}
+
+impl ShadcnTheme {
+    /// The theme with its size scales adjusted for `density`
+    ///
+    /// Colors, radii, line heights and shadows are unchanged.
+    /// `Density::Comfortable` returns the theme as is.
+    pub fn with_density(mut self, density: Density) -> Self {
+        if density == Density::Comfortable {
+            return self;
+        }
+        let controls = density.control_scale();
+        let offset = density.font_offset();
+
+        self.spacing *= density.spacing_scale();
+        self.control_height.sm *= controls;
+        self.control_height.md *= controls;
+        self.control_height.lg *= controls;
+        for size in [
+            &mut self.font_size.xs,
+            &mut self.font_size.sm,
+            &mut self.font_size.base,
+            &mut self.font_size.lg,
+            &mut self.font_size.xl,
+            &mut self.font_size.xxl,
+        ] {
+            *size = (*size + offset).max(1.0);
+        }
+        self
+    }
+}
+
+prop!(
+    /// The density of a subtree. This is an inherited prop that flows down the view tree;
+    /// set it on any view to change the density of its descendants.
+    pub DensityProp: Density { inherited } = Density::Comfortable
+);
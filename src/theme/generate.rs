@@ -0,0 +1,229 @@
+//! Generate a theme from a brand color
+//!
+//! [`ThemeGenerator`] derives every token from one brand color and the hue of
+//! the neutral grays, following the structure of the shadcn/ui base colors:
+//!
+//! - Grays are OKLCH colors with the neutral hue and a little chroma, which
+//!   fades out towards black and white like the Tailwind palettes
+//! - `primary` and `ring` are the brand color; `accent` is a light (or dark)
+//!   tint of its hue
+//! - `destructive` is the shadcn/ui red and the charts use the shadcn/ui palette
+//! - The sidebar tokens mirror the page tokens on a slightly offset surface
+//!
+//! Every background/foreground pair is then pushed apart with
+//! [`ShadcnTheme::adjust_lightness`] until it reaches the minimum contrast.
+//! `primary` also keeps at least 3:1 against the page background so buttons
+//! stay visible.
+
+use peniko::Color;
+use peniko::color::{AlphaColor, Oklch};
+
+use super::contrast::{WCAG_AA, WCAG_AA_LARGE, contrast_ratio};
+use super::{ShadcnTheme, ShadowScale, ThemeMode, ThemePair, ThemePreset, oklch, oklcha};
+
+/// Default chroma of the generated grays (between zinc and slate)
+pub const DEFAULT_NEUTRAL_CHROMA: f32 = 0.015;
+
+/// Lightness step used when pushing colors apart
+const STEP: f32 = 0.01;
+
+/// Enough steps to cross the whole lightness range
+const MAX_STEPS: usize = 100;
+
+/// Builds a light and dark theme from a brand color
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThemeGenerator {
+    brand: Color,
+    neutral_hue: f32,
+    neutral_chroma: f32,
+    min_contrast: f32,
+}
+
+impl ThemeGenerator {
+    /// Create a generator for `brand`, with grays tinted towards `neutral_hue`
+    /// (an OKLCH hue in degrees)
+    pub fn new(brand: Color, neutral_hue: f32) -> Self {
+        Self {
+            brand,
+            neutral_hue,
+            neutral_chroma: DEFAULT_NEUTRAL_CHROMA,
+            min_contrast: WCAG_AA,
+        }
+    }
+
+    /// Set how strongly the grays are tinted (0 for pure grays)
+    pub fn neutral_chroma(mut self, chroma: f32) -> Self {
+        self.neutral_chroma = chroma.max(0.0);
+        self
+    }
+
+    /// Set the minimum contrast ratio of each background/foreground pair
+    ///
+    /// Defaults to 4.5:1 (WCAG AA). Values are clamped to 1..=21.
+    pub fn min_contrast(mut self, ratio: f32) -> Self {
+        self.min_contrast = ratio.clamp(1.0, 21.0);
+        self
+    }
+
+    /// Generate both modes
+    pub fn generate(&self) -> ThemePair {
+        ThemePair {
+            light: self.theme(ThemeMode::Light),
+            dark: self.theme(ThemeMode::Dark),
+        }
+    }
+
+    /// Generate the theme for one mode (`System` generates the light theme)
+    pub fn theme(&self, mode: ThemeMode) -> ShadcnTheme {
+        let min = self.min_contrast;
+        let dark = mode.is_dark();
+        let gray = |l: f32| self.gray(l);
+        let [_, brand_c, brand_h, _] = self.brand.convert::<Oklch>().components;
+        let defaults = ShadcnTheme::preset(ThemePreset::Neutral, mode);
+
+        let background = gray(if dark { 0.145 } else { 1.0 });
+        let surface = gray(if dark { 0.205 } else { 1.0 });
+        let subtle = gray(if dark { 0.269 } else { 0.97 });
+        let text = gray(if dark { 0.985 } else { 0.145 });
+        let strong_text = gray(if dark { 0.985 } else { 0.205 });
+
+        // Primary: the brand color, kept visible on the page, with whichever
+        // of near-white and near-black text reads better on it
+        let primary = push_away(self.brand, background, WCAG_AA_LARGE);
+        let on_primary = [gray(0.985), gray(0.145)]
+            .into_iter()
+            .max_by(|a, b| contrast_ratio(primary, *a).total_cmp(&contrast_ratio(primary, *b)))
+            .unwrap_or(text);
+        let (primary, primary_foreground) = ensure_contrast(primary, on_primary, min);
+
+        let accent = oklch(
+            if dark { 0.3 } else { 0.96 },
+            (brand_c * 0.25).min(0.05),
+            brand_h,
+        );
+        let destructive = if dark {
+            oklch(0.704, 0.191, 22.216)
+        } else {
+            oklch(0.577, 0.245, 27.325)
+        };
+
+        let (background, foreground) = ensure_contrast(background, text, min);
+        let (card, card_foreground) = ensure_contrast(surface, text, min);
+        let (popover, popover_foreground) = ensure_contrast(surface, text, min);
+        let (secondary, secondary_foreground) = ensure_contrast(subtle, strong_text, min);
+        let (accent, accent_foreground) = ensure_contrast(accent, strong_text, min);
+        let (destructive, destructive_foreground) = ensure_contrast(destructive, gray(0.985), min);
+        // Muted text is also used directly on the page background
+        let muted_foreground = gray(if dark { 0.708 } else { 0.556 });
+        let (muted, muted_foreground) = ensure_contrast(subtle, muted_foreground, min);
+        let muted_foreground = push_away(muted_foreground, background, min);
+        // The sidebar sits slightly off the page, like upstream's 50 / 900 shades
+        let (sidebar, sidebar_foreground) =
+            ensure_contrast(gray(if dark { 0.205 } else { 0.985 }), text, min);
+
+        let (border, input) = if dark {
+            (oklcha(1.0, 0.0, 0.0, 0.1), oklcha(1.0, 0.0, 0.0, 0.15))
+        } else {
+            (gray(0.922), gray(0.922))
+        };
+
+        ShadcnTheme {
+            mode: mode.resolve(ThemeMode::Light),
+            contrast: defaults.contrast,
+            background,
+            foreground,
+            card,
+            card_foreground,
+            popover,
+            popover_foreground,
+            primary,
+            primary_foreground,
+            secondary,
+            secondary_foreground,
+            muted,
+            muted_foreground,
+            accent,
+            accent_foreground,
+            destructive,
+            destructive_foreground,
+            border,
+            input,
+            ring: primary,
+            chart_1: defaults.chart_1,
+            chart_2: defaults.chart_2,
+            chart_3: defaults.chart_3,
+            chart_4: defaults.chart_4,
+            chart_5: defaults.chart_5,
+            sidebar,
+            sidebar_foreground,
+            sidebar_primary: primary,
+            sidebar_primary_foreground: primary_foreground,
+            sidebar_accent: accent,
+            sidebar_accent_foreground: accent_foreground,
+            sidebar_border: border,
+            sidebar_ring: primary,
+            radius: defaults.radius,
+            radius_sm: defaults.radius_sm,
+            radius_md: defaults.radius_md,
+            radius_lg: defaults.radius_lg,
+            ring_width: defaults.ring_width,
+            font_size: defaults.font_size,
+            line_height: defaults.line_height,
+            spacing: defaults.spacing,
+            control_height: defaults.control_height,
+            shadow: ShadowScale::with_color(foreground.with_alpha(0.1)),
+        }
+    }
+
+    /// A gray at lightness `l`, with chroma fading out towards black and white
+    fn gray(&self, l: f32) -> Color {
+        let fade = (1.0 - (2.0 * l - 1.0).abs()).clamp(0.0, 1.0);
+        oklch(l, self.neutral_chroma * fade, self.neutral_hue)
+    }
+}
+
+impl ThemePair {
+    /// Generate both modes from a brand color and a neutral hue
+    ///
+    /// See [`ThemeGenerator`] for the options.
+    pub fn from_brand(brand: Color, neutral_hue: f32) -> Self {
+        ThemeGenerator::new(brand, neutral_hue).generate()
+    }
+}
+
+fn lightness(color: Color) -> f32 {
+    color.convert::<Oklch>().components[0]
+}
+
+/// Move `color` away from `fixed` in lightness until they reach `min` contrast
+pub(super) fn push_away(color: Color, fixed: Color, min: f32) -> Color {
+    let delta = if lightness(color) >= lightness(fixed) {
+        STEP
+    } else {
+        -STEP
+    };
+    let mut color = color;
+    for _ in 0..MAX_STEPS {
+        if contrast_ratio(color, fixed) >= min {
+            break;
+        }
+        color = ShadcnTheme::adjust_lightness(color, delta);
+    }
+    clip(color)
+}
+
+/// Push a background/foreground pair apart until they reach `min` contrast
+///
+/// The foreground moves first; if it runs into black or white, the
+/// background moves the other way.
+pub(super) fn ensure_contrast(background: Color, foreground: Color, min: f32) -> (Color, Color) {
+    let foreground = push_away(foreground, background, min);
+    let background = push_away(background, foreground, min);
+    (background, foreground)
+}
+
+/// Bring a color back into the sRGB gamut
+fn clip(color: Color) -> Color {
+    let [r, g, b, a] = color.components;
+    AlphaColor::new([r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0), a])
+}
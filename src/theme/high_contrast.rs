@@ -0,0 +1,203 @@
+//! High-contrast and forced-colors themes
+//!
+//! [`ShadcnTheme::with_high_contrast`] turns any theme into a high-contrast
+//! variant:
+//!
+//! - Borders and inputs become solid colors (no alpha) with at least 3:1
+//!   contrast against the surface they sit on
+//! - Rings reach 4.5:1 against the page and are drawn thicker
+//! - Text pairs are pushed towards 7:1 (WCAG AAA)
+//!
+//! [`ShadcnTheme::forced_colors`] mimics the Windows high-contrast palettes,
+//! where everything is drawn with a handful of system colors.
+//!
+//! Both set [`ShadcnTheme::contrast`], so components can detect the mode
+//! through [`ShadcnThemeProp`](super::ShadcnThemeProp):
+//!
+//! ```rust
+//! # use floem::style::Style;
+//! # use floem_shadcn::theme::ShadcnThemeExt;
+//! # let s = Style::new();
+//! s.with_shadcn_theme(|s, t| {
+//!     s.apply_if(t.is_high_contrast(), |s| s.border(2.0))
+//! })
+//! # ;
+//! ```
+
+use peniko::Color;
+
+use super::contrast::{WCAG_AA, WCAG_AA_LARGE, WCAG_AAA, composite};
+use super::generate::{ensure_contrast, push_away};
+use super::{ShadcnTheme, ThemeMode, ThemePair, ThemePreset};
+
+/// Default width of focus rings in pixels
+pub const DEFAULT_RING_WIDTH: f32 = 2.0;
+
+/// Width of focus rings in high-contrast themes
+pub const HIGH_CONTRAST_RING_WIDTH: f32 = 3.0;
+
+#[cfg(feature = "serde")]
+pub(super) fn default_ring_width() -> f32 {
+    DEFAULT_RING_WIDTH
+}
+
+/// The contrast level a theme is designed for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum ThemeContrast {
+    /// The shadcn/ui colors
+    #[default]
+    Standard,
+    /// Solid, stronger borders and rings; from [`ShadcnTheme::with_high_contrast`]
+    High,
+    /// A system-color palette; from [`ShadcnTheme::forced_colors`]
+    Forced,
+}
+
+impl ShadcnTheme {
+    /// Returns true for high-contrast and forced-colors themes
+    pub fn is_high_contrast(&self) -> bool {
+        self.contrast != ThemeContrast::Standard
+    }
+
+    /// The high-contrast variant of a preset
+    pub fn preset_high_contrast(preset: ThemePreset, mode: ThemeMode) -> Self {
+        Self::preset(preset, mode).with_high_contrast()
+    }
+
+    /// The high-contrast variant of this theme
+    ///
+    /// Forced-colors themes are returned unchanged.
+    pub fn with_high_contrast(self) -> Self {
+        if self.contrast == ThemeContrast::Forced {
+            return self;
+        }
+        let mut t = self;
+        let page = t.background;
+
+        // Text pairs, towards AAA
+        (t.background, t.foreground) = ensure_contrast(t.background, t.foreground, WCAG_AAA);
+        (t.card, t.card_foreground) = ensure_contrast(t.card, t.card_foreground, WCAG_AAA);
+        (t.popover, t.popover_foreground) =
+            ensure_contrast(t.popover, t.popover_foreground, WCAG_AAA);
+        (t.primary, t.primary_foreground) =
+            ensure_contrast(t.primary, t.primary_foreground, WCAG_AAA);
+        (t.secondary, t.secondary_foreground) =
+            ensure_contrast(t.secondary, t.secondary_foreground, WCAG_AAA);
+        (t.muted, t.muted_foreground) = ensure_contrast(t.muted, t.muted_foreground, WCAG_AAA);
+        t.muted_foreground = push_away(t.muted_foreground, t.background, WCAG_AAA);
+        (t.accent, t.accent_foreground) = ensure_contrast(t.accent, t.accent_foreground, WCAG_AAA);
+        (t.destructive, t.destructive_foreground) =
+            ensure_contrast(t.destructive, t.destructive_foreground, WCAG_AA);
+        (t.sidebar, t.sidebar_foreground) =
+            ensure_contrast(t.sidebar, t.sidebar_foreground, WCAG_AAA);
+        (t.sidebar_accent, t.sidebar_accent_foreground) =
+            ensure_contrast(t.sidebar_accent, t.sidebar_accent_foreground, WCAG_AAA);
+        (t.sidebar_primary, t.sidebar_primary_foreground) =
+            ensure_contrast(t.sidebar_primary, t.sidebar_primary_foreground, WCAG_AAA);
+
+        // Solid borders that stand out from the page and from cards
+        let card = t.card;
+        let solid_border = |color: Color, surface: Color| {
+            let color = composite(color, composite(surface, page));
+            push_away(
+                push_away(color, surface, WCAG_AA_LARGE),
+                card,
+                WCAG_AA_LARGE,
+            )
+        };
+        t.border = solid_border(t.border, t.background);
+        t.input = solid_border(t.input, t.background);
+        t.sidebar_border = solid_border(t.sidebar_border, t.sidebar);
+
+        // Rings are focus indicators; keep them clearly visible
+        t.ring = push_away(composite(t.ring, page), t.background, WCAG_AA);
+        t.sidebar_ring = push_away(composite(t.sidebar_ring, t.sidebar), t.sidebar, WCAG_AA);
+        t.ring_width = t.ring_width.max(HIGH_CONTRAST_RING_WIDTH);
+
+        t.contrast = ThemeContrast::High;
+        t
+    }
+
+    /// A forced-colors theme modeled on the Windows high-contrast palettes
+    ///
+    /// Dark mode uses "High Contrast Black" (white on black with cyan
+    /// highlights), light mode uses "High Contrast White" (black on white with
+    /// purple highlights).
+    pub fn forced_colors(mode: ThemeMode) -> Self {
+        let mode = mode.resolve(ThemeMode::Light);
+        let rgb = |r, g, b| Color::from_rgba8(r, g, b, 255);
+        // Canvas, CanvasText, Highlight, HighlightText, GrayText, error red
+        let (canvas, text, highlight, highlight_text, gray_text, error) = if mode.is_dark() {
+            (
+                rgb(0x00, 0x00, 0x00),
+                rgb(0xff, 0xff, 0xff),
+                rgb(0x1a, 0xeb, 0xff),
+                rgb(0x00, 0x00, 0x00),
+                rgb(0x3f, 0xf2, 0x3f),
+                rgb(0xff, 0x80, 0x80),
+            )
+        } else {
+            (
+                rgb(0xff, 0xff, 0xff),
+                rgb(0x00, 0x00, 0x00),
+                rgb(0x37, 0x00, 0x6e),
+                rgb(0xff, 0xff, 0xff),
+                rgb(0x60, 0x00, 0x00),
+                rgb(0xa0, 0x00, 0x00),
+            )
+        };
+
+        ShadcnTheme {
+            contrast: ThemeContrast::Forced,
+            background: canvas,
+            foreground: text,
+            card: canvas,
+            card_foreground: text,
+            popover: canvas,
+            popover_foreground: text,
+            primary: highlight,
+            primary_foreground: highlight_text,
+            secondary: canvas,
+            secondary_foreground: text,
+            muted: canvas,
+            muted_foreground: gray_text,
+            accent: highlight,
+            accent_foreground: highlight_text,
+            destructive: error,
+            destructive_foreground: canvas,
+            border: text,
+            input: text,
+            ring: highlight,
+            sidebar: canvas,
+            sidebar_foreground: text,
+            sidebar_primary: highlight,
+            sidebar_primary_foreground: highlight_text,
+            sidebar_accent: highlight,
+            sidebar_accent_foreground: highlight_text,
+            sidebar_border: text,
+            sidebar_ring: highlight,
+            ring_width: HIGH_CONTRAST_RING_WIDTH,
+            ..Self::preset(ThemePreset::Neutral, mode)
+        }
+    }
+}
+
+impl ThemePair {
+    /// The high-contrast variants of both modes
+    pub fn with_high_contrast(self) -> Self {
+        Self {
+            light: self.light.with_high_contrast(),
+            dark: self.dark.with_high_contrast(),
+        }
+    }
+
+    /// The forced-colors themes for both modes
+    pub fn forced_colors() -> Self {
+        Self {
+            light: ShadcnTheme::forced_colors(ThemeMode::Light),
+            dark: ShadcnTheme::forced_colors(ThemeMode::Dark),
+        }
+    }
+}
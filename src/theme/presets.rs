@@ -0,0 +1,356 @@
+//! Theme presets for the official shadcn/ui colors
+//!
+//! The base colors (neutral, zinc, stone, gray, slate) map each token to a
+//! shade of the matching Tailwind palette, exactly like the `:root` and
+//! `.dark` blocks that `shadcn init` writes. The accent colors (red, rose,
+//! orange, green, blue, yellow, violet) replace `--primary`,
+//! `--primary-foreground` and `--ring` (and their `--sidebar-*` twins) on top
+//! of the base color they ship with upstream.
+
+use peniko::Color;
+
+use super::{
+    ControlHeightScale, DEFAULT_RING_WIDTH, DEFAULT_SPACING, FontSizeScale, LineHeightScale,
+    ShadcnTheme, ShadowScale, ThemeContrast, ThemeMode, oklch, oklcha,
+};
+
+/// An official shadcn/ui color theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum ThemePreset {
+    #[default]
+    Neutral,
+    Zinc,
+    Stone,
+    Gray,
+    Slate,
+    Red,
+    Rose,
+    Orange,
+    Green,
+    Blue,
+    Yellow,
+    Violet,
+}
+
+impl ThemePreset {
+    /// All presets, base colors first
+    pub const ALL: [ThemePreset; 12] = [
+        ThemePreset::Neutral,
+        ThemePreset::Zinc,
+        ThemePreset::Stone,
+        ThemePreset::Gray,
+        ThemePreset::Slate,
+        ThemePreset::Red,
+        ThemePreset::Rose,
+        ThemePreset::Orange,
+        ThemePreset::Green,
+        ThemePreset::Blue,
+        ThemePreset::Yellow,
+        ThemePreset::Violet,
+    ];
+
+    /// The lowercase name used by shadcn/ui (`"slate"`, `"rose"`, ...)
+    pub fn name(self) -> &'static str {
+        match self {
+            ThemePreset::Neutral => "neutral",
+            ThemePreset::Zinc => "zinc",
+            ThemePreset::Stone => "stone",
+            ThemePreset::Gray => "gray",
+            ThemePreset::Slate => "slate",
+            ThemePreset::Red => "red",
+            ThemePreset::Rose => "rose",
+            ThemePreset::Orange => "orange",
+            ThemePreset::Green => "green",
+            ThemePreset::Blue => "blue",
+            ThemePreset::Yellow => "yellow",
+            ThemePreset::Violet => "violet",
+        }
+    }
+
+    /// Look up a preset by its shadcn/ui name, ignoring case
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Returns true for the accent colors
+    pub fn is_accent(self) -> bool {
+        self.base() != self
+    }
+
+    /// The base color an accent preset is built on (base colors return themselves)
+    pub fn base(self) -> ThemePreset {
+        match self {
+            ThemePreset::Red => ThemePreset::Neutral,
+            ThemePreset::Rose | ThemePreset::Green => ThemePreset::Zinc,
+            ThemePreset::Orange | ThemePreset::Yellow => ThemePreset::Stone,
+            ThemePreset::Blue => ThemePreset::Slate,
+            ThemePreset::Violet => ThemePreset::Gray,
+            base => base,
+        }
+    }
+}
+
+impl ShadcnTheme {
+    /// Create the theme for a shadcn/ui preset in the given mode
+    ///
+    /// `ThemeMode::System` creates the light theme.
+    pub fn preset(preset: ThemePreset, mode: ThemeMode) -> Self {
+        let mode = mode.resolve(ThemeMode::Light);
+        let mut theme = base_theme(&palette(preset.base()), mode);
+        if let Some(accent) = accent(preset, mode) {
+            theme.primary = accent.primary;
+            theme.primary_foreground = accent.primary_foreground;
+            theme.ring = accent.ring;
+            theme.sidebar_primary = accent.primary;
+            theme.sidebar_primary_foreground = accent.primary_foreground;
+            theme.sidebar_ring = accent.ring;
+        }
+        theme
+    }
+}
+
+/// The Tailwind shades a base color uses
+struct Palette {
+    s50: Color,
+    s100: Color,
+    s200: Color,
+    s400: Color,
+    s500: Color,
+    s800: Color,
+    s900: Color,
+    s950: Color,
+}
+
+fn palette(base: ThemePreset) -> Palette {
+    match base {
+        ThemePreset::Zinc => Palette {
+            s50: oklch(0.985, 0.0, 0.0),
+            s100: oklch(0.967, 0.001, 286.375),
+            s200: oklch(0.92, 0.004, 286.32),
+            s400: oklch(0.705, 0.015, 286.067),
+            s500: oklch(0.552, 0.016, 285.938),
+            s800: oklch(0.274, 0.006, 286.033),
+            s900: oklch(0.21, 0.006, 285.885),
+            s950: oklch(0.141, 0.005, 285.823),
+        },
+        ThemePreset::Stone => Palette {
+            s50: oklch(0.985, 0.001, 106.423),
+            s100: oklch(0.97, 0.001, 106.424),
+            s200: oklch(0.923, 0.003, 48.717),
+            s400: oklch(0.709, 0.01, 56.259),
+            s500: oklch(0.553, 0.013, 58.071),
+            s800: oklch(0.268, 0.007, 34.298),
+            s900: oklch(0.216, 0.006, 56.043),
+            s950: oklch(0.147, 0.004, 49.25),
+        },
+        ThemePreset::Gray => Palette {
+            s50: oklch(0.985, 0.002, 247.839),
+            s100: oklch(0.967, 0.003, 264.542),
+            s200: oklch(0.928, 0.006, 264.531),
+            s400: oklch(0.707, 0.022, 261.325),
+            s500: oklch(0.551, 0.027, 264.364),
+            s800: oklch(0.278, 0.033, 256.848),
+            s900: oklch(0.21, 0.034, 264.665),
+            s950: oklch(0.13, 0.028, 261.692),
+        },
+        ThemePreset::Slate => Palette {
+            s50: oklch(0.984, 0.003, 247.858),
+            s100: oklch(0.968, 0.007, 247.896),
+            s200: oklch(0.929, 0.013, 255.508),
+            s400: oklch(0.704, 0.04, 256.788),
+            s500: oklch(0.554, 0.046, 257.417),
+            s800: oklch(0.279, 0.041, 260.031),
+            s900: oklch(0.208, 0.042, 265.755),
+            s950: oklch(0.129, 0.042, 264.695),
+        },
+        _ => Palette {
+            s50: oklch(0.985, 0.0, 0.0),
+            s100: oklch(0.97, 0.0, 0.0),
+            s200: oklch(0.922, 0.0, 0.0),
+            s400: oklch(0.708, 0.0, 0.0),
+            s500: oklch(0.556, 0.0, 0.0),
+            s800: oklch(0.269, 0.0, 0.0),
+            s900: oklch(0.205, 0.0, 0.0),
+            s950: oklch(0.145, 0.0, 0.0),
+        },
+    }
+}
+
+// Radius values (in pixels), shared by every preset
+const RADIUS: f32 = 6.0;
+const RADIUS_SM: f32 = 4.0;
+const RADIUS_MD: f32 = 6.0;
+const RADIUS_LG: f32 = 8.0;
+
+/// Map the palette onto the tokens the way shadcn/ui's base colors do
+fn base_theme(p: &Palette, mode: ThemeMode) -> ShadcnTheme {
+    match mode {
+        ThemeMode::Light | ThemeMode::System => ShadcnTheme {
+            mode,
+            contrast: ThemeContrast::Standard,
+            background: oklch(1.0, 0.0, 0.0), // --background: white
+            foreground: p.s950,               // --foreground: 950
+            card: oklch(1.0, 0.0, 0.0),       // --card: white
+            card_foreground: p.s950,          // --card-foreground: 950
+            popover: oklch(1.0, 0.0, 0.0),    // --popover: white
+            popover_foreground: p.s950,       // --popover-foreground: 950
+            primary: p.s900,                  // --primary: 900
+            primary_foreground: p.s50,        // --primary-foreground: 50
+            secondary: p.s100,                // --secondary: 100
+            secondary_foreground: p.s900,     // --secondary-foreground: 900
+            muted: p.s100,                    // --muted: 100
+            muted_foreground: p.s500,         // --muted-foreground: 500
+            accent: p.s100,                   // --accent: 100
+            accent_foreground: p.s900,        // --accent-foreground: 900
+            destructive: oklch(0.577, 0.245, 27.325), // --destructive: oklch(0.577 0.245 27.325)
+            destructive_foreground: oklch(0.985, 0.0, 0.0),
+            border: p.s200, // --border: 200
+            input: p.s200,  // --input: 200
+            ring: p.s400,   // --ring: 400
+            chart_1: oklch(0.646, 0.222, 41.116),
+            chart_2: oklch(0.6, 0.118, 184.704),
+            chart_3: oklch(0.398, 0.07, 227.392),
+            chart_4: oklch(0.828, 0.189, 84.429),
+            chart_5: oklch(0.769, 0.188, 70.08),
+            sidebar: p.s50,                    // --sidebar: 50
+            sidebar_foreground: p.s950,        // --sidebar-foreground: 950
+            sidebar_primary: p.s900,           // --sidebar-primary: 900
+            sidebar_primary_foreground: p.s50, // --sidebar-primary-foreground: 50
+            sidebar_accent: p.s100,            // --sidebar-accent: 100
+            sidebar_accent_foreground: p.s900, // --sidebar-accent-foreground: 900
+            sidebar_border: p.s200,            // --sidebar-border: 200
+            sidebar_ring: p.s400,              // --sidebar-ring: 400
+            radius: RADIUS,
+            radius_sm: RADIUS_SM,
+            radius_md: RADIUS_MD,
+            radius_lg: RADIUS_LG,
+            ring_width: DEFAULT_RING_WIDTH,
+            font_size: FontSizeScale::default(),
+            line_height: LineHeightScale::default(),
+            spacing: DEFAULT_SPACING,
+            control_height: ControlHeightScale::default(),
+            shadow: ShadowScale::with_color(p.s950.with_alpha(0.1)),
+        },
+        ThemeMode::Dark => ShadcnTheme {
+            mode,
+            contrast: ThemeContrast::Standard,
+            background: p.s950,                       // --background: 950
+            foreground: p.s50,                        // --foreground: 50
+            card: p.s900,                             // --card: 900
+            card_foreground: p.s50,                   // --card-foreground: 50
+            popover: p.s900,                          // --popover: 900
+            popover_foreground: p.s50,                // --popover-foreground: 50
+            primary: p.s200,                          // --primary: 200
+            primary_foreground: p.s900,               // --primary-foreground: 900
+            secondary: p.s800,                        // --secondary: 800
+            secondary_foreground: p.s50,              // --secondary-foreground: 50
+            muted: p.s800,                            // --muted: 800
+            muted_foreground: p.s400,                 // --muted-foreground: 400
+            accent: p.s800,                           // --accent: 800
+            accent_foreground: p.s50,                 // --accent-foreground: 50
+            destructive: oklch(0.704, 0.191, 22.216), // --destructive: oklch(0.704 0.191 22.216)
+            destructive_foreground: oklch(0.985, 0.0, 0.0),
+            border: oklcha(1.0, 0.0, 0.0, 0.1), // --border: oklch(1 0 0 / 10%)
+            input: oklcha(1.0, 0.0, 0.0, 0.1),  // --input: oklch(1 0 0 / 10%)
+            ring: p.s500,                       // --ring: 500
+            chart_1: oklch(0.488, 0.243, 264.376),
+            chart_2: oklch(0.696, 0.17, 162.48),
+            chart_3: oklch(0.769, 0.188, 70.08),
+            chart_4: oklch(0.627, 0.265, 303.9),
+            chart_5: oklch(0.645, 0.246, 16.439),
+            sidebar: p.s900,                               // --sidebar: 900
+            sidebar_foreground: p.s50,                     // --sidebar-foreground: 50
+            sidebar_primary: oklch(0.488, 0.243, 264.376), // --sidebar-primary: blue-700
+            sidebar_primary_foreground: p.s50,             // --sidebar-primary-foreground: 50
+            sidebar_accent: p.s800,                        // --sidebar-accent: 800
+            sidebar_accent_foreground: p.s50,              // --sidebar-accent-foreground: 50
+            sidebar_border: oklcha(1.0, 0.0, 0.0, 0.1),    // --sidebar-border: oklch(1 0 0 / 10%)
+            sidebar_ring: p.s500,                          // --sidebar-ring: 500
+            radius: RADIUS,
+            radius_sm: RADIUS_SM,
+            radius_md: RADIUS_MD,
+            radius_lg: RADIUS_LG,
+            ring_width: DEFAULT_RING_WIDTH,
+            font_size: FontSizeScale::default(),
+            line_height: LineHeightScale::default(),
+            spacing: DEFAULT_SPACING,
+            control_height: ControlHeightScale::default(),
+            shadow: ShadowScale::with_color(p.s50.with_alpha(0.1)),
+        },
+    }
+}
+
+/// Tokens replaced by an accent color
+struct Accent {
+    primary: Color,
+    primary_foreground: Color,
+    ring: Color,
+}
+
+fn accent(preset: ThemePreset, mode: ThemeMode) -> Option<Accent> {
+    let dark = mode == ThemeMode::Dark;
+    let (primary, primary_foreground, ring) = match preset {
+        ThemePreset::Red => {
+            let red_600 = oklch(0.577, 0.245, 27.325);
+            (red_600, oklch(0.971, 0.013, 17.38), red_600)
+        }
+        ThemePreset::Rose => {
+            let rose_600 = oklch(0.586, 0.253, 17.585);
+            (rose_600, oklch(0.969, 0.015, 12.422), rose_600)
+        }
+        ThemePreset::Orange => {
+            let primary = if dark {
+                oklch(0.646, 0.222, 41.116) // orange-600
+            } else {
+                oklch(0.705, 0.213, 47.604) // orange-500
+            };
+            (primary, oklch(0.985, 0.001, 106.423), primary)
+        }
+        ThemePreset::Green if dark => (
+            oklch(0.723, 0.219, 149.579), // green-500
+            oklch(0.266, 0.065, 152.934), // green-950
+            oklch(0.527, 0.154, 150.069), // green-700
+        ),
+        ThemePreset::Green => {
+            let green_600 = oklch(0.627, 0.194, 149.214);
+            // Upstream pairs green with a rose-50 foreground
+            (green_600, oklch(0.969, 0.015, 12.422), green_600)
+        }
+        ThemePreset::Blue if dark => (
+            oklch(0.623, 0.214, 259.815), // blue-500
+            oklch(0.208, 0.042, 265.755), // slate-900
+            oklch(0.488, 0.243, 264.376), // blue-700
+        ),
+        ThemePreset::Blue => {
+            let blue_600 = oklch(0.546, 0.245, 262.881);
+            (blue_600, oklch(0.984, 0.003, 247.858), blue_600)
+        }
+        ThemePreset::Yellow => {
+            let yellow_400 = oklch(0.852, 0.199, 91.936);
+            let ring = if dark {
+                oklch(0.476, 0.114, 61.907) // yellow-800
+            } else {
+                yellow_400
+            };
+            (yellow_400, oklch(0.286, 0.066, 53.813), ring)
+        }
+        ThemePreset::Violet => {
+            let primary = if dark {
+                oklch(0.491, 0.27, 292.581) // violet-700
+            } else {
+                oklch(0.541, 0.281, 293.009) // violet-600
+            };
+            (primary, oklch(0.985, 0.002, 247.839), primary)
+        }
+        _ => return None,
+    };
+    Some(Accent {
+        primary,
+        primary_foreground,
+        ring,
+    })
+}
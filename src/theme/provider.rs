@@ -0,0 +1,233 @@
+//! Root wrapper that applies the theme and follows the OS color scheme
+//!
+//! [`ThemeProvider`] sets [`ShadcnThemeProp`](super::ShadcnThemeProp) on its
+//! child and animates between themes whenever the mode or the themes change.
+//! With [`ThemeMode::System`] it starts from the OS color scheme (with the
+//! default `system-theme` feature) and switches between light and dark when
+//! the window reports a new one.
+//!
+//! # Example
+//!
+//! ```rust
+//! use floem::reactive::RwSignal;
+//! use floem_shadcn::theme::{ThemeMode, ThemePreset, ThemeProvider, ThemeState};
+//! # use floem::prelude::*;
+//! # use floem::reactive::ReadSignal;
+//! # fn app_view(_mode: ReadSignal<ThemeMode>) -> impl IntoView {
+//! #     floem::views::Empty::new()
+//! # }
+//!
+//! let theme = ThemeState::new(RwSignal::new(ThemeMode::System));
+//! let resolved = theme.resolved();
+//!
+//! ThemeProvider::new(theme, move || app_view(resolved))
+//!     .preset(ThemePreset::Zinc)
+//!     .transition(std::time::Duration::from_millis(300));
+//! ```
+
+use std::time::Duration;
+
+use floem::event::{Event, EventListener};
+use floem::prelude::*;
+use floem::reactive::{Context, Effect, ReadSignal, RwSignal, Scope, SignalGet, SignalUpdate};
+use floem::style::Transition;
+use floem::views::Decorators;
+use floem::{HasViewId, ViewId};
+
+use super::{ShadcnTheme, ShadcnThemeExt, ShadcnThemeProp, ThemeMode, ThemePair, ThemePreset};
+
+/// Default duration of the animation between themes
+pub const DEFAULT_THEME_TRANSITION: Duration = Duration::from_millis(200);
+
+// ============================================================================
+// ThemeState
+// ============================================================================
+
+/// Theme state shared by a [`ThemeProvider`] and the app
+///
+/// Views built inside the provider can get it with [`ThemeState::current`].
+#[derive(Clone, Copy)]
+pub struct ThemeState {
+    mode: RwSignal<ThemeMode>,
+    system: RwSignal<ThemeMode>,
+    resolved: RwSignal<ThemeMode>,
+    themes: RwSignal<ThemePair>,
+    transition: RwSignal<Duration>,
+}
+
+impl ThemeState {
+    /// Create theme state for the mode chosen by the user
+    ///
+    /// The OS color scheme is read right away, so a dark OS never shows a
+    /// light first frame. Without the `system-theme` feature it counts as
+    /// light until the window reports it.
+    pub fn new(mode: RwSignal<ThemeMode>) -> Self {
+        Self::with_system(mode, os_color_scheme())
+    }
+
+    /// Create theme state with a known OS color scheme, which the provider
+    /// keeps updating from the window
+    pub fn with_system(mode: RwSignal<ThemeMode>, system: ThemeMode) -> Self {
+        let system = RwSignal::new(system.resolve(ThemeMode::Light));
+        let resolved = RwSignal::new(mode.get_untracked().resolve(system.get_untracked()));
+        Effect::new(move |_| {
+            let next = mode.get().resolve(system.get());
+            if resolved.get_untracked() != next {
+                resolved.set(next);
+            }
+        });
+        Self {
+            mode,
+            system,
+            resolved,
+            themes: RwSignal::new(ThemePair::default()),
+            transition: RwSignal::new(DEFAULT_THEME_TRANSITION),
+        }
+    }
+
+    /// Get the theme state of the enclosing provider, if any
+    pub fn current() -> Option<Self> {
+        Context::get::<ThemeState>()
+    }
+
+    /// Create a child scope that provides this state to the content
+    pub fn provide(self) -> Scope {
+        let scope = Scope::current().create_child();
+        scope.provide_context(self);
+        scope
+    }
+
+    /// The mode chosen by the user (may be `System`)
+    pub fn mode(&self) -> RwSignal<ThemeMode> {
+        self.mode
+    }
+
+    /// The OS color scheme, updated by the provider
+    pub fn system(&self) -> RwSignal<ThemeMode> {
+        self.system
+    }
+
+    /// The mode in effect: `Light` or `Dark`, never `System`
+    pub fn resolved(&self) -> ReadSignal<ThemeMode> {
+        self.resolved.read_only()
+    }
+
+    /// The light and dark themes to switch between
+    pub fn themes(&self) -> RwSignal<ThemePair> {
+        self.themes
+    }
+
+    /// Duration of the animation between themes
+    pub fn transition(&self) -> RwSignal<Duration> {
+        self.transition
+    }
+
+    /// The theme for the resolved mode (tracked)
+    pub fn theme(&self) -> ShadcnTheme {
+        self.themes.get().get(self.resolved.get())
+    }
+}
+
+/// The current OS color scheme, or light if it can't be read
+#[cfg(feature = "system-theme")]
+fn os_color_scheme() -> ThemeMode {
+    match dark_light::detect() {
+        Ok(dark_light::Mode::Dark) => ThemeMode::Dark,
+        _ => ThemeMode::Light,
+    }
+}
+
+#[cfg(not(feature = "system-theme"))]
+fn os_color_scheme() -> ThemeMode {
+    ThemeMode::Light
+}
+
+// ============================================================================
+// ThemeProvider
+// ============================================================================
+
+/// Root view that applies the theme in a [`ThemeState`] to its child
+pub struct ThemeProvider {
+    id: ViewId,
+    state: ThemeState,
+    child: Box<dyn View>,
+}
+
+impl ThemeProvider {
+    /// Create a provider; `child` is built inside the provider's scope so
+    /// [`ThemeState::current`] works in the whole tree
+    pub fn new<V: IntoView + 'static>(state: ThemeState, child: impl FnOnce() -> V) -> Self {
+        let child = state.provide().enter(|| child().into_any());
+        Self {
+            id: ViewId::new(),
+            state,
+            child,
+        }
+    }
+
+    /// Switch between the light and dark themes of a preset
+    pub fn preset(self, preset: ThemePreset) -> Self {
+        self.state.themes.set(ThemePair::preset(preset));
+        self
+    }
+
+    /// Switch between custom light and dark themes
+    pub fn themes(self, themes: ThemePair) -> Self {
+        self.state.themes.set(themes);
+        self
+    }
+
+    /// Set how long theme switches animate (zero switches instantly)
+    pub fn transition(self, duration: Duration) -> Self {
+        self.state.transition.set(duration);
+        self
+    }
+
+    /// The state driving this provider
+    pub fn state(&self) -> ThemeState {
+        self.state
+    }
+
+    /// Build the provider view
+    pub fn build(self) -> impl IntoView {
+        let state = self.state;
+
+        floem::views::Container::new(self.child)
+            .style(move |s| {
+                let duration = state.transition.get();
+                s.size_full()
+                    .shadcn_theme(state.theme())
+                    .apply_if(!duration.is_zero(), |s| {
+                        s.transition(ShadcnThemeProp, Transition::ease_in_out(duration))
+                    })
+            })
+            .on_event_cont(EventListener::ThemeChanged, move |e| {
+                if let Event::ThemeChanged(theme) = e {
+                    let mode = match theme {
+                        floem::window::Theme::Dark => ThemeMode::Dark,
+                        _ => ThemeMode::Light,
+                    };
+                    state.system.set(mode);
+                }
+            })
+    }
+}
+
+impl HasViewId for ThemeProvider {
+    fn view_id(&self) -> ViewId {
+        self.id
+    }
+}
+
+impl IntoView for ThemeProvider {
+    type V = Box<dyn View>;
+    type Intermediate = Self;
+
+    fn into_intermediate(self) -> Self::Intermediate {
+        self
+    }
+
+    fn into_view(self) -> Self::V {
+        Box::new(self.build().into_view())
+    }
+}
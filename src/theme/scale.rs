@@ -0,0 +1,204 @@
+//! Size tokens: typography, spacing, control heights and shadows
+//!
+//! The defaults follow the Tailwind scales that shadcn/ui components use
+//! (`text-sm`, `h-10`, `px-3`, `shadow-md`, ...). Components read them from
+//! [`ShadcnTheme`](super::ShadcnTheme) instead of hardcoding pixel values, so
+//! changing the scales in one theme restyles the whole kit:
+//!
+//! ```rust
+//! use floem_shadcn::theme::ShadcnTheme;
+//!
+//! let mut theme = ShadcnTheme::light();
+//! theme.font_size.sm = 15.0;
+//! theme.spacing = 5.0;
+//! theme.control_height.md = 44.0;
+//! ```
+
+use peniko::Color;
+
+use super::{lerp_color, lerp_f32};
+
+/// Default spacing unit in pixels (Tailwind's `--spacing: 0.25rem`)
+pub const DEFAULT_SPACING: f32 = 4.0;
+
+#[cfg(feature = "serde")]
+pub(super) fn default_spacing() -> f32 {
+    DEFAULT_SPACING
+}
+
+/// Font sizes in pixels, named after the Tailwind `text-*` utilities
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct FontSizeScale {
+    pub xs: f32,
+    pub sm: f32,
+    pub base: f32,
+    pub lg: f32,
+    pub xl: f32,
+    /// `text-2xl`
+    pub xxl: f32,
+}
+
+impl Default for FontSizeScale {
+    fn default() -> Self {
+        Self {
+            xs: 12.0,
+            sm: 14.0,
+            base: 16.0,
+            lg: 18.0,
+            xl: 20.0,
+            xxl: 24.0,
+        }
+    }
+}
+
+impl FontSizeScale {
+    pub(super) fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            xs: lerp_f32(self.xs, other.xs, t),
+            sm: lerp_f32(self.sm, other.sm, t),
+            base: lerp_f32(self.base, other.base, t),
+            lg: lerp_f32(self.lg, other.lg, t),
+            xl: lerp_f32(self.xl, other.xl, t),
+            xxl: lerp_f32(self.xxl, other.xxl, t),
+        }
+    }
+}
+
+/// Line heights as multiples of the font size, named after `leading-*`
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct LineHeightScale {
+    pub none: f32,
+    pub tight: f32,
+    pub normal: f32,
+    pub relaxed: f32,
+}
+
+impl Default for LineHeightScale {
+    fn default() -> Self {
+        Self {
+            none: 1.0,
+            tight: 1.25,
+            normal: 1.5,
+            relaxed: 1.625,
+        }
+    }
+}
+
+impl LineHeightScale {
+    pub(super) fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            none: lerp_f32(self.none, other.none, t),
+            tight: lerp_f32(self.tight, other.tight, t),
+            normal: lerp_f32(self.normal, other.normal, t),
+            relaxed: lerp_f32(self.relaxed, other.relaxed, t),
+        }
+    }
+}
+
+/// Heights of buttons, inputs, select triggers and other controls in pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct ControlHeightScale {
+    /// Small controls (`h-9`)
+    pub sm: f32,
+    /// Default controls (`h-10`)
+    pub md: f32,
+    /// Large controls (`h-11`)
+    pub lg: f32,
+}
+
+impl Default for ControlHeightScale {
+    fn default() -> Self {
+        Self {
+            sm: 36.0,
+            md: 40.0,
+            lg: 44.0,
+        }
+    }
+}
+
+impl ControlHeightScale {
+    pub(super) fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            sm: lerp_f32(self.sm, other.sm, t),
+            md: lerp_f32(self.md, other.md, t),
+            lg: lerp_f32(self.lg, other.lg, t),
+        }
+    }
+}
+
+/// A single box shadow
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Shadow {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub offset_x: f32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub offset_y: f32,
+    pub blur: f32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub spread: f32,
+    #[cfg_attr(feature = "serde", serde(with = "super::config::color"))]
+    pub color: Color,
+}
+
+impl Shadow {
+    pub(super) fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            offset_x: lerp_f32(self.offset_x, other.offset_x, t),
+            offset_y: lerp_f32(self.offset_y, other.offset_y, t),
+            blur: lerp_f32(self.blur, other.blur, t),
+            spread: lerp_f32(self.spread, other.spread, t),
+            color: lerp_color(self.color, other.color, t),
+        }
+    }
+}
+
+/// Elevation shadows, from buttons and inputs (`sm`) to floating panels (`md`)
+/// and toasts and dialogs (`lg`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct ShadowScale {
+    pub sm: Shadow,
+    pub md: Shadow,
+    pub lg: Shadow,
+}
+
+impl Default for ShadowScale {
+    fn default() -> Self {
+        Self::with_color(Color::BLACK.with_alpha(0.1))
+    }
+}
+
+impl ShadowScale {
+    /// The default elevations, all cast in `color`
+    pub fn with_color(color: Color) -> Self {
+        let shadow = |offset_y: f32, blur: f32| Shadow {
+            offset_x: 0.0,
+            offset_y,
+            blur,
+            spread: 0.0,
+            color,
+        };
+        Self {
+            sm: shadow(1.0, 2.0),
+            md: shadow(0.0, 8.0),
+            lg: shadow(4.0, 16.0),
+        }
+    }
+
+    pub(super) fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            sm: self.sm.interpolate(&other.sm, t),
+            md: self.md.interpolate(&other.md, t),
+            lg: self.lg.interpolate(&other.lg, t),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 //! Tests for Button component layout and sizing
 //!
 //! These tests verify that buttons have correct dimensions based on their size variant.
-//! Expected sizing (from floem-tailwind):
+//! Expected sizing (from the default theme scales):
 //! - Sm: height 36px (h_9), padding-x 12px (px_3)
 //! - Default: height 40px (h_10), padding-x 16px (px_4), padding-y 8px (py_2)
 //! - Lg: height 44px (h_11), padding-x 32px (px_8)
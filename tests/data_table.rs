@@ -0,0 +1,187 @@
+//! Tests for the DataTable component's sorting, filtering, pagination and
+//! selection logic
+
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
+use floem_shadcn::components::data_table::{ColumnSort, DataColumn, DataTable, SortDirection};
+use floem_test::prelude::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Payment {
+    email: &'static str,
+    status: &'static str,
+    amount: f64,
+}
+
+fn payments() -> Vec<Payment> {
+    vec![
+        Payment {
+            email: "ken99@example.com",
+            status: "success",
+            amount: 316.0,
+        },
+        Payment {
+            email: "abe45@example.com",
+            status: "success",
+            amount: 242.0,
+        },
+        Payment {
+            email: "monserrat44@example.com",
+            status: "processing",
+            amount: 837.0,
+        },
+        Payment {
+            email: "silas22@example.com",
+            status: "success",
+            amount: 874.0,
+        },
+        Payment {
+            email: "carmella@example.com",
+            status: "failed",
+            amount: 721.0,
+        },
+    ]
+}
+
+fn sample_table() -> DataTable<Payment> {
+    DataTable::new(
+        RwSignal::new(payments()),
+        vec![
+            DataColumn::new("Status", |p: &Payment| p.status.to_string()),
+            DataColumn::new("Email", |p: &Payment| p.email.to_string()).sortable(),
+            DataColumn::new("Amount", |p: &Payment| format!("${:.2}", p.amount))
+                .sort_by(|a, b| a.amount.total_cmp(&b.amount))
+                .filterable(false),
+        ],
+    )
+}
+
+#[test]
+fn test_rows_in_data_order_by_default() {
+    let table = sample_table();
+    assert_eq!(table.rows(), vec![0, 1, 2, 3, 4]);
+    assert_eq!(table.page_count(), 1);
+    assert_eq!(table.page_rows(), vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_toggle_sort_cycles_directions() {
+    let table = sample_table();
+    let sort = table.sort_signal();
+
+    table.toggle_sort(2);
+    assert_eq!(
+        sort.get(),
+        Some(ColumnSort {
+            column: 2,
+            direction: SortDirection::Ascending
+        })
+    );
+    assert_eq!(table.rows(), vec![1, 0, 4, 2, 3]);
+
+    table.toggle_sort(2);
+    assert_eq!(table.rows(), vec![3, 2, 4, 0, 1]);
+
+    table.toggle_sort(2);
+    assert_eq!(sort.get(), None);
+    assert_eq!(table.rows(), vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_unsortable_column_ignored() {
+    let table = sample_table();
+    table.toggle_sort(0);
+    assert_eq!(table.sort_signal().get(), None);
+}
+
+#[test]
+fn test_sort_by_accessor_text() {
+    let table = sample_table();
+    table.toggle_sort(1);
+    assert_eq!(table.rows(), vec![1, 4, 0, 2, 3]);
+}
+
+#[test]
+fn test_filter_is_case_insensitive_and_skips_unfilterable_columns() {
+    let table = sample_table();
+    let filter = table.filter_signal();
+
+    filter.set("SUCCESS".to_string());
+    assert_eq!(table.rows(), vec![0, 1, 3]);
+
+    filter.set("44@".to_string());
+    assert_eq!(table.rows(), vec![2]);
+
+    // "837" only appears in the Amount column, which is not filterable
+    filter.set("837".to_string());
+    assert!(table.rows().is_empty());
+}
+
+#[test]
+fn test_pagination_splits_filtered_rows() {
+    let table = sample_table().page_size(2);
+    let page = table.page_signal();
+
+    assert_eq!(table.page_count(), 3);
+    assert_eq!(table.page_rows(), vec![0, 1]);
+
+    page.set(3);
+    assert_eq!(table.page_rows(), vec![4]);
+
+    table.filter_signal().set("success".to_string());
+    assert_eq!(table.page_count(), 2);
+}
+
+#[test]
+fn test_page_count_is_at_least_one() {
+    let table = sample_table().page_size(2);
+    table.filter_signal().set("nothing matches".to_string());
+    assert_eq!(table.page_count(), 1);
+    assert!(table.page_rows().is_empty());
+}
+
+#[test]
+fn test_selected_rows_follow_selection_signal() {
+    let table = sample_table().selectable();
+    let selection = table.selection_signal();
+
+    selection.update(|s| {
+        s.insert(3);
+        s.insert(1);
+    });
+    let selected = table.selected_rows();
+    assert_eq!(selected.len(), 2);
+    assert_eq!(selected[0].email, "abe45@example.com");
+    assert_eq!(selected[1].email, "silas22@example.com");
+
+    // Selection is keyed by data index, so sorting doesn't change it
+    table.toggle_sort(2);
+    assert_eq!(table.selected_rows(), selected);
+}
+
+#[test]
+fn test_selection_cleared_when_data_replaced() {
+    let data = RwSignal::new(payments());
+    let table = DataTable::new(
+        data,
+        vec![DataColumn::new("Email", |p: &Payment| p.email.to_string())],
+    )
+    .selectable();
+    let selection = table.selection_signal();
+
+    let _harness = HeadlessHarness::new_with_size(table, 600.0, 400.0);
+    selection.update(|s| {
+        s.insert(1);
+        s.insert(4);
+    });
+
+    // Removing rows would make index 4 point past the end and index 1 at a
+    // different payment
+    data.update(|rows| {
+        rows.remove(0);
+        rows.truncate(3);
+    });
+    assert!(
+        selection.get().is_empty(),
+        "Selection should not carry over to different rows"
+    );
+}
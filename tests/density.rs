@@ -0,0 +1,170 @@
+//! Tests for density modes
+//!
+//! Density adjusts the size scales handed to components by `with_shadcn_theme`:
+//! - Compact: control heights x0.8, spacing x0.75, font sizes -1px
+//! - Comfortable: the defaults
+//! - Spacious: control heights x1.2, spacing x1.25, font sizes +1px
+
+use floem::prelude::*;
+use floem_shadcn::components::button::Button;
+use floem_shadcn::components::combobox::{Combobox, ComboboxTrigger};
+use floem_shadcn::components::data_table::{DataColumn, DataTable};
+use floem_shadcn::components::input::Input;
+use floem_shadcn::components::sidebar::SidebarMenuButtonSize;
+use floem_shadcn::theme::{Density, ShadcnTheme, ShadcnThemeExt};
+use floem_test::prelude::*;
+
+fn height_of(id: ViewId) -> f32 {
+    id.get_layout().expect("layout should exist").size.height
+}
+
+// =============================================================================
+// Theme scaling
+// =============================================================================
+
+#[test]
+fn test_comfortable_is_the_default_theme() {
+    let theme = ShadcnTheme::light();
+    assert_eq!(Density::default(), Density::Comfortable);
+    assert_eq!(theme.with_density(Density::Comfortable), theme);
+}
+
+#[test]
+fn test_compact_and_spacious_scales() {
+    let theme = ShadcnTheme::light();
+
+    let compact = theme.with_density(Density::Compact);
+    assert_eq!(compact.control_height.md, 32.0);
+    assert_eq!(compact.space(4.0), 12.0);
+    assert_eq!(compact.font_size.sm, 13.0);
+
+    let spacious = theme.with_density(Density::Spacious);
+    assert_eq!(spacious.control_height.md, 48.0);
+    assert_eq!(spacious.space(4.0), 20.0);
+    assert_eq!(spacious.font_size.sm, 15.0);
+
+    // Colors and radii are untouched
+    assert_eq!(compact.primary, theme.primary);
+    assert_eq!(spacious.radius, theme.radius);
+    assert_eq!(compact.shadow, theme.shadow);
+}
+
+#[test]
+fn test_densities_are_ordered() {
+    let theme = ShadcnTheme::dark();
+    let [compact, comfortable, spacious] = Density::ALL.map(|d| theme.with_density(d));
+    for (smaller, larger) in [(compact, comfortable), (comfortable, spacious)] {
+        assert!(smaller.control_height.sm < larger.control_height.sm);
+        assert!(smaller.spacing < larger.spacing);
+        assert!(smaller.font_size.xs < larger.font_size.xs);
+    }
+}
+
+#[test]
+fn test_sidebar_button_heights_follow_spacing() {
+    let theme = ShadcnTheme::light();
+    assert_eq!(SidebarMenuButtonSize::Default.height_in(&theme), 32.0);
+    let compact = theme.with_density(Density::Compact);
+    assert_eq!(SidebarMenuButtonSize::Default.height_in(&compact), 24.0);
+    assert_eq!(SidebarMenuButtonSize::Lg.height_in(&compact), 36.0);
+}
+
+// =============================================================================
+// Inherited prop
+// =============================================================================
+
+#[test]
+fn test_button_height_follows_density() {
+    let default = Button::new("Default");
+    let default_id = default.view_id();
+    let compact = Button::new("Compact");
+    let compact_id = compact.view_id();
+    let spacious = Button::new("Spacious");
+    let spacious_id = spacious.view_id();
+
+    let container = Stack::new((
+        default,
+        floem::views::Container::new(compact).style(|s| s.density(Density::Compact)),
+        floem::views::Container::new(spacious).style(|s| s.density(Density::Spacious)),
+    ))
+    .style(|s| s.flex_col().size(400.0, 400.0));
+
+    let mut harness = HeadlessHarness::new_with_size(container, 400.0, 400.0);
+    harness.rebuild();
+
+    assert!((height_of(default_id) - 40.0).abs() < 0.1);
+    assert!((height_of(compact_id) - 32.0).abs() < 0.1);
+    assert!((height_of(spacious_id) - 48.0).abs() < 0.1);
+}
+
+#[test]
+fn test_density_can_be_overridden_per_subtree() {
+    let inner = Input::new();
+    let inner_id = inner.view_id();
+    let outer = Input::new();
+    let outer_id = outer.view_id();
+
+    let container = Stack::new((
+        outer,
+        floem::views::Container::new(inner).style(|s| s.density(Density::Comfortable)),
+    ))
+    .style(|s| s.flex_col().size(400.0, 400.0).density(Density::Compact));
+
+    let mut harness = HeadlessHarness::new_with_size(container, 400.0, 400.0);
+    harness.rebuild();
+
+    assert!((height_of(outer_id) - 32.0).abs() < 0.1);
+    assert!((height_of(inner_id) - 40.0).abs() < 0.1);
+}
+
+// =============================================================================
+// Components
+// =============================================================================
+
+#[test]
+fn test_combobox_trigger_height_follows_density() {
+    let default = Combobox::new(RwSignal::new(None), RwSignal::new(String::new()))
+        .child(ComboboxTrigger::new("Default"));
+    let default_id = default.view_id();
+    let compact = Combobox::new(RwSignal::new(None), RwSignal::new(String::new()))
+        .child(ComboboxTrigger::new("Compact"));
+    let compact_id = compact.view_id();
+
+    let container = Stack::new((
+        default,
+        floem::views::Container::new(compact).style(|s| s.density(Density::Compact)),
+    ))
+    .style(|s| s.flex_col().size(400.0, 400.0));
+
+    let mut harness = HeadlessHarness::new_with_size(container, 400.0, 400.0);
+    harness.rebuild();
+
+    // h-9
+    assert!((height_of(default_id) - 36.0).abs() < 0.1);
+    assert!((height_of(compact_id) - 28.8).abs() < 0.1);
+}
+
+#[test]
+fn test_data_table_rows_follow_density() {
+    fn empty_table() -> DataTable<&'static str> {
+        DataTable::new(
+            RwSignal::new(Vec::new()),
+            vec![DataColumn::new("Name", |name: &&str| name.to_string()).sortable()],
+        )
+        .show_filter(false)
+    }
+
+    let default = floem::views::Container::new(empty_table());
+    let default_id = default.view_id();
+    let compact =
+        floem::views::Container::new(empty_table()).style(|s| s.density(Density::Compact));
+    let compact_id = compact.view_id();
+
+    let container = Stack::new((default, compact)).style(|s| s.flex_col().size(400.0, 600.0));
+
+    let mut harness = HeadlessHarness::new_with_size(container, 400.0, 600.0);
+    harness.rebuild();
+
+    // The h-24 empty row alone shrinks from 96px to 72px
+    assert!(height_of(default_id) - height_of(compact_id) >= 24.0);
+}
@@ -8,11 +8,13 @@ use floem::reactive::RwSignal;
 use floem::text::Weight;
 use floem::views::Scroll;
 use floem_test::prelude::*;
+use ui_events::keyboard::{Code, Key, KeyState, KeyboardEvent, Location, Modifiers, NamedKey};
 
 use floem_shadcn::components::button::Button;
 use floem_shadcn::components::dialog::{
     Dialog, DialogClose, DialogContent, DialogContext, DialogFooter, DialogHeader, DialogTrigger,
 };
+use floem_shadcn::components::sheet::{Sheet, SheetContent, SheetTrigger};
 use floem_shadcn::theme::ShadcnThemeExt;
 
 // =============================================================================
@@ -594,3 +596,169 @@ fn test_dialog_context_access() {
     // Note: Context access in style closures may not work as expected
     // This test documents the current behavior
 }
+
+// =============================================================================
+// Keyboard focus tests
+// =============================================================================
+
+fn key_down(key: Key) -> Event {
+    Event::Key(KeyboardEvent {
+        state: KeyState::Down,
+        key,
+        modifiers: Modifiers::default(),
+        code: Code::Unidentified,
+        location: Location::Standard,
+        is_composing: false,
+        repeat: false,
+    })
+}
+
+/// Test that Escape closes the dialog and focus returns to the trigger
+#[test]
+fn test_dialog_escape_closes_and_restores_focus() {
+    use floem::HasViewId;
+
+    let trigger = DialogTrigger::new(Button::new("Open"));
+    let trigger_id = trigger.view_id();
+    let dialog = Dialog::new((
+        trigger,
+        DialogContent::new((DialogHeader::new().title("Test"),)),
+    ));
+    let open = dialog.open_signal();
+
+    let view = floem::views::stack((dialog,)).style(|s| s.size(800.0, 600.0));
+    let mut harness = HeadlessHarness::new_with_size(view, 800.0, 600.0);
+    harness.rebuild();
+
+    harness.click(30.0, 15.0);
+    harness.rebuild();
+    assert!(open.get(), "Dialog should open from the trigger");
+    assert!(
+        !harness.is_focused(trigger_id),
+        "Focus should move into the dialog when it opens"
+    );
+
+    harness.dispatch_event(key_down(Key::Named(NamedKey::Escape)));
+    harness.rebuild();
+    assert!(!open.get(), "Escape should close the dialog");
+    assert!(
+        harness.is_focused(trigger_id),
+        "Focus should return to the trigger after closing"
+    );
+}
+
+/// Test that an Escape handler returning false keeps the dialog open
+#[test]
+fn test_dialog_escape_veto() {
+    let dialog = Dialog::new((
+        DialogTrigger::new(Button::new("Open")),
+        DialogContent::new((DialogHeader::new().title("Test"),)),
+    ))
+    .on_escape_key_down(|| false);
+    let open = dialog.open_signal();
+
+    let view = floem::views::stack((dialog,)).style(|s| s.size(800.0, 600.0));
+    let mut harness = HeadlessHarness::new_with_size(view, 800.0, 600.0);
+    harness.rebuild();
+
+    open.set(true);
+    harness.rebuild();
+    harness.dispatch_event(key_down(Key::Named(NamedKey::Escape)));
+    harness.rebuild();
+    assert!(open.get(), "A vetoed Escape should keep the dialog open");
+}
+
+/// Test that Tab cycles through plain buttons and checkboxes in the footer
+/// without leaving the dialog
+#[test]
+fn test_dialog_tab_stays_inside_with_generic_controls() {
+    use floem::HasViewId;
+    use floem_shadcn::components::checkbox::Checkbox;
+
+    let outside = Button::new("Outside");
+    let outside_id = outside.view_id();
+    let checkbox = Checkbox::new(RwSignal::new(false)).label("Remember me");
+    let checkbox_id = checkbox.view_id();
+    let save = Button::new("Save");
+    let save_id = save.view_id();
+
+    let dialog = Dialog::new((
+        DialogTrigger::new(Button::new("Open")),
+        DialogContent::new((
+            DialogHeader::new().title("Test"),
+            DialogFooter::new(floem::views::Stack::horizontal((checkbox, save))),
+        )),
+    ));
+    let open = dialog.open_signal();
+
+    let view = floem::views::stack((dialog, outside)).style(|s| s.size(800.0, 600.0));
+    let mut harness = HeadlessHarness::new_with_size(view, 800.0, 600.0);
+    harness.rebuild();
+
+    open.set(true);
+    harness.rebuild();
+    assert!(
+        harness.is_focused(checkbox_id),
+        "The first control in the dialog should be focused when it opens"
+    );
+
+    harness.dispatch_event(key_down(Key::Named(NamedKey::Tab)));
+    harness.rebuild();
+    assert!(
+        harness.is_focused(save_id),
+        "Tab should reach the footer button"
+    );
+
+    harness.dispatch_event(key_down(Key::Named(NamedKey::Tab)));
+    harness.rebuild();
+    assert!(
+        harness.is_focused(checkbox_id),
+        "Tab should wrap around instead of leaving the dialog"
+    );
+    assert!(!harness.is_focused(outside_id));
+
+    harness.dispatch_event(Event::Key(KeyboardEvent {
+        state: KeyState::Down,
+        key: Key::Named(NamedKey::Tab),
+        modifiers: Modifiers::SHIFT,
+        code: Code::Unidentified,
+        location: Location::Standard,
+        is_composing: false,
+        repeat: false,
+    }));
+    harness.rebuild();
+    assert!(
+        harness.is_focused(save_id),
+        "Shift+Tab should wrap backwards"
+    );
+}
+
+/// Test that Escape closes a sheet opened from a SheetTrigger
+#[test]
+fn test_sheet_escape_closes_and_restores_focus() {
+    use floem::HasViewId;
+
+    let open = RwSignal::new(false);
+    let trigger = SheetTrigger::new(open, Button::new("Open"));
+    let trigger_id = trigger.view_id();
+
+    let view = floem::views::stack((
+        trigger,
+        Sheet::new(
+            open,
+            SheetContent::new(floem::views::Label::new("Sheet content")),
+        ),
+    ))
+    .style(|s| s.size(800.0, 600.0));
+    let mut harness = HeadlessHarness::new_with_size(view, 800.0, 600.0);
+    harness.rebuild();
+
+    harness.click(30.0, 15.0);
+    harness.rebuild();
+    assert!(open.get(), "Sheet should open from the trigger");
+
+    harness.dispatch_event(key_down(Key::Named(NamedKey::Escape)));
+    harness.rebuild();
+    assert!(!open.get(), "Escape should close the sheet");
+    assert!(harness.is_focused(trigger_id));
+}
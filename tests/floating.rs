@@ -0,0 +1,132 @@
+//! Tests for floating content placement
+
+use floem::kurbo::{Point, Rect, Size};
+use floem_shadcn::components::floating::{Align, FloatingOptions, Side, compute_placement};
+
+const WINDOW: Size = Size::new(800.0, 600.0);
+
+#[test]
+fn test_places_on_preferred_side() {
+    let anchor = Rect::new(100.0, 100.0, 200.0, 130.0);
+    let options = FloatingOptions::new(Side::Bottom, Align::Start).side_offset(4.0);
+    let placement = compute_placement(anchor, Size::new(150.0, 80.0), WINDOW, &options);
+    assert_eq!(placement.side, Side::Bottom);
+    assert_eq!(placement.origin, Point::new(100.0, 134.0));
+
+    let options = FloatingOptions::new(Side::Right, Align::Center).side_offset(8.0);
+    let placement = compute_placement(anchor, Size::new(60.0, 40.0), WINDOW, &options);
+    assert_eq!(placement.side, Side::Right);
+    assert_eq!(placement.origin, Point::new(208.0, 95.0));
+}
+
+#[test]
+fn test_alignment() {
+    let anchor = Rect::new(300.0, 100.0, 400.0, 120.0);
+    let content = Size::new(60.0, 40.0);
+    let origin_x = |align| {
+        let options = FloatingOptions::new(Side::Bottom, align);
+        compute_placement(anchor, content, WINDOW, &options)
+            .origin
+            .x
+    };
+    assert_eq!(origin_x(Align::Start), 300.0);
+    assert_eq!(origin_x(Align::Center), 320.0);
+    assert_eq!(origin_x(Align::End), 340.0);
+
+    let options = FloatingOptions::new(Side::Bottom, Align::Start).align_offset(10.0);
+    let placement = compute_placement(anchor, content, WINDOW, &options);
+    assert_eq!(placement.origin.x, 310.0);
+}
+
+#[test]
+fn test_flips_when_preferred_side_overflows() {
+    // Trigger near the bottom edge: no room below, plenty above
+    let anchor = Rect::new(100.0, 550.0, 200.0, 580.0);
+    let options = FloatingOptions::new(Side::Bottom, Align::Start).side_offset(4.0);
+    let placement = compute_placement(anchor, Size::new(150.0, 100.0), WINDOW, &options);
+    assert_eq!(placement.side, Side::Top);
+    assert_eq!(placement.origin, Point::new(100.0, 446.0));
+
+    // Trigger near the right edge
+    let anchor = Rect::new(760.0, 300.0, 790.0, 320.0);
+    let options = FloatingOptions::new(Side::Right, Align::Center).side_offset(4.0);
+    let placement = compute_placement(anchor, Size::new(100.0, 40.0), WINDOW, &options);
+    assert_eq!(placement.side, Side::Left);
+    assert_eq!(placement.origin.x, 656.0);
+}
+
+#[test]
+fn test_does_not_flip_into_less_space() {
+    // Neither side fits; the preferred side has more room so it is kept
+    let anchor = Rect::new(100.0, 250.0, 200.0, 270.0);
+    let options = FloatingOptions::new(Side::Bottom, Align::Start);
+    let placement = compute_placement(anchor, Size::new(100.0, 500.0), WINDOW, &options);
+    assert_eq!(placement.side, Side::Bottom);
+}
+
+#[test]
+fn test_shifts_along_the_anchor_edge() {
+    let content = Size::new(200.0, 50.0);
+    let options = FloatingOptions::new(Side::Bottom, Align::Center).collision_padding(8.0);
+
+    let anchor = Rect::new(0.0, 100.0, 40.0, 120.0);
+    let placement = compute_placement(anchor, content, WINDOW, &options);
+    assert_eq!(placement.origin.x, 8.0);
+
+    let anchor = Rect::new(770.0, 100.0, 800.0, 120.0);
+    let placement = compute_placement(anchor, content, WINDOW, &options);
+    assert_eq!(placement.origin.x, 592.0);
+
+    // Content wider than the window is pinned to the leading edge
+    let placement = compute_placement(anchor, Size::new(900.0, 50.0), WINDOW, &options);
+    assert_eq!(placement.origin.x, 8.0);
+}
+
+#[test]
+fn test_collisions_disabled() {
+    let anchor = Rect::new(770.0, 550.0, 800.0, 580.0);
+    let options = FloatingOptions::new(Side::Bottom, Align::Start)
+        .side_offset(4.0)
+        .avoid_collisions(false);
+    let placement = compute_placement(anchor, Size::new(200.0, 100.0), WINDOW, &options);
+    assert_eq!(placement.side, Side::Bottom);
+    assert_eq!(placement.origin, Point::new(770.0, 584.0));
+}
+
+#[test]
+fn test_unmeasured_window_skips_collisions() {
+    let anchor = Rect::new(770.0, 550.0, 800.0, 580.0);
+    let options = FloatingOptions::new(Side::Bottom, Align::Start).side_offset(4.0);
+    let placement = compute_placement(anchor, Size::new(200.0, 100.0), Size::ZERO, &options);
+    assert_eq!(placement.side, Side::Bottom);
+    assert_eq!(placement.origin, Point::new(770.0, 584.0));
+}
+
+#[test]
+fn test_arrow_points_at_anchor_center() {
+    let options = FloatingOptions::new(Side::Bottom, Align::Center).arrow_padding(8.0);
+    let content = Size::new(200.0, 50.0);
+
+    let anchor = Rect::new(300.0, 100.0, 340.0, 120.0);
+    let placement = compute_placement(anchor, content, WINDOW, &options);
+    assert_eq!(placement.arrow, 100.0);
+
+    // After shifting away from the left edge the arrow still points at the anchor
+    let anchor = Rect::new(0.0, 100.0, 40.0, 120.0);
+    let placement = compute_placement(anchor, content, WINDOW, &options);
+    assert_eq!(placement.origin.x, 8.0);
+    assert_eq!(placement.arrow, 12.0);
+
+    // The arrow stays clear of the corners
+    let anchor = Rect::new(0.0, 100.0, 4.0, 120.0);
+    let placement = compute_placement(anchor, content, WINDOW, &options);
+    assert_eq!(placement.arrow, 8.0);
+}
+
+#[test]
+fn test_opposite_side() {
+    assert_eq!(Side::Top.opposite(), Side::Bottom);
+    assert_eq!(Side::Left.opposite(), Side::Right);
+    assert!(Side::Bottom.is_vertical());
+    assert!(!Side::Right.is_vertical());
+}
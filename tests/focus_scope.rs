@@ -0,0 +1,108 @@
+//! Tests for the shared focus management used by Dialog, AlertDialog, Sheet
+//! and Drawer
+
+use std::rc::Rc;
+
+use floem::ViewId;
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
+use floem_shadcn::components::focus_scope::FocusScope;
+use ui_events::keyboard::{Key, Modifiers, NamedKey};
+
+fn press(scope: &FocusScope, key: Key, shift: bool) -> bool {
+    let modifiers = if shift {
+        Modifiers::SHIFT
+    } else {
+        Modifiers::default()
+    };
+    scope.handle_key(&key, &modifiers)
+}
+
+/// Build an open scope with three focusable elements
+fn sample_scope() -> FocusScope {
+    let scope = FocusScope::new(RwSignal::new(true));
+    for _ in 0..3 {
+        scope.register(ViewId::new());
+    }
+    scope
+}
+
+#[test]
+fn test_tab_cycles_forward_and_wraps() {
+    let scope = sample_scope();
+    scope.focus_first();
+    assert_eq!(scope.focused_index(), Some(0));
+
+    assert!(press(&scope, Key::Named(NamedKey::Tab), false));
+    assert_eq!(scope.focused_index(), Some(1));
+    press(&scope, Key::Named(NamedKey::Tab), false);
+    press(&scope, Key::Named(NamedKey::Tab), false);
+    assert_eq!(
+        scope.focused_index(),
+        Some(0),
+        "Tab on the last element should wrap to the first"
+    );
+}
+
+#[test]
+fn test_shift_tab_cycles_backward_and_wraps() {
+    let scope = sample_scope();
+    scope.focus_first();
+
+    assert!(press(&scope, Key::Named(NamedKey::Tab), true));
+    assert_eq!(
+        scope.focused_index(),
+        Some(2),
+        "Shift+Tab on the first element should wrap to the last"
+    );
+    press(&scope, Key::Named(NamedKey::Tab), true);
+    assert_eq!(scope.focused_index(), Some(1));
+}
+
+#[test]
+fn test_tab_without_focusable_elements_stays_inside() {
+    let scope = FocusScope::new(RwSignal::new(true));
+    assert!(scope.is_empty());
+    assert!(
+        press(&scope, Key::Named(NamedKey::Tab), false),
+        "Tab must be consumed so focus cannot leave the overlay"
+    );
+    assert_eq!(scope.focused_index(), None);
+}
+
+#[test]
+fn test_escape_closes() {
+    let scope = sample_scope();
+    assert!(press(&scope, Key::Named(NamedKey::Escape), false));
+    assert!(!scope.open.get());
+}
+
+#[test]
+fn test_escape_veto_keeps_open() {
+    let scope = sample_scope();
+    let allow = RwSignal::new(false);
+    let calls = RwSignal::new(0);
+    scope.set_on_escape(Some(Rc::new(move || {
+        calls.update(|c| *c += 1);
+        allow.get()
+    })));
+
+    assert!(press(&scope, Key::Named(NamedKey::Escape), false));
+    assert!(
+        scope.open.get(),
+        "A vetoed Escape should keep the overlay open"
+    );
+    assert_eq!(calls.get(), 1);
+
+    allow.set(true);
+    press(&scope, Key::Named(NamedKey::Escape), false);
+    assert!(!scope.open.get());
+}
+
+#[test]
+fn test_keys_ignored_while_closed() {
+    let scope = FocusScope::new(RwSignal::new(false));
+    scope.register(ViewId::new());
+    assert!(!press(&scope, Key::Named(NamedKey::Tab), false));
+    assert!(!press(&scope, Key::Named(NamedKey::Escape), false));
+    assert!(!press(&scope, Key::Character("a".into()), false));
+}
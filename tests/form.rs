@@ -0,0 +1,242 @@
+//! Tests for Form state and validation
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
+use floem_shadcn::components::calendar::SimpleDate;
+use floem_shadcn::components::form::{AsyncValidation, Form, Rules, ValidateOn};
+
+fn submit_counter(form: Form) -> Rc<Cell<usize>> {
+    let submitted = Rc::new(Cell::new(0));
+    let counter = submitted.clone();
+    form.on_submit(move || counter.set(counter.get() + 1));
+    submitted
+}
+
+#[test]
+fn test_submit_mode_validates_only_on_submit() {
+    let name = RwSignal::new(String::new());
+    let form = Form::new();
+    let field = form.register("name", name, Rules::new().required("Name is required"));
+    let submitted = submit_counter(form);
+
+    name.set("a".to_string());
+    name.set(String::new());
+    assert_eq!(field.error(), None);
+
+    form.submit();
+    assert_eq!(field.error(), Some("Name is required".to_string()));
+    assert_eq!(submitted.get(), 0);
+    assert!(field.is_touched());
+
+    // After a submit, fields re-validate on change
+    name.set("Ada".to_string());
+    assert_eq!(field.error(), None);
+
+    form.submit();
+    assert_eq!(submitted.get(), 1);
+    assert_eq!(form.submit_count(), 2);
+}
+
+#[test]
+fn test_change_mode() {
+    let name = RwSignal::new(String::new());
+    let form = Form::new().mode(ValidateOn::Change);
+    let field = form.register(
+        "name",
+        name,
+        Rules::new()
+            .required("Required")
+            .min_length(3, "Too short")
+            .max_length(5, "Too long"),
+    );
+
+    name.set("ab".to_string());
+    assert_eq!(field.error(), Some("Too short".to_string()));
+    name.set("abcdef".to_string());
+    assert_eq!(field.error(), Some("Too long".to_string()));
+    name.set("abcd".to_string());
+    assert_eq!(field.error(), None);
+    assert!(form.is_valid());
+}
+
+#[test]
+fn test_blur_and_touched_modes() {
+    let email = RwSignal::new(String::new());
+    let form = Form::new().mode(ValidateOn::Blur);
+    let field = form.register("email", email, Rules::new().required("Required"));
+
+    email.set("x".to_string());
+    email.set(String::new());
+    assert_eq!(field.error(), None);
+    field.blur();
+    assert!(field.is_touched());
+    assert_eq!(field.error(), Some("Required".to_string()));
+
+    let email = RwSignal::new(String::new());
+    let form = Form::new().mode(ValidateOn::Touched);
+    let field = form.register("email", email, Rules::new().required("Required"));
+
+    email.set(" ".to_string());
+    assert_eq!(field.error(), None);
+    field.blur();
+    assert_eq!(field.error(), Some("Required".to_string()));
+    // Once touched, changes re-validate
+    email.set("me@example.com".to_string());
+    assert_eq!(field.error(), None);
+}
+
+#[test]
+fn test_dirty_tracking_and_reset() {
+    let name = RwSignal::new("Ada".to_string());
+    let agree = RwSignal::new(false);
+    let form = Form::new();
+    let name_field = form.register("name", name, Rules::new());
+    let agree_field = form.register("agree", agree, Rules::new().required("Accept the terms"));
+
+    assert!(!form.is_dirty());
+    name.set("Grace".to_string());
+    assert!(name_field.is_dirty());
+    assert!(form.is_dirty());
+    name.set("Ada".to_string());
+    assert!(!name_field.is_dirty());
+
+    agree.set(true);
+    form.submit();
+    assert!(agree_field.is_dirty());
+    assert!(form.is_valid());
+
+    form.reset();
+    assert!(!agree.get());
+    assert!(!agree_field.is_dirty());
+    assert!(!agree_field.is_touched());
+    assert_eq!(agree_field.error(), None);
+    assert_eq!(form.submit_count(), 0);
+}
+
+#[test]
+fn test_field_kinds() {
+    let plan = RwSignal::new(None::<String>);
+    let date = RwSignal::new(None::<SimpleDate>);
+    let volume = RwSignal::new(50.0);
+    let form = Form::new();
+    form.register("plan", plan, Rules::new().required("Pick a plan"));
+    form.register("date", date, Rules::new().required("Pick a date"));
+    form.register(
+        "volume",
+        volume,
+        Rules::new().validate(|v: &f64| {
+            if *v <= 80.0 {
+                Ok(())
+            } else {
+                Err("Too loud".to_string())
+            }
+        }),
+    );
+
+    volume.set(90.0);
+    assert!(!form.validate());
+    assert_eq!(
+        form.errors(),
+        vec![
+            ("plan".to_string(), "Pick a plan".to_string()),
+            ("date".to_string(), "Pick a date".to_string()),
+            ("volume".to_string(), "Too loud".to_string()),
+        ]
+    );
+
+    plan.set(Some("pro".to_string()));
+    date.set(Some(SimpleDate::new(2024, 12, 1)));
+    volume.set(40.0);
+    assert!(form.validate());
+    assert!(form.errors().is_empty());
+    assert_eq!(form.error("plan"), None);
+}
+
+#[test]
+fn test_async_validation_blocks_submit_until_resolved() {
+    let username = RwSignal::new("taken".to_string());
+    let pending: Rc<RefCell<Vec<(String, AsyncValidation)>>> = Rc::new(RefCell::new(Vec::new()));
+    let queue = pending.clone();
+    let form = Form::new();
+    let field = form.register(
+        "username",
+        username,
+        Rules::new()
+            .required("Required")
+            .validate_async(move |value, done| queue.borrow_mut().push((value, done))),
+    );
+    let submitted = submit_counter(form);
+
+    form.submit();
+    assert!(field.is_validating());
+    assert!(form.is_submitting());
+    assert!(!form.is_valid());
+    assert_eq!(submitted.get(), 0);
+
+    let (value, done) = pending.borrow_mut().pop().unwrap();
+    assert_eq!(value, "taken");
+    done.resolve(Err("Username is taken".to_string()));
+    assert_eq!(field.error(), Some("Username is taken".to_string()));
+    assert!(!form.is_submitting());
+    assert_eq!(submitted.get(), 0);
+
+    username.set("free".to_string());
+    form.submit();
+    let (_, done) = pending.borrow_mut().pop().unwrap();
+    done.resolve(Ok(()));
+    assert!(field.is_valid());
+    assert_eq!(submitted.get(), 1);
+}
+
+#[test]
+fn test_stale_async_results_are_ignored() {
+    let username = RwSignal::new(String::new());
+    let pending: Rc<RefCell<Vec<AsyncValidation>>> = Rc::new(RefCell::new(Vec::new()));
+    let queue = pending.clone();
+    let form = Form::new().mode(ValidateOn::Change);
+    let field = form.register(
+        "username",
+        username,
+        Rules::new().validate_async(move |_, done| queue.borrow_mut().push(done)),
+    );
+
+    username.set("a".to_string());
+    username.set("ab".to_string());
+    let mut results = pending.borrow_mut().drain(..).collect::<Vec<_>>();
+    let latest = results.pop().unwrap();
+    let stale = results.pop().unwrap();
+
+    stale.resolve(Err("stale".to_string()));
+    assert_eq!(field.error(), None);
+    assert!(field.is_validating());
+
+    latest.resolve(Ok(()));
+    assert!(field.is_valid());
+}
+
+#[test]
+fn test_sync_errors_skip_async_validators() {
+    let name = RwSignal::new(String::new());
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    let form = Form::new();
+    form.register(
+        "name",
+        name,
+        Rules::new()
+            .required("Required")
+            .validate_async(move |_, done| {
+                counter.set(counter.get() + 1);
+                done.resolve(Ok(()));
+            }),
+    );
+
+    assert!(!form.validate());
+    assert_eq!(calls.get(), 0);
+
+    name.update(|n| n.push('x'));
+    assert!(form.validate());
+    assert_eq!(calls.get(), 1);
+}
@@ -0,0 +1,105 @@
+//! Tests for the password Input and its reveal toggle
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use floem::prelude::*;
+use floem_shadcn::components::input::Input;
+use floem_shadcn::text::{Command, Document, Keymap};
+use floem_test::prelude::*;
+use ui_events::keyboard::{Code, Key, KeyState, KeyboardEvent, Location, Modifiers, NamedKey};
+
+fn key_down(key: Key, modifiers: Modifiers) -> Event {
+    Event::Key(KeyboardEvent {
+        state: KeyState::Down,
+        key,
+        modifiers,
+        code: Code::Unidentified,
+        location: Location::Standard,
+        is_composing: false,
+        repeat: false,
+    })
+}
+
+/// A 200x40 revealable password input, whose document is handed out by
+/// pressing F2 while it is focused
+fn password_input(
+    text: &str,
+) -> (
+    HeadlessHarness,
+    Rc<RefCell<Option<Document>>>,
+    Rc<RefCell<String>>,
+) {
+    let mut keymap = Keymap::single_line();
+    keymap
+        .bind("f2", Command::from_name("grab-document"))
+        .unwrap();
+
+    let doc = Rc::new(RefCell::new(None));
+    let updated = Rc::new(RefCell::new(text.to_string()));
+    let input = {
+        let doc = doc.clone();
+        let updated = updated.clone();
+        Input::with_text(text)
+            .revealable()
+            .keymap(keymap)
+            .command("grab-document", move |d| {
+                *doc.borrow_mut() = Some(d.clone())
+            })
+            .on_update(move |text| *updated.borrow_mut() = text.to_string())
+    };
+    let container = Stack::new((input,)).style(|s| s.size(200.0, 40.0));
+
+    let mut harness = HeadlessHarness::new_with_size(container, 200.0, 40.0);
+    harness.click(20.0, 20.0);
+    harness.dispatch_event(key_down(Key::Named(NamedKey::F2), Modifiers::default()));
+    assert!(doc.borrow().is_some(), "The input has focus");
+    (harness, doc, updated)
+}
+
+#[test]
+fn test_password_input_masks_text() {
+    let (_harness, doc, _) = password_input("hunter2");
+    let doc = doc.borrow().clone().unwrap();
+    assert!(doc.is_password());
+    assert_eq!(doc.text(), "hunter2", "Only the drawing is masked");
+}
+
+#[test]
+fn test_password_input_toggle_reveals_and_masks() {
+    let (mut harness, doc, _) = password_input("hunter2");
+    let doc = doc.borrow().clone().unwrap();
+
+    // The eye button sits over the right end of the input
+    harness.click(180.0, 20.0);
+    assert!(!doc.is_password());
+    assert_eq!(doc.text(), "hunter2");
+
+    harness.click(180.0, 20.0);
+    assert!(doc.is_password());
+}
+
+#[test]
+fn test_password_input_blocks_copy_and_cut_while_masked() {
+    #[cfg(target_os = "macos")]
+    let cmd_or_ctrl = Modifiers::META;
+    #[cfg(not(target_os = "macos"))]
+    let cmd_or_ctrl = Modifiers::CONTROL;
+
+    let (mut harness, doc, updated) = password_input("hunter2");
+    let doc = doc.borrow().clone().unwrap();
+
+    doc.select_all();
+    assert!(!doc.copy());
+    harness.dispatch_event(key_down(Key::Character("x".into()), cmd_or_ctrl));
+    assert_eq!(doc.text(), "hunter2");
+    assert_eq!(*updated.borrow(), "hunter2", "Nothing was cut");
+
+    // Masking the text again after revealing it blocks cutting too
+    harness.click(180.0, 20.0);
+    assert!(!doc.is_password());
+    harness.click(180.0, 20.0);
+    doc.select_all();
+    assert!(!doc.cut());
+    assert_eq!(doc.text(), "hunter2");
+}
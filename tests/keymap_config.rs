@@ -0,0 +1,147 @@
+//! Tests for loading keymaps with the `serde` feature
+
+#![cfg(feature = "serde")]
+
+mod common;
+
+use floem_editor_core::command::EditCommand;
+use floem_shadcn::text::{Command, KeyChordError, KeyPress, Keymap, KeymapError};
+use ui_events::keyboard::{Key, Modifiers, NamedKey};
+
+use common::TempDir;
+
+#[test]
+fn test_keymap_from_toml() {
+    let mut keymap = Keymap::multi_line();
+    keymap
+        .extend_from_toml(
+            r#"
+[bindings]
+"ctrl+shift+k" = "delete-to-end-of-line"
+"ctrl+enter" = "custom:submit"
+"ctrl+a" = "none"
+"ctrl+x ctrl+s" = "custom:save"
+"#,
+        )
+        .unwrap();
+
+    assert_eq!(
+        keymap.get(
+            &Key::Character("K".into()),
+            &(Modifiers::CONTROL | Modifiers::SHIFT)
+        ),
+        Some(&Command::Edit(EditCommand::DeleteToEndOfLine))
+    );
+    assert_eq!(
+        keymap.get(&Key::Named(NamedKey::Enter), &Modifiers::CONTROL),
+        Some(&Command::Custom("submit".into()))
+    );
+    assert_eq!(
+        keymap.get(&Key::Character("a".into()), &Modifiers::CONTROL),
+        None
+    );
+    assert_eq!(
+        keymap
+            .sequences
+            .get(&KeyPress::parse_sequence("ctrl+x ctrl+s").unwrap()),
+        Some(&Command::Custom("save".into()))
+    );
+}
+
+#[test]
+fn test_keymap_from_json_file() {
+    let dir = TempDir::new();
+    let path = dir.file(
+        "keymap.json",
+        r#"{ "bindings": { "alt+t": "custom:insert-template" } }"#,
+    );
+    let mut keymap = Keymap::single_line();
+    keymap.extend_from_file(&path).unwrap();
+    assert_eq!(
+        keymap.get(&Key::Character("t".into()), &Modifiers::ALT),
+        Some(&Command::Custom("insert-template".into()))
+    );
+
+    let path = dir.file("keymap.yaml", "");
+    assert!(matches!(
+        keymap.extend_from_file(&path),
+        Err(KeymapError::UnsupportedFormat(_))
+    ));
+}
+
+#[test]
+fn test_invalid_chord_reports_line_and_key() {
+    let mut keymap = Keymap::multi_line();
+    let err = keymap
+        .extend_from_toml(
+            r#"[bindings]
+"alt+t" = "custom:insert-template"
+"ctrl+shfit+k" = "delete-to-end-of-line"
+"#,
+        )
+        .unwrap_err();
+
+    let KeymapError::InvalidChord { line, key, error } = &err else {
+        panic!("expected an invalid chord, got {err}");
+    };
+    assert_eq!(*line, Some(3));
+    assert_eq!(key, "ctrl+shfit+k");
+    assert_eq!(*error, KeyChordError::UnknownModifier("shfit".into()));
+    assert_eq!(
+        err.to_string(),
+        "line 3: invalid key `ctrl+shfit+k`: unknown modifier `shfit`"
+    );
+
+    // Nothing is applied when any binding is invalid
+    assert_eq!(
+        keymap.get(&Key::Character("t".into()), &Modifiers::ALT),
+        None
+    );
+}
+
+#[test]
+fn test_misspelled_command_reports_line_and_key() {
+    let mut keymap = Keymap::multi_line();
+    let err = keymap
+        .extend_from_toml(
+            r#"[bindings]
+"alt+t" = "custom:insert-template"
+"ctrl+d" = "delete-foward"
+"#,
+        )
+        .unwrap_err();
+
+    let KeymapError::UnknownCommand { line, key, command } = &err else {
+        panic!("expected an unknown command, got {err}");
+    };
+    assert_eq!(*line, Some(3));
+    assert_eq!(key, "ctrl+d");
+    assert_eq!(command, "delete-foward");
+    assert_eq!(
+        err.to_string(),
+        "line 3: unknown command `delete-foward` for `ctrl+d` \
+         (custom commands need the `custom:` prefix)"
+    );
+    assert_eq!(
+        keymap.get(&Key::Character("t".into()), &Modifiers::ALT),
+        None
+    );
+
+    assert!(matches!(
+        keymap.extend_from_json(r#"{"bindings": {"ctrl+k": "custom:"}}"#),
+        Err(KeymapError::UnknownCommand { .. })
+    ));
+}
+
+#[test]
+fn test_unknown_fields_are_rejected() {
+    let mut keymap = Keymap::multi_line();
+    assert!(matches!(
+        keymap.extend_from_toml("[binding]\n\"ctrl+k\" = \"cut\"\n"),
+        Err(KeymapError::Toml(_))
+    ));
+    assert!(matches!(
+        keymap.extend_from_json(r#"{"bindings": {"ctrl+k": 1}}"#),
+        Err(KeymapError::Json(_))
+    ));
+}
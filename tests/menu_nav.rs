@@ -0,0 +1,162 @@
+//! Tests for the shared menu keyboard navigation used by Select, DropdownMenu,
+//! ContextMenu and Menubar
+
+use std::rc::Rc;
+
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
+use floem_shadcn::components::menu_nav::{MenuNav, MenuTriggerKind};
+use ui_events::keyboard::{Key, Modifiers, NamedKey};
+
+fn press(nav: &MenuNav, key: Key) -> bool {
+    nav.handle_key(&key, &Modifiers::default())
+}
+
+/// Build a menu with "Copy", "Cut" (disabled), "Paste", "Print"
+fn sample_menu() -> (MenuNav, RwSignal<Vec<&'static str>>) {
+    let nav = MenuNav::new(RwSignal::new(false));
+    let activated = RwSignal::new(Vec::new());
+    for (label, disabled) in [
+        ("Copy", false),
+        ("Cut", true),
+        ("Paste", false),
+        ("Print", false),
+    ] {
+        nav.register(
+            label,
+            disabled,
+            Some(Rc::new(move || activated.update(|a| a.push(label)))),
+        );
+    }
+    (nav, activated)
+}
+
+#[test]
+fn test_open_keys_highlight_first_or_last() {
+    let (nav, _) = sample_menu();
+
+    assert!(press(&nav, Key::Named(NamedKey::ArrowDown)));
+    assert!(nav.open.get());
+    assert_eq!(nav.highlighted.get(), Some(0));
+
+    press(&nav, Key::Named(NamedKey::Escape));
+    assert!(!nav.open.get());
+    assert_eq!(nav.highlighted.get(), None);
+
+    press(&nav, Key::Named(NamedKey::ArrowUp));
+    assert!(nav.open.get());
+    assert_eq!(nav.highlighted.get(), Some(3));
+
+    press(&nav, Key::Named(NamedKey::Escape));
+    press(&nav, Key::Character(" ".into()));
+    assert!(nav.open.get(), "Space should open the menu");
+}
+
+#[test]
+fn test_arrows_skip_disabled_and_wrap() {
+    let (nav, _) = sample_menu();
+    press(&nav, Key::Named(NamedKey::Enter));
+    assert_eq!(nav.highlighted.get(), Some(0));
+
+    press(&nav, Key::Named(NamedKey::ArrowDown));
+    assert_eq!(nav.highlighted.get(), Some(2), "Disabled item is skipped");
+    press(&nav, Key::Named(NamedKey::ArrowDown));
+    press(&nav, Key::Named(NamedKey::ArrowDown));
+    assert_eq!(nav.highlighted.get(), Some(0), "Highlight wraps around");
+    press(&nav, Key::Named(NamedKey::ArrowUp));
+    assert_eq!(nav.highlighted.get(), Some(3));
+
+    press(&nav, Key::Named(NamedKey::Home));
+    assert_eq!(nav.highlighted.get(), Some(0));
+    press(&nav, Key::Named(NamedKey::End));
+    assert_eq!(nav.highlighted.get(), Some(3));
+}
+
+#[test]
+fn test_enter_activates_and_closes() {
+    let (nav, activated) = sample_menu();
+    press(&nav, Key::Named(NamedKey::Enter));
+    press(&nav, Key::Named(NamedKey::End));
+    press(&nav, Key::Named(NamedKey::Enter));
+
+    assert_eq!(activated.get(), vec!["Print"]);
+    assert!(!nav.open.get());
+}
+
+#[test]
+fn test_typeahead() {
+    let (nav, activated) = sample_menu();
+    press(&nav, Key::Named(NamedKey::Enter));
+
+    press(&nav, Key::Character("p".into()));
+    assert_eq!(nav.highlighted.get(), Some(2), "First match is Paste");
+    press(&nav, Key::Character("r".into()));
+    assert_eq!(
+        nav.highlighted.get(),
+        Some(3),
+        "Extended query matches Print"
+    );
+
+    press(&nav, Key::Character(" ".into()));
+    assert!(
+        activated.get().is_empty(),
+        "Space continues an active typeahead instead of activating"
+    );
+}
+
+#[test]
+fn test_typeahead_repeated_letter_cycles() {
+    let (nav, _) = sample_menu();
+    press(&nav, Key::Named(NamedKey::Enter));
+
+    press(&nav, Key::Character("p".into()));
+    assert_eq!(nav.highlighted.get(), Some(2));
+    press(&nav, Key::Character("p".into()));
+    assert_eq!(nav.highlighted.get(), Some(3));
+    press(&nav, Key::Character("p".into()));
+    assert_eq!(nav.highlighted.get(), Some(2));
+}
+
+#[test]
+fn test_typeahead_skips_disabled() {
+    let (nav, _) = sample_menu();
+    press(&nav, Key::Named(NamedKey::Enter));
+
+    press(&nav, Key::Character("c".into()));
+    press(&nav, Key::Character("u".into()));
+    assert_eq!(
+        nav.highlighted.get(),
+        Some(0),
+        "Disabled Cut is never highlighted"
+    );
+}
+
+#[test]
+fn test_disabled_item_cannot_be_activated() {
+    let (nav, activated) = sample_menu();
+    nav.open_menu(false);
+    nav.activate(1);
+
+    assert!(activated.get().is_empty());
+    assert!(nav.open.get());
+}
+
+#[test]
+fn test_open_highlights_selected_index() {
+    let (nav, _) = sample_menu();
+    nav.selected_index.set(Some(2));
+
+    press(&nav, Key::Named(NamedKey::ArrowDown));
+    assert_eq!(nav.highlighted.get(), Some(2));
+}
+
+#[test]
+fn test_context_area_ignores_button_keys() {
+    let nav = MenuNav::with_kind(RwSignal::new(false), MenuTriggerKind::ContextArea);
+    nav.register("Copy", false, None);
+
+    assert!(!press(&nav, Key::Named(NamedKey::Enter)));
+    assert!(!nav.open.get());
+
+    assert!(press(&nav, Key::Named(NamedKey::ContextMenu)));
+    assert!(nav.open.get());
+}
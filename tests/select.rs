@@ -4,6 +4,7 @@ use floem::prelude::*;
 use floem::reactive::RwSignal;
 use floem_shadcn::components::select::{Select, SelectItemData};
 use floem_test::prelude::*;
+use ui_events::keyboard::{Code, Key, KeyState, KeyboardEvent, Location, Modifiers, NamedKey};
 
 /// Test basic select click interaction
 #[test]
@@ -154,3 +155,52 @@ fn test_select_with_offset_trigger() {
     // The fix: using on_move for window coordinates ensures the dropdown
     // appears at x=50 (trigger's window x) instead of x=0 (local origin)
 }
+
+fn key_down(key: Key) -> Event {
+    Event::Key(KeyboardEvent {
+        state: KeyState::Down,
+        key,
+        modifiers: Modifiers::default(),
+        code: Code::Unidentified,
+        location: Location::Standard,
+        is_composing: false,
+        repeat: false,
+    })
+}
+
+/// Test choosing an option with the keyboard
+#[test]
+fn test_select_keyboard_navigation() {
+    let selected = RwSignal::new(None::<String>);
+
+    let select = Select::new(selected).placeholder("Select...").items(vec![
+        SelectItemData::new("apple", "Apple"),
+        SelectItemData::new("banana", "Banana").disabled(),
+        SelectItemData::new("cherry", "Cherry"),
+    ]);
+
+    let view = floem::views::Stack::vertical((select,)).style(|s| s.size(400.0, 300.0));
+
+    let mut harness = HeadlessHarness::new_with_size(view, 400.0, 300.0);
+    harness.rebuild();
+
+    // Clicking the trigger focuses it and opens the dropdown; Escape closes it again
+    harness.click(60.0, 18.0);
+    harness.dispatch_event(key_down(Key::Named(NamedKey::Escape)));
+    harness.rebuild();
+
+    // ArrowDown opens with "Apple" highlighted, the next ArrowDown skips the
+    // disabled "Banana", and Enter selects "Cherry"
+    harness.dispatch_event(key_down(Key::Named(NamedKey::ArrowDown)));
+    harness.dispatch_event(key_down(Key::Named(NamedKey::ArrowDown)));
+    harness.dispatch_event(key_down(Key::Named(NamedKey::Enter)));
+    harness.rebuild();
+    assert_eq!(selected.get(), Some("cherry".to_string()));
+
+    // Reopening highlights the selected item; typeahead jumps to "Apple"
+    harness.dispatch_event(key_down(Key::Named(NamedKey::Enter)));
+    harness.dispatch_event(key_down(Key::Character("a".into())));
+    harness.dispatch_event(key_down(Key::Named(NamedKey::Enter)));
+    harness.rebuild();
+    assert_eq!(selected.get(), Some("apple".to_string()));
+}
@@ -0,0 +1,47 @@
+//! Tests for TextInput
+
+use floem::prelude::*;
+use floem_shadcn::text::{Command, Keymap, TextInput};
+use floem_test::prelude::*;
+use ui_events::keyboard::{Code, Key, KeyState, KeyboardEvent, Location, Modifiers, NamedKey};
+
+fn key_down(key: Key) -> Event {
+    Event::Key(KeyboardEvent {
+        state: KeyState::Down,
+        key,
+        modifiers: Modifiers::default(),
+        code: Code::Unidentified,
+        location: Location::Standard,
+        is_composing: false,
+        repeat: false,
+    })
+}
+
+/// Extra cursors in a text input can be typed at and cleared with Escape
+#[test]
+fn test_text_input_multiple_cursors() {
+    assert_eq!(
+        Keymap::single_line().get(&Key::Named(NamedKey::Escape), &Modifiers::default()),
+        Some(&Command::CollapseCursors)
+    );
+
+    let input = TextInput::with_text("ab ab").style(|s| s.size(200.0, 40.0));
+    let doc = input.doc();
+
+    let mut harness = HeadlessHarness::new_with_size(input, 200.0, 40.0);
+    harness.click(100.0, 20.0);
+
+    let document = doc.get_untracked();
+    document.set_offset(2, false);
+    document.add_cursor(5);
+    harness.paint();
+
+    harness.dispatch_event(key_down(Key::Character("x".into())));
+    assert_eq!(document.text(), "abx abx");
+
+    harness.dispatch_event(key_down(Key::Named(NamedKey::Escape)));
+    assert!(!document.has_multiple_cursors());
+
+    harness.dispatch_event(key_down(Key::Character("y".into())));
+    assert_eq!(document.text(), "abx abxy");
+}
@@ -0,0 +1,219 @@
+//! Tests for loading themes with the `serde` feature
+
+#![cfg(feature = "serde")]
+
+mod common;
+
+use floem::reactive::SignalGet;
+use floem_shadcn::theme::{
+    ShadcnTheme, ThemeConfigError, ThemeFile, ThemeMode, ThemeOverride, ThemePair, ThemePreset,
+};
+use peniko::Color;
+
+use common::TempDir;
+
+#[test]
+fn test_theme_round_trips_through_json_and_toml() {
+    for preset in ThemePreset::ALL {
+        for mode in [ThemeMode::Light, ThemeMode::Dark] {
+            for theme in [
+                ShadcnTheme::preset(preset, mode),
+                ShadcnTheme::preset_high_contrast(preset, mode),
+            ] {
+                let json = serde_json::to_string(&theme).unwrap();
+                assert_eq!(serde_json::from_str::<ShadcnTheme>(&json).unwrap(), theme);
+
+                let toml = toml::to_string(&theme).unwrap();
+                assert_eq!(toml::from_str::<ShadcnTheme>(&toml).unwrap(), theme);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_theme_mode_and_preset_names() {
+    assert_eq!(serde_json::to_string(&ThemeMode::Dark).unwrap(), "\"dark\"");
+    assert_eq!(
+        serde_json::from_str::<ThemeMode>("\"light\"").unwrap(),
+        ThemeMode::Light
+    );
+    assert_eq!(
+        serde_json::to_string(&ThemePreset::Rose).unwrap(),
+        "\"rose\""
+    );
+}
+
+#[test]
+fn test_colors_are_css_strings() {
+    let mut theme = ShadcnTheme::light();
+    theme.primary = Color::from_rgba8(0x25, 0x63, 0xeb, 255);
+    theme.ring = Color::from_rgba8(0, 0, 0, 0x80);
+    let value: serde_json::Value = serde_json::to_value(theme).unwrap();
+    assert_eq!(value["mode"], "light");
+    assert_eq!(value["primary"], "#2563eb");
+    assert_eq!(value["ring"], "#00000080");
+    assert!(
+        value["muted_foreground"]
+            .as_str()
+            .unwrap()
+            .starts_with("color(srgb ")
+    );
+}
+
+#[test]
+fn test_full_theme_requires_every_token() {
+    let mut value = serde_json::to_value(ShadcnTheme::light()).unwrap();
+    value.as_object_mut().unwrap().remove("ring");
+    let err = serde_json::from_value::<ShadcnTheme>(value).unwrap_err();
+    assert!(err.to_string().contains("ring"), "{err}");
+}
+
+#[test]
+fn test_partial_override_toml() {
+    let overrides = ThemeOverride::from_toml(
+        r##"
+preset = "zinc"
+primary = "oklch(0.546 0.245 262.881)"
+radius = 8
+
+[dark]
+primary = "#3b82f6"
+"##,
+    )
+    .unwrap();
+    assert_eq!(overrides.preset, Some(ThemePreset::Zinc));
+
+    let pair = overrides.theme(ThemePreset::Neutral);
+    let zinc = ThemePair::preset(ThemePreset::Zinc);
+    assert_eq!(pair.light.radius, 8.0);
+    assert_eq!(pair.dark.radius, 8.0);
+    assert_ne!(pair.light.primary, zinc.light.primary);
+    assert_eq!(pair.dark.primary, Color::from_rgba8(0x3b, 0x82, 0xf6, 255));
+    // Everything else comes from the preset
+    assert_eq!(pair.light.background, zinc.light.background);
+    assert_eq!(pair.dark.border, zinc.dark.border);
+    assert_eq!(pair.light.radius_lg, zinc.light.radius_lg);
+}
+
+#[test]
+fn test_partial_override_json() {
+    let overrides = ThemeOverride::from_json(
+        r#"{ "light": { "background": "hsl(0 0% 98%)" }, "radius_lg": 12 }"#,
+    )
+    .unwrap();
+    assert_eq!(overrides.preset, None);
+
+    let pair = overrides.theme(ThemePreset::Slate);
+    let slate = ThemePair::preset(ThemePreset::Slate);
+    assert_ne!(pair.light.background, slate.light.background);
+    assert_eq!(pair.dark.background, slate.dark.background);
+    assert_eq!(pair.light.radius_lg, 12.0);
+    assert_eq!(pair.dark.radius_lg, 12.0);
+}
+
+#[test]
+fn test_size_scale_overrides() {
+    let overrides = ThemeOverride::from_toml(
+        r##"
+spacing = 3.5
+font_size = { sm = 13, base = 15 }
+
+[dark.shadow]
+sm = { blur = 1, color = "#000000" }
+md = { offset_y = 2, blur = 6, color = "#000000" }
+lg = { offset_y = 8, blur = 24, color = "#000000" }
+"##,
+    )
+    .unwrap();
+
+    let pair = overrides.theme(ThemePreset::Neutral);
+    let neutral = ThemePair::default();
+    assert_eq!(pair.light.spacing, 3.5);
+    assert_eq!(pair.dark.space(2.0), 7.0);
+    assert_eq!(pair.light.font_size.sm, 13.0);
+    assert_eq!(pair.light.font_size.base, 15.0);
+    // Keys left out of a scale use the default scale
+    assert_eq!(pair.light.font_size.xs, 12.0);
+    assert_eq!(pair.light.shadow, neutral.light.shadow);
+    assert_eq!(pair.dark.shadow.md.offset_y, 2.0);
+    assert_eq!(pair.dark.shadow.lg.color, Color::BLACK);
+    assert_eq!(pair.dark.control_height, neutral.dark.control_height);
+
+    let err = ThemeOverride::from_toml("font_size = { huge = 40 }").unwrap_err();
+    assert!(err.to_string().contains("huge"), "{err}");
+}
+
+#[test]
+fn test_override_errors() {
+    let err = ThemeOverride::from_toml("primay = \"#fff\"").unwrap_err();
+    assert!(err.to_string().contains("primay"), "{err}");
+
+    let err = ThemeOverride::from_toml("[light]\nprimay = \"#fff\"").unwrap_err();
+    assert!(err.to_string().contains("primay"), "{err}");
+
+    let err = ThemeOverride::from_json(r##"{ "primary": "#ggg" }"##).unwrap_err();
+    assert!(err.to_string().contains("invalid color `#ggg`"), "{err}");
+
+    let err = ThemeOverride::from_toml("preset = \"purple\"").unwrap_err();
+    assert!(matches!(err, ThemeConfigError::Toml(_)));
+}
+
+#[test]
+fn test_override_round_trip() {
+    let overrides = ThemeOverride::from_toml("radius = 4\n[dark]\nring = \"#ff0000\"\n").unwrap();
+    let text = toml::to_string(&overrides).unwrap();
+    assert_eq!(ThemeOverride::from_toml(&text).unwrap(), overrides);
+}
+
+#[test]
+fn test_theme_file_reload() {
+    let dir = TempDir::new();
+    let path = dir.file("reload.toml", "primary = \"#ff0000\"\n");
+    let file = ThemeFile::load(&path, ThemePreset::Neutral).unwrap();
+    assert_eq!(
+        file.get(ThemeMode::Light).primary,
+        Color::from_rgba8(255, 0, 0, 255)
+    );
+
+    std::fs::write(&path, "primary = \"#00ff00\"\n").unwrap();
+    file.reload().unwrap();
+    assert_eq!(
+        file.get(ThemeMode::Dark).primary,
+        Color::from_rgba8(0, 255, 0, 255)
+    );
+
+    // A broken file keeps the last good theme
+    std::fs::write(&path, "primary = \n").unwrap();
+    assert!(file.reload().is_err());
+    assert_eq!(
+        file.get(ThemeMode::Dark).primary,
+        Color::from_rgba8(0, 255, 0, 255)
+    );
+}
+
+#[test]
+fn test_theme_file_formats() {
+    let dir = TempDir::new();
+    let path = dir.file("theme.json", r#"{ "preset": "blue" }"#);
+    let file = ThemeFile::load(&path, ThemePreset::Neutral).unwrap();
+    assert_eq!(
+        file.themes().get_untracked(),
+        ThemePair::preset(ThemePreset::Blue)
+    );
+
+    let path = dir.file("theme.yaml", "");
+    assert!(matches!(
+        ThemeFile::load(&path, ThemePreset::Neutral),
+        Err(ThemeConfigError::UnsupportedFormat(_))
+    ));
+
+    let file = ThemeFile::new(
+        std::env::temp_dir().join("missing.toml"),
+        ThemePreset::Stone,
+    );
+    assert_eq!(
+        file.get(ThemeMode::Light),
+        ShadcnTheme::preset(ThemePreset::Stone, ThemeMode::Light)
+    );
+    assert!(matches!(file.reload(), Err(ThemeConfigError::Io(..))));
+}
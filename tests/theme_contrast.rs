@@ -0,0 +1,149 @@
+//! Tests for the theme contrast audit
+
+use floem_shadcn::theme::contrast::{
+    APCA_BODY, ContrastOptions, ContrastState, apca_contrast, contrast_ratio,
+};
+use floem_shadcn::theme::{ShadcnTheme, ThemeMode, ThemePair, ThemePreset};
+use peniko::Color;
+
+#[test]
+fn test_apca_reference_values() {
+    let black = Color::from_rgba8(0, 0, 0, 255);
+    let white = Color::from_rgba8(255, 255, 255, 255);
+    assert!((apca_contrast(black, white) - 106.04).abs() < 0.5);
+    assert!((apca_contrast(white, black) + 107.88).abs() < 0.5);
+    assert_eq!(apca_contrast(white, white), 0.0);
+}
+
+#[test]
+fn test_report_covers_states() {
+    let report = ShadcnTheme::light().audit_contrast();
+    for state in [
+        ContrastState::Default,
+        ContrastState::Hover,
+        ContrastState::Active,
+        ContrastState::StrongActive,
+    ] {
+        assert!(report.get("primary", "primary_foreground", state).is_some());
+    }
+    assert!(
+        report
+            .get(
+                "destructive",
+                "destructive_foreground",
+                ContrastState::Hover
+            )
+            .is_some()
+    );
+    assert!(
+        report
+            .get("muted", "muted_foreground", ContrastState::Hover)
+            .is_none()
+    );
+
+    let report = ShadcnTheme::light().audit_contrast_with(ContrastOptions::default().states(false));
+    assert!(
+        report
+            .checks
+            .iter()
+            .all(|check| check.state == ContrastState::Default)
+    );
+}
+
+#[test]
+fn test_shadcn_muted_text_fails_aa_on_muted() {
+    // Upstream neutral-500 on neutral-100 is about 4.35:1
+    let report = ShadcnTheme::light().audit_contrast();
+    let check = report
+        .get("muted", "muted_foreground", ContrastState::Default)
+        .unwrap();
+    assert!(!check.passes);
+    assert!(check.ratio > 4.0 && check.ratio < 4.5, "{}", check.ratio);
+    assert!(report.failures().any(|f| f.background == "muted"));
+    assert!(
+        report
+            .to_string()
+            .contains("muted_foreground on muted: 4.3")
+    );
+
+    // ... but passes on the page background
+    let check = report
+        .get("background", "muted_foreground", ContrastState::Default)
+        .unwrap();
+    assert!(check.passes);
+}
+
+#[test]
+fn test_custom_thresholds() {
+    let theme = ShadcnTheme::light();
+    let relaxed = theme.audit_contrast_with(ContrastOptions::default().min_ratio(4.0));
+    assert!(
+        relaxed
+            .get("muted", "muted_foreground", ContrastState::Default)
+            .unwrap()
+            .passes
+    );
+
+    // APCA is stricter for mid grays on white
+    let apca = theme.audit_contrast_with(ContrastOptions::default().min_ratio(4.0).apca(APCA_BODY));
+    let check = apca
+        .get("background", "muted_foreground", ContrastState::Default)
+        .unwrap();
+    assert!(!check.passes);
+    assert!(
+        check.apca > 60.0 && check.apca < APCA_BODY,
+        "{}",
+        check.apca
+    );
+}
+
+#[test]
+fn test_broken_customization_is_reported() {
+    let mut theme = ShadcnTheme::dark();
+    theme.primary_foreground = theme.primary;
+    let report = theme.audit_contrast();
+    assert!(!report.is_passing());
+    let check = report
+        .get("primary", "primary_foreground", ContrastState::Default)
+        .unwrap();
+    assert!(!check.passes);
+    assert!((check.ratio - 1.0).abs() < 0.01);
+    assert!(
+        report
+            .to_string()
+            .contains("primary_foreground on primary:")
+    );
+}
+
+#[test]
+fn test_hover_colors_are_audited() {
+    // #767676 just passes with white text, but the lighter dark-mode active
+    // color does not
+    let mut theme = ShadcnTheme::preset(ThemePreset::Neutral, ThemeMode::Dark);
+    theme.primary = Color::from_rgba8(0x76, 0x76, 0x76, 255);
+    theme.primary_foreground = Color::from_rgba8(255, 255, 255, 255);
+    assert!(contrast_ratio(theme.primary, theme.primary_foreground) >= 4.5);
+
+    let report = theme.audit_contrast();
+    assert!(
+        report
+            .get("primary", "primary_foreground", ContrastState::Default)
+            .unwrap()
+            .passes
+    );
+    assert!(
+        !report
+            .get("primary", "primary_foreground", ContrastState::Active)
+            .unwrap()
+            .passes
+    );
+}
+
+#[test]
+fn test_generated_themes_pass() {
+    let pair = ThemePair::from_brand(Color::from_rgba8(0x7c, 0x3a, 0xed, 255), 285.0);
+    for theme in [pair.light, pair.dark] {
+        let report = theme.audit_contrast_with(ContrastOptions::default().states(false));
+        assert!(report.is_passing(), "{report}");
+    }
+}
@@ -0,0 +1,336 @@
+//! Tests for importing themes from shadcn/ui CSS variables
+
+use floem_shadcn::theme::{ThemeCssError, ThemeMode, ThemePair, ThemePreset};
+use peniko::Color;
+
+/// The neutral `globals.css` written by `shadcn init` (Tailwind v4)
+const NEUTRAL_CSS: &str = r#"@import "tailwindcss";
+@import "tw-animate-css";
+
+@custom-variant dark (&:is(.dark *));
+
+@theme inline {
+  --color-background: var(--background);
+  --radius-lg: var(--radius);
+}
+
+:root {
+  --radius: 0.625rem;
+  --background: oklch(1 0 0);
+  --foreground: oklch(0.145 0 0);
+  --card: oklch(1 0 0);
+  --card-foreground: oklch(0.145 0 0);
+  --popover: oklch(1 0 0);
+  --popover-foreground: oklch(0.145 0 0);
+  --primary: oklch(0.205 0 0);
+  --primary-foreground: oklch(0.985 0 0);
+  --secondary: oklch(0.97 0 0);
+  --secondary-foreground: oklch(0.205 0 0);
+  --muted: oklch(0.97 0 0);
+  --muted-foreground: oklch(0.556 0 0);
+  --accent: oklch(0.97 0 0);
+  --accent-foreground: oklch(0.205 0 0);
+  --destructive: oklch(0.577 0.245 27.325);
+  --border: oklch(0.922 0 0);
+  --input: oklch(0.922 0 0);
+  --ring: oklch(0.708 0 0);
+  --chart-1: oklch(0.646 0.222 41.116);
+}
+
+.dark {
+  --background: oklch(0.145 0 0);
+  --foreground: oklch(0.985 0 0);
+  --card: oklch(0.205 0 0);
+  --card-foreground: oklch(0.985 0 0);
+  --popover: oklch(0.205 0 0);
+  --popover-foreground: oklch(0.985 0 0);
+  --primary: oklch(0.922 0 0);
+  --primary-foreground: oklch(0.205 0 0);
+  --secondary: oklch(0.269 0 0);
+  --secondary-foreground: oklch(0.985 0 0);
+  --muted: oklch(0.269 0 0);
+  --muted-foreground: oklch(0.708 0 0);
+  --accent: oklch(0.269 0 0);
+  --accent-foreground: oklch(0.985 0 0);
+  --destructive: oklch(0.704 0.191 22.216);
+  --border: oklch(1 0 0 / 10%);
+  --input: oklch(1 0 0 / 15%);
+  --ring: oklch(0.556 0 0);
+}
+
+@layer base {
+  * {
+    @apply border-border outline-ring/50;
+  }
+}
+"#;
+
+/// Every required token with the same value, in the given syntax
+fn uniform_block(selector: &str, value: &str) -> String {
+    let names = [
+        "background",
+        "foreground",
+        "card",
+        "card-foreground",
+        "popover",
+        "popover-foreground",
+        "primary",
+        "primary-foreground",
+        "secondary",
+        "secondary-foreground",
+        "muted",
+        "muted-foreground",
+        "accent",
+        "accent-foreground",
+        "destructive",
+        "border",
+        "input",
+        "ring",
+    ];
+    let body: String = names
+        .iter()
+        .map(|name| format!("  --{name}: {value};\n"))
+        .collect();
+    format!("{selector} {{\n{body}}}\n")
+}
+
+fn assert_close(actual: Color, expected: Color) {
+    for (a, e) in actual.components.iter().zip(expected.components) {
+        assert!((a - e).abs() < 5e-3, "{actual:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn test_shadcn_init_output_matches_neutral_preset() {
+    let pair = ThemePair::from_css(NEUTRAL_CSS).unwrap();
+    let preset = ThemePair::preset(ThemePreset::Neutral);
+
+    assert_eq!(pair.light.mode, ThemeMode::Light);
+    assert_eq!(pair.dark.mode, ThemeMode::Dark);
+    for (theme, expected) in [(pair.light, preset.light), (pair.dark, preset.dark)] {
+        assert_eq!(theme.background, expected.background);
+        assert_eq!(theme.primary, expected.primary);
+        assert_eq!(theme.muted_foreground, expected.muted_foreground);
+        assert_eq!(theme.destructive, expected.destructive);
+        assert_eq!(
+            theme.destructive_foreground,
+            expected.destructive_foreground
+        );
+        assert_eq!(theme.border, expected.border);
+        assert_eq!(theme.ring, expected.ring);
+    }
+    assert_eq!(pair.light.input, preset.light.input);
+    // Newer shadcn releases raised the dark input alpha; the preset keeps
+    // the 10% that `ShadcnTheme::dark()` has always used
+    assert!((pair.dark.input.components[3] - 0.15).abs() < 1e-3);
+    assert!((preset.dark.input.components[3] - 0.1).abs() < 1e-3);
+}
+
+#[test]
+fn test_radius_scale() {
+    let pair = ThemePair::from_css(NEUTRAL_CSS).unwrap();
+    // 0.625rem = 10px; dark inherits the radius from :root
+    for theme in [pair.light, pair.dark] {
+        assert_eq!(theme.radius_lg, 10.0);
+        assert_eq!(theme.radius_md, 8.0);
+        assert_eq!(theme.radius_sm, 6.0);
+        assert_eq!(theme.radius, 8.0);
+    }
+
+    let css = NEUTRAL_CSS.replace("0.625rem", "4px");
+    let pair = ThemePair::from_css(&css).unwrap();
+    assert_eq!(pair.light.radius_lg, 4.0);
+    assert_eq!(pair.light.radius_sm, 0.0);
+
+    // Without --radius the default scale is kept
+    let css = NEUTRAL_CSS.replace("--radius: 0.625rem;", "");
+    let pair = ThemePair::from_css(&css).unwrap();
+    assert_eq!(pair.light.radius, ThemePair::default().light.radius);
+}
+
+#[test]
+fn test_color_formats() {
+    let red = Color::from_rgba8(255, 0, 0, 255);
+    let values = [
+        "#f00",
+        "#ff0000",
+        "#FF0000ff",
+        "rgb(255 0 0)",
+        "rgb(255, 0, 0)",
+        "rgba(255, 0, 0, 1)",
+        "rgb(100% 0% 0%)",
+        "hsl(0 100% 50%)",
+        "hsl(0deg, 100%, 50%)",
+        "hsla(360, 100%, 50%, 1)",
+        "0 100% 50%",
+        "oklch(0.628 0.2577 29.23)",
+        "oklch(62.8% 0.2577 29.23deg)",
+    ];
+    for value in values {
+        let css = uniform_block(":root", value) + &uniform_block(".dark", value);
+        let pair = ThemePair::from_css(&css).unwrap_or_else(|e| panic!("{value}: {e}"));
+        assert_close(pair.light.primary, red);
+        assert_close(pair.dark.ring, red);
+    }
+}
+
+#[test]
+fn test_alpha_values() {
+    let css = uniform_block(":root", "rgb(0 0 0 / 50%)")
+        + &uniform_block(".dark", "hsl(0 0% 100% / 0.25)");
+    let pair = ThemePair::from_css(&css).unwrap();
+    assert_close(pair.light.border, Color::new([0.0, 0.0, 0.0, 0.5]));
+    assert_close(pair.dark.border, Color::new([1.0, 1.0, 1.0, 0.25]));
+
+    let css = uniform_block(":root", "#00000080") + &uniform_block(".dark", "transparent");
+    let pair = ThemePair::from_css(&css).unwrap();
+    assert_eq!(pair.light.input, Color::from_rgba8(0, 0, 0, 128));
+    assert_eq!(pair.dark.input.components[3], 0.0);
+}
+
+#[test]
+fn test_dark_inherits_from_root() {
+    let css = NEUTRAL_CSS.replace("--ring: oklch(0.556 0 0);", "");
+    let pair = ThemePair::from_css(&css).unwrap();
+    assert_eq!(pair.dark.ring, pair.light.ring);
+}
+
+#[test]
+fn test_var_references() {
+    let css = NEUTRAL_CSS.replace(
+        "--card: oklch(1 0 0);",
+        "--card: var(--background);\n  --brand: #ff0000;",
+    );
+    let css = css.replace("--ring: oklch(0.556 0 0);", "--ring: var(--brand);");
+    let pair = ThemePair::from_css(&css).unwrap();
+    assert_eq!(pair.light.card, pair.light.background);
+    assert_eq!(pair.dark.ring, Color::from_rgba8(255, 0, 0, 255));
+
+    let css = NEUTRAL_CSS.replace("--card: oklch(1 0 0);", "--card: var(--nope, #fff);");
+    let pair = ThemePair::from_css(&css).unwrap();
+    assert_eq!(pair.light.card, Color::from_rgba8(255, 255, 255, 255));
+}
+
+#[test]
+fn test_comments_and_layers() {
+    let css = format!(
+        "/* theme */\n@layer base {{\n{}\n/* dark { */\n{}}}\n",
+        uniform_block(":root", "#fff"),
+        uniform_block(".dark", "#000")
+    );
+    let pair = ThemePair::from_css(&css).unwrap();
+    assert_eq!(pair.light.background, Color::from_rgba8(255, 255, 255, 255));
+    assert_eq!(pair.dark.background, Color::from_rgba8(0, 0, 0, 255));
+}
+
+#[test]
+fn test_missing_block() {
+    let err = ThemePair::from_css(&uniform_block(":root", "#fff")).unwrap_err();
+    assert_eq!(err, ThemeCssError::MissingBlock { selector: ".dark" });
+
+    let err = ThemePair::from_css(&uniform_block(".dark", "#fff")).unwrap_err();
+    assert_eq!(err, ThemeCssError::MissingBlock { selector: ":root" });
+}
+
+#[test]
+fn test_missing_variable() {
+    let css = NEUTRAL_CSS.replace("--muted-foreground: oklch(0.556 0 0);", "");
+    let err = ThemePair::from_css(&css).unwrap_err();
+    assert_eq!(
+        err,
+        ThemeCssError::MissingVariable {
+            selector: ":root",
+            name: "muted-foreground".to_string(),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "`:root` does not declare `--muted-foreground`"
+    );
+}
+
+#[test]
+fn test_malformed_value_reports_line() {
+    let css = NEUTRAL_CSS.replace("--accent: oklch(0.269 0 0);", "--accent: oklch(0.269 0);");
+    let err = ThemePair::from_css(&css).unwrap_err();
+    let line = css
+        .lines()
+        .position(|l| l.contains("oklch(0.269 0)"))
+        .unwrap()
+        + 1;
+    match err {
+        ThemeCssError::InvalidValue {
+            selector,
+            name,
+            value,
+            line: reported,
+            ..
+        } => {
+            assert_eq!(selector, ".dark");
+            assert_eq!(name, "accent");
+            assert_eq!(value, "oklch(0.269 0)");
+            assert_eq!(reported, line);
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+
+    for bad in ["#ff000", "#gg0000", "rgb(1 2)", "lab(50% 0 0)", "blue"] {
+        let css = uniform_block(":root", bad) + &uniform_block(".dark", "#000");
+        let err = ThemePair::from_css(&css).unwrap_err();
+        assert!(
+            matches!(err, ThemeCssError::InvalidValue { .. }),
+            "{bad}: {err:?}"
+        );
+    }
+
+    let css = NEUTRAL_CSS.replace("0.625rem", "calc(1rem - 2px)");
+    assert!(matches!(
+        ThemePair::from_css(&css),
+        Err(ThemeCssError::InvalidValue { name, .. }) if name == "radius"
+    ));
+}
+
+#[test]
+fn test_unbalanced_braces() {
+    let err = ThemePair::from_css(":root {\n  --radius: 1rem;\n").unwrap_err();
+    assert_eq!(
+        err,
+        ThemeCssError::Syntax {
+            line: 1,
+            message: "`:root` block is never closed".to_string(),
+        }
+    );
+
+    let err = ThemePair::from_css("}\n").unwrap_err();
+    assert!(matches!(err, ThemeCssError::Syntax { line: 1, .. }));
+}
+
+#[test]
+fn test_chart_and_sidebar_variables() {
+    let pair = ThemePair::from_css(NEUTRAL_CSS).unwrap();
+    let preset = ThemePair::preset(ThemePreset::Neutral);
+    // --chart-1 is declared, the rest use the defaults
+    assert_eq!(pair.light.chart_1, preset.light.chart_1);
+    assert_eq!(pair.light.chart_2, preset.light.chart_2);
+    assert_eq!(pair.dark.chart_5, preset.dark.chart_5);
+    // No --sidebar-* variables: the sidebar looks like the page
+    assert_eq!(pair.light.sidebar, pair.light.background);
+    assert_eq!(pair.dark.sidebar_accent, pair.dark.accent);
+    assert_eq!(pair.dark.sidebar_border, pair.dark.border);
+
+    let css = NEUTRAL_CSS.replace(
+        "--chart-1: oklch(0.646 0.222 41.116);",
+        "--chart-1: #ff0000;\n  --sidebar: #fafafa;\n  --sidebar-primary: #0000ff;",
+    );
+    let pair = ThemePair::from_css(&css).unwrap();
+    assert_eq!(pair.light.chart_1, Color::from_rgba8(255, 0, 0, 255));
+    assert_eq!(pair.light.sidebar, Color::from_rgba8(0xfa, 0xfa, 0xfa, 255));
+    assert_eq!(pair.dark.sidebar_primary, Color::from_rgba8(0, 0, 255, 255));
+    assert_eq!(pair.light.sidebar_foreground, pair.light.foreground);
+
+    let css = NEUTRAL_CSS.replace("--chart-1: oklch(0.646 0.222 41.116);", "--chart-1: nope;");
+    assert!(matches!(
+        ThemePair::from_css(&css),
+        Err(ThemeCssError::InvalidValue { name, .. }) if name == "chart-1"
+    ));
+}
@@ -0,0 +1,111 @@
+//! Tests for generating themes from a brand color
+
+use floem_shadcn::theme::contrast::{WCAG_AA, WCAG_AA_LARGE, contrast_ratio};
+use floem_shadcn::theme::{ShadcnTheme, ThemeGenerator, ThemeMode, ThemePair};
+use peniko::Color;
+use peniko::color::Oklch;
+
+fn pairs(t: &ShadcnTheme) -> [(&'static str, Color, Color); 8] {
+    [
+        ("background", t.background, t.foreground),
+        ("card", t.card, t.card_foreground),
+        ("popover", t.popover, t.popover_foreground),
+        ("primary", t.primary, t.primary_foreground),
+        ("secondary", t.secondary, t.secondary_foreground),
+        ("muted", t.muted, t.muted_foreground),
+        ("accent", t.accent, t.accent_foreground),
+        ("destructive", t.destructive, t.destructive_foreground),
+    ]
+}
+
+fn brands() -> Vec<Color> {
+    vec![
+        Color::from_rgba8(0x25, 0x63, 0xeb, 255), // blue
+        Color::from_rgba8(0xfa, 0xcc, 0x15, 255), // yellow
+        Color::from_rgba8(0x16, 0xa3, 0x4a, 255), // green
+        Color::from_rgba8(0xe1, 0x1d, 0x48, 255), // rose
+        Color::from_rgba8(0xf5, 0xf5, 0xf5, 255), // nearly white
+        Color::from_rgba8(0x0a, 0x0a, 0x0a, 255), // nearly black
+    ]
+}
+
+#[test]
+fn test_contrast_ratio() {
+    let black = Color::from_rgba8(0, 0, 0, 255);
+    let white = Color::from_rgba8(255, 255, 255, 255);
+    assert!((contrast_ratio(black, white) - 21.0).abs() < 0.01);
+    assert!((contrast_ratio(white, black) - 21.0).abs() < 0.01);
+    assert_eq!(contrast_ratio(white, white), 1.0);
+    // #767676 is the lightest gray that passes AA on white
+    let gray = Color::from_rgba8(0x76, 0x76, 0x76, 255);
+    assert!(contrast_ratio(gray, white) >= WCAG_AA);
+}
+
+#[test]
+fn test_every_pair_meets_the_minimum_contrast() {
+    for brand in brands() {
+        for hue in [0.0, 264.0] {
+            let pair = ThemePair::from_brand(brand, hue);
+            for theme in [pair.light, pair.dark] {
+                for (name, bg, fg) in pairs(&theme) {
+                    let ratio = contrast_ratio(bg, fg);
+                    assert!(
+                        ratio >= WCAG_AA,
+                        "{name} in {:?} for {brand:?}: {ratio}",
+                        theme.mode
+                    );
+                }
+                assert!(contrast_ratio(theme.muted_foreground, theme.background) >= WCAG_AA);
+                assert!(contrast_ratio(theme.primary, theme.background) >= WCAG_AA_LARGE);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_custom_minimum_contrast() {
+    let generator =
+        ThemeGenerator::new(Color::from_rgba8(0x25, 0x63, 0xeb, 255), 264.0).min_contrast(7.0);
+    let pair = generator.generate();
+    for theme in [pair.light, pair.dark] {
+        for (name, bg, fg) in pairs(&theme) {
+            assert!(contrast_ratio(bg, fg) >= 7.0, "{name} in {:?}", theme.mode);
+        }
+    }
+}
+
+#[test]
+fn test_brand_is_kept_when_it_already_has_contrast() {
+    let blue = Color::from_rgba8(0x25, 0x63, 0xeb, 255);
+    let pair = ThemePair::from_brand(blue, 264.0);
+    assert_eq!(pair.light.primary, blue);
+    assert_eq!(pair.light.ring, pair.light.primary);
+    // White text reads better on blue-600 than black text
+    assert!(pair.light.primary_foreground.components[0] > 0.9);
+}
+
+#[test]
+fn test_modes_and_neutral_hue() {
+    let brand = Color::from_rgba8(0x16, 0xa3, 0x4a, 255);
+    let generator = ThemeGenerator::new(brand, 264.0);
+    let pair = generator.generate();
+    assert_eq!(pair.light.mode, ThemeMode::Light);
+    assert_eq!(pair.dark.mode, ThemeMode::Dark);
+    assert_eq!(generator.theme(ThemeMode::System), pair.light);
+    assert!(pair.dark.background.components[0] < pair.light.background.components[0]);
+
+    // Grays lean towards the neutral hue
+    let [_, c, h, _] = pair.light.muted_foreground.convert::<Oklch>().components;
+    assert!(c > 0.005);
+    assert!((h - 264.0).abs() < 2.0, "{h}");
+
+    // Without chroma they are pure grays
+    let gray = generator.neutral_chroma(0.0).theme(ThemeMode::Light);
+    let [_, c, _, _] = gray.muted_foreground.convert::<Oklch>().components;
+    assert!(c < 0.001);
+
+    // Accent is a tint of the brand hue
+    let [_, _, accent_h, _] = pair.light.accent.convert::<Oklch>().components;
+    let [_, _, brand_h, _] = brand.convert::<Oklch>().components;
+    assert!((accent_h - brand_h).abs() < 10.0, "{accent_h} vs {brand_h}");
+}
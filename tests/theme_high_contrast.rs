@@ -0,0 +1,97 @@
+//! Tests for high-contrast and forced-colors themes
+
+use floem_shadcn::theme::contrast::{
+    ContrastOptions, WCAG_AA, WCAG_AA_LARGE, WCAG_AAA, contrast_ratio,
+};
+use floem_shadcn::theme::{
+    DEFAULT_RING_WIDTH, HIGH_CONTRAST_RING_WIDTH, ShadcnTheme, ThemeContrast, ThemeMode, ThemePair,
+    ThemePreset,
+};
+
+const MODES: [ThemeMode; 2] = [ThemeMode::Light, ThemeMode::Dark];
+
+#[test]
+fn test_presets_are_standard_contrast() {
+    let theme = ShadcnTheme::light();
+    assert_eq!(theme.contrast, ThemeContrast::Standard);
+    assert!(!theme.is_high_contrast());
+    assert_eq!(theme.ring_width, DEFAULT_RING_WIDTH);
+}
+
+#[test]
+fn test_high_contrast_borders_are_solid() {
+    for preset in ThemePreset::ALL {
+        for mode in MODES {
+            let theme = ShadcnTheme::preset_high_contrast(preset, mode);
+            let name = preset.name();
+            assert_eq!(theme.contrast, ThemeContrast::High);
+            assert!(theme.is_high_contrast());
+
+            for (token, color) in [
+                ("border", theme.border),
+                ("input", theme.input),
+                ("sidebar_border", theme.sidebar_border),
+                ("ring", theme.ring),
+            ] {
+                assert_eq!(color.components[3], 1.0, "{name} {mode:?} {token}");
+            }
+            assert!(contrast_ratio(theme.border, theme.background) >= WCAG_AA_LARGE);
+            assert!(contrast_ratio(theme.input, theme.card) >= WCAG_AA_LARGE);
+            assert!(contrast_ratio(theme.sidebar_border, theme.sidebar) >= WCAG_AA_LARGE);
+            assert!(
+                contrast_ratio(theme.ring, theme.background) >= WCAG_AA,
+                "{name} {mode:?} ring"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_high_contrast_text_and_rings() {
+    for mode in MODES {
+        let standard = ShadcnTheme::preset(ThemePreset::Zinc, mode);
+        let high = standard.with_high_contrast();
+        assert!(contrast_ratio(high.foreground, high.background) >= WCAG_AAA);
+        assert!(contrast_ratio(high.muted_foreground, high.background) >= WCAG_AAA);
+        assert!(
+            contrast_ratio(high.muted_foreground, high.background)
+                > contrast_ratio(standard.muted_foreground, standard.background)
+        );
+        assert_eq!(high.ring_width, HIGH_CONTRAST_RING_WIDTH);
+
+        let report = high.audit_contrast_with(ContrastOptions::default().states(false));
+        assert!(report.is_passing(), "{mode:?}: {report}");
+    }
+}
+
+#[test]
+fn test_high_contrast_pair() {
+    let pair = ThemePair::preset(ThemePreset::Blue).with_high_contrast();
+    assert_eq!(pair.light.contrast, ThemeContrast::High);
+    assert_eq!(pair.dark.contrast, ThemeContrast::High);
+    // Sizes are untouched
+    assert_eq!(pair.light.font_size, ShadcnTheme::light().font_size);
+}
+
+#[test]
+fn test_forced_colors() {
+    for mode in MODES {
+        let theme = ShadcnTheme::forced_colors(mode);
+        assert_eq!(theme.contrast, ThemeContrast::Forced);
+        assert!(theme.is_high_contrast());
+        assert_eq!(theme.mode, mode);
+        assert_eq!(theme.border, theme.foreground);
+        assert_eq!(theme.ring_width, HIGH_CONTRAST_RING_WIDTH);
+        assert!(contrast_ratio(theme.foreground, theme.background) >= 15.0);
+
+        let report = theme.audit_contrast_with(ContrastOptions::default().states(false));
+        assert!(report.is_passing(), "{mode:?}: {report}");
+
+        // Already as strong as it gets
+        assert_eq!(theme.with_high_contrast(), theme);
+    }
+    assert_eq!(
+        ThemePair::forced_colors().get(ThemeMode::System),
+        ShadcnTheme::forced_colors(ThemeMode::Light)
+    );
+}
@@ -0,0 +1,117 @@
+//! Tests for the shadcn/ui theme presets
+
+use floem_shadcn::theme::{ShadcnTheme, ThemeMode, ThemePreset};
+use peniko::Color;
+use peniko::color::{AlphaColor, Oklch, Srgb};
+
+fn oklch(l: f32, c: f32, h: f32) -> Color {
+    AlphaColor::<Oklch>::new([l, c, h, 1.0]).convert::<Srgb>()
+}
+
+#[test]
+fn test_light_and_dark_are_neutral() {
+    assert_eq!(
+        ShadcnTheme::light(),
+        ShadcnTheme::preset(ThemePreset::Neutral, ThemeMode::Light)
+    );
+    assert_eq!(
+        ShadcnTheme::dark(),
+        ShadcnTheme::preset(ThemePreset::Neutral, ThemeMode::Dark)
+    );
+    assert_eq!(ShadcnTheme::light().primary, oklch(0.205, 0.0, 0.0));
+    assert_eq!(ShadcnTheme::dark().background, oklch(0.145, 0.0, 0.0));
+}
+
+#[test]
+fn test_every_preset_has_both_modes() {
+    for preset in ThemePreset::ALL {
+        let light = ShadcnTheme::preset(preset, ThemeMode::Light);
+        let dark = ShadcnTheme::preset(preset, ThemeMode::Dark);
+        assert_eq!(light.mode, ThemeMode::Light);
+        assert_eq!(dark.mode, ThemeMode::Dark);
+        assert_ne!(light.background, dark.background, "{}", preset.name());
+        assert_eq!(light.radius, dark.radius);
+    }
+}
+
+#[test]
+fn test_base_color_values() {
+    let slate = ShadcnTheme::preset(ThemePreset::Slate, ThemeMode::Light);
+    assert_eq!(slate.foreground, oklch(0.129, 0.042, 264.695));
+    assert_eq!(slate.primary, oklch(0.208, 0.042, 265.755));
+    assert_eq!(slate.muted_foreground, oklch(0.554, 0.046, 257.417));
+    assert_eq!(slate.border, oklch(0.929, 0.013, 255.508));
+
+    let stone = ShadcnTheme::preset(ThemePreset::Stone, ThemeMode::Dark);
+    assert_eq!(stone.background, oklch(0.147, 0.004, 49.25));
+    assert_eq!(stone.secondary, oklch(0.268, 0.007, 34.298));
+    assert_eq!(stone.ring, oklch(0.553, 0.013, 58.071));
+
+    let gray = ShadcnTheme::preset(ThemePreset::Gray, ThemeMode::Dark);
+    assert_eq!(gray.primary, oklch(0.928, 0.006, 264.531));
+    assert_eq!(gray.primary_foreground, oklch(0.21, 0.034, 264.665));
+
+    let zinc = ShadcnTheme::preset(ThemePreset::Zinc, ThemeMode::Light);
+    assert_eq!(zinc.accent, oklch(0.967, 0.001, 286.375));
+}
+
+#[test]
+fn test_accents_only_change_primary_and_ring() {
+    for preset in ThemePreset::ALL.into_iter().filter(|p| p.is_accent()) {
+        for mode in [ThemeMode::Light, ThemeMode::Dark] {
+            let accent = ShadcnTheme::preset(preset, mode);
+            let base = ShadcnTheme::preset(preset.base(), mode);
+            assert_ne!(accent.primary, base.primary, "{}", preset.name());
+            assert_eq!(accent.sidebar_primary, accent.primary);
+            assert_eq!(accent.sidebar_ring, accent.ring);
+            let restored = ShadcnTheme {
+                primary: base.primary,
+                primary_foreground: base.primary_foreground,
+                ring: base.ring,
+                sidebar_primary: base.sidebar_primary,
+                sidebar_primary_foreground: base.sidebar_primary_foreground,
+                sidebar_ring: base.sidebar_ring,
+                ..accent
+            };
+            assert_eq!(restored, base, "{}", preset.name());
+        }
+    }
+
+    let blue = ShadcnTheme::preset(ThemePreset::Blue, ThemeMode::Light);
+    assert_eq!(blue.primary, oklch(0.546, 0.245, 262.881));
+    let violet = ShadcnTheme::preset(ThemePreset::Violet, ThemeMode::Dark);
+    assert_eq!(violet.primary, oklch(0.491, 0.27, 292.581));
+}
+
+#[test]
+fn test_preset_names() {
+    assert_eq!(ThemePreset::from_name("Rose"), Some(ThemePreset::Rose));
+    assert_eq!(ThemePreset::from_name(" slate "), Some(ThemePreset::Slate));
+    assert_eq!(ThemePreset::from_name("zinc-ish"), None);
+    for preset in ThemePreset::ALL {
+        assert_eq!(ThemePreset::from_name(preset.name()), Some(preset));
+    }
+    assert_eq!(ThemePreset::Yellow.base(), ThemePreset::Stone);
+    assert!(!ThemePreset::Slate.is_accent());
+}
+
+#[test]
+fn test_chart_and_sidebar_tokens() {
+    let light = ShadcnTheme::light();
+    assert_eq!(light.chart_1, oklch(0.646, 0.222, 41.116));
+    assert_eq!(light.chart_5, oklch(0.769, 0.188, 70.08));
+    assert_eq!(light.sidebar, oklch(0.985, 0.0, 0.0));
+    assert_eq!(light.sidebar_accent, light.accent);
+    assert_eq!(light.sidebar_border, light.border);
+    assert_eq!(light.charts()[2], light.chart_3);
+
+    let dark = ShadcnTheme::dark();
+    assert_eq!(dark.chart_1, oklch(0.488, 0.243, 264.376));
+    assert_eq!(dark.sidebar, dark.card);
+    assert_eq!(dark.sidebar_primary, oklch(0.488, 0.243, 264.376));
+    assert_eq!(dark.sidebar_border, dark.border);
+
+    // Charts are shared by every base color
+    let slate = ShadcnTheme::preset(ThemePreset::Slate, ThemeMode::Dark);
+    assert_eq!(slate.charts(), dark.charts());
+}
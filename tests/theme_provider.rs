@@ -0,0 +1,85 @@
+//! Tests for resolving the theme mode in a ThemeProvider
+
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
+use floem_shadcn::theme::{ShadcnTheme, ThemeMode, ThemePair, ThemePreset, ThemeState};
+
+#[test]
+fn test_resolve_mode() {
+    assert_eq!(ThemeMode::Light.resolve(ThemeMode::Dark), ThemeMode::Light);
+    assert_eq!(ThemeMode::Dark.resolve(ThemeMode::Light), ThemeMode::Dark);
+    assert_eq!(ThemeMode::System.resolve(ThemeMode::Dark), ThemeMode::Dark);
+    assert_eq!(
+        ThemeMode::System.resolve(ThemeMode::Light),
+        ThemeMode::Light
+    );
+    assert_eq!(
+        ThemeMode::System.resolve(ThemeMode::System),
+        ThemeMode::Light
+    );
+    assert!(ThemeMode::Dark.is_dark());
+    assert!(!ThemeMode::System.is_dark());
+}
+
+#[test]
+fn test_system_mode_has_a_light_fallback() {
+    assert_eq!(
+        ShadcnTheme::preset(ThemePreset::Zinc, ThemeMode::System),
+        ShadcnTheme::preset(ThemePreset::Zinc, ThemeMode::Light)
+    );
+    assert_eq!(
+        ThemePair::default().get(ThemeMode::System),
+        ShadcnTheme::light()
+    );
+}
+
+#[test]
+fn test_state_starts_from_the_os_color_scheme() {
+    let state = ThemeState::with_system(RwSignal::new(ThemeMode::System), ThemeMode::Dark);
+    assert_eq!(state.system().get(), ThemeMode::Dark);
+    assert_eq!(state.resolved().get(), ThemeMode::Dark);
+    assert_eq!(state.theme(), ShadcnTheme::dark());
+
+    let state = ThemeState::with_system(RwSignal::new(ThemeMode::Light), ThemeMode::Dark);
+    assert_eq!(state.resolved().get(), ThemeMode::Light);
+}
+
+#[test]
+fn test_state_follows_the_os_color_scheme() {
+    let mode = RwSignal::new(ThemeMode::System);
+    let state = ThemeState::with_system(mode, ThemeMode::Light);
+    let resolved = state.resolved();
+    assert_eq!(resolved.get(), ThemeMode::Light);
+    assert_eq!(state.theme(), ShadcnTheme::light());
+
+    state.system().set(ThemeMode::Dark);
+    assert_eq!(resolved.get(), ThemeMode::Dark);
+    assert_eq!(state.theme(), ShadcnTheme::dark());
+
+    state.system().set(ThemeMode::Light);
+    assert_eq!(resolved.get(), ThemeMode::Light);
+}
+
+#[test]
+fn test_explicit_mode_ignores_the_os() {
+    let mode = RwSignal::new(ThemeMode::Dark);
+    let state = ThemeState::new(mode);
+    state.system().set(ThemeMode::Light);
+    assert_eq!(state.resolved().get(), ThemeMode::Dark);
+
+    mode.set(ThemeMode::Light);
+    state.system().set(ThemeMode::Dark);
+    assert_eq!(state.resolved().get(), ThemeMode::Light);
+
+    mode.set(ThemeMode::System);
+    assert_eq!(state.resolved().get(), ThemeMode::Dark);
+}
+
+#[test]
+fn test_state_uses_its_themes() {
+    let state = ThemeState::new(RwSignal::new(ThemeMode::Dark));
+    state.themes().set(ThemePair::preset(ThemePreset::Violet));
+    assert_eq!(
+        state.theme(),
+        ShadcnTheme::preset(ThemePreset::Violet, ThemeMode::Dark)
+    );
+}
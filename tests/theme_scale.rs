@@ -0,0 +1,119 @@
+//! Tests for the typography, spacing, control height and shadow scales
+
+use floem::style::StylePropValue;
+use floem_shadcn::theme::{
+    ControlHeightScale, DEFAULT_SPACING, FontSizeScale, ShadcnTheme, ShadowScale, ThemeMode,
+    ThemePair, ThemePreset,
+};
+use peniko::Color;
+
+#[test]
+fn test_default_scales_match_tailwind() {
+    let theme = ShadcnTheme::light();
+    assert_eq!(theme.font_size.xs, 12.0);
+    assert_eq!(theme.font_size.sm, 14.0);
+    assert_eq!(theme.font_size.base, 16.0);
+    assert_eq!(theme.font_size.xxl, 24.0);
+    assert_eq!(theme.line_height.normal, 1.5);
+    assert_eq!(theme.spacing, DEFAULT_SPACING);
+    assert_eq!(theme.control_height, ControlHeightScale::default());
+    assert_eq!(theme.control_height.md, 40.0);
+    assert_eq!(theme.shadow.md.blur, 8.0);
+}
+
+#[test]
+fn test_space_uses_the_spacing_unit() {
+    let mut theme = ShadcnTheme::light();
+    assert_eq!(theme.space(2.0), 8.0);
+    assert_eq!(theme.space(1.5), 6.0);
+
+    theme.spacing = 3.0;
+    assert_eq!(theme.space(4.0), 12.0);
+}
+
+#[test]
+fn test_every_preset_shares_the_size_scales() {
+    let light = ShadcnTheme::light();
+    for preset in ThemePreset::ALL {
+        for mode in [ThemeMode::Light, ThemeMode::Dark] {
+            let theme = ShadcnTheme::preset(preset, mode);
+            assert_eq!(theme.font_size, light.font_size, "{}", preset.name());
+            assert_eq!(theme.line_height, light.line_height);
+            assert_eq!(theme.spacing, light.spacing);
+            assert_eq!(theme.control_height, light.control_height);
+        }
+    }
+}
+
+#[test]
+fn test_shadows_are_cast_in_a_translucent_foreground() {
+    let dark = ShadcnTheme::dark();
+    assert_eq!(
+        dark.shadow,
+        ShadowScale::with_color(dark.foreground.with_alpha(0.1))
+    );
+    assert!(dark.shadow.sm.blur < dark.shadow.md.blur);
+    assert!(dark.shadow.md.blur < dark.shadow.lg.blur);
+}
+
+#[test]
+fn test_scales_interpolate_with_the_theme() {
+    let from = ShadcnTheme::light();
+    let mut to = from;
+    to.spacing = 8.0;
+    to.font_size = FontSizeScale {
+        sm: 18.0,
+        ..from.font_size
+    };
+    to.control_height.md = 48.0;
+    to.shadow = ShadowScale::with_color(Color::BLACK);
+
+    let mid = from.interpolate(&to, 0.5).unwrap();
+    assert_eq!(mid.spacing, 6.0);
+    assert_eq!(mid.font_size.sm, 16.0);
+    assert_eq!(mid.font_size.xs, 12.0);
+    assert_eq!(mid.control_height.md, 44.0);
+}
+
+#[test]
+fn test_css_spacing() {
+    let css = r#"
+:root {
+  --spacing: 0.3rem;
+  --background: oklch(1 0 0);
+  --foreground: oklch(0.145 0 0);
+  --card: oklch(1 0 0);
+  --card-foreground: oklch(0.145 0 0);
+  --popover: oklch(1 0 0);
+  --popover-foreground: oklch(0.145 0 0);
+  --primary: oklch(0.205 0 0);
+  --primary-foreground: oklch(0.985 0 0);
+  --secondary: oklch(0.97 0 0);
+  --secondary-foreground: oklch(0.205 0 0);
+  --muted: oklch(0.97 0 0);
+  --muted-foreground: oklch(0.556 0 0);
+  --accent: oklch(0.97 0 0);
+  --accent-foreground: oklch(0.205 0 0);
+  --destructive: oklch(0.577 0.245 27.325);
+  --border: oklch(0.922 0 0);
+  --input: oklch(0.922 0 0);
+  --ring: oklch(0.708 0 0);
+}
+"#;
+    let pair = ThemePair::from_css(css).unwrap();
+    assert!((pair.light.spacing - 4.8).abs() < 1e-4);
+    assert!((pair.dark.spacing - 4.8).abs() < 1e-4);
+    assert_eq!(pair.light.font_size, FontSizeScale::default());
+
+    let without = css.replace("  --spacing: 0.3rem;\n", "");
+    let pair = ThemePair::from_css(&without).unwrap();
+    assert_eq!(pair.light.spacing, DEFAULT_SPACING);
+}
+
+#[test]
+fn test_generated_themes_keep_the_default_scales() {
+    let pair = ThemePair::from_brand(Color::from_rgb8(0x25, 0x63, 0xeb), 260.0);
+    assert_eq!(pair.light.font_size, FontSizeScale::default());
+    assert_eq!(pair.dark.control_height, ControlHeightScale::default());
+    assert_eq!(pair.dark.spacing, DEFAULT_SPACING);
+}
@@ -0,0 +1,211 @@
+//! Tests for VirtualScrollArea row layout, scrolling and row reuse
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use floem::kurbo::Rect;
+use floem::prelude::*;
+use floem::reactive::{RwSignal, SignalUpdate};
+use floem_shadcn::components::scroll_area::{VirtualLayout, VirtualScrollArea};
+use floem_test::prelude::*;
+
+#[test]
+fn test_fixed_layout_positions() {
+    let layout = VirtualLayout::fixed(50_000, 20.0);
+    assert_eq!(layout.len(), 50_000);
+    assert_eq!(layout.total_height(), 1_000_000.0);
+    assert_eq!(layout.row_top(10), 200.0);
+    assert_eq!(layout.row_height(10), 20.0);
+    assert_eq!(layout.row_height(50_000), 0.0);
+    assert_eq!(layout.row_at(205.0), 10);
+    assert_eq!(layout.row_at(-5.0), 0);
+    assert_eq!(layout.row_at(2_000_000.0), 49_999);
+}
+
+#[test]
+fn test_fixed_visible_range_with_overscan() {
+    let layout = VirtualLayout::fixed(1000, 20.0);
+    // Rows 10..=20 intersect 200..400 (row 20 starts exactly at the bottom edge)
+    assert_eq!(layout.visible_range(200.0, 200.0, 0), 10..21);
+    assert_eq!(layout.visible_range(200.0, 200.0, 3), 7..24);
+    assert_eq!(layout.visible_range(0.0, 100.0, 8), 0..14);
+    assert_eq!(layout.visible_range(19_950.0, 100.0, 8), 989..1000);
+}
+
+#[test]
+fn test_variable_layout_positions() {
+    let layout = VirtualLayout::variable([10.0, 30.0, 20.0, 40.0]);
+    assert_eq!(layout.len(), 4);
+    assert_eq!(layout.total_height(), 100.0);
+    assert_eq!(layout.row_top(2), 40.0);
+    assert_eq!(layout.row_height(3), 40.0);
+    assert_eq!(layout.row_top(4), 100.0);
+
+    assert_eq!(layout.row_at(0.0), 0);
+    assert_eq!(layout.row_at(9.9), 0);
+    assert_eq!(layout.row_at(10.0), 1);
+    assert_eq!(layout.row_at(59.0), 2);
+    assert_eq!(layout.row_at(99.0), 3);
+    assert_eq!(layout.row_at(500.0), 3);
+
+    assert_eq!(layout.visible_range(35.0, 10.0, 0), 1..3);
+    assert_eq!(layout.row_rect(1, 200.0), Rect::new(0.0, 10.0, 200.0, 40.0));
+}
+
+#[test]
+fn test_variable_layout_clamps_negative_heights() {
+    let layout = VirtualLayout::variable([10.0, -5.0, 10.0]);
+    assert_eq!(layout.row_height(1), 0.0);
+    assert_eq!(layout.total_height(), 20.0);
+}
+
+#[test]
+fn test_empty_layout() {
+    let layout = VirtualLayout::variable(std::iter::empty());
+    assert!(layout.is_empty());
+    assert_eq!(layout.total_height(), 0.0);
+    assert_eq!(layout.visible_range(0.0, 400.0, 8), 0..0);
+    assert!(
+        VirtualLayout::fixed(0, 20.0)
+            .visible_range(0.0, 400.0, 8)
+            .is_empty()
+    );
+}
+
+/// A mounted 200x100 list of 1000 rows, 20px each
+struct MountedList {
+    harness: HeadlessHarness,
+    /// Indices of the rows built so far, in order
+    built: Rc<RefCell<Vec<usize>>>,
+    /// Index of the last row clicked
+    clicked: Rc<Cell<Option<usize>>>,
+    scroll_to: RwSignal<Option<usize>>,
+}
+
+impl MountedList {
+    fn new(configure: impl FnOnce(VirtualScrollArea<usize>) -> VirtualScrollArea<usize>) -> Self {
+        let items = RwSignal::new((0..1000).collect::<Vec<usize>>());
+        let built = Rc::new(RefCell::new(Vec::new()));
+        let clicked = Rc::new(Cell::new(None));
+        let area = {
+            let built = built.clone();
+            let clicked = clicked.clone();
+            VirtualScrollArea::new(items, move |index, _: &usize| {
+                built.borrow_mut().push(index);
+                let clicked = clicked.clone();
+                floem::views::Label::new(format!("Row {index}"))
+                    .style(|s| s.size_full())
+                    .on_click_stop(move |_| clicked.set(Some(index)))
+            })
+            .item_height(20.0)
+            .overscan(2)
+            .width(200.0)
+            .height(100.0)
+        };
+        let area = configure(area);
+        let scroll_to = area.scroll_to_index_signal();
+        let container = Stack::new((area,)).style(|s| s.size(200.0, 100.0));
+
+        let mut harness = HeadlessHarness::new_with_size(container, 200.0, 100.0);
+        settle(&mut harness);
+        Self {
+            harness,
+            built,
+            clicked,
+            scroll_to,
+        }
+    }
+
+    /// Clicks the row at `y` in the viewport and returns its index
+    fn row_at(&mut self, y: f64) -> Option<usize> {
+        self.clicked.set(None);
+        self.harness.click(10.0, y);
+        self.clicked.get()
+    }
+
+    /// Returns the rows built since the last call, sorted
+    fn take_built(&self) -> Vec<usize> {
+        let mut built = std::mem::take(&mut *self.built.borrow_mut());
+        built.sort_unstable();
+        built
+    }
+}
+
+/// Scrolling moves the viewport during layout, and the rows it brings into
+/// view are laid out on the next pass
+fn settle(harness: &mut HeadlessHarness) {
+    harness.rebuild();
+    harness.rebuild();
+}
+
+#[test]
+fn test_mount_builds_only_the_visible_rows() {
+    let mut list = MountedList::new(|area| area);
+    // Rows 0..=5 touch the viewport, plus 2 overscan rows below
+    assert_eq!(list.take_built(), (0..8).collect::<Vec<_>>());
+    assert_eq!(list.row_at(10.0), Some(0));
+    assert_eq!(list.row_at(90.0), Some(4));
+}
+
+#[test]
+fn test_scrolling_keeps_rows_that_stay_visible() {
+    let mut list = MountedList::new(|area| area);
+    list.take_built();
+
+    list.scroll_to.set(Some(3));
+    settle(&mut list.harness);
+    assert_eq!(
+        list.take_built(),
+        vec![8, 9, 10],
+        "Only the rows scrolled into view are built"
+    );
+    assert_eq!(list.row_at(10.0), Some(3));
+    assert_eq!(list.row_at(90.0), Some(7));
+
+    list.scroll_to.set(Some(0));
+    settle(&mut list.harness);
+    assert_eq!(list.take_built(), vec![0], "Row 0 left the overscan window");
+    assert_eq!(list.row_at(10.0), Some(0));
+}
+
+#[test]
+fn test_scroll_to_index_before_mounting() {
+    let mut list = MountedList::new(|area| {
+        area.scroll_to_index(500);
+        area
+    });
+    assert_eq!(list.row_at(10.0), Some(500));
+    assert!(
+        list.take_built()
+            .iter()
+            .all(|index| (498..508).contains(index)),
+        "Rows near the top are never built"
+    );
+}
+
+#[test]
+fn test_scroll_to_index_signal_jumps_far() {
+    let mut list = MountedList::new(|area| area);
+    list.take_built();
+
+    list.scroll_to.set(Some(998));
+    settle(&mut list.harness);
+    assert_eq!(list.take_built(), (993..1000).collect::<Vec<_>>());
+    // The last rows can't reach the top, so the view stops at the end
+    assert_eq!(list.row_at(90.0), Some(999));
+}
+
+#[test]
+fn test_selected_row_is_scrolled_into_view() {
+    let selected = RwSignal::new(None);
+    let mut list = MountedList::new(|area| area.selected(selected));
+    list.take_built();
+
+    selected.set(Some(50));
+    settle(&mut list.harness);
+    assert!(list.take_built().contains(&50));
+    let visible: Vec<usize> = (0..5)
+        .filter_map(|row| list.row_at(10.0 + row as f64 * 20.0))
+        .collect();
+    assert!(visible.contains(&50), "Row 50 is inside the viewport");
+}